@@ -3,6 +3,7 @@
 //! This module provides the three core functions that external platforms
 //! (WASM, Solana) use to interact with the game engine.
 
+use crate::core::Frame;
 use crate::entity::{
     ActionDefinition, Character, ConditionDefinition, SpawnDefinition, StatusEffectDefinition,
 };
@@ -75,11 +76,21 @@ impl From<&str> for GameError {
 impl From<crate::script::ScriptError> for GameError {
     fn from(err: crate::script::ScriptError) -> Self {
         match err {
-            crate::script::ScriptError::InvalidScript => GameError::InvalidScript,
+            crate::script::ScriptError::InvalidScript
+            | crate::script::ScriptError::InvalidBytecode { .. } => GameError::InvalidScript,
             crate::script::ScriptError::InvalidOperator => GameError::InvalidOperator,
-            crate::script::ScriptError::TypeMismatch => GameError::ScriptExecutionError,
+            crate::script::ScriptError::TypeMismatch
+            | crate::script::ScriptError::QuotaExceeded { .. }
+            | crate::script::ScriptError::RepeatLimit { .. }
+            | crate::script::ScriptError::BudgetExceeded { .. } => GameError::ScriptExecutionError,
             crate::script::ScriptError::IndexOutOfBounds => GameError::ScriptIndexOutOfBounds,
             crate::script::ScriptError::ArithmeticError => GameError::ArithmeticOverflow,
+            crate::script::ScriptError::InvalidEntity { .. } => GameError::InvalidEntityId,
+            crate::script::ScriptError::InvalidCall { .. } => GameError::InvalidActionId,
+            crate::script::ScriptError::IncompatibleProperty { .. }
+            | crate::script::ScriptError::ReadOnlyProperty { .. } => {
+                GameError::InvalidPropertyAddress
+            }
         }
     }
 }
@@ -141,6 +152,37 @@ pub fn game_loop(state: &mut GameState) -> GameResult<()> {
     state.advance_frame()
 }
 
+/// Advance the game state by up to `frames` frames in one call
+///
+/// Intended for fast catch-up (e.g. rejoining a spectated match). Event collection is
+/// skipped for the whole batch; otherwise the result is identical to calling
+/// `game_loop` the same number of times.
+///
+/// # Arguments
+/// * `state` - Mutable reference to the current game state
+/// * `frames` - Number of frames to advance
+///
+/// # Returns
+/// * Number of frames actually advanced (stops early once the game has ended)
+pub fn advance_frames(state: &mut GameState, frames: Frame) -> GameResult<Frame> {
+    // Skip event collection for the whole batch
+    let events_enabled = state.events_enabled;
+    state.events_enabled = false;
+
+    let mut advanced = 0;
+    let mut result = Ok(());
+    while advanced < frames && state.status == crate::state::GameStatus::Playing {
+        result = state.advance_frame();
+        if result.is_err() {
+            break;
+        }
+        advanced += 1;
+    }
+
+    state.events_enabled = events_enabled;
+    result.map(|_| advanced)
+}
+
 /// Get the current game state for external serialization
 ///
 /// # Arguments
@@ -201,6 +243,13 @@ fn validate_definitions(
                 return Err(GameError::InvalidSpawnId);
             }
         }
+
+        // Validate child status effect references
+        for &child_id in &status_effect.children {
+            if child_id >= status_effect_definitions.len() {
+                return Err(GameError::InvalidStatusEffectId);
+            }
+        }
     }
 
     Ok(())
@@ -245,6 +294,9 @@ fn detect_circular_references(
     // Check status effect definition spawn references
     detect_status_effect_spawn_circular_references(status_effect_definitions, spawn_definitions)?;
 
+    // Check status effect bundles (child effects) for cycles
+    detect_status_effect_bundle_circular_references(status_effect_definitions)?;
+
     Ok(())
 }
 
@@ -312,6 +364,63 @@ fn detect_spawn_cycle_dfs(
     Ok(false)
 }
 
+/// Detect status effects that include themselves through their child effects
+fn detect_status_effect_bundle_circular_references(
+    status_effect_definitions: &[StatusEffectDefinition],
+) -> GameResult<()> {
+    for (effect_id, _) in status_effect_definitions.iter().enumerate() {
+        let mut visited = alloc::vec![false; status_effect_definitions.len()];
+        let mut recursion_stack = alloc::vec![false; status_effect_definitions.len()];
+
+        if detect_status_effect_bundle_cycle_dfs(
+            effect_id,
+            status_effect_definitions,
+            &mut visited,
+            &mut recursion_stack,
+        )? {
+            return Err(GameError::CircularReference);
+        }
+    }
+    Ok(())
+}
+
+/// Depth-first search to detect cycles in child status effect references
+fn detect_status_effect_bundle_cycle_dfs(
+    effect_id: usize,
+    status_effect_definitions: &[StatusEffectDefinition],
+    visited: &mut [bool],
+    recursion_stack: &mut [bool],
+) -> GameResult<bool> {
+    if effect_id >= status_effect_definitions.len() {
+        return Err(GameError::InvalidStatusEffectId);
+    }
+
+    visited[effect_id] = true;
+    recursion_stack[effect_id] = true;
+
+    for &child_id in &status_effect_definitions[effect_id].children {
+        if child_id >= status_effect_definitions.len() {
+            return Err(GameError::InvalidStatusEffectId);
+        }
+
+        if !visited[child_id] {
+            if detect_status_effect_bundle_cycle_dfs(
+                child_id,
+                status_effect_definitions,
+                visited,
+                recursion_stack,
+            )? {
+                return Ok(true);
+            }
+        } else if recursion_stack[child_id] {
+            return Ok(true);
+        }
+    }
+
+    recursion_stack[effect_id] = false;
+    Ok(false)
+}
+
 /// Detect circular references between actions and spawns
 fn detect_action_spawn_circular_references(
     action_definitions: &[ActionDefinition],
@@ -0,0 +1,87 @@
+//! Batch simulation for native server-side tooling (requires the `std` feature)
+//!
+//! Each `GameState` is still simulated single-threaded; `run_batch` (`rayon` feature) only
+//! spreads independent states across a rayon thread pool. States share nothing, so every
+//! result is identical to running the same states one after another with
+//! `run_batch_sequential`.
+
+use crate::api::GameResult;
+use crate::core::Frame;
+use crate::state::{GameState, GameStatus};
+use alloc::vec::Vec;
+
+/// Outcome of simulating one state of a batch
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BatchOutcome {
+    pub frames_advanced: Frame, // Frames simulated (fewer than requested if the match ended)
+    pub state_hash: u32,        // `GameState::state_hash` after the last frame
+}
+
+// Batches move states across threads; keep GameState free of thread-bound data
+const _: () = {
+    const fn assert_send<T: Send>() {}
+    assert_send::<GameState>();
+};
+
+/// Advance a single state by up to `frames` frames with event collection disabled
+fn run_one(state: &mut GameState, frames: Frame) -> GameResult<BatchOutcome> {
+    let events_enabled = state.events_enabled;
+    state.events_enabled = false;
+
+    // Reaching the frame limit ends the match without advancing, so count actual frames
+    let start_frame = state.frame;
+    let mut result = Ok(());
+    while state.frame - start_frame < frames && state.status == GameStatus::Playing {
+        let frame = state.frame;
+        result = state.advance_frame();
+        if result.is_err() || state.frame == frame {
+            break;
+        }
+    }
+
+    state.events_enabled = events_enabled;
+    result.map(|_| BatchOutcome {
+        frames_advanced: state.frame - start_frame,
+        state_hash: state.state_hash(),
+    })
+}
+
+/// Simulate every state by up to `frames` frames on the current thread
+/// Reference implementation that `run_batch` results are guaranteed to match
+pub fn run_batch_sequential(
+    states: &mut [GameState],
+    frames: Frame,
+) -> Vec<GameResult<BatchOutcome>> {
+    states
+        .iter_mut()
+        .map(|state| run_one(state, frames))
+        .collect()
+}
+
+/// Simulate every state by up to `frames` frames on a rayon pool of `threads` workers
+/// (0 = the global rayon pool). Outcomes are returned in input order and equal
+/// `run_batch_sequential` for the same states.
+#[cfg(feature = "rayon")]
+pub fn run_batch(
+    states: &mut [GameState],
+    frames: Frame,
+    threads: usize,
+) -> Vec<GameResult<BatchOutcome>> {
+    use rayon::prelude::*;
+
+    let run = |states: &mut [GameState]| -> Vec<GameResult<BatchOutcome>> {
+        states
+            .par_iter_mut()
+            .map(|state| run_one(state, frames))
+            .collect()
+    };
+    if threads == 0 {
+        return run(states);
+    }
+
+    // A dedicated pool when the caller asks for a worker count
+    match rayon::ThreadPoolBuilder::new().num_threads(threads).build() {
+        Ok(pool) => pool.install(|| run(states)),
+        Err(_) => run_batch_sequential(states, frames),
+    }
+}
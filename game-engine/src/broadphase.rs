@@ -0,0 +1,99 @@
+//! AABB broadphase for entity-vs-entity overlap tests
+//!
+//! Tests one rectangle against a block of `LANES` candidates at once. With the `simd`
+//! feature on wasm32 builds that enable `simd128`, the block is evaluated with
+//! `core::arch::wasm32` vector compares; every other build uses the scalar loop. Both
+//! paths evaluate the same integer predicate as `PhysicsSystem::check_entity_collision`,
+//! so results are identical bit for bit.
+//!
+//! State hashing stays scalar: FNV-1a feeds each byte into the previous product, so any
+//! vectorized form would change the hash values that replays and reward rolls depend on.
+
+use crate::entity::EntityCore;
+
+/// Number of candidates tested per `overlap_mask` call
+pub const LANES: usize = 4;
+
+/// Entity bounds in whole pixels (right/bottom exclusive)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PixelRect {
+    pub left: i32,
+    pub top: i32,
+    pub right: i32,
+    pub bottom: i32,
+}
+
+impl PixelRect {
+    /// Zero-sized rectangle used to pad partial blocks (never overlaps anything)
+    pub const EMPTY: PixelRect = PixelRect {
+        left: 0,
+        top: 0,
+        right: 0,
+        bottom: 0,
+    };
+
+    /// Bounds of an entity, truncated to pixels like `check_entity_collision`
+    pub fn from_core(core: &EntityCore) -> Self {
+        let left = core.pos.0.to_int();
+        let top = core.pos.1.to_int();
+        Self {
+            left,
+            top,
+            right: left + core.size.0 as i32,
+            bottom: top + core.size.1 as i32,
+        }
+    }
+
+    /// Whether the two rectangles overlap
+    pub fn overlaps(&self, other: &PixelRect) -> bool {
+        self.left < other.right
+            && self.right > other.left
+            && self.top < other.bottom
+            && self.bottom > other.top
+    }
+}
+
+/// Bit `n` is set when `rect` overlaps `candidates[n]`; only the first `LANES` are tested
+pub fn overlap_mask(rect: &PixelRect, candidates: &[PixelRect]) -> u8 {
+    let count = candidates.len().min(LANES);
+    let mut block = [PixelRect::EMPTY; LANES];
+    block[..count].copy_from_slice(&candidates[..count]);
+    let valid = (1u8 << count) - 1;
+
+    overlap_block(rect, &block) & valid
+}
+
+#[cfg(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128"))]
+fn overlap_block(rect: &PixelRect, block: &[PixelRect; LANES]) -> u8 {
+    use core::arch::wasm32::{i32x4, i32x4_bitmask, i32x4_gt, i32x4_lt, i32x4_splat, v128_and};
+
+    let lane = |field: fn(&PixelRect) -> i32| {
+        i32x4(
+            field(&block[0]),
+            field(&block[1]),
+            field(&block[2]),
+            field(&block[3]),
+        )
+    };
+    let lefts = lane(|r| r.left);
+    let tops = lane(|r| r.top);
+    let rights = lane(|r| r.right);
+    let bottoms = lane(|r| r.bottom);
+
+    let horizontal = v128_and(
+        i32x4_lt(i32x4_splat(rect.left), rights),
+        i32x4_gt(i32x4_splat(rect.right), lefts),
+    );
+    let vertical = v128_and(
+        i32x4_lt(i32x4_splat(rect.top), bottoms),
+        i32x4_gt(i32x4_splat(rect.bottom), tops),
+    );
+    i32x4_bitmask(v128_and(horizontal, vertical))
+}
+
+#[cfg(not(all(feature = "simd", target_arch = "wasm32", target_feature = "simd128")))]
+fn overlap_block(rect: &PixelRect, block: &[PixelRect; LANES]) -> u8 {
+    block.iter().enumerate().fold(0, |mask, (lane, other)| {
+        mask | ((rect.overlaps(other) as u8) << lane)
+    })
+}
@@ -5,7 +5,7 @@
 
 use crate::core::{TILEMAP_HEIGHT, TILEMAP_WIDTH, TILE_SIZE};
 use crate::math::Fixed;
-use crate::tilemap::{TileType, Tilemap};
+use crate::tilemap::Tilemap;
 
 /// Axis-Aligned Bounding Box for collision detection
 #[derive(Debug, Clone, Copy)]
@@ -263,7 +263,7 @@ impl CollisionSystem {
         // Check each overlapping tile
         for tile_y in top_tile..=bottom_tile {
             for tile_x in left_tile..=right_tile {
-                if tilemap.get_tile(tile_x, tile_y) == TileType::Block {
+                if tilemap.get_tile(tile_x, tile_y).is_solid() {
                     // Create AABB for this tile
                     let tile_aabb = AABB::new(
                         Fixed::from_int((tile_x * TILE_SIZE as usize) as i16),
@@ -364,7 +364,7 @@ impl CollisionSystem {
         // Test collision with each solid tile in the swept area
         for tile_y in top_tile..=bottom_tile {
             for tile_x in left_tile..=right_tile {
-                if tilemap.get_tile(tile_x, tile_y) == TileType::Block {
+                if tilemap.get_tile(tile_x, tile_y).is_solid() {
                     let tile_aabb = AABB::new(
                         Fixed::from_int((tile_x * TILE_SIZE as usize) as i16),
                         Fixed::from_int((tile_y * TILE_SIZE as usize) as i16),
@@ -0,0 +1,54 @@
+//! Damage calculation for spawn hits
+//!
+//! Every hit runs the same pipeline: the spawn's launch damage after distance falloff, plus a
+//! seeded roll of up to `damage_range`, a critical roll against `crit_chance` that scales the
+//! damage by `crit_multiplier` percent, and finally the target's flat armor for the spawn's
+//! element. Rolls draw from a dedicated RNG stream, so adding crits to one spawn never shifts
+//! the randomness scripts or launches see.
+
+use crate::entity::SpawnDefinition;
+use crate::random::SeededRng;
+
+/// Crit chances are out of this many, so a `crit_chance` of 255 always crits
+pub const CRIT_CHANCE_SCALE: u16 = 255;
+
+/// Outcome of one damage roll
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DamageRoll {
+    pub raw: u16,       // Damage before armor: base plus range roll, scaled on a crit
+    pub critical: bool, // Whether the crit roll succeeded
+    pub mitigated: u16, // Damage absorbed by armor
+    pub amount: u16,    // Damage dealt after armor
+}
+
+/// Roll the damage of a hit from `base` (launch damage after falloff) against `armor`
+/// Draws nothing for definitions without a damage range or crit chance, and rolls the crit
+/// only when there is damage to scale.
+pub fn roll_damage(
+    definition: &SpawnDefinition,
+    base: u16,
+    armor: u8,
+    rng: &mut SeededRng,
+) -> DamageRoll {
+    let mut raw = base as u32;
+    if definition.damage_range > 0 {
+        raw += rng.next_range(definition.damage_range.saturating_add(1)) as u32;
+    }
+
+    let critical = raw > 0
+        && definition.crit_chance > 0
+        && rng.next_range(CRIT_CHANCE_SCALE) < definition.crit_chance as u16;
+    if critical {
+        raw = raw * definition.crit_multiplier as u32 / 100;
+    }
+
+    let raw = raw.min(u16::MAX as u32) as u16;
+    let amount = raw.saturating_sub(armor as u16);
+    DamageRoll {
+        raw,
+        critical,
+        mitigated: raw - amount,
+        amount,
+    }
+}
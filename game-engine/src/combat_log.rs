@@ -0,0 +1,166 @@
+//! Human-readable combat log lines built from game events
+//!
+//! Turns the event stream into lines such as "Frame 312: Gutsman hits Iceman for 14 Heat"
+//! for debugging and spectator overlays. Purely presentational: the log reads events and
+//! never touches the simulation. Enabled with the `combat-log` feature.
+
+use crate::entity::EntityId;
+use crate::event::{GameEvent, GameEventKind, RemovalReason};
+use alloc::format;
+use alloc::string::String;
+use alloc::vec::Vec;
+
+/// Formats events into log lines using per-character display names
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct CombatLog {
+    pub names: Vec<String>, // Display name per character id; missing ids fall back to "Character {id}"
+}
+
+impl CombatLog {
+    /// Create a log with the given character names, indexed by character id
+    pub fn new(names: Vec<String>) -> Self {
+        Self { names }
+    }
+
+    /// Display name of a character
+    pub fn name(&self, character_id: EntityId) -> String {
+        match self.names.get(character_id as usize) {
+            Some(name) if !name.is_empty() => name.clone(),
+            _ => format!("Character {}", character_id),
+        }
+    }
+
+    /// Log line for a single event, None for events without a combat meaning (camera hints,
+    /// dash steps, spawn, status and action bookkeeping, grapples other than hooking an
+    /// opponent)
+    pub fn line(&self, event: &GameEvent) -> Option<String> {
+        let text = match &event.kind {
+            GameEventKind::CameraHint(_)
+            | GameEventKind::SpawnCreated { .. }
+            | GameEventKind::StatusApplied { .. }
+            | GameEventKind::StatusExpired { .. }
+            | GameEventKind::ActionExecuted { .. }
+            | GameEventKind::Dashed { .. }
+            | GameEventKind::GrappleReleased { .. }
+            | GameEventKind::SpawnCollisionsDeferred { .. } => return None,
+            GameEventKind::GrappleAttached {
+                character_id,
+                source_id,
+                ..
+            } => {
+                if character_id == source_id {
+                    return None;
+                }
+                format!(
+                    "{} hooks {}",
+                    self.name(*source_id),
+                    self.name(*character_id)
+                )
+            }
+            GameEventKind::CharacterHit {
+                character_id,
+                attacker_id,
+                amount,
+                element,
+                critical,
+                ..
+            } => match attacker_id {
+                Some(attacker) => format!(
+                    "{} {} {} for {} {:?}",
+                    self.name(*attacker),
+                    if *critical { "critically hits" } else { "hits" },
+                    self.name(*character_id),
+                    amount,
+                    element
+                ),
+                None => format!(
+                    "{} takes {} {:?} damage",
+                    self.name(*character_id),
+                    amount,
+                    element
+                ),
+            },
+            GameEventKind::EnergyDrained {
+                character_id,
+                drainer_id,
+                amount,
+                stolen,
+            } => match drainer_id {
+                Some(drainer) if *stolen > 0 => format!(
+                    "{} steals {} energy from {}",
+                    self.name(*drainer),
+                    stolen,
+                    self.name(*character_id)
+                ),
+                Some(drainer) => format!(
+                    "{} drains {} energy from {}",
+                    self.name(*drainer),
+                    amount,
+                    self.name(*character_id)
+                ),
+                None => format!("{} loses {} energy", self.name(*character_id), amount),
+            },
+            GameEventKind::DamageReflected {
+                character_id,
+                reflector_id,
+                amount,
+            } => format!(
+                "{} reflects {} damage to {}",
+                self.name(*reflector_id),
+                amount,
+                self.name(*character_id)
+            ),
+            GameEventKind::CharacterDefeated {
+                character_id,
+                reason,
+            } => match reason {
+                RemovalReason::KilledBy(Some(killer)) => format!(
+                    "{} is defeated by {}",
+                    self.name(*character_id),
+                    self.name(*killer)
+                ),
+                RemovalReason::OutOfBounds => {
+                    format!("{} falls out of bounds", self.name(*character_id))
+                }
+                _ => format!("{} is defeated", self.name(*character_id)),
+            },
+            GameEventKind::CharacterRevived { character_id } => {
+                format!("{} respawns", self.name(*character_id))
+            }
+            GameEventKind::CombatStateChanged {
+                character_id,
+                in_combat,
+            } => format!(
+                "{} {} combat",
+                self.name(*character_id),
+                if *in_combat { "enters" } else { "leaves" }
+            ),
+            GameEventKind::SpawnRemoved {
+                spawn_instance_id,
+                reason,
+                ..
+            } => match reason {
+                RemovalReason::AbsorbedBy(absorber) => format!(
+                    "Spawn {} is absorbed by spawn {}",
+                    spawn_instance_id, absorber
+                ),
+                RemovalReason::KilledBy(Some(killer)) => format!(
+                    "Spawn {} is destroyed by {}",
+                    spawn_instance_id,
+                    self.name(*killer)
+                ),
+                // Expiry and boundary exits are routine and would flood the log
+                _ => return None,
+            },
+        };
+        Some(format!("Frame {}: {}", event.frame, text))
+    }
+
+    /// Log lines for a sequence of events, skipping events without a line
+    pub fn lines<'a>(&self, events: impl IntoIterator<Item = &'a GameEvent>) -> Vec<String> {
+        events
+            .into_iter()
+            .filter_map(|event| self.line(event))
+            .collect()
+    }
+}
@@ -1,142 +1,268 @@
 //! Centralized address byte constants for operators and property accessors
 
+/// Declare `u8` constants together with an `ALL` (name, value) table, so tooling
+/// exports are generated from the constants themselves and cannot drift
+macro_rules! named_constants {
+    ($($(#[$meta:meta])* pub const $name:ident: u8 = $value:expr;)*) => {
+        $($(#[$meta])* pub const $name: u8 = $value;)*
+
+        /// Every constant in this module as (name, value), in declaration order
+        pub const ALL: &[(&str, u8)] = &[$((stringify!($name), $name)),*];
+    };
+}
+
 /// Operator address constants for script operators
 ///
 /// This module provides named constants for all operator byte values used in the scripting system,
 /// improving code maintainability and reducing the risk of errors from hardcoded values.
 pub mod operator_address {
-    // ===== EXIT OPERATORS (0-4) =====
-    /// Exit script with specified flag
-    pub const EXIT: u8 = 0;
-    /// Exit if insufficient energy
-    pub const EXIT_IF_NO_ENERGY: u8 = 1;
-    /// Exit if action is on cooldown
-    pub const EXIT_IF_COOLDOWN: u8 = 2;
-    /// Exit if character is not grounded (not touching ground)
-    pub const EXIT_IF_NOT_GROUNDED: u8 = 3;
-    /// Exit with variable value: [ExitWithVar, var_index]
-    pub const EXIT_WITH_VAR: u8 = 4;
-    // Reserved for future exit operators: 5-9
-
-    // ===== CONTROL FLOW OPERATORS (10-14) =====
-    /// Skip specified number of bytes
-    pub const SKIP: u8 = 10;
-    /// Jump to specified position
-    pub const GOTO: u8 = 11;
-
-    // ===== PROPERTY OPERATIONS (15-16) =====
-    /// Read property into variable: [ReadProp, var_index, prop_address]
-    pub const READ_PROP: u8 = 15;
-    /// Write variable to property: [WriteProp, prop_address, var_index]
-    pub const WRITE_PROP: u8 = 16;
-
-    // ===== VARIABLE OPERATIONS (20-24) =====
-    /// Assign byte literal to variable: [AssignByte, var_index, literal_value]
-    pub const ASSIGN_BYTE: u8 = 20;
-    /// Assign fixed-point value: [AssignFixed, var_index, numerator, denominator]
-    pub const ASSIGN_FIXED: u8 = 21;
-    /// Assign random value: [AssignRandom, var_index]
-    pub const ASSIGN_RANDOM: u8 = 22;
-    /// Convert fixed to byte: [ToByte, to_var_index, from_fixed_index]
-    pub const TO_BYTE: u8 = 23;
-    /// Convert byte to fixed: [ToFixed, to_fixed_index, from_var_index]
-    pub const TO_FIXED: u8 = 24;
-
-    // ===== FIXED-POINT ARITHMETIC (30-34) =====
-    /// Add fixed-point values: [Add, dest_fixed, left_fixed, right_fixed]
-    pub const ADD: u8 = 30;
-    /// Subtract fixed-point values: [Sub, dest_fixed, left_fixed, right_fixed]
-    pub const SUB: u8 = 31;
-    /// Multiply fixed-point values: [Mul, dest_fixed, left_fixed, right_fixed]
-    pub const MUL: u8 = 32;
-    /// Divide fixed-point values: [Div, dest_fixed, left_fixed, right_fixed]
-    pub const DIV: u8 = 33;
-    /// Negate fixed-point value: [Negate, fixed_index]
-    pub const NEGATE: u8 = 34;
-
-    // ===== BYTE ARITHMETIC (40-45) =====
-    /// Add byte values: [AddByte, dest_var, left_var, right_var]
-    pub const ADD_BYTE: u8 = 40;
-    /// Subtract byte values: [SubByte, dest_var, left_var, right_var]
-    pub const SUB_BYTE: u8 = 41;
-    /// Multiply byte values: [MulByte, dest_var, left_var, right_var]
-    pub const MUL_BYTE: u8 = 42;
-    /// Divide byte values: [DivByte, dest_var, left_var, right_var]
-    pub const DIV_BYTE: u8 = 43;
-    /// Modulo byte values: [ModByte, dest_var, left_var, right_var]
-    pub const MOD_BYTE: u8 = 44;
-    /// Wrapping add byte values: [WrappingAdd, dest_var, left_var, right_var]
-    pub const WRAPPING_ADD: u8 = 45;
-
-    // ===== CONDITIONAL OPERATIONS (50-53) =====
-    /// Equal comparison: [Equal, dest_var, left_var, right_var]
-    pub const EQUAL: u8 = 50;
-    /// Not equal comparison: [NotEqual, dest_var, left_var, right_var]
-    pub const NOT_EQUAL: u8 = 51;
-    /// Less than comparison: [LessThan, dest_var, left_var, right_var]
-    pub const LESS_THAN: u8 = 52;
-    /// Less than or equal comparison: [LessThanOrEqual, dest_var, left_var, right_var]
-    pub const LESS_THAN_OR_EQUAL: u8 = 53;
-
-    // ===== LOGICAL OPERATIONS (60-62) =====
-    /// Logical NOT: [Not, dest_var, source_var]
-    pub const NOT: u8 = 60;
-    /// Logical OR: [Or, dest_var, left_var, right_var]
-    pub const OR: u8 = 61;
-    /// Logical AND: [And, dest_var, left_var, right_var]
-    pub const AND: u8 = 62;
-
-    // ===== UTILITY OPERATIONS (70-71) =====
-    /// Minimum value: [Min, dest_var, left_var, right_var]
-    pub const MIN: u8 = 70;
-    /// Maximum value: [Max, dest_var, left_var, right_var]
-    pub const MAX: u8 = 71;
-
-    // ===== GAME ACTIONS (80-85) =====
-    /// Lock current action
-    pub const LOCK_ACTION: u8 = 80;
-    /// Unlock current action
-    pub const UNLOCK_ACTION: u8 = 81;
-    /// Apply energy cost
-    pub const APPLY_ENERGY_COST: u8 = 82;
-    /// Apply duration
-    pub const APPLY_DURATION: u8 = 83;
-    /// Spawn entity: [Spawn, spawn_id_var]
-    pub const SPAWN: u8 = 84;
-    /// Spawn entity with variables: [SpawnWithVars, spawn_id_var, var1, var2, var3, var4]
-    pub const SPAWN_WITH_VARS: u8 = 85;
-
-    // ===== DEBUG OPERATIONS (90-91) =====
-    /// Log variable value: [LogVariable, var_index]
-    pub const LOG_VARIABLE: u8 = 90;
-
-    // ===== ARGS AND SPAWNS ACCESS (96-98) =====
-    /// Read argument to variable: [ReadArg, var_index, arg_index]
-    pub const READ_ARG: u8 = 96;
-    /// Read spawn ID to variable: [ReadSpawn, var_index, spawn_index]
-    pub const READ_SPAWN: u8 = 97;
-    /// Write variable to spawn ID: [WriteSpawn, spawn_index, var_index]
-    pub const WRITE_SPAWN: u8 = 98;
-
-    // ===== COOLDOWN OPERATIONS (100-103) =====
-    /// Read action cooldown: [ReadActionCooldown, var_index]
-    pub const READ_ACTION_COOLDOWN: u8 = 100;
-    /// Read action last used timestamp: [ReadActionLastUsed, var_index]
-    pub const READ_ACTION_LAST_USED: u8 = 101;
-    /// Write action last used timestamp: [WriteActionLastUsed, var_index]
-    pub const WRITE_ACTION_LAST_USED: u8 = 102;
-    /// Check if action is on cooldown: [IsActionOnCooldown, var_index]
-    pub const IS_ACTION_ON_COOLDOWN: u8 = 103;
-
-    // ===== ENTITY PROPERTY ACCESS OPERATIONS (104-107) =====
-    /// Read character property: [ReadCharacterProperty, character_id, var_index, property_address]
-    pub const READ_CHARACTER_PROPERTY: u8 = 104;
-    /// Write character property: [WriteCharacterProperty, character_id, property_address, var_index]
-    pub const WRITE_CHARACTER_PROPERTY: u8 = 105;
-    /// Read spawn property: [ReadSpawnProperty, spawn_instance_id, var_index, property_address]
-    pub const READ_SPAWN_PROPERTY: u8 = 106;
-    /// Write spawn property: [WriteSpawnProperty, spawn_instance_id, property_address, var_index]
-    pub const WRITE_SPAWN_PROPERTY: u8 = 107;
+    named_constants! {
+        // ===== EXIT OPERATORS (0-4) =====
+        /// Exit script with specified flag
+        pub const EXIT: u8 = 0;
+        /// Exit if insufficient energy
+        pub const EXIT_IF_NO_ENERGY: u8 = 1;
+        /// Exit if action is on cooldown
+        pub const EXIT_IF_COOLDOWN: u8 = 2;
+        /// Exit if character is not grounded (not touching ground)
+        pub const EXIT_IF_NOT_GROUNDED: u8 = 3;
+        /// Exit with variable value: [ExitWithVar, var_index]
+        pub const EXIT_WITH_VAR: u8 = 4;
+        // Reserved for future exit operators: 5-9
+
+        // ===== CONTROL FLOW OPERATORS (10-14) =====
+        /// Skip specified number of bytes
+        pub const SKIP: u8 = 10;
+        /// Jump to specified position
+        pub const GOTO: u8 = 11;
+        /// Run an action definition's script as a subroutine sharing the caller's variables:
+        /// [Call, action_id]
+        pub const CALL: u8 = 12;
+        /// Resume the caller of the current subroutine (ends the script at the top level)
+        pub const RETURN: u8 = 13;
+        /// Run the next body_length bytes count_var times (at most `core::MAX_REPEAT_COUNT`),
+        /// storing the iteration (from 0) in index_var: [Repeat, index_var, count_var, body_length]
+        pub const REPEAT: u8 = 14;
+
+        // ===== PROPERTY OPERATIONS (15-16) =====
+        /// Read property into variable: [ReadProp, var_index, prop_address]
+        pub const READ_PROP: u8 = 15;
+        /// Write variable to property: [WriteProp, prop_address, var_index]
+        pub const WRITE_PROP: u8 = 16;
+
+        // ===== VARIABLE OPERATIONS (20-24) =====
+        /// Assign byte literal to variable: [AssignByte, var_index, literal_value]
+        pub const ASSIGN_BYTE: u8 = 20;
+        /// Assign fixed-point value: [AssignFixed, var_index, numerator, denominator]
+        pub const ASSIGN_FIXED: u8 = 21;
+        /// Assign random value: [AssignRandom, var_index]
+        pub const ASSIGN_RANDOM: u8 = 22;
+        /// Convert fixed to byte: [ToByte, to_var_index, from_fixed_index]
+        pub const TO_BYTE: u8 = 23;
+        /// Convert byte to fixed: [ToFixed, to_fixed_index, from_var_index]
+        pub const TO_FIXED: u8 = 24;
+
+        // ===== FIXED-POINT ARITHMETIC (30-34) =====
+        /// Add fixed-point values: [Add, dest_fixed, left_fixed, right_fixed]
+        pub const ADD: u8 = 30;
+        /// Subtract fixed-point values: [Sub, dest_fixed, left_fixed, right_fixed]
+        pub const SUB: u8 = 31;
+        /// Multiply fixed-point values: [Mul, dest_fixed, left_fixed, right_fixed]
+        pub const MUL: u8 = 32;
+        /// Divide fixed-point values: [Div, dest_fixed, left_fixed, right_fixed]
+        pub const DIV: u8 = 33;
+        /// Negate fixed-point value: [Negate, fixed_index]
+        pub const NEGATE: u8 = 34;
+
+        // ===== FIXED-POINT MATH (35-39) =====
+        // Angles are binary angles in byte variables: 256 steps per turn, 0 = right, 64 = down
+        /// Sine of an angle: [Sin, dest_fixed, angle_var]
+        pub const SIN: u8 = 35;
+        /// Cosine of an angle: [Cos, dest_fixed, angle_var]
+        pub const COS: u8 = 36;
+        /// Angle of the vector (x, y): [Atan2, dest_var, y_fixed, x_fixed]
+        pub const ATAN2: u8 = 37;
+        /// Square root, 0 for negative values: [Sqrt, dest_fixed, source_fixed]
+        pub const SQRT: u8 = 38;
+
+        // ===== BYTE ARITHMETIC (40-45) =====
+        /// Add byte values: [AddByte, dest_var, left_var, right_var]
+        pub const ADD_BYTE: u8 = 40;
+        /// Subtract byte values: [SubByte, dest_var, left_var, right_var]
+        pub const SUB_BYTE: u8 = 41;
+        /// Multiply byte values: [MulByte, dest_var, left_var, right_var]
+        pub const MUL_BYTE: u8 = 42;
+        /// Divide byte values: [DivByte, dest_var, left_var, right_var]
+        pub const DIV_BYTE: u8 = 43;
+        /// Modulo byte values: [ModByte, dest_var, left_var, right_var]
+        pub const MOD_BYTE: u8 = 44;
+        /// Wrapping add byte values: [WrappingAdd, dest_var, left_var, right_var]
+        pub const WRAPPING_ADD: u8 = 45;
+
+        // ===== CONDITIONAL OPERATIONS (50-53) =====
+        /// Equal comparison: [Equal, dest_var, left_var, right_var]
+        pub const EQUAL: u8 = 50;
+        /// Not equal comparison: [NotEqual, dest_var, left_var, right_var]
+        pub const NOT_EQUAL: u8 = 51;
+        /// Less than comparison: [LessThan, dest_var, left_var, right_var]
+        pub const LESS_THAN: u8 = 52;
+        /// Less than or equal comparison: [LessThanOrEqual, dest_var, left_var, right_var]
+        pub const LESS_THAN_OR_EQUAL: u8 = 53;
+
+        // ===== LOGICAL OPERATIONS (60-62) =====
+        /// Logical NOT: [Not, dest_var, source_var]
+        pub const NOT: u8 = 60;
+        /// Logical OR: [Or, dest_var, left_var, right_var]
+        pub const OR: u8 = 61;
+        /// Logical AND: [And, dest_var, left_var, right_var]
+        pub const AND: u8 = 62;
+
+        // ===== UTILITY OPERATIONS (70-73) =====
+        /// Minimum value: [Min, dest_var, left_var, right_var]
+        pub const MIN: u8 = 70;
+        /// Maximum value: [Max, dest_var, left_var, right_var]
+        pub const MAX: u8 = 71;
+        /// Clamp a fixed-point value between two bounds, given in either order:
+        /// [ClampFixed, fixed_index, bound_fixed, bound_fixed]
+        pub const CLAMP_FIXED: u8 = 72;
+        /// Move a fixed-point value toward a target by a fraction (0-1) of the distance, at
+        /// least one 1/32 step when the fraction is positive: [Lerp, fixed_index, target_fixed, t_fixed]
+        pub const LERP: u8 = 73;
+
+        // ===== GAME ACTIONS (80-85) =====
+        /// Lock current action
+        pub const LOCK_ACTION: u8 = 80;
+        /// Unlock current action
+        pub const UNLOCK_ACTION: u8 = 81;
+        /// Apply energy cost
+        pub const APPLY_ENERGY_COST: u8 = 82;
+        /// Apply duration
+        pub const APPLY_DURATION: u8 = 83;
+        /// Spawn entity: [Spawn, spawn_id_var]
+        pub const SPAWN: u8 = 84;
+        /// Spawn entity with variables: [SpawnWithVars, spawn_id_var, var1, var2, var3, var4]
+        pub const SPAWN_WITH_VARS: u8 = 85;
+
+        // ===== SPAWN MANAGEMENT (86-89) =====
+        /// Count caller's live spawns of a definition: [CountSpawns, var_index, spawn_id_var]
+        pub const COUNT_SPAWNS: u8 = 86;
+        /// Despawn all of caller's spawns of a definition: [DespawnMine, spawn_id_var]
+        pub const DESPAWN_MINE: u8 = 87;
+        /// Despawn a spawn instance if owned by caller: [DespawnById, instance_id_var]
+        pub const DESPAWN_BY_ID: u8 = 88;
+
+        // ===== DEBUG OPERATIONS (90-91) =====
+        /// Log variable value: [LogVariable, var_index]
+        pub const LOG_VARIABLE: u8 = 90;
+
+        // ===== ARGS AND SPAWNS ACCESS (96-98) =====
+        /// Read argument to variable: [ReadArg, var_index, arg_index]
+        pub const READ_ARG: u8 = 96;
+        /// Read spawn ID to variable: [ReadSpawn, var_index, spawn_index]
+        pub const READ_SPAWN: u8 = 97;
+        /// Write variable to spawn ID: [WriteSpawn, spawn_index, var_index]
+        pub const WRITE_SPAWN: u8 = 98;
+
+        // ===== COOLDOWN OPERATIONS (100-103) =====
+        /// Read action cooldown: [ReadActionCooldown, var_index]
+        pub const READ_ACTION_COOLDOWN: u8 = 100;
+        /// Read action last used timestamp: [ReadActionLastUsed, var_index]
+        pub const READ_ACTION_LAST_USED: u8 = 101;
+        /// Write action last used timestamp: [WriteActionLastUsed, var_index]
+        pub const WRITE_ACTION_LAST_USED: u8 = 102;
+        /// Check if action is on cooldown: [IsActionOnCooldown, var_index]
+        pub const IS_ACTION_ON_COOLDOWN: u8 = 103;
+
+        // ===== ENTITY PROPERTY ACCESS OPERATIONS (104-107) =====
+        /// Read character property: [ReadCharacterProperty, character_id, var_index, property_address]
+        pub const READ_CHARACTER_PROPERTY: u8 = 104;
+        /// Write character property: [WriteCharacterProperty, character_id, property_address, var_index]
+        pub const WRITE_CHARACTER_PROPERTY: u8 = 105;
+        /// Read spawn property: [ReadSpawnProperty, spawn_instance_id, var_index, property_address]
+        pub const READ_SPAWN_PROPERTY: u8 = 106;
+        /// Write spawn property: [WriteSpawnProperty, spawn_instance_id, property_address, var_index]
+        pub const WRITE_SPAWN_PROPERTY: u8 = 107;
+
+        // ===== ENERGY OPERATIONS (108-109) =====
+        /// Drain energy from a character: [DrainEnergy, dest_var, character_id_var, amount_var]
+        /// Stores the energy actually removed in dest_var
+        pub const DRAIN_ENERGY: u8 = 108;
+        /// Move energy from a character to the caller: [StealEnergy, dest_var, character_id_var, amount_var]
+        /// Stores the energy actually removed in dest_var; the caller gains it up to its energy cap
+        pub const STEAL_ENERGY: u8 = 109;
+
+        // ===== PRESENTATION OPERATIONS (110-114) =====
+        /// Emit camera hint event: [CameraHint, focus_type_var, focus_id_var, zoom_fixed, shake_var]
+        pub const CAMERA_HINT: u8 = 110;
+
+        // ===== MOVEMENT OPERATIONS (115-119) =====
+        /// Teleport the script's entity: [Teleport, mode, result_var, x_fixed, y_fixed]
+        /// See `teleport_mode`; stores 1 in result_var if the entity moved, 0 otherwise
+        pub const TELEPORT: u8 = 115;
+        /// Start a dash: [Dash, result_var, direction_var, distance_fixed, frames_var]
+        /// See `dash_direction`; stores 1 in result_var if the dash started, 0 otherwise
+        pub const DASH: u8 = 116;
+        /// Jump off a wall touched in the air: [WallJump, result_var, push_fixed, lift_fixed]
+        /// Stores 1 in result_var if the character jumped, 0 otherwise
+        pub const WALL_JUMP: u8 = 117;
+
+        // ===== TARGETING OPERATIONS (120-124) =====
+        // Entities are picked by type (1 = character, 2 = spawn instance, as ENTITY_TARGET_TYPE)
+        // and id; offsets run between hitbox centers
+        /// Distance in pixels to an entity: [DistanceTo, dest_fixed, entity_type_var, entity_id_var]
+        /// Stores 0 when the entity does not exist
+        pub const DISTANCE_TO: u8 = 120;
+        /// Unit vector towards an entity: [DirectionTo, x_fixed, y_fixed, entity_type_var, entity_id_var]
+        /// Stores (0, 0) when the entity does not exist or shares the script entity's center
+        pub const DIRECTION_TO: u8 = 121;
+        /// Pick an enemy character by a `target_query`: [FindTarget, dest_var, query]
+        /// Stores the character id, or 255 when no enemy is standing
+        pub const FIND_TARGET: u8 = 122;
+        /// Make the enemy picked by a `target_query` the script entity's target:
+        /// [AcquireTarget, result_var, query]
+        /// Stores 1 in result_var if a target was set; without enemies the target is cleared
+        pub const ACQUIRE_TARGET: u8 = 123;
+        /// Check that no solid tile lies between the script entity and an entity:
+        /// [HasLineOfSight, result_var, entity_type_var, entity_id_var]
+        /// Stores 1 when the line between centers is clear, 0 when blocked or the entity does
+        /// not exist (see `Tilemap::raycast`)
+        pub const HAS_LINE_OF_SIGHT: u8 = 124;
+
+        // ===== CONSTANT OPERATIONS (125-127) =====
+        // Constants are u16 entries of the running definition's constant pool
+        /// Load a constant as a whole number: [LoadConst, dest_fixed, const_index]
+        /// Values above the fixed-point range saturate, as u16 property reads do
+        pub const LOAD_CONST: u8 = 125;
+        /// Load a constant as raw fixed-point bits (1/32 steps, two's complement):
+        /// [LoadConstFixed, dest_fixed, const_index]
+        pub const LOAD_CONST_FIXED: u8 = 126;
+        /// Assign a 16-bit immediate as raw fixed-point bits: [AssignFixedRaw, dest_fixed, high, low]
+        pub const ASSIGN_FIXED_RAW: u8 = 127;
+
+        // ===== NAVIGATION OPERATIONS (130-134) =====
+        /// Next move toward an entity along the shortest grounded path:
+        /// [PathDirectionTo, dest_var, entity_type_var, entity_id_var]
+        /// Stores a `path_step`; walks, ledge drops and jumps within the walker's jump reach
+        /// are planned over the tilemap (see `Tilemap::path_step`)
+        pub const PATH_DIRECTION_TO: u8 = 130;
+
+        // ===== COMBAT OPERATIONS (135-139) =====
+        /// Roll the script spawn's hit damage against a character without dealing it:
+        /// [RollDamage, dest_var, crit_var, character_id_var]
+        /// Runs the hit damage pipeline (see `combat::roll_damage`) against the character's
+        /// armor for the spawn's element and stores the damage (capped at 255) and 1 in
+        /// crit_var on a critical hit. Stores 0 in both outside spawn scripts or when the
+        /// character does not exist
+        pub const ROLL_DAMAGE: u8 = 135;
+
+        // ===== STATUS EFFECT OPERATIONS (140-144) =====
+        /// Apply a status effect to a character:
+        /// [ApplyStatusEffect, result_var, character_id_var, effect_id_var]
+        /// The calling character is recorded as the effect's source (the owner of a spawn, or
+        /// the source of a status effect); stores 1 in result_var when the effect was applied
+        /// or stacked, 0 when it was ignored or the character or definition does not exist
+        pub const APPLY_STATUS_EFFECT: u8 = 140;
+    }
 }
 
 /// Property address constants for script property access
@@ -145,328 +271,701 @@ pub mod operator_address {
 /// They are organized into logical, sequential blocks by entity type with reserved ranges for future expansion.
 /// All addresses are within u8 range (0-255) with no conflicts or fragmentation.
 pub mod property_address {
-    // ===== GAME STATE PROPERTIES (0x01-0x0F) =====
-    // Reserved range: 0x01-0x0F (15 addresses)
-    /// Game seed value
-    pub const GAME_SEED: u8 = 0x01;
-    /// Current game frame
-    pub const GAME_FRAME: u8 = 0x02;
-    /// Game gravity value
-    pub const GAME_GRAVITY: u8 = 0x03;
-    // Reserved for future game state properties: 0x04-0x0F
-
-    // ===== CHARACTER PROPERTIES (0x10-0x3F) =====
-    // Reserved range: 0x10-0x3F (48 addresses)
-
-    // Character Core Properties (0x10-0x1F)
-    /// Character ID (byte)
-    pub const CHARACTER_ID: u8 = 0x10;
-    /// Character group (byte)
-    pub const CHARACTER_GROUP: u8 = 0x11;
-    /// Character position X (fixed-point)
-    pub const CHARACTER_POS_X: u8 = 0x12;
-    /// Character position Y (fixed-point)
-    pub const CHARACTER_POS_Y: u8 = 0x13;
-    /// Character velocity X (fixed-point)
-    pub const CHARACTER_VEL_X: u8 = 0x14;
-    /// Character velocity Y (fixed-point)
-    pub const CHARACTER_VEL_Y: u8 = 0x15;
-    /// Character size width (fixed-point)
-    pub const CHARACTER_SIZE_W: u8 = 0x16;
-    /// Character size height (fixed-point)
-    pub const CHARACTER_SIZE_H: u8 = 0x17;
-    /// Character health (u16)
-    pub const CHARACTER_HEALTH: u8 = 0x18;
-    /// Character health cap (u16)
-    pub const CHARACTER_HEALTH_CAP: u8 = 0x19;
-    /// Character energy (byte)
-    pub const CHARACTER_ENERGY: u8 = 0x1A;
-    /// Character energy cap (byte)
-    pub const CHARACTER_ENERGY_CAP: u8 = 0x1B;
-    /// Character power (byte)
-    pub const CHARACTER_POWER: u8 = 0x1C;
-    /// Character weight (byte)
-    pub const CHARACTER_WEIGHT: u8 = 0x1D;
-    /// Character jump force (fixed-point)
-    pub const CHARACTER_JUMP_FORCE: u8 = 0x1E;
-    /// Character move speed (fixed-point)
-    pub const CHARACTER_MOVE_SPEED: u8 = 0x1F;
-
-    // Character Energy System (0x20-0x23)
-    /// Passive energy recovery amount per rate (byte)
-    pub const CHARACTER_ENERGY_REGEN: u8 = 0x20;
-    /// Tick interval for passive energy recovery (byte)
-    pub const CHARACTER_ENERGY_REGEN_RATE: u8 = 0x21;
-    /// Active energy recovery amount per rate during Charge action (byte)
-    pub const CHARACTER_ENERGY_CHARGE: u8 = 0x22;
-    /// Tick interval for active energy recovery during Charge action (byte)
-    pub const CHARACTER_ENERGY_CHARGE_RATE: u8 = 0x23;
-
-    // Character Action System (0x24-0x25)
-    /// Locked action instance ID (byte)
-    pub const CHARACTER_LOCKED_ACTION_ID: u8 = 0x24;
-    /// Number of active status effects (byte)
-    pub const CHARACTER_STATUS_EFFECT_COUNT: u8 = 0x25;
-
-    // Character Collision Flags (0x26-0x29)
-    /// Top collision flag (byte: 0 or 1)
-    pub const CHARACTER_COLLISION_TOP: u8 = 0x26;
-    /// Right collision flag (byte: 0 or 1)
-    pub const CHARACTER_COLLISION_RIGHT: u8 = 0x27;
-    /// Bottom collision flag (byte: 0 or 1)
-    pub const CHARACTER_COLLISION_BOTTOM: u8 = 0x28;
-    /// Left collision flag (byte: 0 or 1)
-    pub const CHARACTER_COLLISION_LEFT: u8 = 0x29;
-
-    // Character Armor Values (0x2A-0x32)
-    /// Armor value for Punct element (byte)
-    pub const CHARACTER_ARMOR_PUNCT: u8 = 0x2A;
-    /// Armor value for Blast element (byte)
-    pub const CHARACTER_ARMOR_BLAST: u8 = 0x2B;
-    /// Armor value for Force element (byte)
-    pub const CHARACTER_ARMOR_FORCE: u8 = 0x2C;
-    /// Armor value for Sever element (byte)
-    pub const CHARACTER_ARMOR_SEVER: u8 = 0x2D;
-    /// Armor value for Heat element (byte)
-    pub const CHARACTER_ARMOR_HEAT: u8 = 0x2E;
-    /// Armor value for Cryo element (byte)
-    pub const CHARACTER_ARMOR_CRYO: u8 = 0x2F;
-    /// Armor value for Jolt element (byte)
-    pub const CHARACTER_ARMOR_JOLT: u8 = 0x30;
-    /// Armor value for Acid element (byte)
-    pub const CHARACTER_ARMOR_ACID: u8 = 0x31;
-    /// Armor value for Virus element (byte)
-    pub const CHARACTER_ARMOR_VIRUS: u8 = 0x32;
-    // Reserved for future character properties: 0x33-0x3F
-
-    // ===== ENTITY CORE PROPERTIES (0x40-0x4F) =====
-    // Reserved range: 0x40-0x4F (16 addresses)
-    /// Entity direction horizontal (byte: 0=left, 1=neutral, 2=right)
-    pub const ENTITY_DIR_HORIZONTAL: u8 = 0x40;
-    /// Entity direction vertical (byte: 0=upward, 1=neutral, 2=downward)
-    pub const ENTITY_DIR_VERTICAL: u8 = 0x41;
-    /// Entity enmity level (byte)
-    pub const ENTITY_ENMITY: u8 = 0x42;
-    /// Entity target ID (byte) - Option<EntityId>
-    pub const ENTITY_TARGET_ID: u8 = 0x43;
-    /// Entity target type (byte)
-    pub const ENTITY_TARGET_TYPE: u8 = 0x44;
-    // Reserved for future entity core properties: 0x45-0x4F
-
-    // ===== SPAWN PROPERTIES (0x50-0x7F) =====
-    // Reserved range: 0x50-0x7F (48 addresses)
-
-    // Spawn Definition Properties (0x50-0x5F)
-    /// Spawn definition damage base (u16) - from definition
-    pub const SPAWN_DEF_DAMAGE_BASE: u8 = 0x50;
-    /// Spawn definition damage range (u16) - from definition
-    pub const SPAWN_DEF_DAMAGE_RANGE: u8 = 0x51;
-    /// Spawn definition crit chance (byte) - from definition
-    pub const SPAWN_DEF_CRIT_CHANCE: u8 = 0x52;
-    /// Spawn definition crit multiplier (byte) - from definition
-    pub const SPAWN_DEF_CRIT_MULTIPLIER: u8 = 0x53;
-    /// Spawn definition chance (byte) - from definition
-    pub const SPAWN_DEF_CHANCE: u8 = 0x54;
-    /// Spawn definition health cap (byte) - from definition
-    pub const SPAWN_DEF_HEALTH_CAP: u8 = 0x55;
-    /// Spawn definition duration (fixed-point) - from definition
-    pub const SPAWN_DEF_DURATION: u8 = 0x56;
-    /// Spawn definition element (byte) - from definition
-    pub const SPAWN_DEF_ELEMENT: u8 = 0x57;
-    /// Spawn definition args[0] (byte) - from definition
-    pub const SPAWN_DEF_ARG0: u8 = 0x58;
-    /// Spawn definition args[1] (byte) - from definition
-    pub const SPAWN_DEF_ARG1: u8 = 0x59;
-    /// Spawn definition args[2] (byte) - from definition
-    pub const SPAWN_DEF_ARG2: u8 = 0x5A;
-    /// Spawn definition args[3] (byte) - from definition
-    pub const SPAWN_DEF_ARG3: u8 = 0x5B;
-    // Reserved for future spawn definition properties: 0x5C-0x5F
-
-    // Spawn Instance Core Properties (0x60-0x6F)
-    /// Spawn core ID (byte)
-    pub const SPAWN_CORE_ID: u8 = 0x60;
-    /// Spawn owner ID (EntityId) - from instance
-    pub const SPAWN_OWNER_ID: u8 = 0x61;
-    /// Spawn owner type (byte) - from instance
-    pub const SPAWN_OWNER_TYPE: u8 = 0x62;
-    /// Spawn position X (fixed-point)
-    pub const SPAWN_POS_X: u8 = 0x63;
-    /// Spawn position Y (fixed-point)
-    pub const SPAWN_POS_Y: u8 = 0x64;
-    /// Spawn velocity X (fixed-point)
-    pub const SPAWN_VEL_X: u8 = 0x65;
-    /// Spawn velocity Y (fixed-point)
-    pub const SPAWN_VEL_Y: u8 = 0x66;
-    /// Spawn health (u16) - from instance
-    pub const SPAWN_INST_HEALTH: u8 = 0x67;
-    /// Spawn health cap (u16) - from instance
-    pub const SPAWN_INST_HEALTH_CAP: u8 = 0x68;
-    /// Spawn rotation (fixed-point) - from instance
-    pub const SPAWN_INST_ROTATION: u8 = 0x69;
-    /// Spawn life span (u16) - from instance
-    pub const SPAWN_INST_LIFE_SPAN: u8 = 0x6A;
-    /// Spawn instance element (byte) - from instance
-    pub const SPAWN_INST_ELEMENT: u8 = 0x6B;
-    // Reserved for future spawn instance properties: 0x6C-0x6F
-
-    // Spawn Instance Runtime Variables (0x70-0x77)
-    /// Spawn instance runtime_vars[0] (byte) - from instance
-    pub const SPAWN_INST_VAR0: u8 = 0x70;
-    /// Spawn instance runtime_vars[1] (byte) - from instance
-    pub const SPAWN_INST_VAR1: u8 = 0x71;
-    /// Spawn instance runtime_vars[2] (byte) - from instance
-    pub const SPAWN_INST_VAR2: u8 = 0x72;
-    /// Spawn instance runtime_vars[3] (byte) - from instance
-    pub const SPAWN_INST_VAR3: u8 = 0x73;
-    /// Spawn instance runtime_fixed[0] (fixed-point) - from instance
-    pub const SPAWN_INST_FIXED0: u8 = 0x74;
-    /// Spawn instance runtime_fixed[1] (fixed-point) - from instance
-    pub const SPAWN_INST_FIXED1: u8 = 0x75;
-    /// Spawn instance runtime_fixed[2] (fixed-point) - from instance
-    pub const SPAWN_INST_FIXED2: u8 = 0x76;
-    /// Spawn instance runtime_fixed[3] (fixed-point) - from instance
-    pub const SPAWN_INST_FIXED3: u8 = 0x77;
-    // Reserved for future spawn properties: 0x78-0x7F
-
-    // ===== ACTION PROPERTIES (0x80-0x9F) =====
-    // Reserved range: 0x80-0x9F (32 addresses)
-
-    // Action Definition Properties (0x80-0x8F)
-    /// Action energy cost (byte) - from definition
-    pub const ACTION_DEF_ENERGY_COST: u8 = 0x80;
-    /// Action cooldown (fixed-point) - from definition
-    pub const ACTION_DEF_COOLDOWN: u8 = 0x81;
-    /// Action args[0] (byte) - from definition
-    pub const ACTION_DEF_ARG0: u8 = 0x82;
-    /// Action args[1] (byte) - from definition
-    pub const ACTION_DEF_ARG1: u8 = 0x83;
-    /// Action args[2] (byte) - from definition
-    pub const ACTION_DEF_ARG2: u8 = 0x84;
-    /// Action args[3] (byte) - from definition
-    pub const ACTION_DEF_ARG3: u8 = 0x85;
-    /// Action args[4] (byte) - from definition
-    pub const ACTION_DEF_ARG4: u8 = 0x86;
-    /// Action args[5] (byte) - from definition
-    pub const ACTION_DEF_ARG5: u8 = 0x87;
-    /// Action args[6] (byte) - from definition
-    pub const ACTION_DEF_ARG6: u8 = 0x88;
-    /// Action args[7] (byte) - from definition
-    pub const ACTION_DEF_ARG7: u8 = 0x89;
-    // Reserved for future action definition properties: 0x8A-0x8F
-
-    // Action Instance Properties (0x90-0x9F)
-    /// Action instance runtime_vars[0] (byte) - from instance
-    pub const ACTION_INST_VAR0: u8 = 0x90;
-    /// Action instance runtime_vars[1] (byte) - from instance
-    pub const ACTION_INST_VAR1: u8 = 0x91;
-    /// Action instance runtime_vars[2] (byte) - from instance
-    pub const ACTION_INST_VAR2: u8 = 0x92;
-    /// Action instance runtime_vars[3] (byte) - from instance
-    pub const ACTION_INST_VAR3: u8 = 0x93;
-    /// Action instance runtime_fixed[0] (fixed-point) - from instance
-    pub const ACTION_INST_FIXED0: u8 = 0x94;
-    /// Action instance runtime_fixed[1] (fixed-point) - from instance
-    pub const ACTION_INST_FIXED1: u8 = 0x95;
-    /// Action instance runtime_fixed[2] (fixed-point) - from instance
-    pub const ACTION_INST_FIXED2: u8 = 0x96;
-    /// Action instance runtime_fixed[3] (fixed-point) - from instance
-    pub const ACTION_INST_FIXED3: u8 = 0x97;
-    /// Action instance cooldown (fixed-point) - from instance
-    pub const ACTION_INST_COOLDOWN: u8 = 0x98;
-    /// Action instance last used frame (fixed-point) - from instance
-    pub const ACTION_INST_LAST_USED_FRAME: u8 = 0x99;
-    // Reserved for future action instance properties: 0x9A-0x9F
-
-    // ===== CONDITION PROPERTIES (0xA0-0xBF) =====
-    // Reserved range: 0xA0-0xBF (32 addresses)
-
-    // Condition Definition Properties (0xA0-0xAF)
-    /// Condition ID (byte) - from definition
-    pub const CONDITION_DEF_ID: u8 = 0xA0;
-    /// Condition energy multiplier (fixed-point) - from definition
-    pub const CONDITION_DEF_ENERGY_MUL: u8 = 0xA1;
-    /// Condition args[0] (byte) - from definition
-    pub const CONDITION_DEF_ARG0: u8 = 0xA2;
-    /// Condition args[1] (byte) - from definition
-    pub const CONDITION_DEF_ARG1: u8 = 0xA3;
-    /// Condition args[2] (byte) - from definition
-    pub const CONDITION_DEF_ARG2: u8 = 0xA4;
-    /// Condition args[3] (byte) - from definition
-    pub const CONDITION_DEF_ARG3: u8 = 0xA5;
-    /// Condition args[4] (byte) - from definition
-    pub const CONDITION_DEF_ARG4: u8 = 0xA6;
-    /// Condition args[5] (byte) - from definition
-    pub const CONDITION_DEF_ARG5: u8 = 0xA7;
-    /// Condition args[6] (byte) - from definition
-    pub const CONDITION_DEF_ARG6: u8 = 0xA8;
-    /// Condition args[7] (byte) - from definition
-    pub const CONDITION_DEF_ARG7: u8 = 0xA9;
-    // Reserved for future condition definition properties: 0xAA-0xAF
-
-    // Condition Instance Properties (0xB0-0xBF)
-    /// Condition instance runtime_vars[0] (byte) - from instance
-    pub const CONDITION_INST_VAR0: u8 = 0xB0;
-    /// Condition instance runtime_vars[1] (byte) - from instance
-    pub const CONDITION_INST_VAR1: u8 = 0xB1;
-    /// Condition instance runtime_vars[2] (byte) - from instance
-    pub const CONDITION_INST_VAR2: u8 = 0xB2;
-    /// Condition instance runtime_vars[3] (byte) - from instance
-    pub const CONDITION_INST_VAR3: u8 = 0xB3;
-    /// Condition instance runtime_fixed[0] (fixed-point) - from instance
-    pub const CONDITION_INST_FIXED0: u8 = 0xB4;
-    /// Condition instance runtime_fixed[1] (fixed-point) - from instance
-    pub const CONDITION_INST_FIXED1: u8 = 0xB5;
-    /// Condition instance runtime_fixed[2] (fixed-point) - from instance
-    pub const CONDITION_INST_FIXED2: u8 = 0xB6;
-    /// Condition instance runtime_fixed[3] (fixed-point) - from instance
-    pub const CONDITION_INST_FIXED3: u8 = 0xB7;
-    // Reserved for future condition instance properties: 0xB8-0xBF
-
-    // ===== STATUS EFFECT PROPERTIES (0xC0-0xDF) =====
-    // Reserved range: 0xC0-0xDF (32 addresses)
-
-    // Status Effect Definition Properties (0xC0-0xCF)
-    /// Status effect duration (fixed-point) - from definition
-    pub const STATUS_EFFECT_DEF_DURATION: u8 = 0xC0;
-    /// Status effect stack limit (byte) - from definition
-    pub const STATUS_EFFECT_DEF_STACK_LIMIT: u8 = 0xC1;
-    /// Status effect reset on stack flag (byte) - from definition
-    pub const STATUS_EFFECT_DEF_RESET_ON_STACK: u8 = 0xC2;
-    /// Status effect chance (byte) - from definition
-    pub const STATUS_EFFECT_DEF_CHANCE: u8 = 0xC3;
-    /// Status effect args[0] (byte) - from definition
-    pub const STATUS_EFFECT_DEF_ARG0: u8 = 0xC4;
-    /// Status effect args[1] (byte) - from definition
-    pub const STATUS_EFFECT_DEF_ARG1: u8 = 0xC5;
-    /// Status effect args[2] (byte) - from definition
-    pub const STATUS_EFFECT_DEF_ARG2: u8 = 0xC6;
-    // Reserved for future status effect definition properties: 0xC7-0xCF
-
-    // Status Effect Instance Properties (0xD0-0xDF)
-    /// Status effect instance runtime_vars[0] (byte) - from instance
-    pub const STATUS_EFFECT_INST_VAR0: u8 = 0xD0;
-    /// Status effect instance runtime_vars[1] (byte) - from instance
-    pub const STATUS_EFFECT_INST_VAR1: u8 = 0xD1;
-    /// Status effect instance runtime_vars[2] (byte) - from instance
-    pub const STATUS_EFFECT_INST_VAR2: u8 = 0xD2;
-    /// Status effect instance runtime_vars[3] (byte) - from instance
-    pub const STATUS_EFFECT_INST_VAR3: u8 = 0xD3;
-    /// Status effect instance runtime_fixed[0] (fixed-point) - from instance
-    pub const STATUS_EFFECT_INST_FIXED0: u8 = 0xD4;
-    /// Status effect instance runtime_fixed[1] (fixed-point) - from instance
-    pub const STATUS_EFFECT_INST_FIXED1: u8 = 0xD5;
-    /// Status effect instance runtime_fixed[2] (fixed-point) - from instance
-    pub const STATUS_EFFECT_INST_FIXED2: u8 = 0xD6;
-    /// Status effect instance runtime_fixed[3] (fixed-point) - from instance
-    pub const STATUS_EFFECT_INST_FIXED3: u8 = 0xD7;
-    /// Status effect instance life span (fixed-point) - from instance
-    pub const STATUS_EFFECT_INST_LIFE_SPAN: u8 = 0xD8;
-    /// Status effect instance stack count (byte) - from instance
-    pub const STATUS_EFFECT_INST_STACK_COUNT: u8 = 0xD9;
-    // Reserved for future status effect instance properties: 0xDA-0xDF
-
-    // ===== RESERVED FOR FUTURE EXPANSION (0xE0-0xFF) =====
-    // Reserved range: 0xE0-0xFF (32 addresses)
-    // Available for new entity types or additional properties
+    named_constants! {
+        // ===== GAME STATE PROPERTIES (0x01-0x0F) =====
+        // Reserved range: 0x01-0x0F (15 addresses)
+        /// Game seed value
+        pub const GAME_SEED: u8 = 0x01;
+        /// Current game frame
+        pub const GAME_FRAME: u8 = 0x02;
+        /// Vertical game gravity (fixed-point, positive = downward)
+        pub const GAME_GRAVITY: u8 = 0x03;
+        /// Horizontal game gravity (fixed-point, positive = rightward)
+        pub const GAME_GRAVITY_X: u8 = 0x04;
+        /// Arena width in pixels (fixed-point, read-only)
+        pub const GAME_ARENA_WIDTH: u8 = 0x05;
+        /// Arena height in pixels (fixed-point, read-only)
+        pub const GAME_ARENA_HEIGHT: u8 = 0x06;
+        /// X of the arena center in pixels (fixed-point, read-only)
+        pub const GAME_ARENA_CENTER_X: u8 = 0x07;
+        /// Y of the arena center in pixels (fixed-point, read-only)
+        pub const GAME_ARENA_CENTER_Y: u8 = 0x08;
+        // Reserved for future game state properties: 0x09-0x0F
+
+        // ===== CHARACTER PROPERTIES (0x10-0x3F) =====
+        // Reserved range: 0x10-0x3F (48 addresses)
+
+        // Character Core Properties (0x10-0x1F)
+        /// Character ID (byte)
+        pub const CHARACTER_ID: u8 = 0x10;
+        /// Character group (byte)
+        pub const CHARACTER_GROUP: u8 = 0x11;
+        /// Character position X (fixed-point)
+        pub const CHARACTER_POS_X: u8 = 0x12;
+        /// Character position Y (fixed-point)
+        pub const CHARACTER_POS_Y: u8 = 0x13;
+        /// Character velocity X (fixed-point)
+        pub const CHARACTER_VEL_X: u8 = 0x14;
+        /// Character velocity Y (fixed-point)
+        pub const CHARACTER_VEL_Y: u8 = 0x15;
+        /// Character size width (fixed-point)
+        pub const CHARACTER_SIZE_W: u8 = 0x16;
+        /// Character size height (fixed-point)
+        pub const CHARACTER_SIZE_H: u8 = 0x17;
+        /// Character health (u16 as fixed-point, saturating at 1023; see CHARACTER_HEALTH_BYTE)
+        pub const CHARACTER_HEALTH: u8 = 0x18;
+        /// Character health cap (u16 as fixed-point, saturating at 1023)
+        pub const CHARACTER_HEALTH_CAP: u8 = 0x19;
+        /// Character energy (byte)
+        pub const CHARACTER_ENERGY: u8 = 0x1A;
+        /// Character energy cap (byte)
+        pub const CHARACTER_ENERGY_CAP: u8 = 0x1B;
+        /// Character power (byte)
+        pub const CHARACTER_POWER: u8 = 0x1C;
+        /// Character weight (byte)
+        pub const CHARACTER_WEIGHT: u8 = 0x1D;
+        /// Character jump force (fixed-point)
+        pub const CHARACTER_JUMP_FORCE: u8 = 0x1E;
+        /// Character move speed (fixed-point)
+        pub const CHARACTER_MOVE_SPEED: u8 = 0x1F;
+
+        // Character Energy System (0x20-0x23)
+        /// Passive energy recovery amount per rate (byte)
+        pub const CHARACTER_ENERGY_REGEN: u8 = 0x20;
+        /// Tick interval for passive energy recovery (byte)
+        pub const CHARACTER_ENERGY_REGEN_RATE: u8 = 0x21;
+        /// Active energy recovery amount per rate during Charge action (byte)
+        pub const CHARACTER_ENERGY_CHARGE: u8 = 0x22;
+        /// Tick interval for active energy recovery during Charge action (byte)
+        pub const CHARACTER_ENERGY_CHARGE_RATE: u8 = 0x23;
+
+        // Character Action System (0x24-0x25)
+        /// Locked action instance ID (byte)
+        pub const CHARACTER_LOCKED_ACTION_ID: u8 = 0x24;
+        /// Number of active status effects (byte)
+        pub const CHARACTER_STATUS_EFFECT_COUNT: u8 = 0x25;
+
+        // Character Collision Flags (0x26-0x29)
+        /// Top collision flag (byte: 0 or 1)
+        pub const CHARACTER_COLLISION_TOP: u8 = 0x26;
+        /// Right collision flag (byte: 0 or 1)
+        pub const CHARACTER_COLLISION_RIGHT: u8 = 0x27;
+        /// Bottom collision flag (byte: 0 or 1)
+        pub const CHARACTER_COLLISION_BOTTOM: u8 = 0x28;
+        /// Left collision flag (byte: 0 or 1)
+        pub const CHARACTER_COLLISION_LEFT: u8 = 0x29;
+
+        // Character Armor Values (0x2A-0x32)
+        /// Armor value for Punct element (byte)
+        pub const CHARACTER_ARMOR_PUNCT: u8 = 0x2A;
+        /// Armor value for Blast element (byte)
+        pub const CHARACTER_ARMOR_BLAST: u8 = 0x2B;
+        /// Armor value for Force element (byte)
+        pub const CHARACTER_ARMOR_FORCE: u8 = 0x2C;
+        /// Armor value for Sever element (byte)
+        pub const CHARACTER_ARMOR_SEVER: u8 = 0x2D;
+        /// Armor value for Heat element (byte)
+        pub const CHARACTER_ARMOR_HEAT: u8 = 0x2E;
+        /// Armor value for Cryo element (byte)
+        pub const CHARACTER_ARMOR_CRYO: u8 = 0x2F;
+        /// Armor value for Jolt element (byte)
+        pub const CHARACTER_ARMOR_JOLT: u8 = 0x30;
+        /// Armor value for Acid element (byte)
+        pub const CHARACTER_ARMOR_ACID: u8 = 0x31;
+        /// Armor value for Virus element (byte)
+        pub const CHARACTER_ARMOR_VIRUS: u8 = 0x32;
+
+        // Character Match Statistics (0x33-0x36, read-only)
+        /// Total damage dealt to other characters (fixed-point)
+        pub const CHARACTER_DAMAGE_DEALT: u8 = 0x33;
+        /// Total damage taken from any source (fixed-point)
+        pub const CHARACTER_DAMAGE_TAKEN: u8 = 0x34;
+        /// Frames since the character last dealt damage (fixed-point)
+        pub const CHARACTER_FRAMES_SINCE_HIT: u8 = 0x35;
+        /// Frames since the character last took damage (fixed-point)
+        pub const CHARACTER_FRAMES_SINCE_DAMAGED: u8 = 0x36;
+
+        // Character Combat State (0x37-0x38, read-only)
+        /// Damage taken within the game's damage window (fixed-point)
+        pub const CHARACTER_RECENT_DAMAGE: u8 = 0x37;
+        /// Whether damage was taken within the damage window (byte: 0 or 1)
+        pub const CHARACTER_IN_COMBAT: u8 = 0x38;
+
+        // Character Detection (0x39-0x3A)
+        /// Enemy detection radius in pixels (byte)
+        pub const CHARACTER_DETECTION_RADIUS: u8 = 0x39;
+        /// Bitmask of enemies detected this frame, bit n = character id n (byte, read-only)
+        pub const CHARACTER_DETECTED_ENEMIES: u8 = 0x3A;
+
+        // Character Footing (0x3B-0x3E, read-only, updated by the physics pass)
+        /// Tile type under the character's feet (byte)
+        pub const CHARACTER_GROUND_TILE: u8 = 0x3B;
+        /// Tile column under the character's feet (byte, 255 = outside the map)
+        pub const CHARACTER_GROUND_TILE_X: u8 = 0x3C;
+        /// Tile row under the character's feet (byte, 255 = outside the map)
+        pub const CHARACTER_GROUND_TILE_Y: u8 = 0x3D;
+        /// Tile type just beyond the character's facing edge (byte)
+        pub const CHARACTER_FACING_TILE: u8 = 0x3E;
+        /// Character health clamped to a byte (0-255); writes set health directly
+        pub const CHARACTER_HEALTH_BYTE: u8 = 0x3F;
+
+        // ===== ENTITY CORE PROPERTIES (0x40-0x4F) =====
+        // Reserved range: 0x40-0x4F (16 addresses)
+        /// Entity direction horizontal (byte: 0=left, 1=neutral, 2=right)
+        pub const ENTITY_DIR_HORIZONTAL: u8 = 0x40;
+        /// Entity direction vertical (byte: 0=upward, 1=neutral, 2=downward)
+        pub const ENTITY_DIR_VERTICAL: u8 = 0x41;
+        /// Entity enmity level (byte)
+        pub const ENTITY_ENMITY: u8 = 0x42;
+        /// Entity target ID (byte) - Option<EntityId>
+        pub const ENTITY_TARGET_ID: u8 = 0x43;
+        /// Entity target type (byte)
+        pub const ENTITY_TARGET_TYPE: u8 = 0x44;
+        // Reserved for future entity core properties: 0x45-0x4F
+
+        // ===== SPAWN PROPERTIES (0x50-0x7F) =====
+        // Reserved range: 0x50-0x7F (48 addresses)
+
+        // Spawn Definition Properties (0x50-0x5F)
+        /// Spawn definition damage base (u16) - from definition
+        pub const SPAWN_DEF_DAMAGE_BASE: u8 = 0x50;
+        /// Spawn definition damage range (u16) - from definition
+        pub const SPAWN_DEF_DAMAGE_RANGE: u8 = 0x51;
+        /// Spawn definition crit chance (byte) - from definition
+        pub const SPAWN_DEF_CRIT_CHANCE: u8 = 0x52;
+        /// Spawn definition crit multiplier (byte) - from definition
+        pub const SPAWN_DEF_CRIT_MULTIPLIER: u8 = 0x53;
+        /// Spawn definition chance (byte) - from definition
+        pub const SPAWN_DEF_CHANCE: u8 = 0x54;
+        /// Spawn definition health cap (byte) - from definition
+        pub const SPAWN_DEF_HEALTH_CAP: u8 = 0x55;
+        /// Spawn definition duration (fixed-point) - from definition
+        pub const SPAWN_DEF_DURATION: u8 = 0x56;
+        /// Spawn definition element (byte) - from definition
+        pub const SPAWN_DEF_ELEMENT: u8 = 0x57;
+        /// Spawn definition args[0] (byte) - from definition
+        pub const SPAWN_DEF_ARG0: u8 = 0x58;
+        /// Spawn definition args[1] (byte) - from definition
+        pub const SPAWN_DEF_ARG1: u8 = 0x59;
+        /// Spawn definition args[2] (byte) - from definition
+        pub const SPAWN_DEF_ARG2: u8 = 0x5A;
+        /// Spawn definition args[3] (byte) - from definition
+        pub const SPAWN_DEF_ARG3: u8 = 0x5B;
+        /// Spawn definition spawn-vs-spawn collision mask (byte) - from definition
+        pub const SPAWN_DEF_COLLISION_MASK: u8 = 0x5C;
+        /// Spawn definition damage falloff start distance (u16 as fixed-point, saturating at
+        /// 1023) - from definition
+        pub const SPAWN_DEF_FALLOFF_START: u8 = 0x5D;
+        /// Spawn definition damage falloff end distance (u16 as fixed-point, saturating at
+        /// 1023) - from definition
+        pub const SPAWN_DEF_FALLOFF_END: u8 = 0x5E;
+        // Reserved for future spawn definition properties: 0x5F
+
+        // Spawn Instance Core Properties (0x60-0x6F)
+        /// Spawn core ID (byte)
+        pub const SPAWN_CORE_ID: u8 = 0x60;
+        /// Spawn owner ID (EntityId) - from instance
+        pub const SPAWN_OWNER_ID: u8 = 0x61;
+        /// Spawn owner type (byte) - from instance
+        pub const SPAWN_OWNER_TYPE: u8 = 0x62;
+        /// Spawn position X (fixed-point)
+        pub const SPAWN_POS_X: u8 = 0x63;
+        /// Spawn position Y (fixed-point)
+        pub const SPAWN_POS_Y: u8 = 0x64;
+        /// Spawn velocity X (fixed-point)
+        pub const SPAWN_VEL_X: u8 = 0x65;
+        /// Spawn velocity Y (fixed-point)
+        pub const SPAWN_VEL_Y: u8 = 0x66;
+        /// Spawn health (u16 as fixed-point, saturating at 1023) - from instance
+        pub const SPAWN_INST_HEALTH: u8 = 0x67;
+        /// Spawn health cap (u16 as fixed-point, saturating at 1023) - from instance
+        pub const SPAWN_INST_HEALTH_CAP: u8 = 0x68;
+        /// Spawn rotation (fixed-point) - from instance
+        pub const SPAWN_INST_ROTATION: u8 = 0x69;
+        /// Spawn life span (u16) - from instance
+        pub const SPAWN_INST_LIFE_SPAN: u8 = 0x6A;
+        /// Spawn instance element (byte) - from instance
+        pub const SPAWN_INST_ELEMENT: u8 = 0x6B;
+        /// Spawn origin X where it was created (fixed-point) - from instance
+        pub const SPAWN_INST_ORIGIN_X: u8 = 0x6C;
+        /// Spawn origin Y where it was created (fixed-point) - from instance
+        pub const SPAWN_INST_ORIGIN_Y: u8 = 0x6D;
+        /// Spawn damage after distance falloff (u16 as fixed-point, saturating at 1023,
+        /// read-only) - computed
+        pub const SPAWN_INST_DAMAGE: u8 = 0x6E;
+        /// Spawn health clamped to a byte (0-255) - from instance
+        pub const SPAWN_INST_HEALTH_BYTE: u8 = 0x6F;
+
+        // Spawn Instance Runtime Variables (0x70-0x77)
+        /// Spawn instance runtime_vars[0] (byte) - from instance
+        pub const SPAWN_INST_VAR0: u8 = 0x70;
+        /// Spawn instance runtime_vars[1] (byte) - from instance
+        pub const SPAWN_INST_VAR1: u8 = 0x71;
+        /// Spawn instance runtime_vars[2] (byte) - from instance
+        pub const SPAWN_INST_VAR2: u8 = 0x72;
+        /// Spawn instance runtime_vars[3] (byte) - from instance
+        pub const SPAWN_INST_VAR3: u8 = 0x73;
+        /// Spawn instance runtime_fixed[0] (fixed-point) - from instance
+        pub const SPAWN_INST_FIXED0: u8 = 0x74;
+        /// Spawn instance runtime_fixed[1] (fixed-point) - from instance
+        pub const SPAWN_INST_FIXED1: u8 = 0x75;
+        /// Spawn instance runtime_fixed[2] (fixed-point) - from instance
+        pub const SPAWN_INST_FIXED2: u8 = 0x76;
+        /// Spawn instance runtime_fixed[3] (fixed-point) - from instance
+        pub const SPAWN_INST_FIXED3: u8 = 0x77;
+
+        // Spawn Definition Static Variables (0x78-0x7B), shared by all spawns of the definition
+        /// Spawn definition static_vars[0] (byte, writable) - from `GameState::static_vars`
+        pub const SPAWN_DEF_STATIC0: u8 = 0x78;
+        /// Spawn definition static_vars[1] (byte, writable) - from `GameState::static_vars`
+        pub const SPAWN_DEF_STATIC1: u8 = 0x79;
+        /// Spawn definition static_vars[2] (byte, writable) - from `GameState::static_vars`
+        pub const SPAWN_DEF_STATIC2: u8 = 0x7A;
+        /// Spawn definition static_vars[3] (byte, writable) - from `GameState::static_vars`
+        pub const SPAWN_DEF_STATIC3: u8 = 0x7B;
+        // Reserved for future spawn properties: 0x7C-0x7F
+
+        // ===== ACTION PROPERTIES (0x80-0x9F) =====
+        // Reserved range: 0x80-0x9F (32 addresses)
+
+        // Action Definition Properties (0x80-0x8F)
+        /// Action energy cost (byte) - from definition
+        pub const ACTION_DEF_ENERGY_COST: u8 = 0x80;
+        /// Action cooldown (fixed-point) - from definition
+        pub const ACTION_DEF_COOLDOWN: u8 = 0x81;
+        /// Action args[0] (byte) - from definition
+        pub const ACTION_DEF_ARG0: u8 = 0x82;
+        /// Action args[1] (byte) - from definition
+        pub const ACTION_DEF_ARG1: u8 = 0x83;
+        /// Action args[2] (byte) - from definition
+        pub const ACTION_DEF_ARG2: u8 = 0x84;
+        /// Action args[3] (byte) - from definition
+        pub const ACTION_DEF_ARG3: u8 = 0x85;
+        /// Action args[4] (byte) - from definition
+        pub const ACTION_DEF_ARG4: u8 = 0x86;
+        /// Action args[5] (byte) - from definition
+        pub const ACTION_DEF_ARG5: u8 = 0x87;
+        /// Action args[6] (byte) - from definition
+        pub const ACTION_DEF_ARG6: u8 = 0x88;
+        /// Action args[7] (byte) - from definition
+        pub const ACTION_DEF_ARG7: u8 = 0x89;
+        /// Energy the action costs the character right now (byte, read-only); condition scripts
+        /// read the cost of the action their behavior would run (0 outside a behavior)
+        pub const ACTION_ENERGY_REQUIRED: u8 = 0x8A;
+        /// Action definition static_vars[0] (byte, writable) - from `GameState::static_vars`
+        pub const ACTION_DEF_STATIC0: u8 = 0x8B;
+        /// Action definition static_vars[1] (byte, writable) - from `GameState::static_vars`
+        pub const ACTION_DEF_STATIC1: u8 = 0x8C;
+        /// Action definition static_vars[2] (byte, writable) - from `GameState::static_vars`
+        pub const ACTION_DEF_STATIC2: u8 = 0x8D;
+        /// Action definition static_vars[3] (byte, writable) - from `GameState::static_vars`
+        pub const ACTION_DEF_STATIC3: u8 = 0x8E;
+        // Reserved for future action definition properties: 0x8F
+
+        // Action Instance Properties (0x90-0x9F)
+        /// Action instance runtime_vars[0] (byte) - from instance
+        pub const ACTION_INST_VAR0: u8 = 0x90;
+        /// Action instance runtime_vars[1] (byte) - from instance
+        pub const ACTION_INST_VAR1: u8 = 0x91;
+        /// Action instance runtime_vars[2] (byte) - from instance
+        pub const ACTION_INST_VAR2: u8 = 0x92;
+        /// Action instance runtime_vars[3] (byte) - from instance
+        pub const ACTION_INST_VAR3: u8 = 0x93;
+        /// Action instance runtime_fixed[0] (fixed-point) - from instance
+        pub const ACTION_INST_FIXED0: u8 = 0x94;
+        /// Action instance runtime_fixed[1] (fixed-point) - from instance
+        pub const ACTION_INST_FIXED1: u8 = 0x95;
+        /// Action instance runtime_fixed[2] (fixed-point) - from instance
+        pub const ACTION_INST_FIXED2: u8 = 0x96;
+        /// Action instance runtime_fixed[3] (fixed-point) - from instance
+        pub const ACTION_INST_FIXED3: u8 = 0x97;
+        /// Action instance cooldown (fixed-point) - from instance
+        pub const ACTION_INST_COOLDOWN: u8 = 0x98;
+        /// Action instance last used frame (fixed-point) - from instance
+        pub const ACTION_INST_LAST_USED_FRAME: u8 = 0x99;
+        // Reserved for future action instance properties: 0x9A-0x9F
+
+        // ===== CONDITION PROPERTIES (0xA0-0xBF) =====
+        // Reserved range: 0xA0-0xBF (32 addresses)
+
+        // Condition Definition Properties (0xA0-0xAF)
+        /// Condition ID (byte) - from definition
+        pub const CONDITION_DEF_ID: u8 = 0xA0;
+        /// Condition energy multiplier (fixed-point) - from definition
+        pub const CONDITION_DEF_ENERGY_MUL: u8 = 0xA1;
+        /// Condition args[0] (byte) - from definition
+        pub const CONDITION_DEF_ARG0: u8 = 0xA2;
+        /// Condition args[1] (byte) - from definition
+        pub const CONDITION_DEF_ARG1: u8 = 0xA3;
+        /// Condition args[2] (byte) - from definition
+        pub const CONDITION_DEF_ARG2: u8 = 0xA4;
+        /// Condition args[3] (byte) - from definition
+        pub const CONDITION_DEF_ARG3: u8 = 0xA5;
+        /// Condition args[4] (byte) - from definition
+        pub const CONDITION_DEF_ARG4: u8 = 0xA6;
+        /// Condition args[5] (byte) - from definition
+        pub const CONDITION_DEF_ARG5: u8 = 0xA7;
+        /// Condition args[6] (byte) - from definition
+        pub const CONDITION_DEF_ARG6: u8 = 0xA8;
+        /// Condition args[7] (byte) - from definition
+        pub const CONDITION_DEF_ARG7: u8 = 0xA9;
+        /// Condition definition static_vars[0] (byte, writable) - from `GameState::static_vars`
+        pub const CONDITION_DEF_STATIC0: u8 = 0xAA;
+        /// Condition definition static_vars[1] (byte, writable) - from `GameState::static_vars`
+        pub const CONDITION_DEF_STATIC1: u8 = 0xAB;
+        /// Condition definition static_vars[2] (byte, writable) - from `GameState::static_vars`
+        pub const CONDITION_DEF_STATIC2: u8 = 0xAC;
+        /// Condition definition static_vars[3] (byte, writable) - from `GameState::static_vars`
+        pub const CONDITION_DEF_STATIC3: u8 = 0xAD;
+        // Reserved for future condition definition properties: 0xAE-0xAF
+
+        // Condition Instance Properties (0xB0-0xBF)
+        /// Condition instance runtime_vars[0] (byte) - from instance
+        pub const CONDITION_INST_VAR0: u8 = 0xB0;
+        /// Condition instance runtime_vars[1] (byte) - from instance
+        pub const CONDITION_INST_VAR1: u8 = 0xB1;
+        /// Condition instance runtime_vars[2] (byte) - from instance
+        pub const CONDITION_INST_VAR2: u8 = 0xB2;
+        /// Condition instance runtime_vars[3] (byte) - from instance
+        pub const CONDITION_INST_VAR3: u8 = 0xB3;
+        /// Condition instance runtime_fixed[0] (fixed-point) - from instance
+        pub const CONDITION_INST_FIXED0: u8 = 0xB4;
+        /// Condition instance runtime_fixed[1] (fixed-point) - from instance
+        pub const CONDITION_INST_FIXED1: u8 = 0xB5;
+        /// Condition instance runtime_fixed[2] (fixed-point) - from instance
+        pub const CONDITION_INST_FIXED2: u8 = 0xB6;
+        /// Condition instance runtime_fixed[3] (fixed-point) - from instance
+        pub const CONDITION_INST_FIXED3: u8 = 0xB7;
+        // Reserved for future condition instance properties: 0xB8-0xBF
+
+        // ===== STATUS EFFECT PROPERTIES (0xC0-0xDF) =====
+        // Reserved range: 0xC0-0xDF (32 addresses)
+
+        // Status Effect Definition Properties (0xC0-0xCF)
+        /// Status effect duration (fixed-point) - from definition
+        pub const STATUS_EFFECT_DEF_DURATION: u8 = 0xC0;
+        /// Status effect stack limit (byte) - from definition
+        pub const STATUS_EFFECT_DEF_STACK_LIMIT: u8 = 0xC1;
+        /// Status effect reset on stack flag (byte) - from definition
+        pub const STATUS_EFFECT_DEF_RESET_ON_STACK: u8 = 0xC2;
+        /// Status effect chance (byte) - from definition
+        pub const STATUS_EFFECT_DEF_CHANCE: u8 = 0xC3;
+        /// Status effect args[0] (byte) - from definition
+        pub const STATUS_EFFECT_DEF_ARG0: u8 = 0xC4;
+        /// Status effect args[1] (byte) - from definition
+        pub const STATUS_EFFECT_DEF_ARG1: u8 = 0xC5;
+        /// Status effect args[2] (byte) - from definition
+        pub const STATUS_EFFECT_DEF_ARG2: u8 = 0xC6;
+        /// Status effect stacking rule (byte, 0=Stack, 1=Refresh, 2=Ignore) - from definition
+        pub const STATUS_EFFECT_DEF_STACK_BEHAVIOR: u8 = 0xC7;
+        /// Status effect definition static_vars[0] (byte, writable) - from `GameState::static_vars`
+        pub const STATUS_EFFECT_DEF_STATIC0: u8 = 0xC8;
+        /// Status effect definition static_vars[1] (byte, writable) - from `GameState::static_vars`
+        pub const STATUS_EFFECT_DEF_STATIC1: u8 = 0xC9;
+        /// Status effect definition static_vars[2] (byte, writable) - from `GameState::static_vars`
+        pub const STATUS_EFFECT_DEF_STATIC2: u8 = 0xCA;
+        /// Status effect definition static_vars[3] (byte, writable) - from `GameState::static_vars`
+        pub const STATUS_EFFECT_DEF_STATIC3: u8 = 0xCB;
+        // Reserved for future status effect definition properties: 0xCC-0xCF
+
+        // Status Effect Instance Properties (0xD0-0xDF)
+        /// Status effect instance runtime_vars[0] (byte) - from instance
+        pub const STATUS_EFFECT_INST_VAR0: u8 = 0xD0;
+        /// Status effect instance runtime_vars[1] (byte) - from instance
+        pub const STATUS_EFFECT_INST_VAR1: u8 = 0xD1;
+        /// Status effect instance runtime_vars[2] (byte) - from instance
+        pub const STATUS_EFFECT_INST_VAR2: u8 = 0xD2;
+        /// Status effect instance runtime_vars[3] (byte) - from instance
+        pub const STATUS_EFFECT_INST_VAR3: u8 = 0xD3;
+        /// Status effect instance runtime_fixed[0] (fixed-point) - from instance
+        pub const STATUS_EFFECT_INST_FIXED0: u8 = 0xD4;
+        /// Status effect instance runtime_fixed[1] (fixed-point) - from instance
+        pub const STATUS_EFFECT_INST_FIXED1: u8 = 0xD5;
+        /// Status effect instance runtime_fixed[2] (fixed-point) - from instance
+        pub const STATUS_EFFECT_INST_FIXED2: u8 = 0xD6;
+        /// Status effect instance runtime_fixed[3] (fixed-point) - from instance
+        pub const STATUS_EFFECT_INST_FIXED3: u8 = 0xD7;
+        /// Status effect instance life span (fixed-point) - from instance
+        pub const STATUS_EFFECT_INST_LIFE_SPAN: u8 = 0xD8;
+        /// Status effect instance stack count (byte) - from instance
+        pub const STATUS_EFFECT_INST_STACK_COUNT: u8 = 0xD9;
+        // Reserved for future status effect instance properties: 0xDA-0xDF
+
+        // ===== CHARACTER TERRAIN PROPERTIES (0xE0-0xEF) =====
+        // Reserved range: 0xE0-0xEF (16 addresses, read-only, updated by the physics pass)
+        /// Terrain contact bits, see `terrain_contact` (byte)
+        pub const CHARACTER_TERRAIN_CONTACT: u8 = 0xE0;
+        // Reserved for future character terrain properties: 0xE1-0xEF
+
+        // ===== CHARACTER ENERGY OVERFLOW PROPERTIES (0xF0-0xF2) =====
+        /// Overheal shield absorbing hit damage before health (fixed-point, writable)
+        pub const CHARACTER_SHIELD: u8 = 0xF0;
+        /// Charge stacks built from overflow energy (byte, writable, capped at
+        /// `core::MAX_CHARGE_STACKS`)
+        pub const CHARACTER_CHARGE: u8 = 0xF1;
+        /// Energy overflow mode, see `energy_overflow` (byte, writable)
+        pub const CHARACTER_OVERFLOW_MODE: u8 = 0xF2;
+
+        // ===== RESERVED FOR FUTURE EXPANSION (0xF3-0xFF) =====
+        // Reserved range: 0xF3-0xFF (13 addresses)
+        // Available for new entity types or additional properties
+    }
+}
+
+/// Exit values of a spawn's collision script when it touches a character
+///
+/// Empty collision scripts exit with 0, so a spawn without a script is consumed on hit.
+pub mod hit_result {
+    named_constants! {
+        /// Apply damage and remove the spawn
+        pub const CONSUME: u8 = 0;
+        /// Apply damage and reverse the spawn's velocity; the spawn keeps flying
+        pub const BOUNCE: u8 = 1;
+        /// No damage and no removal (the script handled the hit itself)
+        pub const PASS: u8 = 2;
+    }
+}
+
+/// Bits of `CHARACTER_TERRAIN_CONTACT` (see `tilemap::TileType`)
+///
+/// Slopes and one-way platforms only catch characters moving down onto their surface, so
+/// characters jump up through them and stand on top. Spawns pass through both.
+pub mod terrain_contact {
+    named_constants! {
+        /// Standing on a slope surface
+        pub const SLOPE: u8 = 1;
+        /// Standing on a one-way platform tile or a platform spawn
+        pub const PLATFORM: u8 = 2;
+        /// Hitbox center inside a ladder tile; gravity does not pull the character
+        pub const LADDER: u8 = 4;
+        /// Hitbox overlapping a hazard tile
+        pub const HAZARD: u8 = 8;
+    }
+}
+
+/// Destination modes of the TELEPORT operator
+///
+/// Destinations are clamped into the world. A destination blocked by solid tiles is pulled
+/// back towards the starting position until the entity fits, so a blink into a wall stops
+/// short of it; the teleport fails only when no spot along the way is free.
+pub mod teleport_mode {
+    named_constants! {
+        /// Move by (x, y) pixels from the current position
+        pub const OFFSET: u8 = 0;
+        /// Move to the current target's position plus (x, y) pixels
+        pub const TARGET: u8 = 1;
+        /// Move a random whole number of tiles, up to (x, y) tiles on each axis, to a free spot
+        pub const RANDOM: u8 = 2;
+    }
+}
+
+/// Queries of the FIND_TARGET and ACQUIRE_TARGET operators (see `crate::targeting`)
+///
+/// Candidates are the standing characters of other groups. Ties go to the nearest
+/// candidate, then to the lowest character id, so every query is deterministic.
+pub mod target_query {
+    named_constants! {
+        /// Shortest distance between hitbox centers
+        pub const NEAREST: u8 = 0;
+        /// Least health left
+        pub const LOWEST_HEALTH: u8 = 1;
+        /// Highest `ENTITY_ENMITY`
+        pub const HIGHEST_ENMITY: u8 = 2;
+        /// Highest aggro held toward the candidate (a spawn uses its owner's aggro)
+        pub const HIGHEST_AGGRO: u8 = 3;
+    }
+}
+
+/// Moves stored by the PATH_DIRECTION_TO operator
+///
+/// Characters jump as far as their jump force, move speed and the arena gravity carry them;
+/// spawns only walk. Ladders are not climbed.
+pub mod path_step {
+    named_constants! {
+        /// Standing on the target's cell
+        pub const ARRIVED: u8 = 0;
+        pub const LEFT: u8 = 1;
+        pub const RIGHT: u8 = 2;
+        pub const JUMP_LEFT: u8 = 3;
+        pub const JUMP_RIGHT: u8 = 4;
+        pub const JUMP_UP: u8 = 5;
+        /// The target does not exist, has no footing below it or cannot be reached
+        pub const NO_PATH: u8 = 255;
+    }
+}
+
+/// Directions of the DASH operator, clockwise from right (y grows downward)
+///
+/// A dash covers its distance in equal steps over its frames. The physics system sweeps
+/// each step against solid tiles, so a dash stops at the first wall in its way.
+pub mod dash_direction {
+    named_constants! {
+        pub const RIGHT: u8 = 0;
+        pub const DOWN_RIGHT: u8 = 1;
+        pub const DOWN: u8 = 2;
+        pub const DOWN_LEFT: u8 = 3;
+        pub const LEFT: u8 = 4;
+        pub const UP_LEFT: u8 = 5;
+        pub const UP: u8 = 6;
+        pub const UP_RIGHT: u8 = 7;
+        /// Left or right, whichever way the character faces (fails when facing neutral)
+        pub const FACING: u8 = 8;
+    }
+}
+
+/// Grapple modes of spawn definitions (`SpawnDefinition::grapple`)
+///
+/// A grapple starts when the spawn hits a character or touches solid tiles. The pulled
+/// character moves at `grapple_speed` with velocity and gravity suspended; the pull ends on
+/// arrival and breaks when blocked by tiles, after `grapple_frames`, on a dash, or when the
+/// grappling character (or the character being pulled toward) is defeated.
+pub mod grapple_mode {
+    named_constants! {
+        /// Not a grapple
+        pub const NONE: u8 = 0;
+        /// Pull the owner toward the hit point
+        pub const PULL_OWNER: u8 = 1;
+        /// Pull the hit character toward the owner; on terrain, pull the owner instead
+        pub const PULL_TARGET: u8 = 2;
+    }
+}
+
+/// Energy overflow modes of characters (`Character::overflow_mode`)
+///
+/// Energy gained above the cap by regeneration or energy steals is banked and converted in
+/// the status phase. Shields absorb spawn hit damage before health; charge stacks are read
+/// and spent by scripts through `CHARACTER_CHARGE`.
+pub mod energy_overflow {
+    named_constants! {
+        /// Energy above the cap is lost
+        pub const NONE: u8 = 0;
+        /// Each overflow energy adds a shield point, up to the health cap
+        pub const SHIELD: u8 = 1;
+        /// Every `core::ENERGY_PER_CHARGE` overflow energy adds a charge stack, up to
+        /// `core::MAX_CHARGE_STACKS`
+        pub const CHARGE: u8 = 2;
+    }
+}
+
+/// Per-context property permission tables
+///
+/// Every context may read any property. Writes are limited to the address ranges listed
+/// for the executing context; conditions are read-only and may not write at all.
+pub mod property_permission {
+    use crate::script::ScriptContextKind;
+
+    /// Inclusive address ranges writable from condition scripts
+    pub const CONDITION_WRITABLE: &[(u8, u8)] = &[];
+    /// Inclusive address ranges writable from action scripts
+    pub const ACTION_WRITABLE: &[(u8, u8)] = &[
+        (0x01, 0x0F),
+        (0x10, 0x4F),
+        (0x50, 0x7F),
+        (0x80, 0x9F),
+        (0xF0, 0xF2),
+    ];
+    /// Inclusive address ranges writable from spawn scripts
+    pub const SPAWN_WRITABLE: &[(u8, u8)] =
+        &[(0x01, 0x0F), (0x10, 0x4F), (0x50, 0x7F), (0xF0, 0xF2)];
+    /// Inclusive address ranges writable from status effect scripts
+    pub const STATUS_EFFECT_WRITABLE: &[(u8, u8)] = &[
+        (0x01, 0x0F),
+        (0x10, 0x4F),
+        (0x50, 0x7F),
+        (0xC0, 0xDF),
+        (0xF0, 0xF2),
+    ];
+
+    /// Write permission table for a script context
+    pub const fn writable_ranges(context: ScriptContextKind) -> &'static [(u8, u8)] {
+        match context {
+            ScriptContextKind::Condition => CONDITION_WRITABLE,
+            ScriptContextKind::Action => ACTION_WRITABLE,
+            ScriptContextKind::Spawn => SPAWN_WRITABLE,
+            ScriptContextKind::StatusEffect => STATUS_EFFECT_WRITABLE,
+        }
+    }
+
+    /// Check if scripts running in a context may write a property address
+    pub fn is_writable(context: ScriptContextKind, address: u8) -> bool {
+        writable_ranges(context)
+            .iter()
+            .any(|&(start, end)| address >= start && address <= end)
+    }
+}
+
+/// Built-in baseline characters for configurations that do not tune every stat
+///
+/// Armor is indexed by `Element` (Punct, Blast, Force, Sever, Heat, Cryo, Jolt, Acid, Virus).
+pub mod archetype {
+    use crate::entity::Archetype;
+    use crate::math::Fixed;
+
+    /// Slow, heavy close-range fighter with high health and physical armor
+    pub const BRUISER: Archetype = Archetype {
+        size: (16, 24),
+        health: 160,
+        energy: 60,
+        power: 12,
+        weight: 160,
+        jump_force: Fixed::from_raw(144), // 4.5
+        move_speed: Fixed::from_raw(64),  // 2
+        wall_slide_speed: Fixed::ZERO,
+        armor: [115, 110, 120, 110, 100, 100, 95, 100, 100],
+        energy_regen: 1,
+        energy_regen_rate: 12,
+        energy_charge: 3,
+        energy_charge_rate: 6,
+        detection_radius: 64,
+    };
+
+    /// Light long-range shooter with a large energy pool and wide detection
+    pub const SNIPER: Archetype = Archetype {
+        size: (14, 20),
+        health: 80,
+        energy: 140,
+        power: 8,
+        weight: 70,
+        jump_force: Fixed::from_int(5),
+        move_speed: Fixed::from_int(3),
+        wall_slide_speed: Fixed::from_int(1),
+        armor: [95, 90, 90, 95, 100, 100, 105, 100, 100],
+        energy_regen: 2,
+        energy_regen_rate: 10,
+        energy_charge: 5,
+        energy_charge_rate: 5,
+        detection_radius: 160,
+    };
+
+    /// Mobile ally backer with fast energy recovery and status effect resistance
+    pub const SUPPORT: Archetype = Archetype {
+        size: (16, 20),
+        health: 100,
+        energy: 120,
+        power: 4,
+        weight: 90,
+        jump_force: Fixed::from_raw(176), // 5.5
+        move_speed: Fixed::from_raw(112), // 3.5
+        wall_slide_speed: Fixed::from_int(1),
+        armor: [100, 100, 100, 100, 105, 105, 110, 115, 115],
+        energy_regen: 3,
+        energy_regen_rate: 8,
+        energy_charge: 6,
+        energy_charge_rate: 4,
+        detection_radius: 112,
+    };
+
+    /// Every archetype as (name, stats), in declaration order
+    pub const ALL: &[(&str, Archetype)] = &[
+        ("bruiser", BRUISER),
+        ("sniper", SNIPER),
+        ("support", SUPPORT),
+    ];
+
+    /// Look up an archetype by its lowercase name
+    pub fn find(name: &str) -> Option<&'static Archetype> {
+        ALL.iter()
+            .find(|(archetype_name, _)| *archetype_name == name)
+            .map(|(_, archetype)| archetype)
+    }
 }
@@ -5,7 +5,27 @@
 /// Game timing constants
 pub const FRAMES_PER_SECOND: u16 = 60;
 pub const GAME_DURATION_SECONDS: u16 = 64;
-pub const MAX_FRAMES: u16 = FRAMES_PER_SECOND * GAME_DURATION_SECONDS; // 3840
+pub const MAX_FRAMES: Frame = FRAMES_PER_SECOND as Frame * GAME_DURATION_SECONDS as Frame; // 3840
+
+/// Frame counter type, wide enough for long training sessions without wraparound
+pub type Frame = u32;
+/// Frame timestamp sentinel meaning "never happened"
+pub const NEVER_FRAME: Frame = Frame::MAX;
+
+/// Default recent-damage window used for "in combat" tracking
+pub const DEFAULT_DAMAGE_WINDOW: u16 = FRAMES_PER_SECOND * 3; // 180
+
+/// Aggro a taunted character gains toward its taunter every frame
+pub const TAUNT_AGGRO_PER_FRAME: u16 = 4;
+
+/// Frames between aggro decay steps (each step removes a quarter of every entry)
+pub const AGGRO_DECAY_INTERVAL: Frame = 30;
+
+/// Default number of recent frames kept in the event history
+pub const DEFAULT_EVENT_HISTORY: u16 = FRAMES_PER_SECOND * 5; // 300
+
+/// Most events an undrained event queue holds before dropping the oldest
+pub const MAX_QUEUED_EVENTS: usize = 4096;
 
 /// Screen dimensions
 pub const SCREEN_WIDTH: u16 = 256;
@@ -14,12 +34,46 @@ pub const TILE_SIZE: u8 = 16;
 pub const TILEMAP_WIDTH: usize = 16;
 pub const TILEMAP_HEIGHT: usize = 15;
 
+/// Pixels a character already on a slope is pulled down per frame to stay on it downhill
+pub const SLOPE_SNAP: i32 = 4;
+
+/// Number of damage elements (one armor slot per element)
+pub const ELEMENT_COUNT: usize = 9;
+
 /// Entity limits
 pub const MAX_CHARACTERS: usize = 8;
 pub const MAX_SPAWNS: usize = 64;
 pub const MAX_STATUS_EFFECTS: usize = 32;
+pub const MAX_STATUS_EFFECT_CHILDREN: usize = 4;
+pub const MAX_STATUS_EFFECT_BUNDLE_DEPTH: usize = 4;
+
+/// Damage reflection: how many times reflected damage may bounce between characters
+pub const MAX_REFLECT_DEPTH: usize = 3;
+
+/// Knockback: Force element spawns push this percent of their knockback, and pushes are
+/// capped at this speed in pixels per frame after weight
+pub const FORCE_KNOCKBACK_PERCENT: u16 = 150;
+pub const MAX_KNOCKBACK_SPEED: i16 = 12;
+
+/// Size scaling: combined status effect scale is clamped to this percent range
+pub const MIN_SIZE_SCALE: u16 = 25;
+pub const MAX_SIZE_SCALE: u16 = 400;
 
 /// Script execution limits
 pub const MAX_SCRIPT_LENGTH: usize = 256;
 pub const MAX_SCRIPT_VARIABLES: usize = 16;
 pub const MAX_SCRIPT_STACK: usize = 32;
+/// Default nesting limit of script CALLs (see `GameState::max_call_depth`)
+pub const DEFAULT_MAX_CALL_DEPTH: u8 = 4;
+/// Most iterations a script REPEAT runs
+pub const MAX_REPEAT_COUNT: u8 = 32;
+/// Default instruction budget of one script run (see `GameState::script_step_budget`)
+pub const DEFAULT_SCRIPT_STEP_BUDGET: u16 = 4096;
+/// Current opcode table version (see `script::migrate`); bumped when operators are added
+/// or retired
+pub const OPCODE_VERSION: u8 = 2;
+
+/// Energy overflow: banked energy per charge stack, and the most stacks a character holds
+/// (see `constants::energy_overflow`)
+pub const ENERGY_PER_CHARGE: u8 = 10;
+pub const MAX_CHARGE_STACKS: u8 = 10;
@@ -1,6 +1,11 @@
 //! Entity system for characters, spawns, and status effects
 
+use crate::constants::energy_overflow;
+use crate::core::{
+    Frame, ELEMENT_COUNT, ENERGY_PER_CHARGE, MAX_CHARACTERS, MAX_CHARGE_STACKS, NEVER_FRAME,
+};
 use crate::math::Fixed;
+use crate::script::ScriptResource;
 use alloc::vec;
 use alloc::vec::Vec;
 
@@ -18,28 +23,126 @@ pub type StatusEffectId = usize;
 pub type ActionInstanceId = u8;
 pub type StatusEffectInstanceId = u8;
 
+/// Frames elapsed between two timestamps, saturated to the u16 range scripts read
+pub fn elapsed_frames(current_frame: Frame, since: Frame) -> u16 {
+    current_frame.saturating_sub(since).min(u16::MAX as Frame) as u16
+}
+
+/// Serde migration for frame timestamps saved while frames were u16
+///
+/// Matches only ever lasted MAX_FRAMES frames back then, so a stored `u16::MAX`
+/// can only be the old "never used" sentinel and maps to `NEVER_FRAME`.
+#[cfg(feature = "serde")]
+mod legacy_frames {
+    use super::{Frame, NEVER_FRAME};
+    use alloc::vec::Vec;
+    use serde::{Deserialize, Deserializer};
+
+    fn migrate(frame: Frame) -> Frame {
+        if frame == u16::MAX as Frame {
+            NEVER_FRAME
+        } else {
+            frame
+        }
+    }
+
+    pub fn deserialize_frame<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Frame, D::Error> {
+        Frame::deserialize(deserializer).map(migrate)
+    }
+
+    pub fn deserialize_frames<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<Frame>, D::Error> {
+        Vec::<Frame>::deserialize(deserializer)
+            .map(|frames| frames.into_iter().map(migrate).collect())
+    }
+}
+
 /// Action definition - static configuration for actions
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ActionDefinition {
     pub energy_cost: u8,
     pub cooldown: u16,
     pub args: [u8; 8],
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub constants: Vec<u16>, // Constant pool read by LOAD_CONST operators
     pub spawns: [u8; 4],
     pub script: Vec<u8>,
 }
 
 /// Action instance - runtime state for active actions
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ActionInstance {
     pub definition_id: ActionId,
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub character_id: CharacterId, // Character this instance belongs to
     pub cooldown: u16,
-    pub last_used_frame: u16,
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "legacy_frames::deserialize_frame")
+    )]
+    pub last_used_frame: Frame,
     pub runtime_vars: [u8; 4],
     pub runtime_fixed: [Fixed; 4],
 }
 
+/// Running per-character match statistics, readable by scripts for adaptive behaviors
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CharacterStats {
+    pub damage_dealt: u16, // Total damage dealt to other characters (saturating)
+    pub damage_taken: u16, // Total damage taken from any source (saturating)
+    pub last_hit_frame: Frame, // Frame this character last dealt damage (0 = never)
+    pub last_damaged_frame: Frame, // Frame this character last took damage (0 = never)
+    pub kills: u8,         // Characters of other groups this character defeated (saturating)
+}
+
+/// Dash in progress, moved by the physics system in place of the character's velocity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Dash {
+    pub remaining: (Fixed, Fixed), // Displacement still to cover
+    pub frames_left: u8,           // Frames until the dash ends; each covers an equal share
+}
+
+/// Grapple pull in progress, moved by the physics system in place of the character's velocity
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct Grapple {
+    pub source_id: CharacterId,         // Owner of the grapple spawn
+    pub anchor: (Fixed, Fixed),         // Point the character's center is pulled toward
+    pub anchor_id: Option<CharacterId>, // Character whose center the anchor follows
+    pub speed: Fixed,                   // Pull speed in pixels per frame
+    pub frames_left: u8,                // Frames until the pull breaks (0 = no limit)
+}
+
+/// Per-frame script budget for one character (0 = unlimited)
+/// Covers every script the character pays for: its conditions and actions, the behavior
+/// and collision scripts of spawns it owns, and status effect scripts running on it
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScriptQuota {
+    pub max_instructions: u16,    // Instructions executed per frame
+    pub max_property_writes: u16, // Successful property writes per frame
+    pub max_spawns: u16,          // Spawn creation requests per frame
+}
+
+/// Script work charged to a character during the current frame (reset every frame)
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct ScriptUsage {
+    pub instructions: u16,
+    pub property_writes: u16,
+    pub spawns: u16,
+}
+
 /// Programmable fighting characters
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Character {
     pub core: EntityCore,
     pub health: u16,
@@ -50,27 +153,79 @@ pub struct Character {
     pub weight: u8,
     pub jump_force: Fixed,
     pub move_speed: Fixed,
-    pub armor: [u8; 9],         // Armor values for all 9 elements (baseline 100)
-    pub energy_regen: u8,       // Passive energy recovery amount per rate
-    pub energy_regen_rate: u8,  // Tick interval for passive energy recovery
-    pub energy_charge: u8,      // Active energy recovery amount per rate during Charge action
-    pub energy_charge_rate: u8, // Tick interval for active energy recovery during Charge action
+    pub wall_slide_speed: Fixed, // Max fall speed while pressing into a wall in the air (0 = no slide)
+    pub armor: Armor,            // Armor values for all 9 elements (baseline 100)
+    pub energy_regen: u8,        // Passive energy recovery amount per rate
+    pub energy_regen_rate: u8,   // Tick interval for passive energy recovery
+    pub energy_charge: u8,       // Active energy recovery amount per rate during Charge action
+    pub energy_charge_rate: u8,  // Tick interval for active energy recovery during Charge action
     pub behaviors: Vec<(ConditionId, ActionId)>, // todo: add slot type Vec<(SlotType, ConditionId, ActionId)>. slot types are needed for the virus status effect to know which action should be disabled.
     pub locked_action: Option<ActionInstanceId>,
     pub status_effects: Vec<StatusEffectInstanceId>,
-    pub action_last_used: Vec<u16>, // Tracks when each action was last executed (game frame timestamp)
+    #[cfg_attr(
+        feature = "serde",
+        serde(deserialize_with = "legacy_frames::deserialize_frames")
+    )]
+    pub action_last_used: Vec<Frame>, // Tracks when each action was last executed (game frame timestamp)
+    pub stats: CharacterStats, // Running match statistics (read-only to scripts)
+    pub recent_damage: Vec<(Frame, u16)>, // (frame, amount) damage taken within the damage window
+    pub detection_radius: u8,  // Enemy detection range in pixels (0 = detects nothing)
+    pub detection_requires_los: bool, // Detection is blocked by solid tiles
+    pub detected_enemies: u8,  // Bitmask of detected enemy character ids (bit n = id n)
+    pub ground_tile: u8,       // Tile type directly under the character's feet
+    pub ground_tile_pos: (u8, u8), // Tile coordinates under the feet (u8::MAX = outside the map)
+    pub facing_tile: u8,       // Tile type one pixel beyond the facing edge at mid height
+    pub terrain_contact: u8, // Slope, platform, ladder and hazard contact bits (see constants::terrain_contact)
+    pub taunted_by: Option<CharacterId>, // Character a taunt status effect forces as the target
+    pub aggro: [u8; MAX_CHARACTERS], // Aggro toward each character id, raised by damage and taunts (decays)
+    pub size_scale: u16, // Percent of the base size applied by status effects (100 = unscaled)
+    pub base_size: Option<(u8, u8)>, // Unscaled size while a size status effect is active
+    pub dash: Option<Dash>, // Dash in progress (velocity and gravity are suspended)
+    pub grapple: Option<Grapple>, // Grapple pull in progress (velocity and gravity are suspended)
+    pub hitstun: u8,     // Frames left in which behaviors are suspended after a knockback hit
+    pub respawn_timer: u16, // Frames until a defeated character revives (0 = not reviving)
+    pub overflow_mode: u8, // What energy gained above the cap turns into (see constants::energy_overflow)
+    pub overflow_energy: u8, // Energy gained above the cap, converted in the status phase
+    pub shield: u16,       // Overheal shield absorbing hit damage before health
+    pub charge: u8,        // Charge stacks built from overflow energy (spent by scripts)
+    pub quota: ScriptQuota, // Per-frame script limits (default: unlimited)
+    pub script_usage: ScriptUsage, // Script work charged so far this frame
+}
+
+/// Baseline character stats a configuration can start from (see `constants::archetype`)
+/// Characters built from an archetype start at full health and energy.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct Archetype {
+    pub size: (u8, u8),
+    pub health: u16, // Starting health and health cap
+    pub energy: u8,  // Starting energy and energy cap
+    pub power: u8,
+    pub weight: u8,
+    pub jump_force: Fixed,
+    pub move_speed: Fixed,
+    pub wall_slide_speed: Fixed,
+    pub armor: Armor,
+    pub energy_regen: u8,
+    pub energy_regen_rate: u8,
+    pub energy_charge: u8,
+    pub energy_charge_rate: u8,
+    pub detection_radius: u8,
 }
 
 /// Condition definition - static configuration for conditions
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConditionDefinition {
     pub energy_mul: Fixed,
     pub args: [u8; 8],
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub constants: Vec<u16>, // Constant pool read by LOAD_CONST operators
     pub script: Vec<u8>,
 }
 
 /// Condition instance - runtime state for condition evaluations
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct ConditionInstance {
     pub definition_id: ConditionId,
     pub character_id: CharacterId, // NEW: Track which character this instance belongs to
@@ -78,8 +233,56 @@ pub struct ConditionInstance {
     pub runtime_fixed: [Fixed; 4],
 }
 
+/// Static variables - one bank of script variables per definition, shared by all its instances
+///
+/// Every character using an action, and every spawn or status effect of a definition, reads
+/// and writes the same bank (e.g. a count of live turrets). Banks start zeroed when a match is
+/// created and keep their values until it ends; snapshots carry them.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StaticVars {
+    pub actions: Vec<[u8; 4]>,        // Indexed by ActionId
+    pub conditions: Vec<[u8; 4]>,     // Indexed by ConditionId
+    pub spawns: Vec<[u8; 4]>,         // Indexed by spawn definition ID
+    pub status_effects: Vec<[u8; 4]>, // Indexed by StatusEffectId
+}
+
+impl StaticVars {
+    /// Zeroed banks for the given number of definitions of each kind
+    pub fn new(actions: usize, conditions: usize, spawns: usize, status_effects: usize) -> Self {
+        Self {
+            actions: vec![[0; 4]; actions],
+            conditions: vec![[0; 4]; conditions],
+            spawns: vec![[0; 4]; spawns],
+            status_effects: vec![[0; 4]; status_effects],
+        }
+    }
+
+    /// Whether no script has left a non-zero value in any bank
+    pub fn is_zeroed(&self) -> bool {
+        [
+            &self.actions,
+            &self.conditions,
+            &self.spawns,
+            &self.status_effects,
+        ]
+        .iter()
+        .all(|banks| banks.iter().all(|bank| *bank == [0; 4]))
+    }
+}
+
+/// Canonical identity used to order simultaneous interactions (see `crate::pipeline`)
+/// Characters sort before spawns; within a kind, entities sort by stable ID, never by
+/// storage position, so removals and reuse cannot reorder collision processing
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum EntityHandle {
+    Character(CharacterId),
+    Spawn(EntityId),
+}
+
 /// Base entity properties shared by all game objects
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct EntityCore {
     pub id: EntityId,
     pub group: u8,
@@ -95,6 +298,7 @@ pub struct EntityCore {
 
 /// Definition template for spawn objects
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpawnDefinition {
     pub damage_base: u16,
     pub damage_range: u16,
@@ -104,16 +308,74 @@ pub struct SpawnDefinition {
     pub duration: u16,
     pub element: Option<Element>,
     pub chance: u8,
-    pub size: (u8, u8),  // [width, height] in pixels
-    pub args: [u8; 8],   // Passed when calling scripts (read-only)
-    pub spawns: [u8; 4], // Spawn IDs
+    pub size: (u8, u8),         // [width, height] in pixels
+    pub collision_mask: u8,     // Spawn-vs-spawn collision layers (0 = passes through other spawns)
+    pub falloff_start: u16,     // Full damage up to this distance in pixels from origin
+    pub falloff_end: u16,       // Damage decays linearly to zero at this distance (0 = no falloff)
+    pub velocity_spread: Fixed, // Max per-axis velocity jitter added at creation (0 = none)
+    pub duration_variance: u16, // Max lifetime jitter in frames (+/-) rolled at creation
+    pub damage_variance: u16,   // Max base damage jitter (+/-) rolled at creation
+    pub energy_damage: u8,      // Energy drained from a hit character (armor does not apply)
+    pub energy_steal: u8,       // Percent of drained energy given to the owning character
+    pub terrain: u8,            // Tile written into the empty tiles covered at creation (0 = none)
+    pub grapple: u8,            // Pull started on hitting terrain or a character (see grapple_mode)
+    pub grapple_speed: Fixed,   // Grapple pull speed in pixels per frame
+    pub grapple_frames: u8,     // Frames before a grapple pull breaks (0 = no limit)
+    pub platform: bool,         // Moving platform: characters land on its top edge and ride along
+    pub knockback: Fixed,       // Speed a hit character is pushed away at, before weight (0 = none)
+    pub hitstun: u8,            // Frames a hit character's behaviors are suspended (0 = none)
+    pub args: [u8; 8],          // Passed when calling scripts (read-only)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub constants: Vec<u16>, // Constant pool read by LOAD_CONST operators
+    pub spawns: [u8; 4],        // Spawn IDs
     pub behavior_script: Vec<u8>,
     pub collision_script: Vec<u8>,
     pub despawn_script: Vec<u8>,
 }
 
+/// Everything the spawn physics passes read: the spawn's own motion state plus world
+/// gravity and the tile map revision
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct PhysicsInputs {
+    pub pos: (Fixed, Fixed),
+    pub vel: (Fixed, Fixed),
+    pub size: (u8, u8),
+    pub collision: (bool, bool, bool, bool),
+    pub gravity_dir: u8,
+    pub gravity: (Fixed, Fixed),
+    pub tiles: u32,
+}
+
+impl PhysicsInputs {
+    pub fn of(core: &EntityCore, gravity: (Fixed, Fixed), tiles: u32) -> Self {
+        Self {
+            pos: core.pos,
+            vel: core.vel,
+            size: core.size,
+            collision: core.collision,
+            gravity_dir: core.dir.1,
+            gravity,
+            tiles,
+        }
+    }
+}
+
+/// Idle tracking for a spawn (a cache: not part of snapshots or the state hash)
+/// Once a whole frame of physics left a spawn's inputs unchanged, later frames that start
+/// from the same inputs skip the spawn in the collision flag, overlap correction, gravity,
+/// velocity constraint and movement passes; any change to the inputs wakes it up.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpawnSleep {
+    pub rest: Option<PhysicsInputs>, // Inputs the physics passes are known to leave unchanged
+    pub probe: Option<PhysicsInputs>, // Inputs at the start of this frame, while still unchanged
+    pub asleep: bool,                // Skipped by the physics pass currently running
+}
+
 /// Projectiles and temporary objects
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct SpawnInstance {
     pub core: EntityCore,
     pub spawn_id: SpawnLookupId,
@@ -123,33 +385,77 @@ pub struct SpawnInstance {
     pub health_cap: u16,
     pub rotation: Fixed,
     pub life_span: u16,
-    pub element: Element,          // Element type carried by this spawn
-    pub runtime_vars: [u8; 4],     // Script variables
+    pub origin: (Fixed, Fixed), // Position where the spawn was created (for damage falloff)
+    pub damage: u16,            // Base damage rolled at creation (definition base + variance)
+    pub element: Element,       // Element type carried by this spawn
+    pub runtime_vars: [u8; 4],  // Script variables
     pub runtime_fixed: [Fixed; 4], // Fixed-point variables
+    pub sleep: SpawnSleep,      // Idle tracking used to skip physics work
 }
 
 /// Status effect definition - static configuration for status effects
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StatusEffectDefinition {
     pub duration: u16,
     pub stack_limit: u8,
     pub reset_on_stack: bool,
+    pub stack_behavior: StackBehavior, // What re-applying the effect to the same character does
     pub chance: u8,
-    pub args: [u8; 8],        // Passed when calling scripts (read-only)
-    pub spawns: [u8; 4],      // Spawn IDs
-    pub on_script: Vec<u8>,   // Runs when applied
-    pub tick_script: Vec<u8>, // Runs every frame
-    pub off_script: Vec<u8>,  // Runs when removed
+    pub category: u8,  // UI grouping (0=neutral, 1=buff, 2=debuff)
+    pub reflect: u8,   // Percent of incoming spawn damage returned to the attacker, per stack
+    pub taunt: bool,   // Forces the afflicted character to target the character that applied it
+    pub scale: u8,     // Percent of the base size per stack while active (0 = no size change)
+    pub priority: u8,  // UI display priority, higher shows first (ignored by the simulation)
+    pub args: [u8; 8], // Passed when calling scripts (read-only)
+    #[cfg_attr(feature = "serde", serde(default))]
+    pub constants: Vec<u16>, // Constant pool read by LOAD_CONST operators
+    pub spawns: [u8; 4], // Spawn IDs
+    pub children: Vec<StatusEffectId>, // Effects applied together with this one, sharing its lifetime
+    pub on_script: Vec<u8>,            // Runs when applied
+    pub tick_script: Vec<u8>,          // Runs every frame
+    pub off_script: Vec<u8>,           // Runs when removed
+}
+
+/// What applying a status effect does when the character already has it
+#[repr(u8)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum StackBehavior {
+    /// Add a stack up to `stack_limit`, resetting the life span if `reset_on_stack` is set
+    #[default]
+    Stack = 0,
+    /// Reset the life span without adding a stack
+    Refresh = 1,
+    /// Keep the existing instance unchanged
+    Ignore = 2,
+}
+
+impl StackBehavior {
+    /// Convert from u8 value
+    pub fn from_u8(value: u8) -> Option<StackBehavior> {
+        match value {
+            0 => Some(StackBehavior::Stack),
+            1 => Some(StackBehavior::Refresh),
+            2 => Some(StackBehavior::Ignore),
+            _ => None,
+        }
+    }
 }
 
 /// Active status effect on a character
 #[derive(Debug, Clone)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct StatusEffectInstance {
     pub definition_id: StatusEffectId,
+    pub character_id: CharacterId, // Character this effect is applied to
+    pub source_id: Option<EntityId>, // Entity that applied the effect (None = game/system)
+    pub source_type: u8,           // Source entity type (0=None, 1=Character, 2=Spawn)
     pub life_span: u16,
     pub stack_count: u8,
-    pub runtime_vars: [u8; 4],     // Script variables
-    pub runtime_fixed: [Fixed; 4], // Fixed-point variables
+    pub parent: Option<StatusEffectInstanceId>, // Bundle instance this effect was applied with
+    pub runtime_vars: [u8; 4],                  // Script variables
+    pub runtime_fixed: [Fixed; 4],              // Fixed-point variables
 }
 
 impl ActionDefinition {
@@ -159,6 +465,7 @@ impl ActionDefinition {
             energy_cost,
             cooldown,
             args: [0; 8],
+            constants: Vec::new(),
             spawns: [0; 4],
             script,
         }
@@ -175,12 +482,17 @@ impl ActionDefinition {
         Ok(())
     }
 
-    /// Create an instance from this definition
-    pub fn create_instance(&self, definition_id: ActionId) -> ActionInstance {
+    /// Create an instance from this definition for a specific character
+    pub fn create_instance(
+        &self,
+        character_id: CharacterId,
+        definition_id: ActionId,
+    ) -> ActionInstance {
         ActionInstance {
             definition_id,
+            character_id,
             cooldown: 0,
-            last_used_frame: u16::MAX, // Never used
+            last_used_frame: NEVER_FRAME,
             runtime_vars: [0; 4],
             runtime_fixed: [Fixed::ZERO; 4],
         }
@@ -188,12 +500,13 @@ impl ActionDefinition {
 }
 
 impl ActionInstance {
-    /// Create a new action instance
-    pub fn new(definition_id: ActionId) -> Self {
+    /// Create a new action instance for a specific character
+    pub fn new(character_id: CharacterId, definition_id: ActionId) -> Self {
         Self {
             definition_id,
+            character_id,
             cooldown: 0,
-            last_used_frame: u16::MAX,
+            last_used_frame: NEVER_FRAME,
             runtime_vars: [0; 4],
             runtime_fixed: [Fixed::ZERO; 4],
         }
@@ -205,11 +518,11 @@ impl ActionInstance {
     }
 
     /// Check if this action is on cooldown
-    pub fn is_on_cooldown(&self, current_frame: u16, cooldown_duration: u16) -> bool {
-        if self.last_used_frame == u16::MAX {
+    pub fn is_on_cooldown(&self, current_frame: Frame, cooldown_duration: u16) -> bool {
+        if self.last_used_frame == NEVER_FRAME {
             return false; // Never used
         }
-        current_frame.saturating_sub(self.last_used_frame) < cooldown_duration
+        current_frame.saturating_sub(self.last_used_frame) < cooldown_duration as Frame
     }
 }
 
@@ -225,6 +538,7 @@ impl Character {
             weight: 100,
             jump_force: Fixed::from_int(5),
             move_speed: Fixed::from_int(3),
+            wall_slide_speed: Fixed::ZERO,
             armor: [100; 9], // Default armor values (baseline 100)
             energy_regen: 0, // Values will be set during new_game/game initialization
             energy_regen_rate: 0,
@@ -234,12 +548,297 @@ impl Character {
             locked_action: None,
             status_effects: Vec::new(),
             action_last_used: Vec::new(), // Will be sized during game initialization
+            stats: CharacterStats::default(),
+            recent_damage: Vec::new(),
+            detection_radius: 0,
+            detection_requires_los: false,
+            detected_enemies: 0,
+            ground_tile: 0,
+            ground_tile_pos: (u8::MAX, u8::MAX),
+            facing_tile: 0,
+            terrain_contact: 0,
+            taunted_by: None,
+            aggro: [0; MAX_CHARACTERS],
+            size_scale: 100,
+            base_size: None,
+            dash: None,
+            grapple: None,
+            hitstun: 0,
+            respawn_timer: 0,
+            overflow_mode: 0,
+            overflow_energy: 0,
+            shield: 0,
+            charge: 0,
+            quota: ScriptQuota::default(),
+            script_usage: ScriptUsage::default(),
         }
     }
 
+    /// Create a character with an archetype's baseline stats
+    pub fn from_archetype(id: CharacterId, group: u8, archetype: &Archetype) -> Self {
+        let mut character = Self::new(id, group);
+        character.core.size = archetype.size;
+        character.health = archetype.health;
+        character.health_cap = archetype.health;
+        character.energy = archetype.energy;
+        character.energy_cap = archetype.energy;
+        character.power = archetype.power;
+        character.weight = archetype.weight;
+        character.jump_force = archetype.jump_force;
+        character.move_speed = archetype.move_speed;
+        character.wall_slide_speed = archetype.wall_slide_speed;
+        character.armor = archetype.armor;
+        character.energy_regen = archetype.energy_regen;
+        character.energy_regen_rate = archetype.energy_regen_rate;
+        character.energy_charge = archetype.energy_charge;
+        character.energy_charge_rate = archetype.energy_charge_rate;
+        character.detection_radius = archetype.detection_radius;
+        character
+    }
+
+    /// Charge one unit of script work against this character's per-frame quota
+    /// Returns false (charging nothing) once the limit for that resource is reached
+    pub fn charge_script(&mut self, resource: ScriptResource) -> bool {
+        let (used, limit) = match resource {
+            ScriptResource::Instruction => (
+                &mut self.script_usage.instructions,
+                self.quota.max_instructions,
+            ),
+            ScriptResource::PropertyWrite => (
+                &mut self.script_usage.property_writes,
+                self.quota.max_property_writes,
+            ),
+            ScriptResource::Spawn => (&mut self.script_usage.spawns, self.quota.max_spawns),
+        };
+        if limit != 0 && *used >= limit {
+            return false;
+        }
+        *used = used.saturating_add(1);
+        true
+    }
+
+    /// Canonical ordering handle of this character
+    pub fn handle(&self) -> EntityHandle {
+        EntityHandle::Character(self.core.id)
+    }
+
+    /// Total damage taken within the current damage window
+    pub fn recent_damage_taken(&self) -> u16 {
+        self.recent_damage
+            .iter()
+            .fold(0u16, |total, &(_, amount)| total.saturating_add(amount))
+    }
+
+    /// Center of the character's bounding box in whole pixels
+    pub fn center(&self) -> (i32, i32) {
+        (
+            self.core.pos.0.to_int() + self.core.size.0 as i32 / 2,
+            self.core.pos.1.to_int() + self.core.size.1 as i32 / 2,
+        )
+    }
+
+    /// Remove up to `amount` energy, returning how much was removed
+    pub fn drain_energy(&mut self, amount: u8) -> u8 {
+        let drained = amount.min(self.energy);
+        self.energy -= drained;
+        drained
+    }
+
+    /// Add up to `amount` energy without exceeding the energy cap, returning how much was added
+    /// The rest is banked as overflow energy (see `bank_overflow`)
+    pub fn gain_energy(&mut self, amount: u8) -> u8 {
+        let gained = amount.min(self.energy_cap.saturating_sub(self.energy));
+        self.energy += gained;
+        self.bank_overflow(amount - gained);
+        gained
+    }
+
+    /// Bank energy gained above the cap for the status phase to convert; characters without
+    /// an overflow mode discard it
+    pub fn bank_overflow(&mut self, excess: u8) {
+        if self.overflow_mode != energy_overflow::NONE {
+            self.overflow_energy = self.overflow_energy.saturating_add(excess);
+        }
+    }
+
+    /// Convert banked overflow energy, plus any energy held above the cap, into the shield or
+    /// charge stacks picked by the overflow mode
+    pub fn convert_overflow(&mut self) {
+        if self.overflow_mode == energy_overflow::NONE {
+            self.overflow_energy = 0;
+            return;
+        }
+        if self.energy > self.energy_cap {
+            self.bank_overflow(self.energy - self.energy_cap);
+            self.energy = self.energy_cap;
+        }
+        match self.overflow_mode {
+            // One shield point per energy, up to the health cap
+            energy_overflow::SHIELD => {
+                let room = self.health_cap.saturating_sub(self.shield);
+                self.shield += (self.overflow_energy as u16).min(room);
+                self.overflow_energy = 0;
+            }
+            // One stack per ENERGY_PER_CHARGE energy; the remainder waits for the next frame
+            energy_overflow::CHARGE => {
+                let room = MAX_CHARGE_STACKS.saturating_sub(self.charge);
+                self.charge += (self.overflow_energy / ENERGY_PER_CHARGE).min(room);
+                self.overflow_energy = if self.charge < MAX_CHARGE_STACKS {
+                    self.overflow_energy % ENERGY_PER_CHARGE
+                } else {
+                    0
+                };
+            }
+            _ => self.overflow_energy = 0,
+        }
+    }
+
+    /// Soak up to `amount` damage with the shield, returning the damage left for health
+    pub fn absorb_with_shield(&mut self, amount: u16) -> u16 {
+        let absorbed = amount.min(self.shield);
+        self.shield -= absorbed;
+        amount - absorbed
+    }
+
+    /// Start a dash for a character, replacing any dash in progress
+    /// Returns false for unknown directions, a neutral facing with `FACING`, or an empty dash
+    pub fn start_dash(&mut self, direction: u8, distance: Fixed, frames: u8) -> bool {
+        use crate::constants::dash_direction;
+
+        if frames == 0 || !distance.is_positive() {
+            return false;
+        }
+        // Diagonals cover the distance along the diagonal (181/256 ~ 1/sqrt(2) per axis)
+        let diagonal = Fixed::from_raw((distance.raw() as i32 * 181 / 256) as i16);
+        let remaining = match direction {
+            dash_direction::RIGHT => (distance, Fixed::ZERO),
+            dash_direction::DOWN_RIGHT => (diagonal, diagonal),
+            dash_direction::DOWN => (Fixed::ZERO, distance),
+            dash_direction::DOWN_LEFT => (diagonal.neg(), diagonal),
+            dash_direction::LEFT => (distance.neg(), Fixed::ZERO),
+            dash_direction::UP_LEFT => (diagonal.neg(), diagonal.neg()),
+            dash_direction::UP => (Fixed::ZERO, distance.neg()),
+            dash_direction::UP_RIGHT => (diagonal, diagonal.neg()),
+            dash_direction::FACING => match self.core.dir.0 {
+                0 => (distance.neg(), Fixed::ZERO),
+                2 => (distance, Fixed::ZERO),
+                _ => return false,
+            },
+            _ => return false,
+        };
+
+        // Dashing out of a grapple pull breaks it
+        self.grapple = None;
+        self.dash = Some(Dash {
+            remaining,
+            frames_left: frames,
+        });
+        self.core.vel = (Fixed::ZERO, Fixed::ZERO);
+        true
+    }
+
+    /// Side of the wall an airborne character touches, as a horizontal direction (0 = left,
+    /// 2 = right); with walls on both sides the one it faces wins
+    pub fn wall_side(&self) -> Option<u8> {
+        let (_, right, bottom, left) = self.core.collision;
+        if bottom {
+            return None;
+        }
+        match (left, right) {
+            (true, true) if self.core.dir.0 == 0 => Some(0),
+            (_, true) => Some(2),
+            (true, false) => Some(0),
+            (false, false) => None,
+        }
+    }
+
+    /// Whether the character touches terrain with any of the given `terrain_contact` bits
+    pub fn has_terrain_contact(&self, bits: u8) -> bool {
+        self.terrain_contact & bits != 0
+    }
+
+    /// Whether the character presses into a wall in the air: it touches the wall while facing
+    /// it or moving toward it
+    pub fn is_pressing_wall(&self) -> bool {
+        match self.wall_side() {
+            Some(0) => self.core.dir.0 == 0 || self.core.vel.0 < Fixed::ZERO,
+            Some(_) => self.core.dir.0 == 2 || self.core.vel.0 > Fixed::ZERO,
+            None => false,
+        }
+    }
+
+    /// Whether the character is sliding down a wall (falling while pressing into it with a wall
+    /// slide speed set)
+    pub fn is_wall_sliding(&self) -> bool {
+        self.wall_slide_speed.is_positive()
+            && self.core.vel.1.is_positive()
+            && self.is_pressing_wall()
+    }
+
+    /// Jump off the wall the character touches in the air, turning away from it
+    /// `push` is the horizontal speed away from the wall and `lift` the speed against gravity.
+    /// Any dash or grapple pull in progress ends. Returns false when no wall is touched.
+    pub fn wall_jump(&mut self, push: Fixed, lift: Fixed) -> bool {
+        let Some(side) = self.wall_side() else {
+            return false;
+        };
+        let (away, facing) = match side {
+            0 => (push, 2),
+            _ => (push.neg(), 0),
+        };
+        let lift = match self.core.dir.1 {
+            0 => lift,
+            _ => lift.neg(),
+        };
+        self.dash = None;
+        self.grapple = None;
+        self.core.vel = (away, lift);
+        self.core.dir.0 = facing;
+        true
+    }
+
+    /// Whether a taunt status effect currently forces this character's target
+    pub fn is_taunted(&self) -> bool {
+        self.taunted_by.is_some()
+    }
+
+    /// Raise aggro toward a character, saturating at 255; unknown ids are ignored
+    pub fn add_aggro(&mut self, toward: CharacterId, amount: u16) {
+        if let Some(entry) = self.aggro.get_mut(toward as usize) {
+            *entry = entry.saturating_add(amount.min(u8::MAX as u16) as u8);
+        }
+    }
+
+    /// Lose a quarter (rounded up) of the aggro toward every character
+    pub fn decay_aggro(&mut self) {
+        for entry in &mut self.aggro {
+            *entry -= entry.div_ceil(4);
+        }
+    }
+
+    /// Size without status effect scaling
+    pub fn unscaled_size(&self) -> (u8, u8) {
+        self.base_size.unwrap_or(self.core.size)
+    }
+
+    /// Whether the character has taken damage within the current damage window
+    pub fn is_in_combat(&self) -> bool {
+        !self.recent_damage.is_empty()
+    }
+
+    /// Frames elapsed since this character last dealt damage (or since match start), saturating
+    pub fn frames_since_hit(&self, current_frame: Frame) -> u16 {
+        elapsed_frames(current_frame, self.stats.last_hit_frame)
+    }
+
+    /// Frames elapsed since this character last took damage (or since match start), saturating
+    pub fn frames_since_damaged(&self, current_frame: Frame) -> u16 {
+        elapsed_frames(current_frame, self.stats.last_damaged_frame)
+    }
+
     /// Initialize action_last_used vector with appropriate size
     pub fn init_action_cooldowns(&mut self, action_count: usize) {
-        self.action_last_used = vec![u16::MAX; action_count]; // u16::MAX means "never used"
+        self.action_last_used = vec![NEVER_FRAME; action_count];
     }
 
     /// Get armor value for a specific element
@@ -259,6 +858,7 @@ impl ConditionDefinition {
         Self {
             energy_mul,
             args: [0; 8],
+            constants: Vec::new(),
             script,
         }
     }
@@ -388,10 +988,13 @@ impl SpawnInstance {
             health: 1,
             health_cap: 1,
             rotation: Fixed::ZERO,
-            life_span: 0,            // Will be set from spawn definition
+            life_span: 0, // Will be set from spawn definition
+            origin: pos,
+            damage: 0,               // Will be set from spawn definition
             element: Element::Punct, // Default element, will be set from spawn definition
             runtime_vars: [0; 4],
             runtime_fixed: [Fixed::ZERO; 4],
+            sleep: SpawnSleep::default(),
         }
     }
 
@@ -414,11 +1017,32 @@ impl SpawnInstance {
             health_cap: 1,
             rotation: Fixed::ZERO,
             life_span: 0, // Will be set from spawn definition
+            origin: pos,
+            damage: 0, // Will be set from spawn definition
             element,
             runtime_vars: [0; 4],
             runtime_fixed: [Fixed::ZERO; 4],
+            sleep: SpawnSleep::default(),
         }
     }
+
+    /// Canonical ordering handle of this spawn instance
+    pub fn handle(&self) -> EntityHandle {
+        EntityHandle::Spawn(self.core.id)
+    }
+
+    /// Whether the physics passes skip this spawn because its inputs are at rest
+    pub fn is_resting(&self) -> bool {
+        self.sleep.rest.is_some()
+    }
+
+    /// Distance in whole pixels between the spawn's origin and its current position
+    pub fn distance_from_origin(&self) -> u16 {
+        let dx = self.core.pos.0.to_int() - self.origin.0.to_int();
+        let dy = self.core.pos.1.to_int() - self.origin.1.to_int();
+        let distance_squared = (dx * dx + dy * dy) as u32;
+        crate::math::isqrt(distance_squared).min(u16::MAX as u32) as u16
+    }
 }
 
 impl StatusEffectDefinition {
@@ -436,9 +1060,17 @@ impl StatusEffectDefinition {
             duration,
             stack_limit,
             reset_on_stack,
+            stack_behavior: StackBehavior::Stack,
             chance,
+            category: 0,
+            reflect: 0,
+            taunt: false,
+            scale: 0,
+            priority: 0,
             args: [0; 8],
+            constants: Vec::new(),
             spawns: [0; 4],
+            children: Vec::new(),
             on_script,
             tick_script,
             off_script,
@@ -459,6 +1091,9 @@ impl StatusEffectDefinition {
         if self.stack_limit == 0 {
             return Err("Stack limit must be at least 1");
         }
+        if self.children.len() > crate::core::MAX_STATUS_EFFECT_CHILDREN {
+            return Err("Too many child status effects");
+        }
         Ok(())
     }
 
@@ -466,8 +1101,12 @@ impl StatusEffectDefinition {
     pub fn create_instance(&self, definition_id: StatusEffectId) -> StatusEffectInstance {
         StatusEffectInstance {
             definition_id,
+            character_id: 0, // Set when applied to a character
+            source_id: None,
+            source_type: 0,
             life_span: self.duration,
             stack_count: 1,
+            parent: None,
             runtime_vars: [0; 4],
             runtime_fixed: [Fixed::ZERO; 4],
         }
@@ -479,8 +1118,12 @@ impl StatusEffectInstance {
     pub fn new(definition_id: StatusEffectId) -> Self {
         Self {
             definition_id,
+            character_id: 0, // Set when applied to a character
+            source_id: None,
+            source_type: 0,
             life_span: 0, // Will be set from definition
             stack_count: 1,
+            parent: None,
             runtime_vars: [0; 4],
             runtime_fixed: [Fixed::ZERO; 4],
         }
@@ -495,6 +1138,7 @@ impl StatusEffectInstance {
 /// Element types for damage and interactions
 #[repr(u8)]
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum Element {
     Punct = 0, // Puncture / piercing - goes through multiple enemies and walls, ignores force fields
     Blast = 1, // Explosive AOE damage
@@ -508,24 +1152,27 @@ pub enum Element {
 }
 
 impl Element {
+    /// Every element in armor slot order; sized by `ELEMENT_COUNT`, so the enum, the armor
+    /// array and the constant cannot drift apart
+    pub const ALL: [Element; ELEMENT_COUNT] = [
+        Element::Punct,
+        Element::Blast,
+        Element::Force,
+        Element::Sever,
+        Element::Heat,
+        Element::Cryo,
+        Element::Jolt,
+        Element::Acid,
+        Element::Virus,
+    ];
+
     /// Convert from u8 value
     pub fn from_u8(value: u8) -> Option<Element> {
-        match value {
-            0 => Some(Element::Punct),
-            1 => Some(Element::Blast),
-            2 => Some(Element::Force),
-            3 => Some(Element::Sever),
-            4 => Some(Element::Heat),
-            5 => Some(Element::Cryo),
-            6 => Some(Element::Jolt),
-            7 => Some(Element::Acid),
-            8 => Some(Element::Virus),
-            _ => None,
-        }
+        Self::ALL.get(value as usize).copied()
     }
 }
 
 /// Character armor values (0-255, baseline 100) - simplified elemental immunity
 /// Index corresponds to Element enum values: [Punct, Blast, Force, Sever, Heat, Cryo, Jolt, Acid, Virus]
 /// Lower values = more vulnerable, higher values = more resistant
-pub type Armor = [u8; 9];
+pub type Armor = [u8; ELEMENT_COUNT];
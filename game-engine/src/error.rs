@@ -21,7 +21,7 @@ impl ErrorRecovery {
     /// Validate game state integrity and attempt recovery
     pub fn validate_and_recover_game_state(
         characters: &mut [crate::entity::Character],
-        spawn_instances: &mut alloc::vec::Vec<crate::entity::SpawnInstance>,
+        spawn_instances: &mut crate::pool::SpawnPool,
     ) -> GameResult<()> {
         // Validate character data
         for character in characters.iter_mut() {
@@ -128,7 +128,7 @@ impl ErrorRecovery {
         _id: usize,
         collection_name: &'static str,
         _collection_size: usize,
-        _frame: u16,
+        _frame: crate::core::Frame,
     ) -> GameError {
         // In a production environment, this would log detailed error information
         // For now, we create appropriate error types based on collection name
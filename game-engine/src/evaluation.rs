@@ -0,0 +1,116 @@
+//! Heuristic evaluation of a match in progress
+//!
+//! Spectator win bars and lookahead experiments need a quick read of who is ahead without
+//! playing the match out. `GameState::evaluate` scores every group from the state alone in
+//! integer per-mille steps, so every client shows the same numbers for the same frame.
+
+use crate::core::{SCREEN_HEIGHT, SCREEN_WIDTH};
+use crate::entity::EntityId;
+use crate::state::GameState;
+use alloc::vec::Vec;
+
+/// Weight of the health share in a group's score
+pub const HEALTH_WEIGHT: u32 = 6;
+/// Weight of the position share in a group's score
+pub const POSITION_WEIGHT: u32 = 2;
+/// Weight of the spawn share in a group's score
+pub const SPAWN_WEIGHT: u32 = 2;
+
+/// Heuristic standing of one group, in order of first appearance among the characters
+/// Shares are per mille (0-1000)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupEvaluation {
+    pub group: EntityId,
+    pub health: u16,     // Health left out of the group's total health cap
+    pub position: u16,   // Closeness of the group's survivors to the arena center
+    pub spawns: u16,     // Share of the live spawns owned by characters of any group
+    pub score: u16,      // Weighted sum of the shares, divided by the total weight
+    pub win_chance: u16, // Share of the scores of all groups; 0 once eliminated
+}
+
+impl GameState {
+    /// Score every group by health left, position and live spawns
+    ///
+    /// Eliminated groups score 0. Win chances are rounded down, so they may add up to
+    /// slightly less than 1000; they are all 0 when no group has a score.
+    pub fn evaluate(&self) -> Vec<GroupEvaluation> {
+        let owned_spawns = |group: EntityId| {
+            self.spawn_instances()
+                .iter()
+                .filter(|spawn| spawn.owner_type == 1)
+                .filter_map(|spawn| self.characters.get(spawn.owner_id as usize))
+                .filter(|owner| owner.core.group == group)
+                .count() as u32
+        };
+        let standings = self.group_standings();
+        let total_spawns: u32 = standings
+            .iter()
+            .map(|standing| owned_spawns(standing.group))
+            .sum();
+
+        let mut evaluations: Vec<GroupEvaluation> = standings
+            .iter()
+            .map(|standing| {
+                if standing.survivors == 0 {
+                    return GroupEvaluation {
+                        group: standing.group,
+                        health: 0,
+                        position: 0,
+                        spawns: 0,
+                        score: 0,
+                        win_chance: 0,
+                    };
+                }
+                let health = per_mille(standing.health, standing.health_cap);
+                let position = self.center_control(standing.group);
+                let spawns = per_mille(owned_spawns(standing.group), total_spawns);
+                let score = (HEALTH_WEIGHT * health as u32
+                    + POSITION_WEIGHT * position as u32
+                    + SPAWN_WEIGHT * spawns as u32)
+                    / (HEALTH_WEIGHT + POSITION_WEIGHT + SPAWN_WEIGHT);
+                GroupEvaluation {
+                    group: standing.group,
+                    health,
+                    position,
+                    spawns,
+                    score: score as u16,
+                    win_chance: 0,
+                }
+            })
+            .collect();
+
+        let total_score: u32 = evaluations.iter().map(|group| group.score as u32).sum();
+        for evaluation in &mut evaluations {
+            evaluation.win_chance = per_mille(evaluation.score as u32, total_score);
+        }
+        evaluations
+    }
+
+    /// Average closeness of a group's standing characters to the arena center, per mille
+    /// 1000 at the center and 0 at a corner, by the distance between hitbox centers
+    fn center_control(&self, group: EntityId) -> u16 {
+        let (half_width, half_height) = (SCREEN_WIDTH as i32 / 2, SCREEN_HEIGHT as i32 / 2);
+        let farthest = (half_width + half_height) as u32;
+        let (mut total, mut count) = (0u32, 0u32);
+        for character in &self.characters {
+            if character.core.group != group || character.health == 0 {
+                continue;
+            }
+            let center = Self::core_center(&character.core);
+            let distance = (center.0.to_int() - half_width).unsigned_abs()
+                + (center.1.to_int() - half_height).unsigned_abs();
+            total += per_mille(farthest.saturating_sub(distance), farthest) as u32;
+            count += 1;
+        }
+        total.checked_div(count).unwrap_or(0) as u16
+    }
+}
+
+/// `part` out of `whole` in per-mille steps, rounded down; 0 for an empty whole
+fn per_mille(part: u32, whole: u32) -> u16 {
+    if whole == 0 {
+        return 0;
+    }
+    (part.min(whole) as u64 * 1000 / whole as u64) as u16
+}
@@ -0,0 +1,321 @@
+//! Game events emitted during frame processing
+//!
+//! Events are presentation-only: they describe what happened during the most
+//! recent frame so clients (e.g. a spectator view) can react, and never feed
+//! back into the simulation or the state hash. `EventHistory` additionally keeps
+//! the last few seconds of events for clients that attach mid-match, and
+//! `EventQueue` keeps them until a frontend drains them.
+
+use crate::core::Frame;
+use crate::entity::{
+    ActionId, Element, EntityId, SpawnLookupId, StatusEffectId, StatusEffectInstanceId,
+};
+use crate::math::Fixed;
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+/// Camera focus target types
+pub mod camera_focus {
+    /// Keep the current camera focus
+    pub const NONE: u8 = 0;
+    /// Focus on a character (focus_id = character id)
+    pub const CHARACTER: u8 = 1;
+    /// Focus on a spawn instance (focus_id = spawn instance id)
+    pub const SPAWN: u8 = 2;
+}
+
+/// Numeric removal reason codes for analytics (see `RemovalReason::code`)
+pub mod removal_reason {
+    /// Lifetime ran out or a script despawned the entity
+    pub const EXPIRED: u8 = 0;
+    /// Health reached zero
+    pub const KILLED: u8 = 1;
+    /// Left the arena through a world boundary policy
+    pub const OUT_OF_BOUNDS: u8 = 2;
+    /// Consumed while colliding with another spawn
+    pub const ABSORBED: u8 = 3;
+}
+
+/// Why an entity left the match
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum RemovalReason {
+    Expired,
+    /// Killed by a character (None when no character dealt the final damage)
+    KilledBy(Option<EntityId>),
+    OutOfBounds,
+    /// Absorbed by the spawn instance it collided with
+    AbsorbedBy(EntityId),
+}
+
+impl RemovalReason {
+    /// Numeric reason code (see `removal_reason`)
+    pub fn code(&self) -> u8 {
+        match self {
+            RemovalReason::Expired => removal_reason::EXPIRED,
+            RemovalReason::KilledBy(_) => removal_reason::KILLED,
+            RemovalReason::OutOfBounds => removal_reason::OUT_OF_BOUNDS,
+            RemovalReason::AbsorbedBy(_) => removal_reason::ABSORBED,
+        }
+    }
+
+    /// Responsible entity: killing character or absorbing spawn instance
+    pub fn by(&self) -> Option<EntityId> {
+        match self {
+            RemovalReason::KilledBy(killer) => *killer,
+            RemovalReason::AbsorbedBy(absorber) => Some(*absorber),
+            RemovalReason::Expired | RemovalReason::OutOfBounds => None,
+        }
+    }
+}
+
+/// Default shake magnitude used by engine-emitted camera hints
+pub const DEFEAT_CAMERA_SHAKE: u8 = 8;
+
+/// Camera direction hint for spectator clients
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CameraHint {
+    pub focus_type: u8,     // 0=None, 1=Character, 2=Spawn (see camera_focus)
+    pub focus_id: EntityId, // Character or spawn instance id, ignored when focus_type is None
+    pub zoom: Fixed,        // Requested zoom level (1.0 = default, 0 = keep current)
+    pub shake: u8,          // Screen shake magnitude (0 = none)
+}
+
+/// Event payloads
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum GameEventKind {
+    CameraHint(CameraHint),
+    /// Character entered (took damage) or left (window elapsed) combat
+    CombatStateChanged {
+        character_id: EntityId,
+        in_combat: bool,
+    },
+    /// Spawn instance entered the world (scripts, platforms and arena placement alike)
+    SpawnCreated {
+        spawn_instance_id: EntityId,
+        spawn_id: SpawnLookupId,
+        owner_id: Option<EntityId>, // Owning character, if any
+    },
+    /// Spawn instance was removed from the world during cleanup
+    SpawnRemoved {
+        spawn_instance_id: EntityId,
+        spawn_id: SpawnLookupId,
+        reason: RemovalReason,
+    },
+    /// Spawn hit a character (amount after armor, 0 when fully absorbed)
+    CharacterHit {
+        character_id: EntityId,
+        spawn_instance_id: EntityId,
+        attacker_id: Option<EntityId>, // Owning character of the spawn, if any
+        amount: u16,
+        element: Element,
+        critical: bool, // The damage roll was a critical hit
+        mitigated: u16, // Damage absorbed by the character's armor and shield
+    },
+    /// Part of a hit was returned to the attacker by a reflect status effect
+    DamageReflected {
+        character_id: EntityId, // Character that took the reflected damage
+        reflector_id: EntityId, // Character whose status effect reflected it
+        amount: u16,
+    },
+    /// Energy was drained from a character by a spawn or script
+    EnergyDrained {
+        character_id: EntityId,       // Character that lost the energy
+        drainer_id: Option<EntityId>, // Character responsible, if any
+        amount: u8,                   // Energy removed
+        stolen: u8, // Part of `amount` the drainer gained (capped by its energy cap)
+    },
+    /// Character moved one frame of a dash; renderers draw an after-image per event
+    Dashed {
+        character_id: EntityId,
+        from: (Fixed, Fixed), // Position before this frame's step
+        to: (Fixed, Fixed),   // Position after the step
+        frames_left: u8,      // Dash frames still to come (0 = the dash ended)
+        blocked: bool,        // Solid tiles stopped the step short, ending the dash
+    },
+    /// Grapple spawn caught terrain or a character and started pulling a character
+    GrappleAttached {
+        character_id: EntityId, // Character being pulled
+        source_id: EntityId,    // Owner of the grapple spawn
+        anchor: (Fixed, Fixed), // Point the character's center is pulled toward
+    },
+    /// Grapple pull ended
+    GrappleReleased {
+        character_id: EntityId,
+        source_id: EntityId,
+        arrived: bool, // Reached the anchor (false = broken by tiles, time or defeat)
+    },
+    /// Status effect was applied to a character, as a new instance or an added stack
+    StatusApplied {
+        character_id: EntityId,
+        status_effect_id: StatusEffectId,
+        instance_id: StatusEffectInstanceId,
+        stacks: u8,      // Stack count after the application
+        refreshed: bool, // An existing instance was stacked or refreshed instead of created
+    },
+    /// Status effect instance ended: its duration ran out, it was removed, or its bundle ended
+    StatusExpired {
+        character_id: EntityId,
+        status_effect_id: StatusEffectId,
+        instance_id: StatusEffectInstanceId,
+    },
+    /// Character started an action; its script's own events follow
+    ActionExecuted {
+        character_id: EntityId,
+        action_id: ActionId,
+    },
+    /// Character health dropped to zero
+    CharacterDefeated {
+        character_id: EntityId,
+        reason: RemovalReason,
+    },
+    /// Defeated character's respawn timer ran out; it is back at full health and energy
+    CharacterRevived {
+        character_id: EntityId,
+    },
+    /// The frame work budget ran out during the spawn-vs-spawn pass; the checks of the
+    /// remaining spawns wait for the next frame
+    SpawnCollisionsDeferred {
+        remaining: u8, // Spawns whose interception checks were deferred
+        work: u32,     // Frame work (script instructions + collision checks) when deferring
+    },
+}
+
+/// Single event recorded during a frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GameEvent {
+    pub frame: Frame, // Frame during which the event was emitted
+    pub kind: GameEventKind,
+}
+
+/// Bounded history of recent events, queryable by frame range
+///
+/// Keeps every event emitted during the last `frames` frames so late-mounting clients
+/// (kill feeds, combat logs) can backfill without replaying the match. Older frames drop
+/// out as new ones are recorded; a window of 0 disables the history.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventHistory {
+    frames: u16,
+    events: VecDeque<GameEvent>,
+}
+
+impl EventHistory {
+    /// Create an empty history covering the given number of frames
+    pub fn new(frames: u16) -> Self {
+        Self {
+            frames,
+            events: VecDeque::new(),
+        }
+    }
+
+    /// Number of frames covered by the history
+    pub fn frames(&self) -> u16 {
+        self.frames
+    }
+
+    /// Change the covered window; shrinking takes effect at the next recorded frame
+    pub fn set_frames(&mut self, frames: u16) {
+        self.frames = frames;
+        if frames == 0 {
+            self.events.clear();
+        }
+    }
+
+    /// Append the events of `frame` and drop frames that fell out of the window
+    pub fn record(&mut self, frame: Frame, events: &[GameEvent]) {
+        if self.frames == 0 {
+            return;
+        }
+        self.events.extend(events.iter().cloned());
+
+        let oldest = frame.saturating_add(1).saturating_sub(self.frames as Frame);
+        while let Some(event) = self.events.front() {
+            if event.frame >= oldest {
+                break;
+            }
+            self.events.pop_front();
+        }
+    }
+
+    /// Events emitted during frames `first..=last`, oldest first
+    pub fn range(&self, first: Frame, last: Frame) -> impl Iterator<Item = &GameEvent> {
+        self.events
+            .iter()
+            .skip_while(move |event| event.frame < first)
+            .take_while(move |event| event.frame <= last)
+    }
+
+    /// Every retained event, oldest first
+    pub fn iter(&self) -> impl Iterator<Item = &GameEvent> {
+        self.events.iter()
+    }
+
+    /// Number of retained events
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether no events are retained
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+
+    /// Forget all retained events, keeping the window
+    pub fn clear(&mut self) {
+        self.events.clear();
+    }
+}
+
+/// Events queued since the last drain, and how many were dropped meanwhile
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct DrainedEvents {
+    pub events: Vec<GameEvent>, // Oldest first
+    pub dropped: u32,           // Oldest events discarded because the queue was full
+}
+
+/// Events waiting for a frontend to drain them
+///
+/// Collects every event emitted while events are enabled, during frames and between them
+/// (e.g. effects applied through the API), so a renderer can trigger sounds and effects at
+/// its own pace instead of reading `GameState::events` after each frame. An undrained queue
+/// holds at most `MAX_QUEUED_EVENTS` events, dropping the oldest ones first.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct EventQueue {
+    events: VecDeque<GameEvent>,
+    dropped: u32,
+}
+
+impl EventQueue {
+    /// Append an event, dropping the oldest event past the limit
+    pub fn push(&mut self, event: GameEvent) {
+        if self.events.len() >= crate::core::MAX_QUEUED_EVENTS {
+            self.events.pop_front();
+            self.dropped = self.dropped.saturating_add(1);
+        }
+        self.events.push_back(event);
+    }
+
+    /// Take every queued event, resetting the drop count
+    pub fn drain(&mut self) -> DrainedEvents {
+        DrainedEvents {
+            events: self.events.drain(..).collect(),
+            dropped: core::mem::take(&mut self.dropped),
+        }
+    }
+
+    /// Number of queued events
+    pub fn len(&self) -> usize {
+        self.events.len()
+    }
+
+    /// Whether no events are queued
+    pub fn is_empty(&self) -> bool {
+        self.events.is_empty()
+    }
+}
@@ -0,0 +1,103 @@
+//! Read-only views of script instances for tools and front ends
+//!
+//! Instances live in flat lists on `GameState`, found by character and definition. The
+//! views pair each instance with its id (its position in the list, as used by scripts and
+//! snapshots) and its definition, so callers never depend on how instances are stored.
+
+use crate::entity::{
+    ActionDefinition, ActionInstance, CharacterId, ConditionDefinition, ConditionInstance,
+    StatusEffectDefinition, StatusEffectInstance, StatusEffectInstanceId,
+};
+use crate::state::GameState;
+
+/// A character's action instance, created the first time the action runs
+#[derive(Debug, Clone, Copy)]
+pub struct ActionInstanceView<'a> {
+    pub id: usize,
+    pub instance: &'a ActionInstance,
+    pub definition: Option<&'a ActionDefinition>,
+    pub locked: bool, // The character is locked into this action (see LOCK_ACTION)
+}
+
+/// A character's condition instance, created the first time the condition is evaluated
+#[derive(Debug, Clone, Copy)]
+pub struct ConditionInstanceView<'a> {
+    pub id: usize,
+    pub instance: &'a ConditionInstance,
+    pub definition: Option<&'a ConditionDefinition>,
+}
+
+/// A status effect active on a character
+#[derive(Debug, Clone, Copy)]
+pub struct StatusEffectInstanceView<'a> {
+    pub id: StatusEffectInstanceId,
+    pub instance: &'a StatusEffectInstance,
+    pub definition: Option<&'a StatusEffectDefinition>,
+}
+
+impl GameState {
+    /// Action instances of a character, in creation order
+    pub fn action_instances_of(
+        &self,
+        character_id: CharacterId,
+    ) -> impl Iterator<Item = ActionInstanceView<'_>> + '_ {
+        let locked = self
+            .characters
+            .iter()
+            .find(|character| character.core.id == character_id)
+            .and_then(|character| character.locked_action);
+        self.action_instances
+            .iter()
+            .enumerate()
+            .filter(move |(_, instance)| instance.character_id == character_id)
+            .map(move |(id, instance)| ActionInstanceView {
+                id,
+                instance,
+                definition: self.action_definitions.get(instance.definition_id),
+                locked: locked == Some(id as u8),
+            })
+    }
+
+    /// Condition instances of a character with their persisted script variables, in creation
+    /// order
+    pub fn condition_instances_of(
+        &self,
+        character_id: CharacterId,
+    ) -> impl Iterator<Item = ConditionInstanceView<'_>> + '_ {
+        self.condition_instances
+            .iter()
+            .enumerate()
+            .filter(move |(_, instance)| instance.character_id == character_id)
+            .map(move |(id, instance)| ConditionInstanceView {
+                id,
+                instance,
+                definition: self.condition_definitions.get(instance.definition_id),
+            })
+    }
+
+    /// Status effects active on a character, in application order
+    pub fn status_effect_instances_of(
+        &self,
+        character_id: CharacterId,
+    ) -> impl Iterator<Item = StatusEffectInstanceView<'_>> + '_ {
+        self.characters
+            .iter()
+            .find(|character| character.core.id == character_id)
+            .into_iter()
+            .flat_map(|character| character.status_effects.iter())
+            .filter_map(move |&id| {
+                let instance = self.status_effect_instances.get(id as usize)?;
+                Some(StatusEffectInstanceView {
+                    id,
+                    instance,
+                    definition: self.status_effect_definitions.get(instance.definition_id),
+                })
+            })
+    }
+
+    /// The action a character is locked into, if any
+    pub fn active_action_of(&self, character_id: CharacterId) -> Option<ActionInstanceView<'_>> {
+        self.action_instances_of(character_id)
+            .find(|view| view.locked)
+    }
+}
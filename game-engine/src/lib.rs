@@ -9,19 +9,37 @@ extern crate alloc;
 
 // Core modules
 pub mod api;
+#[cfg(feature = "std")]
+pub mod batch;
+pub mod broadphase;
 pub mod collision;
+pub mod combat;
+#[cfg(feature = "combat-log")]
+pub mod combat_log;
 pub mod constants;
 pub mod core;
 pub mod entity;
 pub mod error;
+pub mod evaluation;
+pub mod event;
+pub mod inspect;
 pub mod math;
+pub mod pathfinding;
 pub mod physics;
+pub mod pipeline;
+pub mod pool;
 pub mod random;
+pub mod replay;
+pub mod reward;
 pub mod script;
 pub mod spawn;
 pub mod state;
 pub mod status;
+pub mod stream;
+pub mod summary;
+pub mod targeting;
 pub mod tilemap;
+pub mod victory;
 
 // Re-export public API
 pub use api::*;
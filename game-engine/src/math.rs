@@ -5,6 +5,7 @@ use core::ops;
 
 /// Fixed-point number with 5-bit precision for optimal storage/performance balance
 #[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub struct Fixed(i16);
 
 impl Fixed {
@@ -24,7 +25,7 @@ impl Fixed {
     pub const CONTACT_TOLERANCE: Fixed = Fixed(328); // ~0.01 units
 
     /// Create a Fixed from an integer value
-    pub fn from_int(value: i16) -> Self {
+    pub const fn from_int(value: i16) -> Self {
         Fixed(value << Self::FRACTIONAL_BITS)
     }
 
@@ -43,8 +44,19 @@ impl Fixed {
         Fixed(result.clamp(i16::MIN as i32, i16::MAX as i32) as i16)
     }
 
+    /// Create a Fixed from an unsigned integer, saturating at the largest representable integer
+    pub fn from_u16_saturating(value: u16) -> Self {
+        let max_int = (i16::MAX >> Self::FRACTIONAL_BITS) as u16;
+        Self::from_int(value.min(max_int) as i16)
+    }
+
+    /// Convert to u16, clamping negatives to zero
+    pub fn to_u16_saturating(self) -> u16 {
+        self.to_int().max(0) as u16
+    }
+
     /// Create a Fixed from raw internal representation
-    pub fn from_raw(raw: i16) -> Self {
+    pub const fn from_raw(raw: i16) -> Self {
         Fixed(raw)
     }
 
@@ -141,8 +153,107 @@ impl Fixed {
             Fixed(self.0 & !fractional_mask)
         }
     }
+
+    /// Sine of a binary angle: 256 steps per turn, 0 = +x, 64 = +y (down), 128 = -x
+    /// Looked up from a quarter-wave table, so every build returns the same values.
+    pub fn sin(angle: u8) -> Fixed {
+        let step = (angle & 63) as usize;
+        match angle >> 6 {
+            0 => Fixed(SIN_QUARTER[step]),
+            1 => Fixed(SIN_QUARTER[64 - step]),
+            2 => Fixed(-SIN_QUARTER[step]),
+            _ => Fixed(-SIN_QUARTER[64 - step]),
+        }
+    }
+
+    /// Cosine of a binary angle (see `sin`)
+    pub fn cos(angle: u8) -> Fixed {
+        Self::sin(angle.wrapping_add(64))
+    }
+
+    /// Binary angle of the vector (x, y) (see `sin`), 0 for the zero vector
+    /// The angle of a target offset aims `cos`/`sin` velocities at the target.
+    pub fn atan2(y: Fixed, x: Fixed) -> u8 {
+        let (abs_x, abs_y) = ((x.0 as i32).abs(), (y.0 as i32).abs());
+        if abs_x == 0 && abs_y == 0 {
+            return 0;
+        }
+
+        // Angle within the first quadrant from the octant's tangent ratio
+        let angle = if abs_y <= abs_x {
+            ATAN_OCTANT[((abs_y * 32 + abs_x / 2) / abs_x) as usize]
+        } else {
+            64 - ATAN_OCTANT[((abs_x * 32 + abs_y / 2) / abs_y) as usize]
+        };
+        match (x.0 < 0, y.0 < 0) {
+            (false, false) => angle,
+            (true, false) => 128 - angle,
+            (true, true) => 128 + angle,
+            (false, true) => 0u8.wrapping_sub(angle),
+        }
+    }
+
+    /// Square root (floor), 0 for negative values
+    pub fn sqrt(self) -> Fixed {
+        if self.0 <= 0 {
+            return Fixed::ZERO;
+        }
+        // sqrt(raw / 32) * 32 = sqrt(raw * 32)
+        Fixed(isqrt((self.0 as u32) << Self::FRACTIONAL_BITS) as i16)
+    }
+
+    /// Length of the vector (x, y) (floor), saturating at `Fixed::MAX`
+    /// Squares are summed in 32 bits, so offsets across the whole screen never overflow.
+    pub fn length(x: Fixed, y: Fixed) -> Fixed {
+        let squared = (x.0 as i32 * x.0 as i32) as u32 + (y.0 as i32 * y.0 as i32) as u32;
+        Fixed(isqrt(squared).min(i16::MAX as u32) as i16)
+    }
+
+    /// Clamp between two bounds given in either order
+    pub fn clamp_between(self, a: Fixed, b: Fixed) -> Fixed {
+        Fixed(self.0.clamp(a.0.min(b.0), a.0.max(b.0)))
+    }
+
+    /// Move toward `target` by the fraction `t` of the remaining distance (clamped to 0-1)
+    /// A positive `t` always moves at least one raw step, so repeated steps reach the target
+    /// instead of stalling once the fraction rounds to zero.
+    pub fn lerp(self, target: Fixed, t: Fixed) -> Fixed {
+        let distance = target.0 as i32 - self.0 as i32;
+        let t = t.0.clamp(0, Self::ONE.0) as i32;
+        let mut step = distance * t / Self::ONE.0 as i32;
+        if step == 0 && t > 0 {
+            step = distance.signum();
+        }
+        Fixed((self.0 as i32 + step) as i16)
+    }
+
+    /// Unit vector pointing along (x, y), the zero vector for (0, 0)
+    pub fn normalize(x: Fixed, y: Fixed) -> (Fixed, Fixed) {
+        let length = Self::length(x, y).0 as i32;
+        if length == 0 {
+            return (Fixed::ZERO, Fixed::ZERO);
+        }
+        let one = 1 << Self::FRACTIONAL_BITS;
+        (
+            Fixed((x.0 as i32 * one / length) as i16),
+            Fixed((y.0 as i32 * one / length) as i16),
+        )
+    }
 }
 
+/// Raw sine values for binary angles 0-64 (a quarter turn), round(32 * sin)
+const SIN_QUARTER: [i16; 65] = [
+    0, 1, 2, 2, 3, 4, 5, 5, 6, 7, 8, 9, 9, 10, 11, 12, 12, 13, 14, 14, 15, 16, 16, 17, 18, 18, 19,
+    20, 20, 21, 21, 22, 23, 23, 24, 24, 25, 25, 26, 26, 27, 27, 27, 28, 28, 29, 29, 29, 30, 30, 30,
+    30, 31, 31, 31, 31, 31, 32, 32, 32, 32, 32, 32, 32, 32,
+];
+
+/// Binary angles of tangent ratios 0/32 to 32/32 (the first octant), round(atan * 128 / pi)
+const ATAN_OCTANT: [u8; 33] = [
+    0, 1, 3, 4, 5, 6, 8, 9, 10, 11, 12, 13, 15, 16, 17, 18, 19, 20, 21, 22, 23, 24, 25, 25, 26, 27,
+    28, 29, 29, 30, 31, 31, 32,
+];
+
 // Standard arithmetic trait implementations for Fixed type
 impl ops::Add for Fixed {
     type Output = Self;
@@ -219,6 +330,21 @@ impl ops::Add for Vec2 {
     }
 }
 
+/// Integer square root (floor) using Newton's method
+pub fn isqrt(value: u32) -> u32 {
+    if value < 2 {
+        return value;
+    }
+
+    let mut x = value;
+    let mut y = x.div_ceil(2);
+    while y < x {
+        x = y;
+        y = (x + value / x) / 2;
+    }
+    x
+}
+
 /// Precomputed trigonometry tables for performance
 pub struct TrigTables {
     sin_table: [Fixed; 360], // 1-degree precision
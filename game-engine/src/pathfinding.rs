@@ -0,0 +1,239 @@
+//! Grounded pathfinding over the tilemap
+//!
+//! Walkers steer with `GameState::path_step_between` (the PATH_DIRECTION_TO operator) instead
+//! of heading straight at a target and stalling against the first wall. The search is a
+//! cheapest-first flood fill over the cells a body can stand in, moving by walking, dropping
+//! off ledges and jumping within the walker's `JumpReach`. Jumps cost more than walking the
+//! same ground, so walkers only jump when it gets them somewhere. Moves are always tried in
+//! the same order, so every peer picks the same step.
+
+use crate::constants::path_step;
+use crate::core::{TILEMAP_HEIGHT, TILEMAP_WIDTH, TILE_SIZE};
+use crate::entity::{Character, EntityCore};
+use crate::math::Fixed;
+use crate::state::GameState;
+use crate::tilemap::{TileType, Tilemap};
+
+/// Longest jump simulated when working out a reach, so weak gravity still gives a finite one
+const MAX_JUMP_FRAMES: i32 = 120;
+
+const CELLS: usize = TILEMAP_WIDTH * TILEMAP_HEIGHT;
+
+/// How far a walker's jump carries it, in whole tiles
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct JumpReach {
+    pub height: u8,   // Rows the feet can rise above the takeoff cell
+    pub distance: u8, // Columns crossed while airborne, landing at takeoff height
+}
+
+impl JumpReach {
+    /// Walkers that cannot jump, such as spawns
+    pub const NONE: JumpReach = JumpReach {
+        height: 0,
+        distance: 0,
+    };
+
+    /// Reach of a jump at `jump_force` against downward `gravity`, moving at `move_speed`
+    /// Gravity is applied before each frame's rise, so the reach never overestimates the arc
+    pub fn of(jump_force: Fixed, move_speed: Fixed, gravity: Fixed) -> Self {
+        let (mut speed, gravity) = (jump_force.raw() as i32, gravity.raw() as i32);
+        let (mut rise, mut frames) = (0, 0);
+        while frames < MAX_JUMP_FRAMES {
+            speed -= gravity;
+            if speed <= 0 {
+                break;
+            }
+            rise += speed;
+            frames += 1;
+        }
+
+        // Falling back to the takeoff height takes as long as the rise
+        let tile = TILE_SIZE as i32 * 32;
+        let travel = (move_speed.raw() as i32).max(0) * frames * 2;
+        JumpReach {
+            height: (rise / tile).min(TILEMAP_HEIGHT as i32) as u8,
+            distance: (travel / tile).min(TILEMAP_WIDTH as i32) as u8,
+        }
+    }
+}
+
+impl Tilemap {
+    /// Whether a body `body` tiles tall fits with its feet in cell (x, y)
+    /// Rows above the map are open; columns outside it are not
+    fn is_clear(&self, x: i32, y: i32, body: i32) -> bool {
+        if !(0..TILEMAP_WIDTH as i32).contains(&x) || !(0..TILEMAP_HEIGHT as i32).contains(&y) {
+            return false;
+        }
+        (0..body)
+            .map(|row| y - row)
+            .take_while(|&row| row >= 0)
+            .all(|row| !self.get_tile(x as usize, row as usize).is_solid())
+    }
+
+    /// Whether a body `body` tiles tall can stand with its feet in cell (x, y): the cell is
+    /// clear and is a slope or has a solid tile, slope or platform below it
+    /// Ladders are not climbed, so they only count when there is footing under them
+    pub fn is_standable(&self, x: i32, y: i32, body: i32) -> bool {
+        if !self.is_clear(x, y, body) {
+            return false;
+        }
+        let is_footing = |tile: TileType| tile.is_solid() || tile.surface_offset(0).is_some();
+        let cell = self.get_tile(x as usize, y as usize);
+        let below = self.get_tile(x as usize, y as usize + 1);
+        matches!(cell, TileType::SlopeUp | TileType::SlopeDown) || is_footing(below)
+    }
+
+    /// Cell a body falling from (x, y) comes to rest in; None when it hits no footing before
+    /// a solid tile
+    fn landing(&self, x: i32, y: i32, body: i32) -> Option<(i32, i32)> {
+        (y..TILEMAP_HEIGHT as i32)
+            .take_while(|&row| self.is_clear(x, row, body))
+            .find(|&row| self.is_standable(x, row, body))
+            .map(|row| (x, row))
+    }
+
+    /// Cells reachable from (x, y) in one move, with the `path_step` that starts the move and
+    /// its cost: 1 per tile walked, and 1 plus the tiles risen and crossed for a jump
+    /// Walks come before jumps, left before right and lower jumps before higher ones
+    fn moves(
+        &self,
+        (x, y): (i32, i32),
+        body: i32,
+        reach: JumpReach,
+        mut visit: impl FnMut((i32, i32), u8, u16),
+    ) {
+        let on_slope = matches!(
+            self.get_tile(x as usize, y as usize),
+            TileType::SlopeUp | TileType::SlopeDown
+        );
+        for (step, walk) in [(-1, path_step::LEFT), (1, path_step::RIGHT)] {
+            // Walking on, down a slope or off a ledge, or up the top of a slope
+            if let Some(cell) = self.landing(x + step, y, body) {
+                visit(cell, walk, 1);
+            } else if on_slope && self.is_standable(x + step, y - 1, body) {
+                visit((x + step, y - 1), walk, 1);
+            }
+        }
+
+        for rise in 1..=reach.height as i32 {
+            if !self.is_clear(x, y - rise, body) {
+                break;
+            }
+            if let Some(cell) = self.landing(x, y - rise, body) {
+                visit(cell, path_step::JUMP_UP, 1 + rise as u16);
+            }
+        }
+        for (step, jump) in [(-1, path_step::JUMP_LEFT), (1, path_step::JUMP_RIGHT)] {
+            // Rise straight up, cross at the top of the arc, then fall onto the first footing
+            for rise in 0..=reach.height as i32 {
+                if !self.is_clear(x, y - rise, body) {
+                    break;
+                }
+                for across in 1..=reach.distance as i32 {
+                    let column = x + step * across;
+                    if !self.is_clear(column, y - rise, body) {
+                        break;
+                    }
+                    if let Some(cell) = self.landing(column, y - rise, body) {
+                        visit(cell, jump, 1 + (rise + across) as u16);
+                    }
+                }
+            }
+        }
+    }
+
+    /// First move on the cheapest path from cell `from` to cell `to` for a body `body` tiles
+    /// tall (see `constants::path_step`)
+    /// Both ends fall onto the footing below them first; NO_PATH when either has none or
+    /// the target cannot be reached. Of equally cheap cells the one found first is expanded
+    /// first, so ties always resolve the same way.
+    pub fn path_step(&self, from: (i32, i32), to: (i32, i32), body: i32, reach: JumpReach) -> u8 {
+        let (Some(start), Some(goal)) = (
+            self.landing(from.0, from.1, body),
+            self.landing(to.0, to.1, body),
+        ) else {
+            return path_step::NO_PATH;
+        };
+        if start == goal {
+            return path_step::ARRIVED;
+        }
+
+        let index = |(x, y): (i32, i32)| y as usize * TILEMAP_WIDTH + x as usize;
+        let mut costs = [u16::MAX; CELLS];
+        // First move of the cheapest known path to each cell
+        let mut first_steps = [path_step::NO_PATH; CELLS];
+        // Cells found but not expanded yet, in the order they were found
+        let mut open = [(0i32, 0i32); CELLS];
+        let mut open_len = 1;
+        open[0] = start;
+        costs[index(start)] = 0;
+
+        while open_len > 0 {
+            let slot = (0..open_len)
+                .min_by_key(|&slot| costs[index(open[slot])])
+                .unwrap_or(0);
+            let cell = open[slot];
+            open.copy_within(slot + 1..open_len, slot);
+            open_len -= 1;
+            if cell == goal {
+                return first_steps[index(goal)];
+            }
+
+            let cost = costs[index(cell)];
+            self.moves(cell, body, reach, |next, step, move_cost| {
+                let next_cost = cost + move_cost;
+                if next_cost >= costs[index(next)] {
+                    return;
+                }
+                if costs[index(next)] == u16::MAX {
+                    open[open_len] = next;
+                    open_len += 1;
+                }
+                costs[index(next)] = next_cost;
+                first_steps[index(next)] = if cell == start {
+                    step
+                } else {
+                    first_steps[index(cell)]
+                };
+            });
+        }
+        path_step::NO_PATH
+    }
+}
+
+impl GameState {
+    /// Jump reach of a character under the current gravity
+    pub fn jump_reach(&self, character: &Character) -> JumpReach {
+        JumpReach::of(character.jump_force, character.move_speed, self.gravity.1)
+    }
+
+    /// First move on the shortest grounded path from `from` to an entity (addressed as in
+    /// `offset_between`), see `constants::path_step`
+    /// Paths run between the cells under the hitbox centers at the feet; NO_PATH when the
+    /// entity does not exist or cannot be reached
+    pub fn path_step_between(
+        &self,
+        from: &EntityCore,
+        from_type: u8,
+        reach: JumpReach,
+        entity_type: u8,
+        entity_id: u8,
+    ) -> u8 {
+        let Some(target) = self.entity_core(from, from_type, entity_type, entity_id) else {
+            return path_step::NO_PATH;
+        };
+        let body = (from.size.1 as u32).div_ceil(TILE_SIZE as u32).max(1) as i32;
+        self.tile_map
+            .path_step(Self::feet_cell(from), Self::feet_cell(target), body, reach)
+    }
+
+    /// Tile cell holding the bottom center pixel of a hitbox
+    fn feet_cell(core: &EntityCore) -> (i32, i32) {
+        let center = Self::core_center(core);
+        let feet = core.pos.1.to_int() + core.size.1.max(1) as i32 - 1;
+        (
+            center.0.to_int().div_euclid(TILE_SIZE as i32),
+            feet.div_euclid(TILE_SIZE as i32),
+        )
+    }
+}
@@ -1,8 +1,74 @@
 //! Physics system for collision detection and movement
 
+use crate::core::{SCREEN_HEIGHT, SCREEN_WIDTH};
 use crate::entity::EntityCore;
+use crate::math::Fixed;
+use crate::tilemap::{CollisionRect, TileType, Tilemap};
 use alloc::vec::Vec;
 
+/// Policy applied when an entity leaves the 256x240 world
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+#[repr(u8)]
+pub enum BoundaryPolicy {
+    Clamp = 0,   // Keep the entity inside the world edges
+    Wrap = 1,    // Re-enter from the opposite edge once fully outside
+    Despawn = 2, // Remove the entity once fully outside (characters fall back to Clamp)
+    Damage = 3,  // Clamp and apply boundary damage while touching outside the world
+}
+
+impl BoundaryPolicy {
+    pub fn from_u8(value: u8) -> Option<BoundaryPolicy> {
+        match value {
+            0 => Some(BoundaryPolicy::Clamp),
+            1 => Some(BoundaryPolicy::Wrap),
+            2 => Some(BoundaryPolicy::Despawn),
+            3 => Some(BoundaryPolicy::Damage),
+            _ => None,
+        }
+    }
+}
+
+/// Movement modifier applied to grounded characters based on the tile underfoot
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct FootingModifier {
+    pub traction: Fixed, // Fraction of a script velocity change applied per frame (1 = instant)
+    pub speed: Fixed,    // Multiplier on horizontal displacement
+    pub push: Fixed,     // Horizontal displacement added per frame in pixels
+}
+
+impl FootingModifier {
+    /// Footing with no effect on movement
+    pub const NORMAL: FootingModifier = FootingModifier {
+        traction: Fixed::ONE,
+        speed: Fixed::ONE,
+        push: Fixed::ZERO,
+    };
+
+    /// Modifier for a raw tile value
+    pub fn for_tile(tile: u8) -> FootingModifier {
+        match TileType::from(tile) {
+            TileType::Ice => FootingModifier {
+                traction: Fixed::from_frac(1, 8),
+                ..Self::NORMAL
+            },
+            TileType::Mud => FootingModifier {
+                speed: Fixed::from_frac(1, 2),
+                ..Self::NORMAL
+            },
+            TileType::ConveyorLeft => FootingModifier {
+                push: Fixed::from_int(-1),
+                ..Self::NORMAL
+            },
+            TileType::ConveyorRight => FootingModifier {
+                push: Fixed::ONE,
+                ..Self::NORMAL
+            },
+            _ => Self::NORMAL,
+        }
+    }
+}
+
 /// AABB collision detection between two rectangles
 pub fn aabb(
     a_pos_x: u16,
@@ -214,6 +280,35 @@ impl PhysicsSystem {
         entity.pos.1 = entity.pos.1.add(entity.vel.1);
     }
 
+    /// Move an entity by `delta` one axis at a time, horizontal first, advancing at most
+    /// one pixel per tile check so thin walls are never skipped
+    /// Returns true when solid tiles stopped the movement short. An entity that already
+    /// overlaps tiles may move through them until it is free.
+    pub fn sweep(entity: &mut EntityCore, tile_map: &Tilemap, delta: (Fixed, Fixed)) -> bool {
+        let pixel = Fixed::from_int(1).raw() as i32;
+        let size = entity.size;
+        let collides = |pos| tile_map.check_collision(CollisionRect::from_entity(pos, size));
+
+        for (axis, total) in [(0, delta.0.raw() as i32), (1, delta.1.raw() as i32)] {
+            let mut moved = 0;
+            while moved != total {
+                let step = (total - moved).clamp(-pixel, pixel);
+                let mut next = entity.pos;
+                if axis == 0 {
+                    next.0 = next.0.add(Fixed::from_raw(step as i16));
+                } else {
+                    next.1 = next.1.add(Fixed::from_raw(step as i16));
+                }
+                if collides(next) && !collides(entity.pos) {
+                    return true;
+                }
+                entity.pos = next;
+                moved += step;
+            }
+        }
+        false
+    }
+
     /// Check collision between two entities
     pub fn check_entity_collision(a: &EntityCore, b: &EntityCore) -> bool {
         let (ax, ay) = a.pos;
@@ -227,4 +322,64 @@ impl PhysicsSystem {
             && ay.to_int() < by.to_int() + bh as i32
             && ay.to_int() + ah as i32 > by.to_int()
     }
+
+    /// Check whether any part of the entity is outside the world
+    pub fn is_partially_outside_world(entity: &EntityCore) -> bool {
+        let (x, y) = (entity.pos.0.to_int(), entity.pos.1.to_int());
+        let (w, h) = (entity.size.0 as i32, entity.size.1 as i32);
+
+        x < 0 || y < 0 || x + w > SCREEN_WIDTH as i32 || y + h > SCREEN_HEIGHT as i32
+    }
+
+    /// Check whether the entity is entirely outside the world
+    pub fn is_fully_outside_world(entity: &EntityCore) -> bool {
+        let (x, y) = (entity.pos.0.to_int(), entity.pos.1.to_int());
+        let (w, h) = (entity.size.0 as i32, entity.size.1 as i32);
+
+        x + w <= 0 || y + h <= 0 || x >= SCREEN_WIDTH as i32 || y >= SCREEN_HEIGHT as i32
+    }
+
+    /// Clamp the entity inside the world edges, zeroing velocity into the edge
+    pub fn clamp_to_world(entity: &mut EntityCore) {
+        let max_x = Fixed::from_int(SCREEN_WIDTH as i16 - entity.size.0 as i16);
+        let max_y = Fixed::from_int(SCREEN_HEIGHT as i16 - entity.size.1 as i16);
+
+        if entity.pos.0 < Fixed::ZERO {
+            entity.pos.0 = Fixed::ZERO;
+            entity.vel.0 = entity.vel.0.max(Fixed::ZERO);
+        } else if entity.pos.0 > max_x {
+            entity.pos.0 = max_x;
+            entity.vel.0 = entity.vel.0.min(Fixed::ZERO);
+        }
+
+        if entity.pos.1 < Fixed::ZERO {
+            entity.pos.1 = Fixed::ZERO;
+            entity.vel.1 = entity.vel.1.max(Fixed::ZERO);
+        } else if entity.pos.1 > max_y {
+            entity.pos.1 = max_y;
+            entity.vel.1 = entity.vel.1.min(Fixed::ZERO);
+        }
+    }
+
+    /// Move an entity that fully left the world to the opposite edge
+    pub fn wrap_around_world(entity: &mut EntityCore) {
+        let width = Fixed::from_int(SCREEN_WIDTH as i16);
+        let height = Fixed::from_int(SCREEN_HEIGHT as i16);
+        let (w, h) = (
+            Fixed::from_int(entity.size.0 as i16),
+            Fixed::from_int(entity.size.1 as i16),
+        );
+
+        if entity.pos.0.add(w) <= Fixed::ZERO {
+            entity.pos.0 = entity.pos.0.add(width);
+        } else if entity.pos.0 >= width {
+            entity.pos.0 = entity.pos.0.sub(width);
+        }
+
+        if entity.pos.1.add(h) <= Fixed::ZERO {
+            entity.pos.1 = entity.pos.1.add(height);
+        } else if entity.pos.1 >= height {
+            entity.pos.1 = entity.pos.1.sub(height);
+        }
+    }
 }
@@ -0,0 +1,142 @@
+//! Per-frame processing pipeline
+//!
+//! The phase order is data: `GameState::advance_frame` runs exactly `FRAME_PIPELINE`,
+//! so external verifiers and tests can assert the ordering determinism depends on.
+//!
+//! Collision ordering contract:
+//! - Entity-vs-entity collisions found in the same frame are resolved in ascending
+//!   `EntityHandle` order (characters before spawns, then by stable entity ID), pairs by
+//!   the first handle and then the second. Storage position never decides the order.
+//! - Under a `frame_work_budget`, spawn-vs-spawn interception may stop part way; the next
+//!   frame's pass starts at the first spawn handle it did not check and wraps around.
+//! - Entity-vs-tile collisions are resolved per entity on one axis at a time, horizontal
+//!   before vertical. They never affect other entities, so visiting order is irrelevant.
+
+/// Order in which a phase visits entities
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum EntityOrder {
+    World = 0,                // Whole-state pass with no per-entity script order
+    CharactersByIndex = 1,    // Characters in ascending index order
+    CharactersThenSpawns = 2, // Characters by index, then spawn instances by index
+    CharacterEffects = 3,     // Characters by index, each character's effects in application order
+    CharacterBehaviors = 4, // Characters by index, behaviors in list order (first passing one acts)
+    SpawnPairs = 5,         // Spawn pairs in ascending handle order, lower handle's script first
+    SpawnsByIndex = 6,      // Spawn instances in ascending index order
+    CharacterSpawnPairs = 7, // (character, spawn) pairs by character handle, then spawn handle
+}
+
+impl EntityOrder {
+    /// Stable identifier used in exported pipeline descriptions
+    pub fn name(&self) -> &'static str {
+        match self {
+            EntityOrder::World => "world",
+            EntityOrder::CharactersByIndex => "characters_by_index",
+            EntityOrder::CharactersThenSpawns => "characters_then_spawns",
+            EntityOrder::CharacterEffects => "character_effects",
+            EntityOrder::CharacterBehaviors => "character_behaviors",
+            EntityOrder::SpawnPairs => "spawn_pairs",
+            EntityOrder::SpawnsByIndex => "spawns_by_index",
+            EntityOrder::CharacterSpawnPairs => "character_spawn_pairs",
+        }
+    }
+}
+
+/// Processing phase of a single frame
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+pub enum FramePhase {
+    StatusEffects = 0,       // Status effect tick scripts and expiry
+    CollisionFlags = 1,      // Collision flags and footing tiles seen by scripts
+    OverlapCorrection = 2,   // Push entities out of solid tiles
+    Behaviors = 3,           // Conditions and actions, footing traction, then platform scripts
+    Gravity = 4,             // Gravity vector scaled by each entity's multiplier
+    VelocityConstraints = 5, // Stop velocity into solid tiles
+    Movement = 6, // Apply velocity, dash steps or grapple pulls, then footing displacement
+    WorldBoundaries = 7, // Boundary policies for entities leaving the world
+    Detection = 8, // Enemy detection from final positions
+    SpawnCollisions = 9, // Spawn-vs-spawn interception scripts
+    SpawnHits = 10, // Spawn-vs-character hit scripts and damage
+    Cleanup = 11, // Remove expired spawns and report removals
+    StateRecovery = 12, // Validate and repair invalid state
+    DamageTracking = 13, // Damage statistics, combat windows and aggro
+    DefeatEvents = 14, // Revivals, then defeat events, camera hints, kill credit and respawn timers
+    Victory = 15, // End the match when its victory condition is met
+}
+
+/// Phases run by `GameState::advance_frame`, in execution order
+pub const FRAME_PIPELINE: [FramePhase; 16] = [
+    FramePhase::StatusEffects,
+    FramePhase::CollisionFlags,
+    FramePhase::OverlapCorrection,
+    FramePhase::Behaviors,
+    FramePhase::Gravity,
+    FramePhase::VelocityConstraints,
+    FramePhase::Movement,
+    FramePhase::WorldBoundaries,
+    FramePhase::Detection,
+    FramePhase::SpawnCollisions,
+    FramePhase::SpawnHits,
+    FramePhase::Cleanup,
+    FramePhase::StateRecovery,
+    FramePhase::DamageTracking,
+    FramePhase::DefeatEvents,
+    FramePhase::Victory,
+];
+
+impl FramePhase {
+    /// Stable identifier used in exported pipeline descriptions
+    pub fn name(&self) -> &'static str {
+        match self {
+            FramePhase::StatusEffects => "status_effects",
+            FramePhase::CollisionFlags => "collision_flags",
+            FramePhase::OverlapCorrection => "overlap_correction",
+            FramePhase::Behaviors => "behaviors",
+            FramePhase::Gravity => "gravity",
+            FramePhase::VelocityConstraints => "velocity_constraints",
+            FramePhase::Movement => "movement",
+            FramePhase::WorldBoundaries => "world_boundaries",
+            FramePhase::Detection => "detection",
+            FramePhase::SpawnCollisions => "spawn_collisions",
+            FramePhase::SpawnHits => "spawn_hits",
+            FramePhase::Cleanup => "cleanup",
+            FramePhase::StateRecovery => "state_recovery",
+            FramePhase::DamageTracking => "damage_tracking",
+            FramePhase::DefeatEvents => "defeat_events",
+            FramePhase::Victory => "victory",
+        }
+    }
+
+    /// Order in which this phase visits entities
+    pub fn entity_order(&self) -> EntityOrder {
+        match self {
+            FramePhase::StatusEffects => EntityOrder::CharacterEffects,
+            FramePhase::CollisionFlags => EntityOrder::CharactersThenSpawns,
+            FramePhase::OverlapCorrection => EntityOrder::CharactersThenSpawns,
+            FramePhase::Behaviors => EntityOrder::CharacterBehaviors,
+            FramePhase::Gravity => EntityOrder::CharactersThenSpawns,
+            FramePhase::VelocityConstraints => EntityOrder::CharactersThenSpawns,
+            FramePhase::Movement => EntityOrder::CharactersThenSpawns,
+            FramePhase::WorldBoundaries => EntityOrder::CharactersThenSpawns,
+            FramePhase::Detection => EntityOrder::CharactersByIndex,
+            FramePhase::SpawnCollisions => EntityOrder::SpawnPairs,
+            FramePhase::SpawnHits => EntityOrder::CharacterSpawnPairs,
+            FramePhase::Cleanup => EntityOrder::SpawnsByIndex,
+            FramePhase::StateRecovery => EntityOrder::World,
+            FramePhase::DamageTracking => EntityOrder::CharactersByIndex,
+            FramePhase::DefeatEvents => EntityOrder::CharactersByIndex,
+            FramePhase::Victory => EntityOrder::World,
+        }
+    }
+
+    /// Whether scripts (conditions, actions, spawn or status effect scripts) run in this phase
+    pub fn runs_scripts(&self) -> bool {
+        matches!(
+            self,
+            FramePhase::StatusEffects
+                | FramePhase::Behaviors
+                | FramePhase::SpawnCollisions
+                | FramePhase::SpawnHits
+        )
+    }
+}
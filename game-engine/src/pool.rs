@@ -0,0 +1,195 @@
+//! Fixed-capacity storage for spawn instances
+//!
+//! The pool owns the spawn instances as a dense list in creation order, since iteration order
+//! is part of the simulation. Its storage is reserved once for `MAX_SPAWNS` instances and
+//! insertion stops at that limit, so spawn churn never reallocates. Alongside the list it keeps
+//! the live stable IDs as a bitset and a generation per ID that is bumped whenever a spawn with
+//! that ID is removed, so a `SpawnHandle` held across frames can tell a recycled ID from the
+//! spawn it was taken from. Spawns only enter and leave through the pool, so the live set
+//! always matches the list.
+
+use alloc::vec::Vec;
+
+use crate::core::MAX_SPAWNS;
+use crate::entity::{EntityId, SpawnInstance};
+
+/// Reference to one spawn instance that goes stale once the spawn is removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct SpawnHandle {
+    pub id: EntityId,
+    pub generation: u8,
+}
+
+/// Live spawn instances, their IDs and per-ID generations
+#[derive(Debug)]
+pub struct SpawnPool {
+    spawns: Vec<SpawnInstance>, // Creation order, capacity MAX_SPAWNS
+    live: [u64; 4],             // Bit n set = spawn ID n is in use
+    generations: [u8; 256],     // Bumped (wrapping) each time the ID is released
+}
+
+// Clones reserve the full capacity too, so a cloned state never reallocates either
+impl Clone for SpawnPool {
+    fn clone(&self) -> Self {
+        let mut spawns = Vec::with_capacity(MAX_SPAWNS);
+        spawns.extend_from_slice(&self.spawns);
+        Self {
+            spawns,
+            live: self.live,
+            generations: self.generations,
+        }
+    }
+}
+
+impl Default for SpawnPool {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl SpawnPool {
+    pub fn new() -> Self {
+        Self {
+            spawns: Vec::with_capacity(MAX_SPAWNS),
+            live: [0; 4],
+            generations: [0; 256],
+        }
+    }
+
+    /// Live spawns in creation order
+    pub fn as_slice(&self) -> &[SpawnInstance] {
+        &self.spawns
+    }
+
+    /// Live spawns in creation order, mutably (the list itself cannot grow or shrink)
+    pub fn as_mut_slice(&mut self) -> &mut [SpawnInstance] {
+        &mut self.spawns
+    }
+
+    /// Iterate live spawns in creation order
+    pub fn iter(&self) -> core::slice::Iter<'_, SpawnInstance> {
+        self.spawns.iter()
+    }
+
+    /// Iterate live spawns in creation order, mutably
+    pub fn iter_mut(&mut self) -> core::slice::IterMut<'_, SpawnInstance> {
+        self.spawns.iter_mut()
+    }
+
+    /// Number of live spawns
+    pub fn len(&self) -> usize {
+        self.spawns.len()
+    }
+
+    /// Whether no spawn is live
+    pub fn is_empty(&self) -> bool {
+        self.spawns.is_empty()
+    }
+
+    /// Whether another spawn fits below `MAX_SPAWNS`
+    pub fn has_capacity(&self) -> bool {
+        self.spawns.len() < MAX_SPAWNS
+    }
+
+    /// Whether a spawn with this ID is in use
+    pub fn is_live(&self, id: EntityId) -> bool {
+        self.live[id as usize / 64] & (1 << (id % 64)) != 0
+    }
+
+    /// Add a spawn under its `core.id`
+    /// Returns its position, or None (dropping the spawn) when the pool is full or the ID is
+    /// already in use
+    pub fn insert(&mut self, spawn: SpawnInstance) -> Option<usize> {
+        let id = spawn.core.id;
+        if !self.has_capacity() || self.is_live(id) {
+            return None;
+        }
+        self.live[id as usize / 64] |= 1 << (id % 64);
+        self.spawns.push(spawn);
+        Some(self.spawns.len() - 1)
+    }
+
+    /// Keep the spawns matching a predicate, releasing the IDs of the others
+    pub fn retain(&mut self, mut keep: impl FnMut(&SpawnInstance) -> bool) {
+        let (live, generations) = (&mut self.live, &mut self.generations);
+        self.spawns.retain(|spawn| {
+            let kept = keep(spawn);
+            if !kept {
+                let id = spawn.core.id;
+                live[id as usize / 64] &= !(1 << (id % 64));
+                generations[id as usize] = generations[id as usize].wrapping_add(1);
+            }
+            kept
+        });
+    }
+
+    /// Replace every live spawn, keeping the generations (snapshot and state restores)
+    /// Returns false, leaving the pool empty, when the spawns exceed `MAX_SPAWNS` or share an ID
+    pub fn restore(&mut self, spawns: Vec<SpawnInstance>) -> bool {
+        self.spawns.clear();
+        self.live = [0; 4];
+        if spawns.len() > MAX_SPAWNS {
+            return false;
+        }
+        for spawn in spawns {
+            if self.insert(spawn).is_none() {
+                self.spawns.clear();
+                self.live = [0; 4];
+                return false;
+            }
+        }
+        true
+    }
+
+    /// Current generation of an ID
+    pub fn generation(&self, id: EntityId) -> u8 {
+        self.generations[id as usize]
+    }
+
+    /// Restore a generation (snapshot decoding)
+    pub fn set_generation(&mut self, id: EntityId, generation: u8) {
+        self.generations[id as usize] = generation;
+    }
+
+    /// IDs with a non-zero generation, in ID order (what snapshots store)
+    pub fn generations(&self) -> impl Iterator<Item = (EntityId, u8)> + '_ {
+        self.generations
+            .iter()
+            .enumerate()
+            .filter(|(_, &generation)| generation != 0)
+            .map(|(id, &generation)| (id as EntityId, generation))
+    }
+}
+
+impl core::ops::Index<usize> for SpawnPool {
+    type Output = SpawnInstance;
+
+    fn index(&self, index: usize) -> &SpawnInstance {
+        &self.spawns[index]
+    }
+}
+
+impl core::ops::IndexMut<usize> for SpawnPool {
+    fn index_mut(&mut self, index: usize) -> &mut SpawnInstance {
+        &mut self.spawns[index]
+    }
+}
+
+impl<'a> IntoIterator for &'a SpawnPool {
+    type Item = &'a SpawnInstance;
+    type IntoIter = core::slice::Iter<'a, SpawnInstance>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.spawns.iter()
+    }
+}
+
+impl<'a> IntoIterator for &'a mut SpawnPool {
+    type Item = &'a mut SpawnInstance;
+    type IntoIter = core::slice::IterMut<'a, SpawnInstance>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.spawns.iter_mut()
+    }
+}
@@ -1,36 +1,74 @@
 //! Deterministic seeded pseudo-random number generator
 //!
-//! This module provides a Linear Congruential Generator (LCG) implementation
-//! that ensures reproducible sequences with the same seed values.
+//! This module provides a counter-based generator: every value is a pure function of
+//! (seed, stream, frame, call index). There is no hidden evolving state, so snapshots,
+//! rollback and chunked execution can seek the randomness of any frame directly.
 
-/// Deterministic pseudo-random number generator using Linear Congruential Generator
+use crate::core::Frame;
+
+/// Deterministic counter-based pseudo-random number generator
 #[derive(Debug, Clone, PartialEq, Eq)]
 pub struct SeededRng {
-    state: u16,
-    initial_seed: u16,
+    seed: u16,
+    stream: u16,  // Independent sequence selector (e.g. scripts vs spawn launch variance)
+    frame: Frame, // Frame the generator is currently positioned at
+    counter: u16, // Number of values drawn so far within the current frame
 }
 
 impl SeededRng {
-    /// LCG constants for good distribution with 16-bit arithmetic
-    /// These constants are chosen to provide a full period of 65536
-    const MULTIPLIER: u16 = 25173;
-    const INCREMENT: u16 = 13849;
-
-    /// Create a new seeded random number generator
+    /// Create a new seeded random number generator on stream 0
     pub fn new(seed: u16) -> Self {
+        Self::with_stream(seed, 0)
+    }
+
+    /// Create a generator on a specific stream; streams with the same seed never overlap
+    pub fn with_stream(seed: u16, stream: u16) -> Self {
         Self {
-            state: seed,
-            initial_seed: seed,
+            seed,
+            stream,
+            frame: 0,
+            counter: 0,
         }
     }
 
+    /// Recreate a generator at an exact position (save/resume)
+    pub fn from_parts(seed: u16, stream: u16, frame: Frame, counter: u16) -> Self {
+        Self {
+            seed,
+            stream,
+            frame,
+            counter,
+        }
+    }
+
+    /// Value at an absolute position, without constructing or advancing a generator
+    pub fn value_at(seed: u16, stream: u16, frame: Frame, index: u16) -> u16 {
+        let key = Self::mix(((seed as u32) << 16) | stream as u32);
+        let frame_key = Self::mix(frame ^ key);
+        (Self::mix(frame_key ^ index as u32) >> 16) as u16
+    }
+
+    /// 32-bit integer hash (lowbias32) with good avalanche for sequential inputs
+    fn mix(mut x: u32) -> u32 {
+        x ^= x >> 16;
+        x = x.wrapping_mul(0x7feb_352d);
+        x ^= x >> 15;
+        x = x.wrapping_mul(0x846c_a68b);
+        x ^= x >> 16;
+        x
+    }
+
+    /// Position the generator at the start of a frame
+    pub fn seek(&mut self, frame: Frame) {
+        self.frame = frame;
+        self.counter = 0;
+    }
+
     /// Generate the next random u16 value
     pub fn next_u16(&mut self) -> u16 {
-        self.state = self
-            .state
-            .wrapping_mul(Self::MULTIPLIER)
-            .wrapping_add(Self::INCREMENT);
-        self.state
+        let value = Self::value_at(self.seed, self.stream, self.frame, self.counter);
+        self.counter = self.counter.wrapping_add(1);
+        value
     }
 
     /// Generate a random u8 value
@@ -46,23 +84,42 @@ impl SeededRng {
         self.next_u16() % max
     }
 
+    /// Generate a random value in the range [-magnitude, magnitude]
+    pub fn next_signed(&mut self, magnitude: u16) -> i32 {
+        if magnitude == 0 {
+            return 0;
+        }
+        let span = magnitude as u32 * 2 + 1;
+        (self.next_u16() as u32 % span) as i32 - magnitude as i32
+    }
+
     /// Generate a random boolean value
     pub fn next_bool(&mut self) -> bool {
         (self.next_u16() & 1) == 1
     }
 
-    /// Reset the generator to its initial seed
+    /// Reset the generator to the start of frame 0
     pub fn reset(&mut self) {
-        self.state = self.initial_seed;
+        self.seek(0);
+    }
+
+    /// Get the frame the generator is positioned at
+    pub fn frame(&self) -> Frame {
+        self.frame
+    }
+
+    /// Get the number of values drawn within the current frame
+    pub fn counter(&self) -> u16 {
+        self.counter
     }
 
-    /// Get the current state (for debugging/testing)
-    pub fn current_state(&self) -> u16 {
-        self.state
+    /// Get the stream selector
+    pub fn stream(&self) -> u16 {
+        self.stream
     }
 
     /// Get the initial seed (for debugging/testing)
     pub fn initial_seed(&self) -> u16 {
-        self.initial_seed
+        self.seed
     }
 }
@@ -0,0 +1,177 @@
+//! Match recordings for sharing and deterministic playback
+//!
+//! A replay is the byte snapshot of the match before its first recorded frame plus the
+//! `GameState::state_hash` after every recorded frame. Because frames are a pure function
+//! of the previous state, re-simulating from the snapshot must reproduce every checksum;
+//! `GameState::verify_replay` reports the first frame where it does not.
+
+use crate::api::{GameError, GameResult};
+use crate::core::Frame;
+use crate::state::{GameState, GameStatus};
+use alloc::vec::Vec;
+
+/// Leading bytes identifying a replay
+const REPLAY_MAGIC: [u8; 4] = *b"RMRP";
+/// Replay layout version, bumped whenever the encoding changes
+const REPLAY_VERSION: u8 = 1;
+
+/// Recorded match: initial snapshot plus one checksum per frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Replay {
+    pub initial_state: Vec<u8>, // GameState::to_bytes before the first recorded frame
+    pub checksums: Vec<u32>,    // GameState::state_hash after each recorded frame
+}
+
+/// First frame whose re-simulated checksum differs from the recording
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ReplayDesync {
+    pub frame: Frame, // Frame counter of the re-simulated state after the diverging frame
+    pub expected: u32,
+    pub actual: u32,
+}
+
+/// Result of re-simulating a replay
+#[derive(Debug)]
+pub struct ReplayOutcome {
+    pub state: GameState, // Re-simulated state, stopped at the first desync (if any)
+    pub frames_verified: u32, // Recorded frames whose checksum matched
+    pub desync: Option<ReplayDesync>,
+}
+
+impl ReplayOutcome {
+    /// Whether every recorded frame reproduced its checksum
+    pub fn is_verified(&self) -> bool {
+        self.desync.is_none()
+    }
+}
+
+impl Replay {
+    /// Start recording from the current state
+    pub fn new(state: &GameState) -> Self {
+        Self {
+            initial_state: state.to_bytes(),
+            checksums: Vec::new(),
+        }
+    }
+
+    /// Number of recorded frames
+    pub fn frame_count(&self) -> u32 {
+        self.checksums.len() as u32
+    }
+
+    /// Record the state reached after one more frame
+    pub fn record_frame(&mut self, state: &GameState) {
+        self.checksums.push(state.state_hash());
+    }
+
+    /// Advance `state` by one frame and record it
+    pub fn advance_frame(&mut self, state: &mut GameState) -> GameResult<()> {
+        state.advance_frame()?;
+        self.record_frame(state);
+        Ok(())
+    }
+
+    /// Recording counterpart of `api::advance_frames`: skips events, stops once the game
+    /// has ended and records every advanced frame
+    pub fn advance_frames(&mut self, state: &mut GameState, frames: Frame) -> GameResult<Frame> {
+        let events_enabled = state.events_enabled;
+        state.events_enabled = false;
+
+        let mut advanced = 0;
+        let mut result = Ok(());
+        while advanced < frames && state.status == GameStatus::Playing {
+            result = self.advance_frame(state);
+            if result.is_err() {
+                break;
+            }
+            advanced += 1;
+        }
+
+        state.events_enabled = events_enabled;
+        result.map(|_| advanced)
+    }
+
+    /// Encode the replay for sharing: magic, version, then length-prefixed snapshot and
+    /// checksum list (all little-endian)
+    pub fn to_bytes(&self) -> Vec<u8> {
+        let mut bytes =
+            Vec::with_capacity(13 + self.initial_state.len() + self.checksums.len() * 4);
+        bytes.extend_from_slice(&REPLAY_MAGIC);
+        bytes.push(REPLAY_VERSION);
+        bytes.extend_from_slice(&(self.initial_state.len() as u32).to_le_bytes());
+        bytes.extend_from_slice(&self.initial_state);
+        bytes.extend_from_slice(&(self.checksums.len() as u32).to_le_bytes());
+        for checksum in &self.checksums {
+            bytes.extend_from_slice(&checksum.to_le_bytes());
+        }
+        bytes
+    }
+
+    /// Decode a replay produced by `to_bytes`
+    /// Fails with `GameError::InvalidInput` on a foreign, truncated or over-long buffer;
+    /// the embedded snapshot itself is only decoded during verification
+    pub fn from_bytes(bytes: &[u8]) -> GameResult<Self> {
+        let mut pos: usize = 0;
+        let mut take = |len: usize| -> GameResult<&[u8]> {
+            let end = pos.checked_add(len).ok_or(GameError::InvalidInput)?;
+            let slice = bytes.get(pos..end).ok_or(GameError::InvalidInput)?;
+            pos = end;
+            Ok(slice)
+        };
+        let read_u32 = |slice: &[u8]| u32::from_le_bytes([slice[0], slice[1], slice[2], slice[3]]);
+
+        if take(4)? != REPLAY_MAGIC || take(1)?[0] != REPLAY_VERSION {
+            return Err(GameError::InvalidInput);
+        }
+        let snapshot_len = read_u32(take(4)?) as usize;
+        let initial_state = take(snapshot_len)?.to_vec();
+        let count = read_u32(take(4)?);
+        let mut checksums = Vec::new();
+        for _ in 0..count {
+            checksums.push(read_u32(take(4)?));
+        }
+
+        if pos != bytes.len() {
+            return Err(GameError::InvalidInput);
+        }
+        Ok(Self {
+            initial_state,
+            checksums,
+        })
+    }
+}
+
+impl GameState {
+    /// Re-simulate a recorded match from its initial snapshot, comparing every checksum
+    ///
+    /// Stops at the first frame whose state hash differs from the recording. Fails only when
+    /// the snapshot cannot be restored or a frame errors during re-simulation.
+    pub fn verify_replay(replay: &Replay) -> GameResult<ReplayOutcome> {
+        let mut state = GameState::from_bytes(&replay.initial_state)?;
+        let events_enabled = state.events_enabled;
+        state.events_enabled = false;
+
+        let mut frames_verified = 0;
+        let mut desync = None;
+        for &expected in &replay.checksums {
+            state.advance_frame()?;
+            let actual = state.state_hash();
+            if actual != expected {
+                desync = Some(ReplayDesync {
+                    frame: state.frame,
+                    expected,
+                    actual,
+                });
+                break;
+            }
+            frames_verified += 1;
+        }
+
+        state.events_enabled = events_enabled;
+        Ok(ReplayOutcome {
+            state,
+            frames_verified,
+            desync,
+        })
+    }
+}
@@ -0,0 +1,99 @@
+//! Post-match reward rolls
+//!
+//! Rewards are derived deterministically from the final state hash and a
+//! reward table, so any platform (WASM, Solana) can verify the same loot
+//! from the same inputs.
+
+use crate::api::{GameError, GameResult};
+use crate::random::SeededRng;
+use alloc::vec::Vec;
+
+/// Single weighted entry in a reward table
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardEntry {
+    pub item_id: u16,
+    pub weight: u16,      // Relative chance of this entry being picked (0 = never)
+    pub min_quantity: u8, // Inclusive lower bound of the rolled quantity
+    pub max_quantity: u8, // Inclusive upper bound of the rolled quantity
+}
+
+/// Configurable reward table
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardTable {
+    pub rolls: u8, // Number of picks made from the table
+    pub entries: Vec<RewardEntry>,
+}
+
+/// Single rolled reward
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct RewardDrop {
+    pub item_id: u16,
+    pub quantity: u8,
+}
+
+impl RewardTable {
+    /// Validate the reward table
+    pub fn validate(&self) -> Result<(), &'static str> {
+        if self.entries.is_empty() {
+            return Err("Reward table must have at least one entry");
+        }
+        if self.total_weight() == 0 {
+            return Err("Reward table total weight must be greater than zero");
+        }
+        for entry in &self.entries {
+            if entry.min_quantity > entry.max_quantity {
+                return Err("Reward entry min_quantity cannot exceed max_quantity");
+            }
+        }
+        Ok(())
+    }
+
+    /// Sum of all entry weights
+    pub fn total_weight(&self) -> u32 {
+        self.entries.iter().map(|entry| entry.weight as u32).sum()
+    }
+}
+
+/// Generator for the reward rolls of a state hash
+///
+/// The hash keys the generator whole, its high half as the seed and its low half as the
+/// stream, so every one of the 2^32 hashes gets its own sequence.
+pub fn reward_rng(state_hash: u32) -> SeededRng {
+    SeededRng::with_stream((state_hash >> 16) as u16, state_hash as u16)
+}
+
+/// Deterministically roll rewards from a final state hash and a reward table
+///
+/// Each roll draws a weighted entry, then a quantity within the entry's range.
+/// The same hash and table always produce the same drops in the same order.
+pub fn roll_rewards(state_hash: u32, table: &RewardTable) -> GameResult<Vec<RewardDrop>> {
+    table.validate().map_err(|_| GameError::InvalidInput)?;
+
+    let mut rng = reward_rng(state_hash);
+    let total_weight = table.total_weight();
+    let mut drops = Vec::with_capacity(table.rolls as usize);
+
+    for _ in 0..table.rolls {
+        // Combine two draws so tables with total weight above u16::MAX stay reachable
+        let roll = (((rng.next_u16() as u32) << 16) | rng.next_u16() as u32) % total_weight;
+
+        let mut cumulative = 0u32;
+        for entry in &table.entries {
+            cumulative += entry.weight as u32;
+            if roll < cumulative {
+                let span = (entry.max_quantity - entry.min_quantity) as u16 + 1;
+                let quantity = entry.min_quantity + rng.next_range(span) as u8;
+                drops.push(RewardDrop {
+                    item_id: entry.item_id,
+                    quantity,
+                });
+                break;
+            }
+        }
+    }
+
+    Ok(drops)
+}
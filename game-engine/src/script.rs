@@ -1,10 +1,57 @@
 //! Bytecode scripting system for game logic
 
-use crate::constants::operator_address;
+use crate::constants::{operator_address, property_address, property_permission};
 use crate::math::Fixed;
 
 extern crate alloc;
 
+/// Kind of script being executed, selecting its property permission table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptContextKind {
+    Condition,
+    Action,
+    Spawn,
+    StatusEffect,
+}
+
+/// Script work metered by per-character quotas (see `entity::ScriptQuota`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScriptResource {
+    Instruction,
+    PropertyWrite,
+    Spawn,
+}
+
+/// Step budget a script ran past (see `ScriptError::BudgetExceeded`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BudgetScope {
+    /// One script run, CALLs and REPEAT iterations included
+    Script,
+    /// Every script run during the frame
+    Frame,
+}
+
+/// REPEAT loop in progress
+#[derive(Debug, Clone, Copy)]
+struct Repeat {
+    depth: usize,     // Call depth of the script holding the loop
+    start: usize,     // Offset of the first body instruction
+    end: usize,       // Offset just past the body
+    index_var: usize, // Variable holding the current iteration
+    iteration: u8,
+    count: u8,
+}
+
+/// Engine state after a single instruction, recorded by `ScriptEngine::execute_traced`
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScriptTraceStep {
+    pub pos: u16,          // Offset of the instruction's opcode in the script
+    pub depth: u8,         // CALL nesting of the script holding it (0 = the executed script)
+    pub opcode: u8,        // Operator executed
+    pub vars: [u8; 8],     // Byte variables after the instruction
+    pub fixed: [Fixed; 4], // Fixed-point variables after the instruction
+}
+
 /// Script execution engine with execution context
 #[derive(Debug)]
 pub struct ScriptEngine {
@@ -20,6 +67,10 @@ pub struct ScriptEngine {
     pub args: [u8; 8],
     /// Spawn IDs for spawn creation
     pub spawns: [u8; 4],
+    /// Instructions executed by the current or last run, CALLs and loops included
+    pub steps: u32,
+    /// Set by exit operators, which end subroutines and their callers alike
+    exited: bool,
 }
 
 impl ScriptEngine {
@@ -31,6 +82,8 @@ impl ScriptEngine {
             fixed: [Fixed::ZERO; 4],
             args: [0; 8],
             spawns: [0; 4],
+            steps: 0,
+            exited: false,
         }
     }
 
@@ -43,6 +96,8 @@ impl ScriptEngine {
             fixed: [Fixed::ZERO; 4],
             args,
             spawns: [0; 4],
+            steps: 0,
+            exited: false,
         }
     }
 
@@ -55,6 +110,8 @@ impl ScriptEngine {
             fixed: [Fixed::ZERO; 4],
             args,
             spawns,
+            steps: 0,
+            exited: false,
         }
     }
 
@@ -62,6 +119,8 @@ impl ScriptEngine {
     pub fn reset(&mut self) {
         self.pos = 0;
         self.exit_flag = 0;
+        self.exited = false;
+        self.steps = 0;
         self.vars = [0; 8];
         self.fixed = [Fixed::ZERO; 4];
         // Note: args and spawns are NOT reset - they persist across script executions
@@ -71,6 +130,8 @@ impl ScriptEngine {
     pub fn reset_with_args(&mut self, args: [u8; 8]) {
         self.pos = 0;
         self.exit_flag = 0;
+        self.exited = false;
+        self.steps = 0;
         self.vars = [0; 8];
         self.fixed = [Fixed::ZERO; 4];
         self.args = args;
@@ -81,6 +142,8 @@ impl ScriptEngine {
     pub fn reset_with_args_and_spawns(&mut self, args: [u8; 8], spawns: [u8; 4]) {
         self.pos = 0;
         self.exit_flag = 0;
+        self.exited = false;
+        self.steps = 0;
         self.vars = [0; 8];
         self.fixed = [Fixed::ZERO; 4];
         self.args = args;
@@ -112,32 +175,29 @@ impl ScriptEngine {
         match op_byte {
             // Control flow operations
             operator_address::EXIT => {
-                self.exit_flag = self.read_u8(script)?;
-                self.pos = script.len();
+                let exit_flag = self.read_u8(script)?;
+                self.exit(exit_flag, script);
             }
 
             operator_address::EXIT_IF_NO_ENERGY => {
                 let exit_flag = self.read_u8(script)?;
                 let energy_req = context.get_energy_requirement();
                 if context.get_current_energy() < energy_req {
-                    self.exit_flag = exit_flag;
-                    self.pos = script.len();
+                    self.exit(exit_flag, script);
                 }
             }
 
             operator_address::EXIT_IF_COOLDOWN => {
                 let exit_flag = self.read_u8(script)?;
                 if context.is_on_cooldown() {
-                    self.exit_flag = exit_flag;
-                    self.pos = script.len();
+                    self.exit(exit_flag, script);
                 }
             }
 
             operator_address::EXIT_IF_NOT_GROUNDED => {
                 let exit_flag = self.read_u8(script)?;
                 if !context.is_grounded() {
-                    self.exit_flag = exit_flag;
-                    self.pos = script.len();
+                    self.exit(exit_flag, script);
                 }
             }
 
@@ -170,7 +230,11 @@ impl ScriptEngine {
                 if var_index >= self.vars.len() + self.fixed.len() {
                     return Err(ScriptError::InvalidScript);
                 }
-                context.write_property(self, prop_address, var_index);
+                if context.check_write_permission(prop_address)?
+                    && context.check_quota(ScriptResource::PropertyWrite)?
+                {
+                    context.write_property(self, prop_address, var_index);
+                }
             }
 
             // Variable assignment operations
@@ -240,6 +304,39 @@ impl ScriptEngine {
                 self.fixed[fixed_index] = self.fixed[fixed_index].neg();
             }
 
+            operator_address::SIN | operator_address::COS => {
+                let dest = self.read_u8(script)? as usize;
+                let angle_var = self.read_u8(script)? as usize;
+                if dest >= self.fixed.len() || angle_var >= self.vars.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                let angle = self.vars[angle_var];
+                self.fixed[dest] = if op_byte == operator_address::SIN {
+                    Fixed::sin(angle)
+                } else {
+                    Fixed::cos(angle)
+                };
+            }
+
+            operator_address::ATAN2 => {
+                let dest = self.read_u8(script)? as usize;
+                let y = self.read_u8(script)? as usize;
+                let x = self.read_u8(script)? as usize;
+                if dest >= self.vars.len() || y >= self.fixed.len() || x >= self.fixed.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                self.vars[dest] = Fixed::atan2(self.fixed[y], self.fixed[x]);
+            }
+
+            operator_address::SQRT => {
+                let dest = self.read_u8(script)? as usize;
+                let source = self.read_u8(script)? as usize;
+                if dest >= self.fixed.len() || source >= self.fixed.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                self.fixed[dest] = self.fixed[source].sqrt();
+            }
+
             // Generic 3-operand byte arithmetic
             operator_address::ADD_BYTE
             | operator_address::SUB_BYTE
@@ -277,6 +374,22 @@ impl ScriptEngine {
                 self.execute_utility_binary(script, op_byte)?;
             }
 
+            operator_address::CLAMP_FIXED | operator_address::LERP => {
+                let dest = self.read_u8(script)? as usize;
+                let left = self.read_u8(script)? as usize;
+                let right = self.read_u8(script)? as usize;
+                if dest >= self.fixed.len() || left >= self.fixed.len() || right >= self.fixed.len()
+                {
+                    return Err(ScriptError::InvalidScript);
+                }
+                let value = self.fixed[dest];
+                self.fixed[dest] = if op_byte == operator_address::CLAMP_FIXED {
+                    value.clamp_between(self.fixed[left], self.fixed[right])
+                } else {
+                    value.lerp(self.fixed[left], self.fixed[right])
+                };
+            }
+
             // Game-specific operations
             operator_address::LOCK_ACTION => {
                 context.lock_action();
@@ -296,7 +409,9 @@ impl ScriptEngine {
 
             operator_address::SPAWN => {
                 let spawn_id = self.vars[self.read_u8(script)? as usize] as usize;
-                context.create_spawn(spawn_id, None);
+                if context.check_quota(ScriptResource::Spawn)? {
+                    context.create_spawn(spawn_id, None);
+                }
             }
 
             operator_address::SPAWN_WITH_VARS => {
@@ -307,7 +422,35 @@ impl ScriptEngine {
                     self.vars[self.read_u8(script)? as usize],
                     self.vars[self.read_u8(script)? as usize],
                 ];
-                context.create_spawn(spawn_id, Some(vars));
+                if context.check_quota(ScriptResource::Spawn)? {
+                    context.create_spawn(spawn_id, Some(vars));
+                }
+            }
+
+            operator_address::COUNT_SPAWNS => {
+                let var_index = self.read_u8(script)? as usize;
+                let spawn_var = self.read_u8(script)? as usize;
+                if var_index >= self.vars.len() || spawn_var >= self.vars.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                let spawn_id = self.vars[spawn_var] as usize;
+                self.vars[var_index] = context.count_owned_spawns(spawn_id);
+            }
+
+            operator_address::DESPAWN_MINE => {
+                let spawn_var = self.read_u8(script)? as usize;
+                if spawn_var >= self.vars.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                context.despawn_owned_spawns(self.vars[spawn_var] as usize);
+            }
+
+            operator_address::DESPAWN_BY_ID => {
+                let instance_var = self.read_u8(script)? as usize;
+                if instance_var >= self.vars.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                context.despawn_owned_spawn(self.vars[instance_var]);
             }
 
             operator_address::LOG_VARIABLE => {
@@ -324,11 +467,11 @@ impl ScriptEngine {
                 if var_index >= self.vars.len() {
                     return Err(ScriptError::InvalidScript);
                 }
-                self.exit_flag = match self.vars[var_index] {
+                let exit_flag = match self.vars[var_index] {
                     0 => 0,
                     _ => 1,
                 };
-                self.pos = script.len();
+                self.exit(exit_flag, script);
             }
 
             // Cooldown operators
@@ -398,6 +541,7 @@ impl ScriptEngine {
                 let character_id = self.read_u8(script)?;
                 let var_index = self.read_u8(script)? as usize;
                 let property_address = self.read_u8(script)?;
+                context.check_character_access(character_id, property_address)?;
                 context.read_character_property(self, character_id, var_index, property_address);
             }
 
@@ -405,13 +549,24 @@ impl ScriptEngine {
                 let character_id = self.read_u8(script)?;
                 let property_address = self.read_u8(script)?;
                 let var_index = self.read_u8(script)? as usize;
-                context.write_character_property(self, character_id, property_address, var_index);
+                context.check_character_access(character_id, property_address)?;
+                if context.check_write_permission(property_address)?
+                    && context.check_quota(ScriptResource::PropertyWrite)?
+                {
+                    context.write_character_property(
+                        self,
+                        character_id,
+                        property_address,
+                        var_index,
+                    );
+                }
             }
 
             operator_address::READ_SPAWN_PROPERTY => {
                 let spawn_instance_id = self.read_u8(script)?;
                 let var_index = self.read_u8(script)? as usize;
                 let property_address = self.read_u8(script)?;
+                context.check_spawn_access(spawn_instance_id, property_address)?;
                 context.read_spawn_property(self, spawn_instance_id, var_index, property_address);
             }
 
@@ -419,9 +574,195 @@ impl ScriptEngine {
                 let spawn_instance_id = self.read_u8(script)?;
                 let property_address = self.read_u8(script)?;
                 let var_index = self.read_u8(script)? as usize;
-                context.write_spawn_property(self, spawn_instance_id, property_address, var_index);
+                context.check_spawn_access(spawn_instance_id, property_address)?;
+                if context.check_write_permission(property_address)?
+                    && context.check_quota(ScriptResource::PropertyWrite)?
+                {
+                    context.write_spawn_property(
+                        self,
+                        spawn_instance_id,
+                        property_address,
+                        var_index,
+                    );
+                }
+            }
+
+            operator_address::DRAIN_ENERGY | operator_address::STEAL_ENERGY => {
+                let dest_var = self.read_u8(script)? as usize;
+                let character_id = self.vars[self.read_u8(script)? as usize];
+                let amount = self.vars[self.read_u8(script)? as usize];
+                let steal = op_byte == operator_address::STEAL_ENERGY;
+                if context.check_quota(ScriptResource::PropertyWrite)? {
+                    let drained = context.drain_energy(character_id, amount, steal);
+                    if dest_var < self.vars.len() {
+                        self.vars[dest_var] = drained;
+                    }
+                }
+            }
+
+            operator_address::CAMERA_HINT => {
+                let focus_type = self.vars[self.read_u8(script)? as usize];
+                let focus_id = self.vars[self.read_u8(script)? as usize];
+                let zoom = self.fixed[self.read_u8(script)? as usize];
+                let shake = self.vars[self.read_u8(script)? as usize];
+                context.emit_camera_hint(crate::event::CameraHint {
+                    focus_type,
+                    focus_id,
+                    zoom,
+                    shake,
+                });
+            }
+
+            operator_address::TELEPORT => {
+                let mode = self.read_u8(script)?;
+                let result_var = self.read_u8(script)? as usize;
+                let x = self.fixed[self.read_u8(script)? as usize];
+                let y = self.fixed[self.read_u8(script)? as usize];
+                let moved = context.check_quota(ScriptResource::PropertyWrite)?
+                    && context.teleport(mode, (x, y));
+                if result_var < self.vars.len() {
+                    self.vars[result_var] = moved as u8;
+                }
+            }
+
+            operator_address::DASH => {
+                let result_var = self.read_u8(script)? as usize;
+                let direction = self.vars[self.read_u8(script)? as usize];
+                let distance = self.fixed[self.read_u8(script)? as usize];
+                let frames = self.vars[self.read_u8(script)? as usize];
+                let started = context.check_quota(ScriptResource::PropertyWrite)?
+                    && context.dash(direction, distance, frames);
+                if result_var < self.vars.len() {
+                    self.vars[result_var] = started as u8;
+                }
+            }
+
+            operator_address::WALL_JUMP => {
+                let result_var = self.read_u8(script)? as usize;
+                let push = self.fixed[self.read_u8(script)? as usize];
+                let lift = self.fixed[self.read_u8(script)? as usize];
+                let jumped = context.check_quota(ScriptResource::PropertyWrite)?
+                    && context.wall_jump(push, lift);
+                if result_var < self.vars.len() {
+                    self.vars[result_var] = jumped as u8;
+                }
+            }
+
+            operator_address::DISTANCE_TO => {
+                let dest = self.read_u8(script)? as usize;
+                let entity_type = self.vars[self.read_u8(script)? as usize];
+                let entity_id = self.vars[self.read_u8(script)? as usize];
+                if dest >= self.fixed.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                self.fixed[dest] = context
+                    .offset_to(entity_type, entity_id)
+                    .map_or(Fixed::ZERO, |(x, y)| Fixed::length(x, y));
+            }
+
+            operator_address::LOAD_CONST | operator_address::LOAD_CONST_FIXED => {
+                let dest = self.read_u8(script)? as usize;
+                let index = self.read_u8(script)?;
+                let value = context.constant(index).ok_or(ScriptError::InvalidScript)?;
+                if dest >= self.fixed.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                self.fixed[dest] = if op_byte == operator_address::LOAD_CONST {
+                    Fixed::from_u16_saturating(value)
+                } else {
+                    Fixed::from_raw(value as i16)
+                };
+            }
+
+            operator_address::ASSIGN_FIXED_RAW => {
+                let dest = self.read_u8(script)? as usize;
+                let high = self.read_u8(script)?;
+                let low = self.read_u8(script)?;
+                if dest >= self.fixed.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                self.fixed[dest] = Fixed::from_raw(i16::from_be_bytes([high, low]));
             }
 
+            operator_address::DIRECTION_TO => {
+                let dest_x = self.read_u8(script)? as usize;
+                let dest_y = self.read_u8(script)? as usize;
+                let entity_type = self.vars[self.read_u8(script)? as usize];
+                let entity_id = self.vars[self.read_u8(script)? as usize];
+                if dest_x >= self.fixed.len() || dest_y >= self.fixed.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                let (x, y) = context
+                    .offset_to(entity_type, entity_id)
+                    .map_or((Fixed::ZERO, Fixed::ZERO), |(x, y)| Fixed::normalize(x, y));
+                self.fixed[dest_x] = x;
+                self.fixed[dest_y] = y;
+            }
+
+            operator_address::HAS_LINE_OF_SIGHT => {
+                let result_var = self.read_u8(script)? as usize;
+                let entity_type = self.vars[self.read_u8(script)? as usize];
+                let entity_id = self.vars[self.read_u8(script)? as usize];
+                if result_var >= self.vars.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                self.vars[result_var] = context.has_line_of_sight(entity_type, entity_id) as u8;
+            }
+
+            operator_address::PATH_DIRECTION_TO => {
+                let dest = self.read_u8(script)? as usize;
+                let entity_type = self.vars[self.read_u8(script)? as usize];
+                let entity_id = self.vars[self.read_u8(script)? as usize];
+                if dest >= self.vars.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                self.vars[dest] = context.path_step_to(entity_type, entity_id);
+            }
+
+            operator_address::ROLL_DAMAGE => {
+                let dest = self.read_u8(script)? as usize;
+                let crit_var = self.read_u8(script)? as usize;
+                let character_id = self.vars[self.read_u8(script)? as usize];
+                if dest >= self.vars.len() || crit_var >= self.vars.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                let roll = context.roll_damage(character_id).unwrap_or_default();
+                self.vars[dest] = roll.amount.min(u8::MAX as u16) as u8;
+                self.vars[crit_var] = roll.critical as u8;
+            }
+
+            operator_address::APPLY_STATUS_EFFECT => {
+                let dest = self.read_u8(script)? as usize;
+                let character_id = self.vars[self.read_u8(script)? as usize];
+                let effect_id = self.vars[self.read_u8(script)? as usize];
+                if dest >= self.vars.len() {
+                    return Err(ScriptError::InvalidScript);
+                }
+                let applied = context.check_quota(ScriptResource::PropertyWrite)?
+                    && context.apply_status_effect(character_id, effect_id);
+                self.vars[dest] = applied as u8;
+            }
+
+            operator_address::FIND_TARGET => {
+                let dest = self.read_u8(script)? as usize;
+                let query = self.read_u8(script)?;
+                let found = context.find_target(query);
+                if dest < self.vars.len() {
+                    self.vars[dest] = found.unwrap_or(255);
+                }
+            }
+
+            operator_address::ACQUIRE_TARGET => {
+                let result_var = self.read_u8(script)? as usize;
+                let query = self.read_u8(script)?;
+                let acquired = context.check_quota(ScriptResource::PropertyWrite)?
+                    && context.acquire_target(query);
+                if result_var < self.vars.len() {
+                    self.vars[result_var] = acquired as u8;
+                }
+            }
+
+            // CALL, RETURN and REPEAT need the call and loop stacks kept by `execute`
             // Invalid operator
             _ => return Err(ScriptError::InvalidOperator),
         }
@@ -434,16 +775,188 @@ impl ScriptEngine {
         &mut self,
         script: &[u8],
         context: &mut T,
+    ) -> Result<u8, ScriptError> {
+        self.run(script, context, None)
+    }
+
+    /// Execute a complete script, recording the engine state after every instruction
+    /// Steps executed before an error stay in `trace`, so failures can be inspected too
+    pub fn execute_traced<T: ScriptContext>(
+        &mut self,
+        script: &[u8],
+        context: &mut T,
+        trace: &mut alloc::vec::Vec<ScriptTraceStep>,
+    ) -> Result<u8, ScriptError> {
+        self.run(script, context, Some(trace))
+    }
+
+    /// Run a script to its end, following CALLs into action scripts and back
+    fn run<T: ScriptContext>(
+        &mut self,
+        script: &[u8],
+        context: &mut T,
+        mut trace: Option<&mut alloc::vec::Vec<ScriptTraceStep>>,
     ) -> Result<u8, ScriptError> {
         self.reset();
+        // Subroutine scripts entered through CALL and the caller position to resume, innermost last
+        let mut calls: alloc::vec::Vec<(alloc::vec::Vec<u8>, usize)> = alloc::vec::Vec::new();
+        // REPEAT loops in progress, innermost last
+        let mut repeats: alloc::vec::Vec<Repeat> = alloc::vec::Vec::new();
+
+        while !self.exited && self.exit_flag == 0 {
+            // Reaching the end of a REPEAT body starts its next iteration; finished loops and
+            // loops jumped out of are dropped
+            while let Some(repeat) = repeats.last_mut() {
+                if repeat.depth != calls.len() {
+                    break;
+                }
+                if self.pos == repeat.end && repeat.iteration + 1 < repeat.count {
+                    repeat.iteration += 1;
+                    self.vars[repeat.index_var] = repeat.iteration;
+                    self.pos = repeat.start;
+                    break;
+                }
+                if (repeat.start..repeat.end).contains(&self.pos) {
+                    break;
+                }
+                repeats.pop();
+            }
+
+            let current = calls.last().map_or(script, |(callee, _)| callee.as_slice());
+            if self.pos >= current.len() {
+                // Running off the end of a subroutine returns from it
+                match calls.pop() {
+                    Some((_, resume)) => self.pos = resume,
+                    None => break,
+                }
+                repeats.retain(|repeat| repeat.depth <= calls.len());
+                continue;
+            }
+            self.charge_step(context)?;
+            // Halt without an exit value once the paying character's budget is spent
+            if !context.check_quota(ScriptResource::Instruction)? {
+                break;
+            }
 
-        while self.pos < script.len() && self.exit_flag == 0 {
-            self.execute_instruction(script, context)?;
+            let (pos, depth, opcode, end) =
+                (self.pos, calls.len(), current[self.pos], current.len());
+            match opcode {
+                operator_address::CALL => {
+                    if let Some(callee) = self.call(current, context, depth)? {
+                        calls.push((callee, self.pos));
+                        self.pos = 0;
+                    }
+                }
+                operator_address::RETURN => {
+                    match calls.pop() {
+                        Some((_, resume)) => self.pos = resume,
+                        None => self.pos = end,
+                    }
+                    repeats.retain(|repeat| repeat.depth < depth);
+                }
+                operator_address::REPEAT => {
+                    if let Some(repeat) = self.repeat(current, context, depth)? {
+                        repeats.push(repeat);
+                    }
+                }
+                _ => self.execute_instruction(current, context)?,
+            }
+            if let Some(trace) = trace.as_deref_mut() {
+                trace.push(ScriptTraceStep {
+                    pos: pos as u16,
+                    depth: depth as u8,
+                    opcode,
+                    vars: self.vars,
+                    fixed: self.fixed,
+                });
+            }
         }
 
         Ok(self.exit_flag)
     }
 
+    /// Count one instruction against the script and frame step budgets
+    /// Unlike quotas, budgets stop runaway scripts with an error in every mode.
+    fn charge_step<T: ScriptContext>(&mut self, context: &mut T) -> Result<(), ScriptError> {
+        self.steps = self.steps.saturating_add(1);
+        let budget = context.step_budget();
+        if budget != 0 && self.steps > budget as u32 {
+            return Err(ScriptError::BudgetExceeded {
+                scope: BudgetScope::Script,
+            });
+        }
+        if !context.charge_frame_step() {
+            return Err(ScriptError::BudgetExceeded {
+                scope: BudgetScope::Frame,
+            });
+        }
+        Ok(())
+    }
+
+    /// Decode a REPEAT made at `depth`, starting its first iteration
+    /// Counts above `MAX_REPEAT_COUNT` are clamped (or an error in strict mode); a count of 0
+    /// skips the body and returns None.
+    fn repeat<T: ScriptContext>(
+        &mut self,
+        script: &[u8],
+        context: &mut T,
+        depth: usize,
+    ) -> Result<Option<Repeat>, ScriptError> {
+        self.pos += 1;
+        let index_var = self.read_u8(script)? as usize;
+        let count_var = self.read_u8(script)? as usize;
+        let length = self.read_u8(script)? as usize;
+        if index_var >= self.vars.len() || count_var >= self.vars.len() {
+            return Err(ScriptError::IndexOutOfBounds);
+        }
+
+        let count = self.vars[count_var];
+        if count > crate::core::MAX_REPEAT_COUNT && context.is_strict_mode() {
+            return Err(ScriptError::RepeatLimit { count });
+        }
+        let start = self.pos;
+        if count == 0 {
+            self.pos += length;
+            return Ok(None);
+        }
+        self.vars[index_var] = 0;
+        Ok(Some(Repeat {
+            depth,
+            start,
+            end: start + length,
+            index_var,
+            iteration: 0,
+            count: count.min(crate::core::MAX_REPEAT_COUNT),
+        }))
+    }
+
+    /// Decode a CALL made at `depth` and look up the called script
+    /// Calls to unknown actions or past the context's call depth are skipped (or an error in
+    /// strict mode).
+    fn call<T: ScriptContext>(
+        &mut self,
+        script: &[u8],
+        context: &mut T,
+        depth: usize,
+    ) -> Result<Option<alloc::vec::Vec<u8>>, ScriptError> {
+        self.pos += 1;
+        let action_id = self.read_u8(script)?;
+        let callee = context
+            .subroutine(action_id)
+            .filter(|_| depth < context.max_call_depth() as usize);
+        if callee.is_none() && context.is_strict_mode() {
+            return Err(ScriptError::InvalidCall { action_id });
+        }
+        Ok(callee)
+    }
+
+    /// End the script, including every CALL in progress
+    fn exit(&mut self, exit_flag: u8, script: &[u8]) {
+        self.exit_flag = exit_flag;
+        self.pos = script.len();
+        self.exited = true;
+    }
+
     // Generic arithmetic operation handlers
     fn execute_fixed_arithmetic(&mut self, script: &[u8], op: u8) -> Result<(), ScriptError> {
         let dest = self.read_u8(script)? as usize;
@@ -618,6 +1131,12 @@ pub trait ScriptContext {
     fn apply_duration(&mut self);
     /// Create spawn
     fn create_spawn(&mut self, spawn_id: usize, vars: Option<[u8; 4]>);
+    /// Count live spawns of a definition owned by the calling character
+    fn count_owned_spawns(&self, spawn_id: usize) -> u8;
+    /// Despawn all live spawns of a definition owned by the calling character
+    fn despawn_owned_spawns(&mut self, spawn_id: usize);
+    /// Despawn a spawn instance by stable ID if owned by the calling character
+    fn despawn_owned_spawn(&mut self, instance_id: u8);
     /// Log debug message
     fn log_debug(&self, message: &str);
     /// Read action cooldown value
@@ -626,21 +1145,168 @@ pub trait ScriptContext {
     fn read_action_last_used(&self, engine: &mut ScriptEngine, var_index: usize);
     /// Write action last used timestamp
     fn write_action_last_used(&mut self, engine: &mut ScriptEngine, var_index: usize);
+    /// Emit a presentation-only camera hint event
+    fn emit_camera_hint(&mut self, hint: crate::event::CameraHint);
+    /// Drain energy from a character on behalf of the calling character, giving it to the
+    /// caller when stealing; returns the energy removed
+    fn drain_energy(&mut self, character_id: u8, amount: u8, steal: bool) -> u8;
+    /// Move the script's entity to a teleport destination (see `constants::teleport_mode`)
+    /// that is free of solid tiles; returns whether it moved
+    fn teleport(&mut self, mode: u8, offset: (Fixed, Fixed)) -> bool;
+    /// Start a dash for the script's character (see `constants::dash_direction`); returns
+    /// whether the dash started
+    fn dash(&mut self, direction: u8, distance: Fixed, frames: u8) -> bool;
+    /// Jump the script's character off a wall it touches in the air; returns whether it jumped
+    fn wall_jump(&mut self, push: Fixed, lift: Fixed) -> bool;
+    /// Offset from the script entity's center to an entity's center (see
+    /// `GameState::offset_between`); None when the entity does not exist
+    fn offset_to(&self, entity_type: u8, entity_id: u8) -> Option<(Fixed, Fixed)>;
+    /// Whether no solid tile lies between the script entity's center and an entity's center
+    /// (see `GameState::line_of_sight_between`); false when the entity does not exist
+    fn has_line_of_sight(&self, entity_type: u8, entity_id: u8) -> bool;
+    /// First move toward an entity along the shortest grounded path (see
+    /// `GameState::path_step_between` and `constants::path_step`)
+    fn path_step_to(&self, entity_type: u8, entity_id: u8) -> u8;
+
+    /// Roll the script spawn's hit damage against a character (see `combat::roll_damage`)
+    /// None outside spawn scripts or when the character does not exist
+    fn roll_damage(&mut self, character_id: u8) -> Option<crate::combat::DamageRoll>;
+    /// Apply a status effect to a character on behalf of the calling character (see
+    /// `status::apply_status_effect_to`); returns whether it was applied or stacked
+    fn apply_status_effect(&mut self, character_id: u8, effect_id: u8) -> bool;
+    /// Enemy character picked for the script's entity by a `constants::target_query`
+    fn find_target(&self, query: u8) -> Option<u8>;
+    /// Point the script's entity at the enemy picked by a query, clearing its target when
+    /// there is none; returns whether a target was set
+    fn acquire_target(&mut self, query: u8) -> bool;
+    /// Entry of the constant pool of the definition whose script is running, None past its end
+    fn constant(&self, index: u8) -> Option<u16>;
 
     /// Check if property address is compatible with character entity access
     fn is_character_property_compatible(&self, property_address: u8) -> bool {
-        // Character properties: 0x10-0x3F
-        // EntityCore properties: 0x40-0x4F
-        (property_address >= 0x10 && property_address <= 0x3F)
-            || (property_address >= 0x40 && property_address <= 0x4F)
+        is_character_property(property_address)
     }
 
     /// Check if property address is compatible with spawn entity access
     fn is_spawn_property_compatible(&self, property_address: u8) -> bool {
-        // Spawn properties: 0x50-0x7F
-        // EntityCore properties: 0x40-0x4F
-        (property_address >= 0x50 && property_address <= 0x7F)
-            || (property_address >= 0x40 && property_address <= 0x4F)
+        is_spawn_property(property_address)
+    }
+
+    /// Kind of script this context executes, used for property permission checks
+    fn context_kind(&self) -> ScriptContextKind;
+
+    /// Check a property write against the context's permission table
+    /// Returns false when the write must be skipped (or an error in strict mode)
+    fn check_write_permission(&self, property_address: u8) -> Result<bool, ScriptError> {
+        if property_permission::is_writable(self.context_kind(), property_address) {
+            return Ok(true);
+        }
+        if self.is_strict_mode() {
+            return Err(ScriptError::ReadOnlyProperty { property_address });
+        }
+        Ok(false)
+    }
+
+    /// Charge one unit of work to the character paying for this script
+    /// Returns false once that character's per-frame quota for the resource is spent
+    fn charge_quota(&mut self, _resource: ScriptResource) -> bool {
+        true
+    }
+
+    /// Check a resource against the paying character's quota
+    /// Returns false when the operation must be skipped (or an error in strict mode)
+    fn check_quota(&mut self, resource: ScriptResource) -> Result<bool, ScriptError> {
+        if self.charge_quota(resource) {
+            return Ok(true);
+        }
+        if self.is_strict_mode() {
+            return Err(ScriptError::QuotaExceeded { resource });
+        }
+        Ok(false)
+    }
+
+    /// Whether invalid entity property accesses raise errors instead of being ignored
+    fn is_strict_mode(&self) -> bool {
+        false
+    }
+
+    /// Script of the action definition a CALL runs as a subroutine, None for unknown actions
+    fn subroutine(&self, _action_id: u8) -> Option<alloc::vec::Vec<u8>> {
+        None
+    }
+
+    /// How many CALLs may be in progress at once
+    fn max_call_depth(&self) -> u8 {
+        crate::core::DEFAULT_MAX_CALL_DEPTH
+    }
+
+    /// Most instructions one script run may execute (0 = unlimited)
+    fn step_budget(&self) -> u16 {
+        crate::core::DEFAULT_SCRIPT_STEP_BUDGET
+    }
+
+    /// Charge one instruction against the frame's step budget
+    /// Returns false once the frame budget is spent
+    fn charge_frame_step(&mut self) -> bool {
+        true
+    }
+
+    /// Check if a character ID resolves to a character
+    fn has_character(&self, _character_id: u8) -> bool {
+        true
+    }
+
+    /// Check if a stable spawn instance ID resolves to a live spawn
+    fn has_spawn(&self, _spawn_instance_id: u8) -> bool {
+        true
+    }
+
+    /// Validate a character property access (errors only in strict mode)
+    fn check_character_access(
+        &self,
+        character_id: u8,
+        property_address: u8,
+    ) -> Result<(), ScriptError> {
+        if !self.is_strict_mode() {
+            return Ok(());
+        }
+        if !self.is_character_property_compatible(property_address) {
+            return Err(ScriptError::IncompatibleProperty {
+                entity_id: character_id,
+                property_address,
+            });
+        }
+        if !self.has_character(character_id) {
+            return Err(ScriptError::InvalidEntity {
+                entity_id: character_id,
+                property_address,
+            });
+        }
+        Ok(())
+    }
+
+    /// Validate a spawn property access (errors only in strict mode)
+    fn check_spawn_access(
+        &self,
+        spawn_instance_id: u8,
+        property_address: u8,
+    ) -> Result<(), ScriptError> {
+        if !self.is_strict_mode() {
+            return Ok(());
+        }
+        if !self.is_spawn_property_compatible(property_address) {
+            return Err(ScriptError::IncompatibleProperty {
+                entity_id: spawn_instance_id,
+                property_address,
+            });
+        }
+        if !self.has_spawn(spawn_instance_id) {
+            return Err(ScriptError::InvalidEntity {
+                entity_id: spawn_instance_id,
+                property_address,
+            });
+        }
+        Ok(())
     }
 
     /// Read character property by ID with compatibility checking
@@ -772,6 +1438,676 @@ pub enum ScriptError {
     TypeMismatch,
     IndexOutOfBounds,
     ArithmeticError,
+    /// Strict mode: entity ID did not resolve to a character or live spawn
+    InvalidEntity {
+        entity_id: u8,
+        property_address: u8,
+    },
+    /// Strict mode: property address is not valid for the accessed entity kind
+    IncompatibleProperty {
+        entity_id: u8,
+        property_address: u8,
+    },
+    /// Strict mode: property is not writable from the executing context
+    ReadOnlyProperty {
+        property_address: u8,
+    },
+    /// Strict mode: the paying character's per-frame script quota is spent
+    QuotaExceeded {
+        resource: ScriptResource,
+    },
+    /// Strict mode: CALL named no action, or nested deeper than the call depth limit
+    InvalidCall {
+        action_id: u8,
+    },
+    /// Strict mode: REPEAT asked for more than `core::MAX_REPEAT_COUNT` iterations
+    RepeatLimit {
+        count: u8,
+    },
+    /// A script run or the frame executed more instructions than its step budget allows
+    BudgetExceeded {
+        scope: BudgetScope,
+    },
+    /// Static validation: the instruction whose opcode is at `offset` is malformed
+    InvalidBytecode {
+        offset: u16,
+        problem: BytecodeProblem,
+    },
+}
+
+/// What `validate` found wrong with an instruction
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BytecodeProblem {
+    /// No operator has this opcode
+    UnknownOperator { opcode: u8 },
+    /// The script ends before all operands of the instruction
+    Truncated,
+    /// Variable, argument or spawn slot index out of range for the operand
+    IndexOutOfRange { index: u8 },
+    /// SKIP/GOTO target past the end of the script
+    JumpOutOfRange { target: u16 },
+    /// SKIP/GOTO target inside another instruction's operands
+    JumpIntoInstruction { target: u16 },
+    /// Address is not a property
+    UnknownProperty { address: u8 },
+    /// Property does not belong to the entity kind accessed by the operator
+    IncompatibleProperty { address: u8 },
+    /// Property is not writable from the script's context
+    ReadOnlyProperty { address: u8 },
+    /// Constant index past the end of the definition's constant pool
+    UnknownConstant { index: u8 },
+    /// The operator is not part of the opcode table version the script was written for
+    UnavailableOperator { opcode: u8, version: u8 },
+}
+
+/// Summary of a script that passed `validate`
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct ScriptInfo {
+    pub instructions: u16,                           // Instructions in the script
+    pub has_loops: bool, // A GOTO jumps backwards (runs until an exit or the quota)
+    pub creates_spawns: bool, // Contains SPAWN or SPAWN_WITH_VARS
+    pub property_writes: alloc::vec::Vec<(u16, u8)>, // (opcode offset, address) of each write
+    pub constant_reads: alloc::vec::Vec<(u16, u8)>, // (opcode offset, index) of each constant load
+}
+
+/// Meaning of an operand byte, as checked by `validate`
+#[derive(Debug, Clone, Copy)]
+enum Operand {
+    Literal,           // Any value
+    Var,               // Byte variable index
+    Fixed,             // Fixed-point variable index
+    AnyVar,            // Byte variable index, or fixed-point variable index + 8
+    EntityVar,         // Variable index for entity property access (0-3)
+    Arg,               // Argument index
+    SpawnSlot,         // Spawn slot index
+    Constant,          // Constant pool index
+    Skip,              // Forward offset from the next instruction
+    Goto,              // Absolute offset
+    Property,          // Any property address
+    CharacterProperty, // Character or entity core property address
+    SpawnProperty,     // Spawn or entity core property address
+}
+
+/// Operand layout of an operator, None for unknown opcodes
+fn operands(opcode: u8) -> Option<&'static [Operand]> {
+    use operator_address::*;
+    use Operand::*;
+
+    Some(match opcode {
+        EXIT | EXIT_IF_NO_ENERGY | EXIT_IF_COOLDOWN | EXIT_IF_NOT_GROUNDED => &[Literal],
+        EXIT_WITH_VAR => &[Var],
+        SKIP => &[Skip],
+        GOTO => &[Goto],
+        CALL => &[Literal],
+        RETURN => &[],
+        REPEAT => &[Var, Var, Skip],
+        READ_PROP => &[AnyVar, Property],
+        WRITE_PROP => &[Property, AnyVar],
+        ASSIGN_BYTE => &[Var, Literal],
+        ASSIGN_FIXED => &[Fixed, Literal, Literal],
+        ASSIGN_RANDOM => &[Var],
+        TO_BYTE => &[Var, Fixed],
+        TO_FIXED => &[Fixed, Var],
+        ADD | SUB | MUL | DIV => &[Fixed, Fixed, Fixed],
+        NEGATE => &[Fixed],
+        SIN | COS => &[Fixed, Var],
+        ATAN2 => &[Var, Fixed, Fixed],
+        SQRT => &[Fixed, Fixed],
+        ADD_BYTE | SUB_BYTE | MUL_BYTE | DIV_BYTE | MOD_BYTE | WRAPPING_ADD => &[Var, Var, Var],
+        EQUAL | NOT_EQUAL | LESS_THAN | LESS_THAN_OR_EQUAL => &[Var, Var, Var],
+        NOT => &[Var, Var],
+        OR | AND | MIN | MAX => &[Var, Var, Var],
+        CLAMP_FIXED | LERP => &[Fixed, Fixed, Fixed],
+        LOCK_ACTION | UNLOCK_ACTION | APPLY_ENERGY_COST | APPLY_DURATION => &[],
+        SPAWN => &[Var],
+        SPAWN_WITH_VARS => &[Var, Var, Var, Var, Var],
+        COUNT_SPAWNS => &[Var, Var],
+        DESPAWN_MINE | DESPAWN_BY_ID => &[Var],
+        LOG_VARIABLE => &[Literal],
+        READ_ARG => &[Var, Arg],
+        READ_SPAWN => &[Var, SpawnSlot],
+        WRITE_SPAWN => &[SpawnSlot, Var],
+        READ_ACTION_COOLDOWN | READ_ACTION_LAST_USED | WRITE_ACTION_LAST_USED => &[Var],
+        IS_ACTION_ON_COOLDOWN => &[Var],
+        READ_CHARACTER_PROPERTY => &[Literal, EntityVar, CharacterProperty],
+        WRITE_CHARACTER_PROPERTY => &[Literal, CharacterProperty, EntityVar],
+        READ_SPAWN_PROPERTY => &[Literal, EntityVar, SpawnProperty],
+        WRITE_SPAWN_PROPERTY => &[Literal, SpawnProperty, EntityVar],
+        // The drained amount is discarded when dest_var is out of range
+        DRAIN_ENERGY | STEAL_ENERGY => &[Literal, Var, Var],
+        CAMERA_HINT => &[Var, Var, Fixed, Var],
+        TELEPORT => &[Literal, Var, Fixed, Fixed],
+        DASH => &[Var, Var, Fixed, Var],
+        WALL_JUMP => &[Var, Fixed, Fixed],
+        DISTANCE_TO => &[Fixed, Var, Var],
+        DIRECTION_TO => &[Fixed, Fixed, Var, Var],
+        HAS_LINE_OF_SIGHT | PATH_DIRECTION_TO => &[Var, Var, Var],
+        ROLL_DAMAGE => &[Var, Var, Var],
+        APPLY_STATUS_EFFECT => &[Var, Var, Var],
+        FIND_TARGET | ACQUIRE_TARGET => &[Var, Literal],
+        LOAD_CONST | LOAD_CONST_FIXED => &[Fixed, Constant],
+        ASSIGN_FIXED_RAW => &[Fixed, Literal, Literal],
+        _ => return None,
+    })
+}
+
+/// Whether an address is a character or entity core property (0x10-0x4F), or a character
+/// energy overflow property (0xF0-0xF2)
+pub fn is_character_property(address: u8) -> bool {
+    matches!(address, 0x10..=0x4F | 0xF0..=0xF2)
+}
+
+/// Whether an address is a spawn or entity core property (0x40-0x7F)
+pub fn is_spawn_property(address: u8) -> bool {
+    (0x40..=0x7F).contains(&address)
+}
+
+/// Opcode table version that introduced an operator, None for unknown opcodes
+///
+/// Opcodes are never renumbered or reused: a new table version only adds operators, and an
+/// operator whose meaning has to change is retired behind an `OpcodeAlias` instead, so a
+/// script written for any version keeps decoding to the same operations.
+pub fn operator_version(opcode: u8) -> Option<u8> {
+    use operator_address::*;
+
+    operands(opcode)?;
+    Some(match opcode {
+        CALL | RETURN | REPEAT | SIN | COS | ATAN2 | SQRT | CLAMP_FIXED | LERP | COUNT_SPAWNS
+        | DESPAWN_MINE | DESPAWN_BY_ID | DRAIN_ENERGY | STEAL_ENERGY | CAMERA_HINT | TELEPORT
+        | DASH | WALL_JUMP | DISTANCE_TO | DIRECTION_TO | FIND_TARGET | ACQUIRE_TARGET
+        | HAS_LINE_OF_SIGHT | LOAD_CONST | LOAD_CONST_FIXED | ASSIGN_FIXED_RAW
+        | PATH_DIRECTION_TO | ROLL_DAMAGE | APPLY_STATUS_EFFECT => 2,
+        _ => 1,
+    })
+}
+
+/// Retired operator, replaced by a current one with the same operand layout
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct OpcodeAlias {
+    pub opcode: u8,      // Retired opcode (never reused)
+    pub replacement: u8, // Current opcode running the same operation
+    pub removed_in: u8,  // First opcode table version without the retired opcode
+}
+
+/// Operators retired from the opcode table; no operator has been retired yet
+pub const OPCODE_ALIASES: &[OpcodeAlias] = &[];
+
+/// Rewrite a script written for opcode table `version` to the current table
+///
+/// Retired opcodes are replaced by their aliases, so scripts from stored configurations keep
+/// running the same operations. Fails like `validate` on unknown opcodes and truncated
+/// instructions, and on operators the script's table version did not have yet; the
+/// migrated script still needs `validate`.
+pub fn migrate(script: &[u8], version: u8) -> Result<alloc::vec::Vec<u8>, ScriptError> {
+    migrate_with(script, version, OPCODE_ALIASES)
+}
+
+/// `migrate` with an explicit alias list
+pub fn migrate_with(
+    script: &[u8],
+    version: u8,
+    aliases: &[OpcodeAlias],
+) -> Result<alloc::vec::Vec<u8>, ScriptError> {
+    let mut migrated = script.to_vec();
+    let mut pos = 0;
+    while pos < script.len() {
+        let opcode = script[pos];
+        let fail = |problem| ScriptError::InvalidBytecode {
+            offset: pos as u16,
+            problem,
+        };
+        let current = aliases
+            .iter()
+            .find(|alias| alias.opcode == opcode && version < alias.removed_in)
+            .map_or(opcode, |alias| alias.replacement);
+        let layout = operands(current).ok_or(fail(BytecodeProblem::UnknownOperator { opcode }))?;
+        // Aliased operators existed in the script's version by definition
+        if current == opcode && operator_version(opcode).is_some_and(|added| added > version) {
+            return Err(fail(BytecodeProblem::UnavailableOperator {
+                opcode,
+                version,
+            }));
+        }
+        let end = pos + 1 + layout.len();
+        if end > script.len() {
+            return Err(fail(BytecodeProblem::Truncated));
+        }
+        migrated[pos] = current;
+        pos = end;
+    }
+    Ok(migrated)
+}
+
+/// Check a script without running it
+///
+/// Scripts are decoded linearly from offset 0, so every byte must belong to an instruction.
+/// Each opcode must exist with all of its operands present, variable/argument/spawn slot
+/// indices must be in range, property addresses must exist and suit the accessed entity, and
+/// SKIP/GOTO targets and REPEAT bodies must land on an instruction (SKIP and REPEAT may also
+/// end at the end of the script).
+/// Facts only known while running (entity IDs, variable values, quotas) are not checked.
+pub fn validate(script: &[u8]) -> Result<ScriptInfo, ScriptError> {
+    let mut info = ScriptInfo::default();
+    let mut starts = alloc::vec![false; script.len() + 1];
+    starts[script.len()] = true;
+    let mut jumps = alloc::vec::Vec::new();
+
+    let mut pos = 0;
+    while pos < script.len() {
+        let opcode = script[pos];
+        let fail = |problem| ScriptError::InvalidBytecode {
+            offset: pos as u16,
+            problem,
+        };
+        let layout = operands(opcode).ok_or(fail(BytecodeProblem::UnknownOperator { opcode }))?;
+        let end = pos + 1 + layout.len();
+        if end > script.len() {
+            return Err(fail(BytecodeProblem::Truncated));
+        }
+
+        for (&operand, &value) in layout.iter().zip(&script[pos + 1..end]) {
+            let limit = match operand {
+                Operand::Var | Operand::Arg => 8,
+                Operand::Fixed | Operand::EntityVar | Operand::SpawnSlot => 4,
+                Operand::AnyVar => 12,
+                _ => u8::MAX as u16 + 1,
+            };
+            if value as u16 >= limit {
+                return Err(fail(BytecodeProblem::IndexOutOfRange { index: value }));
+            }
+            let address = value;
+            match operand {
+                Operand::Skip => jumps.push((pos, end + value as usize)),
+                Operand::Goto if value as usize >= script.len() => {
+                    return Err(fail(BytecodeProblem::JumpOutOfRange {
+                        target: value as u16,
+                    }));
+                }
+                Operand::Goto => {
+                    jumps.push((pos, value as usize));
+                    info.has_loops |= (value as usize) <= pos;
+                }
+                Operand::Property | Operand::CharacterProperty | Operand::SpawnProperty
+                    if !property_address::ALL
+                        .iter()
+                        .any(|&(_, known)| known == address) =>
+                {
+                    return Err(fail(BytecodeProblem::UnknownProperty { address }));
+                }
+                Operand::CharacterProperty if !is_character_property(address) => {
+                    return Err(fail(BytecodeProblem::IncompatibleProperty { address }));
+                }
+                Operand::SpawnProperty if !is_spawn_property(address) => {
+                    return Err(fail(BytecodeProblem::IncompatibleProperty { address }));
+                }
+                _ => {}
+            }
+        }
+
+        match opcode {
+            operator_address::WRITE_PROP => {
+                info.property_writes.push((pos as u16, script[pos + 1]))
+            }
+            operator_address::WRITE_CHARACTER_PROPERTY | operator_address::WRITE_SPAWN_PROPERTY => {
+                info.property_writes.push((pos as u16, script[pos + 2]))
+            }
+            operator_address::SPAWN | operator_address::SPAWN_WITH_VARS => {
+                info.creates_spawns = true
+            }
+            operator_address::LOAD_CONST | operator_address::LOAD_CONST_FIXED => {
+                info.constant_reads.push((pos as u16, script[pos + 2]))
+            }
+            _ => {}
+        }
+        starts[pos] = true;
+        info.instructions = info.instructions.saturating_add(1);
+        pos = end;
+    }
+
+    for (offset, target) in jumps {
+        let problem = if target > script.len() {
+            BytecodeProblem::JumpOutOfRange {
+                target: target as u16,
+            }
+        } else if !starts[target] {
+            BytecodeProblem::JumpIntoInstruction {
+                target: target as u16,
+            }
+        } else {
+            continue;
+        };
+        return Err(ScriptError::InvalidBytecode {
+            offset: offset as u16,
+            problem,
+        });
+    }
+
+    Ok(info)
+}
+
+/// `validate`, also requiring every property write to be allowed in the script's context
+pub fn validate_for(script: &[u8], context: ScriptContextKind) -> Result<ScriptInfo, ScriptError> {
+    let info = validate(script)?;
+    for &(offset, address) in &info.property_writes {
+        if !property_permission::is_writable(context, address) {
+            return Err(ScriptError::InvalidBytecode {
+                offset,
+                problem: BytecodeProblem::ReadOnlyProperty { address },
+            });
+        }
+    }
+    Ok(info)
+}
+
+/// `validate_for`, also requiring every constant load to index into a pool of `constants`
+/// entries (the constant pool of the definition the script belongs to)
+pub fn validate_with_constants(
+    script: &[u8],
+    context: ScriptContextKind,
+    constants: usize,
+) -> Result<ScriptInfo, ScriptError> {
+    let info = validate_for(script, context)?;
+    for &(offset, index) in &info.constant_reads {
+        if index as usize >= constants {
+            return Err(ScriptError::InvalidBytecode {
+                offset,
+                problem: BytecodeProblem::UnknownConstant { index },
+            });
+        }
+    }
+    Ok(info)
+}
+
+/// Render a script as a mnemonic listing, one instruction per line
+///
+/// Each line starts with the instruction's byte offset, e.g. `0003: ReadProp v0, CHARACTER_HEALTH`.
+/// Operands are shown as `v0` (byte variable), `f0` (fixed variable), `r0` (entity property
+/// register, byte or fixed by property type), `arg0`, `spawn0`, `const0` (constant pool
+/// entry), property names, jump targets and plain literals. Unknown opcodes and truncated instructions are listed as `.byte`
+/// lines, so malformed scripts can be inspected too.
+pub fn disassemble(script: &[u8]) -> alloc::string::String {
+    use alloc::string::String;
+    use core::fmt::Write;
+
+    let mut listing = String::new();
+    let mut pos = 0;
+    while pos < script.len() {
+        if !listing.is_empty() {
+            listing.push('\n');
+        }
+        let opcode = script[pos];
+        let layout = operands(opcode).filter(|layout| pos + layout.len() < script.len());
+        let (Some(layout), Some(name)) = (layout, operator_name(opcode)) else {
+            let _ = write!(listing, "{:04}: .byte {}", pos, opcode);
+            pos += 1;
+            continue;
+        };
+
+        let _ = write!(listing, "{:04}: {}", pos, mnemonic(name));
+        let end = pos + 1 + layout.len();
+        for (index, (&operand, &value)) in layout.iter().zip(&script[pos + 1..end]).enumerate() {
+            listing.push_str(if index == 0 { " " } else { ", " });
+            let _ = match operand {
+                Operand::Var => write!(listing, "v{}", value),
+                Operand::Fixed => write!(listing, "f{}", value),
+                Operand::AnyVar if value >= 8 => write!(listing, "f{}", value - 8),
+                Operand::AnyVar => write!(listing, "v{}", value),
+                Operand::EntityVar => write!(listing, "r{}", value),
+                Operand::Arg => write!(listing, "arg{}", value),
+                Operand::SpawnSlot => write!(listing, "spawn{}", value),
+                Operand::Constant => write!(listing, "const{}", value),
+                Operand::Skip => write!(listing, "{:04}", end + value as usize),
+                Operand::Goto => write!(listing, "{:04}", value),
+                Operand::Property | Operand::CharacterProperty | Operand::SpawnProperty => {
+                    match property_name(value) {
+                        Some(name) => write!(listing, "{}", name),
+                        None => write!(listing, "0x{:02X}", value),
+                    }
+                }
+                Operand::Literal => write!(listing, "{}", value),
+            };
+        }
+        pos = end;
+    }
+    listing
+}
+
+/// Constant name of an operator, None for unknown opcodes
+pub fn operator_name(opcode: u8) -> Option<&'static str> {
+    operator_address::ALL
+        .iter()
+        .find(|&&(_, value)| value == opcode)
+        .map(|&(name, _)| name)
+}
+
+/// Constant name of a property address, None for unknown addresses
+pub fn property_name(address: u8) -> Option<&'static str> {
+    property_address::ALL
+        .iter()
+        .find(|&&(_, value)| value == address)
+        .map(|&(name, _)| name)
+}
+
+/// Operator constant name in mnemonic form (`READ_PROP` -> `ReadProp`)
+fn mnemonic(name: &str) -> alloc::string::String {
+    name.split('_')
+        .flat_map(|word| {
+            let (first, rest) = word.split_at(1);
+            first
+                .chars()
+                .chain(rest.chars().flat_map(char::to_lowercase))
+        })
+        .collect()
+}
+
+/// Where and why `assemble` rejected its source
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct AssembleError {
+    pub line: u16, // 1-based source line
+    pub problem: AssemblyProblem,
+}
+
+/// What `assemble` found wrong with a statement
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum AssemblyProblem {
+    /// No operator has this name
+    UnknownOperator { name: alloc::string::String },
+    /// The operator takes `expected` operands
+    OperandCount { expected: u8, found: u8 },
+    /// Operand `index` (0-based) cannot be encoded in its position
+    InvalidOperand {
+        index: u8,
+        text: alloc::string::String,
+    },
+    /// SKIP/GOTO names a label that is never defined
+    UnknownLabel { name: alloc::string::String },
+    /// A label is defined twice
+    DuplicateLabel { name: alloc::string::String },
+    /// SKIP target behind the instruction or more than 255 bytes ahead, GOTO target past 255
+    JumpOutOfRange,
+}
+
+/// Compile a mnemonic listing into bytecode, the inverse of `disassemble`
+///
+/// Statements are separated by newlines or `;`, operands by spaces or commas, and `#` starts
+/// a comment. Operators are written as constant names (`READ_PROP`) or mnemonics (`ReadProp`),
+/// in any case. Operands take the forms `disassemble` prints (`v0`, `f0`, `r0`, `arg0`,
+/// `spawn0`, `const0`, property names and decimal or `0x` literals); a plain number is always taken as
+/// the raw operand byte. SKIP and GOTO jump to an absolute offset or to a label defined by a
+/// `name:` prefix. Numeric prefixes such as `0003:` are offsets printed by `disassemble` and
+/// are ignored, so listings assemble back into the original bytes. `.byte N ...` emits raw
+/// bytes. The result is not validated; see `validate`.
+pub fn assemble(source: &str) -> Result<alloc::vec::Vec<u8>, AssembleError> {
+    use alloc::string::{String, ToString};
+    use alloc::vec::Vec;
+
+    let mut bytecode = Vec::new();
+    let mut labels: Vec<(&str, usize)> = Vec::new();
+    // (line, operand byte index, offset SKIP counts from, label) of each label jump
+    let mut fixups: Vec<(u16, usize, Option<usize>, &str)> = Vec::new();
+
+    for (line_index, line) in source.lines().enumerate() {
+        let line_number = (line_index + 1).min(u16::MAX as usize) as u16;
+        let fail = |problem| AssembleError {
+            line: line_number,
+            problem,
+        };
+        let code = line.split('#').next().unwrap_or("");
+        for statement in code.split(';') {
+            let mut tokens = statement
+                .split(|c: char| c.is_whitespace() || c == ',')
+                .filter(|token| !token.is_empty())
+                .peekable();
+
+            while let Some(label) = tokens.peek().and_then(|token| token.strip_suffix(':')) {
+                tokens.next();
+                if label.is_empty() || label.bytes().all(|byte| byte.is_ascii_digit()) {
+                    continue;
+                }
+                if labels.iter().any(|&(name, _)| name == label) {
+                    return Err(fail(AssemblyProblem::DuplicateLabel {
+                        name: label.to_string(),
+                    }));
+                }
+                labels.push((label, bytecode.len()));
+            }
+            let Some(name) = tokens.next() else {
+                continue;
+            };
+            let arguments: Vec<&str> = tokens.collect();
+            let invalid = |index: usize| {
+                fail(AssemblyProblem::InvalidOperand {
+                    index: index as u8,
+                    text: arguments[index].to_string(),
+                })
+            };
+
+            if name == ".byte" {
+                for (index, &text) in arguments.iter().enumerate() {
+                    bytecode.push(parse_number(text).ok_or_else(|| invalid(index))?);
+                }
+                continue;
+            }
+
+            let opcode = operator_address::ALL
+                .iter()
+                .find(|&&(constant, _)| same_name(constant, name))
+                .map(|&(_, opcode)| opcode)
+                .ok_or_else(|| {
+                    fail(AssemblyProblem::UnknownOperator {
+                        name: String::from(name),
+                    })
+                })?;
+            let layout = operands(opcode).unwrap_or(&[]);
+            if arguments.len() != layout.len() {
+                return Err(fail(AssemblyProblem::OperandCount {
+                    expected: layout.len() as u8,
+                    found: arguments.len().min(u8::MAX as usize) as u8,
+                }));
+            }
+
+            let end = bytecode.len() + 1 + layout.len();
+            bytecode.push(opcode);
+            for (index, (&operand, &text)) in layout.iter().zip(&arguments).enumerate() {
+                let value = match operand {
+                    Operand::Skip | Operand::Goto
+                        if !text.starts_with(|c: char| c.is_ascii_digit()) =>
+                    {
+                        let from = matches!(operand, Operand::Skip).then_some(end);
+                        fixups.push((line_number, bytecode.len(), from, text));
+                        Some(0)
+                    }
+                    Operand::Skip => parse_target(text)
+                        .map(|target| {
+                            skip_length(end, target)
+                                .ok_or_else(|| fail(AssemblyProblem::JumpOutOfRange))
+                        })
+                        .transpose()?,
+                    Operand::Goto => parse_target(text)
+                        .map(|target| {
+                            u8::try_from(target).map_err(|_| fail(AssemblyProblem::JumpOutOfRange))
+                        })
+                        .transpose()?,
+                    Operand::AnyVar => match text.strip_prefix('f') {
+                        Some(index) => parse_number(index).and_then(|index| index.checked_add(8)),
+                        None => parse_operand(text, "v"),
+                    },
+                    Operand::Var => parse_operand(text, "v"),
+                    Operand::Fixed => parse_operand(text, "f"),
+                    Operand::EntityVar => parse_operand(text, "r"),
+                    Operand::Arg => parse_operand(text, "arg"),
+                    Operand::SpawnSlot => parse_operand(text, "spawn"),
+                    Operand::Constant => parse_operand(text, "const"),
+                    Operand::Property | Operand::CharacterProperty | Operand::SpawnProperty => {
+                        property_address::ALL
+                            .iter()
+                            .find(|&&(constant, _)| constant.eq_ignore_ascii_case(text))
+                            .map(|&(_, address)| address)
+                            .or_else(|| parse_number(text))
+                    }
+                    Operand::Literal => parse_number(text),
+                };
+                bytecode.push(value.ok_or_else(|| invalid(index))?);
+            }
+        }
+    }
+
+    for (line, index, from, name) in fixups {
+        let fail = |problem| AssembleError { line, problem };
+        let target = labels
+            .iter()
+            .find(|&&(label, _)| label == name)
+            .map(|&(_, offset)| offset)
+            .ok_or_else(|| {
+                fail(AssemblyProblem::UnknownLabel {
+                    name: name.to_string(),
+                })
+            })?;
+        bytecode[index] = match from {
+            Some(from) => skip_length(from, target),
+            None => u8::try_from(target).ok(),
+        }
+        .ok_or_else(|| fail(AssemblyProblem::JumpOutOfRange))?;
+    }
+
+    Ok(bytecode)
+}
+
+/// Whether an operator constant name matches a name written in assembler source, ignoring
+/// case and underscores (`READ_PROP`, `ReadProp`, `read_prop`)
+fn same_name(constant: &str, written: &str) -> bool {
+    let letters = |name: &str| {
+        name.bytes()
+            .filter(|&byte| byte != b'_')
+            .map(|byte| byte.to_ascii_uppercase())
+            .collect::<alloc::vec::Vec<u8>>()
+    };
+    letters(constant) == letters(written)
+}
+
+/// Decimal or `0x` hexadecimal byte
+fn parse_number(text: &str) -> Option<u8> {
+    match text.strip_prefix("0x").or_else(|| text.strip_prefix("0X")) {
+        Some(hex) => u8::from_str_radix(hex, 16).ok(),
+        None => text.parse().ok(),
+    }
+}
+
+/// Operand index written with its prefix (`v3`) or as a plain number
+fn parse_operand(text: &str, prefix: &str) -> Option<u8> {
+    parse_number(text.strip_prefix(prefix).unwrap_or(text))
+}
+
+/// Absolute jump target offset
+fn parse_target(text: &str) -> Option<usize> {
+    text.parse().ok()
+}
+
+/// SKIP operand jumping from `from` (the next instruction) to `target`
+fn skip_length(from: usize, target: usize) -> Option<u8> {
+    target
+        .checked_sub(from)
+        .and_then(|length| u8::try_from(length).ok())
 }
 
 impl Default for ScriptEngine {
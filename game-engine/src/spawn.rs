@@ -1,9 +1,11 @@
 //! Spawn system for projectiles and temporary objects
 
 use crate::{
-    entity::{Element, SpawnDefinition, SpawnInstance},
+    combat::DamageRoll,
+    entity::{Element, SpawnDefinition, SpawnInstance, StatusEffectId},
     math::Fixed,
-    script::{ScriptContext, ScriptEngine, ScriptError},
+    pathfinding::JumpReach,
+    script::{ScriptContext, ScriptContextKind, ScriptEngine, ScriptError},
     state::GameState,
 };
 
@@ -32,7 +34,23 @@ impl SpawnDefinition {
                 element: None,
                 chance: 100,
                 size: (16, 16), // Default size
+                collision_mask: 0,
+                falloff_start: 0,
+                falloff_end: 0,
+                velocity_spread: Fixed::ZERO,
+                duration_variance: 0,
+                damage_variance: 0,
+                energy_damage: 0,
+                energy_steal: 0,
+                terrain: 0,
+                grapple: 0,
+                grapple_speed: Fixed::ZERO,
+                grapple_frames: 0,
+                platform: false,
+                knockback: Fixed::ZERO,
+                hitstun: 0,
                 args: [0; 8],
+                constants: Vec::new(),
                 spawns: [0; 4],
                 behavior_script: Vec::new(),
                 collision_script: Vec::new(),
@@ -59,7 +77,23 @@ impl SpawnDefinition {
             element,
             chance: 100,
             size: (16, 16), // Default size
+            collision_mask: 0,
+            falloff_start: 0,
+            falloff_end: 0,
+            velocity_spread: Fixed::ZERO,
+            duration_variance: 0,
+            damage_variance: 0,
+            energy_damage: 0,
+            energy_steal: 0,
+            terrain: 0,
+            grapple: 0,
+            grapple_speed: Fixed::ZERO,
+            grapple_frames: 0,
+            platform: false,
+            knockback: Fixed::ZERO,
+            hitstun: 0,
             args: [0; 8],
+            constants: Vec::new(),
             spawns: [0; 4],
             behavior_script: Vec::new(),
             collision_script: Vec::new(),
@@ -84,6 +118,7 @@ impl SpawnDefinition {
         // Set size from definition
         instance.core.size = self.size;
         instance.life_span = self.duration;
+        instance.damage = self.damage_base;
         if let Some(vars) = vars {
             instance.runtime_vars = vars;
         }
@@ -93,6 +128,26 @@ impl SpawnDefinition {
         instance
     }
 
+    /// Scale base damage by distance traveled: full damage up to `falloff_start`,
+    /// then linear decay reaching zero at `falloff_end`
+    pub fn damage_at_distance(&self, distance: u16) -> u16 {
+        self.apply_falloff(self.damage_base, distance)
+    }
+
+    /// Scale an instance's rolled damage by distance traveled using this definition's falloff
+    pub fn apply_falloff(&self, damage: u16, distance: u16) -> u16 {
+        if self.falloff_end == 0 || distance <= self.falloff_start {
+            return damage;
+        }
+        if distance >= self.falloff_end || self.falloff_end <= self.falloff_start {
+            return 0;
+        }
+
+        let remaining = (self.falloff_end - distance) as u32;
+        let falloff_range = (self.falloff_end - self.falloff_start) as u32;
+        (damage as u32 * remaining / falloff_range) as u16
+    }
+
     /// Execute behavior script for spawn movement and logic
     pub fn execute_behavior_script(
         &self,
@@ -173,6 +228,24 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
                     engine.fixed[var_index] = Fixed::from_int(self.game_state.seed as i16);
                 }
             }
+            property_address::GAME_GRAVITY => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = self.game_state.gravity.1;
+                }
+            }
+            property_address::GAME_GRAVITY_X => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = self.game_state.gravity.0;
+                }
+            }
+            property_address::GAME_ARENA_WIDTH
+            | property_address::GAME_ARENA_HEIGHT
+            | property_address::GAME_ARENA_CENTER_X
+            | property_address::GAME_ARENA_CENTER_Y => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = self.game_state.arena_property(prop_address);
+                }
+            }
 
             // Spawn definition properties (read from definition)
             property_address::SPAWN_DEF_DAMAGE_BASE => {
@@ -215,6 +288,21 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
                     engine.vars[var_index] = self.spawn_def.element.map_or(255, |e| e as u8);
                 }
             }
+            property_address::SPAWN_DEF_COLLISION_MASK => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.spawn_def.collision_mask;
+                }
+            }
+            property_address::SPAWN_DEF_FALLOFF_START => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(self.spawn_def.falloff_start);
+                }
+            }
+            property_address::SPAWN_DEF_FALLOFF_END => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(self.spawn_def.falloff_end);
+                }
+            }
             property_address::SPAWN_DEF_ARG0
             | property_address::SPAWN_DEF_ARG1
             | property_address::SPAWN_DEF_ARG2
@@ -227,6 +315,22 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
                 }
             }
 
+            // Variables shared by every spawn of this definition
+            property_address::SPAWN_DEF_STATIC0
+            | property_address::SPAWN_DEF_STATIC1
+            | property_address::SPAWN_DEF_STATIC2
+            | property_address::SPAWN_DEF_STATIC3 => {
+                let bank = self
+                    .game_state
+                    .static_vars
+                    .spawns
+                    .get(self.spawn_instance.spawn_id as usize);
+                if let (Some(bank), true) = (bank, var_index < engine.vars.len()) {
+                    let static_idx = (prop_address - property_address::SPAWN_DEF_STATIC0) as usize;
+                    engine.vars[var_index] = bank[static_idx];
+                }
+            }
+
             // Spawn instance properties (read from instance)
             property_address::SPAWN_INST_VAR0
             | property_address::SPAWN_INST_VAR1
@@ -257,13 +361,19 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
             }
             property_address::SPAWN_INST_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(self.spawn_instance.health as i16);
+                    engine.fixed[var_index] =
+                        Fixed::from_u16_saturating(self.spawn_instance.health);
+                }
+            }
+            property_address::SPAWN_INST_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.spawn_instance.health.min(u8::MAX as u16) as u8;
                 }
             }
             property_address::SPAWN_INST_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
                     engine.fixed[var_index] =
-                        Fixed::from_int(self.spawn_instance.health_cap as i16);
+                        Fixed::from_u16_saturating(self.spawn_instance.health_cap);
                 }
             }
             property_address::SPAWN_INST_ROTATION => {
@@ -276,6 +386,25 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
                     engine.vars[var_index] = self.spawn_instance.element as u8;
                 }
             }
+            property_address::SPAWN_INST_ORIGIN_X => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = self.spawn_instance.origin.0;
+                }
+            }
+            property_address::SPAWN_INST_ORIGIN_Y => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = self.spawn_instance.origin.1;
+                }
+            }
+            property_address::SPAWN_INST_DAMAGE => {
+                if var_index < engine.fixed.len() {
+                    let distance = self.spawn_instance.distance_from_origin();
+                    let damage = self
+                        .spawn_def
+                        .apply_falloff(self.spawn_instance.damage, distance);
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(damage);
+                }
+            }
 
             // Spawn core properties
             property_address::SPAWN_CORE_ID => {
@@ -293,6 +422,17 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
                     engine.vars[var_index] = self.spawn_instance.owner_type;
                 }
             }
+            // Entity target properties (a collision script's target is the character it hit)
+            property_address::ENTITY_TARGET_ID => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.spawn_instance.core.target_id.unwrap_or(255);
+                }
+            }
+            property_address::ENTITY_TARGET_TYPE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.spawn_instance.core.target_type;
+                }
+            }
             property_address::SPAWN_POS_X => {
                 if var_index < engine.fixed.len() {
                     engine.fixed[var_index] = self.spawn_instance.core.pos.0;
@@ -338,6 +478,22 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
         use crate::constants::property_address;
 
         match prop_address {
+            // Variables shared by every spawn of this definition
+            property_address::SPAWN_DEF_STATIC0
+            | property_address::SPAWN_DEF_STATIC1
+            | property_address::SPAWN_DEF_STATIC2
+            | property_address::SPAWN_DEF_STATIC3 => {
+                let bank = self
+                    .game_state
+                    .static_vars
+                    .spawns
+                    .get_mut(self.spawn_instance.spawn_id as usize);
+                if let (Some(bank), true) = (bank, var_index < engine.vars.len()) {
+                    let static_idx = (prop_address - property_address::SPAWN_DEF_STATIC0) as usize;
+                    bank[static_idx] = engine.vars[var_index];
+                }
+            }
+
             // Spawn instance properties (writable)
             property_address::SPAWN_INST_VAR0
             | property_address::SPAWN_INST_VAR1
@@ -368,12 +524,17 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
             }
             property_address::SPAWN_INST_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    self.spawn_instance.health = engine.fixed[var_index].to_int().max(0) as u16;
+                    self.spawn_instance.health = engine.fixed[var_index].to_u16_saturating();
+                }
+            }
+            property_address::SPAWN_INST_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    self.spawn_instance.health = engine.vars[var_index] as u16;
                 }
             }
             property_address::SPAWN_INST_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
-                    self.spawn_instance.health_cap = engine.fixed[var_index].to_int().max(0) as u16;
+                    self.spawn_instance.health_cap = engine.fixed[var_index].to_u16_saturating();
                 }
             }
             property_address::SPAWN_INST_ROTATION => {
@@ -473,12 +634,46 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
         }
 
         // Set properties from spawn definition
+        new_spawn.core.size = spawn_def.size;
         new_spawn.life_span = spawn_def.duration;
         new_spawn.element = spawn_def.element.unwrap_or(crate::entity::Element::Punct);
+        self.game_state
+            .apply_launch_variance(spawn_id, &mut new_spawn);
 
         self.to_spawn.push(new_spawn);
     }
 
+    fn count_owned_spawns(&self, spawn_id: usize) -> u8 {
+        if self.spawn_instance.owner_type != 1 {
+            return 0;
+        }
+        self.game_state
+            .count_owned_spawns(self.spawn_instance.owner_id, spawn_id)
+    }
+
+    fn despawn_owned_spawns(&mut self, spawn_id: usize) {
+        if self.spawn_instance.owner_type != 1 {
+            return;
+        }
+        self.game_state
+            .despawn_owned_spawns(self.spawn_instance.owner_id, spawn_id);
+        // The running spawn is a working copy that is written back after the script
+        if self.spawn_instance.spawn_id as usize == spawn_id {
+            self.spawn_instance.life_span = 0;
+        }
+    }
+
+    fn despawn_owned_spawn(&mut self, instance_id: u8) {
+        if self.spawn_instance.owner_type != 1 {
+            return;
+        }
+        self.game_state
+            .despawn_owned_spawn(self.spawn_instance.owner_id, instance_id);
+        if self.spawn_instance.core.id == instance_id {
+            self.spawn_instance.life_span = 0;
+        }
+    }
+
     fn log_debug(&self, _message: &str) {}
 
     fn read_action_cooldown(&self, _engine: &mut ScriptEngine, _var_index: usize) {
@@ -493,6 +688,150 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
         // Spawns can't modify action last used data
     }
 
+    fn emit_camera_hint(&mut self, hint: crate::event::CameraHint) {
+        self.game_state.emit_camera_hint(hint);
+    }
+
+    fn drain_energy(&mut self, character_id: u8, amount: u8, steal: bool) -> u8 {
+        // Energy is credited to (and stolen for) the owning character, if any
+        let owner_id =
+            (self.spawn_instance.owner_type == 1).then_some(self.spawn_instance.owner_id);
+        let steal_percent = if steal { 100 } else { 0 };
+        self.game_state
+            .drain_character_energy(character_id, amount, owner_id, steal_percent)
+    }
+
+    fn teleport(&mut self, mode: u8, offset: (Fixed, Fixed)) -> bool {
+        let core = self.spawn_instance.core.clone();
+        match self.game_state.teleport_destination(&core, mode, offset) {
+            Some(pos) => {
+                self.spawn_instance.core.pos = pos;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn dash(&mut self, _direction: u8, _distance: Fixed, _frames: u8) -> bool {
+        // Only characters dash; spawns set their velocity directly
+        false
+    }
+
+    fn wall_jump(&mut self, _push: Fixed, _lift: Fixed) -> bool {
+        // Spawns bounce off walls through their collision scripts instead
+        false
+    }
+
+    fn offset_to(&self, entity_type: u8, entity_id: u8) -> Option<(Fixed, Fixed)> {
+        self.game_state
+            .offset_between(&self.spawn_instance.core, 2, entity_type, entity_id)
+    }
+
+    fn has_line_of_sight(&self, entity_type: u8, entity_id: u8) -> bool {
+        self.game_state
+            .line_of_sight_between(&self.spawn_instance.core, 2, entity_type, entity_id)
+    }
+
+    fn path_step_to(&self, entity_type: u8, entity_id: u8) -> u8 {
+        let core = &self.spawn_instance.core;
+        self.game_state
+            .path_step_between(core, 2, JumpReach::NONE, entity_type, entity_id)
+    }
+
+    fn roll_damage(&mut self, character_id: u8) -> Option<DamageRoll> {
+        let character = self.game_state.characters.get(character_id as usize)?;
+        let armor = character.get_armor(self.spawn_instance.element);
+        let base = self.spawn_def.apply_falloff(
+            self.spawn_instance.damage,
+            self.spawn_instance.distance_from_origin(),
+        );
+        Some(
+            self.game_state
+                .roll_spawn_damage(self.spawn_def, base, armor),
+        )
+    }
+
+    fn apply_status_effect(&mut self, character_id: u8, effect_id: u8) -> bool {
+        // Effects are credited to the owning character, like the spawn's hits
+        let source = if self.spawn_instance.owner_type == 1 {
+            (self.spawn_instance.owner_id, 1)
+        } else {
+            (self.spawn_instance.core.id, 2)
+        };
+        crate::status::apply_status_effect_to(
+            self.game_state,
+            character_id,
+            effect_id as StatusEffectId,
+            Some(source),
+        )
+        .unwrap_or(false)
+    }
+
+    fn find_target(&self, query: u8) -> Option<u8> {
+        let aggro = self.game_state.owner_aggro(self.spawn_instance);
+        self.game_state
+            .find_target(&self.spawn_instance.core, &aggro, query)
+    }
+
+    fn acquire_target(&mut self, query: u8) -> bool {
+        let target = self.find_target(query);
+        self.spawn_instance.core.set_character_target(target)
+    }
+
+    fn constant(&self, index: u8) -> Option<u16> {
+        self.spawn_def.constants.get(index as usize).copied()
+    }
+
+    fn context_kind(&self) -> ScriptContextKind {
+        ScriptContextKind::Spawn
+    }
+
+    fn is_strict_mode(&self) -> bool {
+        self.game_state.strict_mode
+    }
+
+    fn subroutine(&self, action_id: u8) -> Option<Vec<u8>> {
+        self.game_state
+            .action_definitions
+            .get(action_id as usize)
+            .map(|definition| definition.script.clone())
+    }
+
+    fn max_call_depth(&self) -> u8 {
+        self.game_state.max_call_depth
+    }
+
+    fn step_budget(&self) -> u16 {
+        self.game_state.script_step_budget
+    }
+
+    fn charge_frame_step(&mut self) -> bool {
+        self.game_state.charge_frame_step()
+    }
+
+    fn charge_quota(&mut self, resource: crate::script::ScriptResource) -> bool {
+        // Spawn scripts are paid for by the owning character
+        if self.spawn_instance.owner_type != 1 {
+            return true;
+        }
+        match self
+            .game_state
+            .characters
+            .get_mut(self.spawn_instance.owner_id as usize)
+        {
+            Some(owner) => owner.charge_script(resource),
+            None => true,
+        }
+    }
+
+    fn has_character(&self, character_id: u8) -> bool {
+        (character_id as usize) < self.game_state.characters.len()
+    }
+
+    fn has_spawn(&self, spawn_instance_id: u8) -> bool {
+        self.game_state.spawn_index(spawn_instance_id).is_some()
+    }
+
     fn read_character_property_impl(
         &mut self,
         engine: &mut ScriptEngine,
@@ -553,7 +892,12 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
             }
             property_address::CHARACTER_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(character.health as i16);
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(character.health);
+                }
+            }
+            property_address::CHARACTER_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.health.min(u8::MAX as u16) as u8;
                 }
             }
             property_address::CHARACTER_ENERGY => {
@@ -568,7 +912,7 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
             }
             property_address::CHARACTER_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(character.health_cap as i16);
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(character.health_cap);
                 }
             }
             property_address::CHARACTER_POWER => {
@@ -646,47 +990,137 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
             // Character armor values
             property_address::CHARACTER_ARMOR_PUNCT => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[0];
+                    engine.vars[var_index] = character.get_armor(Element::Punct);
                 }
             }
             property_address::CHARACTER_ARMOR_BLAST => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[1];
+                    engine.vars[var_index] = character.get_armor(Element::Blast);
                 }
             }
             property_address::CHARACTER_ARMOR_FORCE => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[2];
+                    engine.vars[var_index] = character.get_armor(Element::Force);
                 }
             }
             property_address::CHARACTER_ARMOR_SEVER => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[3];
+                    engine.vars[var_index] = character.get_armor(Element::Sever);
                 }
             }
             property_address::CHARACTER_ARMOR_HEAT => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[4];
+                    engine.vars[var_index] = character.get_armor(Element::Heat);
                 }
             }
             property_address::CHARACTER_ARMOR_CRYO => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[5];
+                    engine.vars[var_index] = character.get_armor(Element::Cryo);
                 }
             }
             property_address::CHARACTER_ARMOR_JOLT => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[6];
+                    engine.vars[var_index] = character.get_armor(Element::Jolt);
                 }
             }
             property_address::CHARACTER_ARMOR_ACID => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[7];
+                    engine.vars[var_index] = character.get_armor(Element::Acid);
                 }
             }
             property_address::CHARACTER_ARMOR_VIRUS => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[8];
+                    engine.vars[var_index] = character.get_armor(Element::Virus);
+                }
+            }
+            // Character match statistics (read-only)
+            property_address::CHARACTER_DAMAGE_DEALT => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] =
+                        Fixed::from_u16_saturating(character.stats.damage_dealt);
+                }
+            }
+            property_address::CHARACTER_DAMAGE_TAKEN => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] =
+                        Fixed::from_u16_saturating(character.stats.damage_taken);
+                }
+            }
+            property_address::CHARACTER_FRAMES_SINCE_HIT => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(
+                        character.frames_since_hit(self.game_state.frame),
+                    );
+                }
+            }
+            property_address::CHARACTER_FRAMES_SINCE_DAMAGED => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(
+                        character.frames_since_damaged(self.game_state.frame),
+                    );
+                }
+            }
+            // Character combat state (read-only)
+            property_address::CHARACTER_RECENT_DAMAGE => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] =
+                        Fixed::from_u16_saturating(character.recent_damage_taken());
+                }
+            }
+            property_address::CHARACTER_IN_COMBAT => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = if character.is_in_combat() { 1 } else { 0 };
+                }
+            }
+            // Character detection
+            property_address::CHARACTER_DETECTION_RADIUS => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.detection_radius;
+                }
+            }
+            property_address::CHARACTER_DETECTED_ENEMIES => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.detected_enemies;
+                }
+            }
+            property_address::CHARACTER_GROUND_TILE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.ground_tile;
+                }
+            }
+            property_address::CHARACTER_GROUND_TILE_X => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.ground_tile_pos.0;
+                }
+            }
+            property_address::CHARACTER_GROUND_TILE_Y => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.ground_tile_pos.1;
+                }
+            }
+            property_address::CHARACTER_FACING_TILE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.facing_tile;
+                }
+            }
+            property_address::CHARACTER_TERRAIN_CONTACT => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.terrain_contact;
+                }
+            }
+            property_address::CHARACTER_SHIELD => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(character.shield);
+                }
+            }
+            property_address::CHARACTER_CHARGE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.charge;
+                }
+            }
+            property_address::CHARACTER_OVERFLOW_MODE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.overflow_mode;
                 }
             }
             // EntityCore properties
@@ -735,6 +1169,7 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
             return; // Invalid character ID - silent failure
         }
 
+        let mut damage_dealt = 0;
         let character = &mut self.game_state.characters[character_id as usize];
 
         match property_address {
@@ -761,7 +1196,16 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
             }
             property_address::CHARACTER_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    character.health = engine.fixed[var_index].to_int().max(0) as u16;
+                    let previous = character.health;
+                    character.health = engine.fixed[var_index].to_u16_saturating();
+                    damage_dealt = previous.saturating_sub(character.health);
+                }
+            }
+            property_address::CHARACTER_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    let previous = character.health;
+                    character.health = engine.vars[var_index] as u16;
+                    damage_dealt = previous.saturating_sub(character.health);
                 }
             }
             property_address::CHARACTER_ENERGY => {
@@ -769,6 +1213,11 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
                     character.energy = engine.vars[var_index];
                 }
             }
+            property_address::CHARACTER_DETECTION_RADIUS => {
+                if var_index < engine.vars.len() {
+                    character.detection_radius = engine.vars[var_index];
+                }
+            }
             property_address::CHARACTER_ENERGY_CAP => {
                 if var_index < engine.vars.len() {
                     character.energy_cap = engine.vars[var_index];
@@ -776,7 +1225,7 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
             }
             property_address::CHARACTER_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
-                    character.health_cap = engine.fixed[var_index].to_int().max(0) as u16;
+                    character.health_cap = engine.fixed[var_index].to_u16_saturating();
                 }
             }
             property_address::CHARACTER_POWER => {
@@ -799,6 +1248,21 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
                     character.move_speed = engine.fixed[var_index];
                 }
             }
+            property_address::CHARACTER_SHIELD => {
+                if var_index < engine.fixed.len() {
+                    character.shield = engine.fixed[var_index].to_u16_saturating();
+                }
+            }
+            property_address::CHARACTER_CHARGE => {
+                if var_index < engine.vars.len() {
+                    character.charge = engine.vars[var_index].min(crate::core::MAX_CHARGE_STACKS);
+                }
+            }
+            property_address::CHARACTER_OVERFLOW_MODE => {
+                if var_index < engine.vars.len() {
+                    character.overflow_mode = engine.vars[var_index];
+                }
+            }
             property_address::CHARACTER_ENERGY_REGEN => {
                 if var_index < engine.vars.len() {
                     character.energy_regen = engine.vars[var_index];
@@ -822,47 +1286,47 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
             // Character armor values (writable)
             property_address::CHARACTER_ARMOR_PUNCT => {
                 if var_index < engine.vars.len() {
-                    character.armor[0] = engine.vars[var_index];
+                    character.set_armor(Element::Punct, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_BLAST => {
                 if var_index < engine.vars.len() {
-                    character.armor[1] = engine.vars[var_index];
+                    character.set_armor(Element::Blast, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_FORCE => {
                 if var_index < engine.vars.len() {
-                    character.armor[2] = engine.vars[var_index];
+                    character.set_armor(Element::Force, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_SEVER => {
                 if var_index < engine.vars.len() {
-                    character.armor[3] = engine.vars[var_index];
+                    character.set_armor(Element::Sever, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_HEAT => {
                 if var_index < engine.vars.len() {
-                    character.armor[4] = engine.vars[var_index];
+                    character.set_armor(Element::Heat, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_CRYO => {
                 if var_index < engine.vars.len() {
-                    character.armor[5] = engine.vars[var_index];
+                    character.set_armor(Element::Cryo, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_JOLT => {
                 if var_index < engine.vars.len() {
-                    character.armor[6] = engine.vars[var_index];
+                    character.set_armor(Element::Jolt, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_ACID => {
                 if var_index < engine.vars.len() {
-                    character.armor[7] = engine.vars[var_index];
+                    character.set_armor(Element::Acid, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_VIRUS => {
                 if var_index < engine.vars.len() {
-                    character.armor[8] = engine.vars[var_index];
+                    character.set_armor(Element::Virus, engine.vars[var_index]);
                 }
             }
             // EntityCore properties (writable)
@@ -882,7 +1346,7 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
                 }
             }
             property_address::ENTITY_TARGET_ID => {
-                if var_index < engine.vars.len() {
+                if var_index < engine.vars.len() && !character.is_taunted() {
                     character.core.target_id = if engine.vars[var_index] == 255 {
                         None
                     } else {
@@ -891,12 +1355,24 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
                 }
             }
             property_address::ENTITY_TARGET_TYPE => {
-                if var_index < engine.vars.len() {
+                if var_index < engine.vars.len() && !character.is_taunted() {
                     character.core.target_type = engine.vars[var_index];
                 }
             }
             _ => {} // Property not writable or not supported
         }
+
+        // Credit damage to the owning character
+        if damage_dealt > 0
+            && self.spawn_instance.owner_type == 1
+            && self.spawn_instance.owner_id != character_id
+        {
+            self.game_state.record_damage_dealt(
+                self.spawn_instance.owner_id,
+                character_id,
+                damage_dealt,
+            );
+        }
     }
 
     fn read_spawn_property_impl(
@@ -908,12 +1384,12 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
     ) {
         use crate::constants::property_address;
 
-        // Validate spawn instance ID
-        if spawn_instance_id as usize >= self.game_state.spawn_instances.len() {
+        // Resolve stable spawn instance ID
+        let Some(index) = self.game_state.spawn_index(spawn_instance_id) else {
             return; // Invalid spawn instance ID - silent failure
-        }
+        };
 
-        let spawn_instance = &self.game_state.spawn_instances[spawn_instance_id as usize];
+        let spawn_instance = &self.game_state.spawn_instances()[index];
 
         match property_address {
             // EntityCore properties
@@ -983,12 +1459,17 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
             // Spawn instance properties
             property_address::SPAWN_INST_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(spawn_instance.health as i16);
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(spawn_instance.health);
+                }
+            }
+            property_address::SPAWN_INST_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = spawn_instance.health.min(u8::MAX as u16) as u8;
                 }
             }
             property_address::SPAWN_INST_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(spawn_instance.health_cap as i16);
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(spawn_instance.health_cap);
                 }
             }
             property_address::SPAWN_INST_ROTATION => {
@@ -1043,12 +1524,12 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
     ) {
         use crate::constants::property_address;
 
-        // Validate spawn instance ID
-        if spawn_instance_id as usize >= self.game_state.spawn_instances.len() {
+        // Resolve stable spawn instance ID
+        let Some(index) = self.game_state.spawn_index(spawn_instance_id) else {
             return; // Invalid spawn instance ID - silent failure
-        }
+        };
 
-        let spawn_instance = &mut self.game_state.spawn_instances[spawn_instance_id as usize];
+        let spawn_instance = &mut self.game_state.spawn_instances_mut()[index];
 
         match property_address {
             // EntityCore properties (writable)
@@ -1105,12 +1586,17 @@ impl ScriptContext for SpawnBehaviorContext<'_> {
             // Spawn instance properties (writable)
             property_address::SPAWN_INST_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    spawn_instance.health = engine.fixed[var_index].to_int().max(0) as u16;
+                    spawn_instance.health = engine.fixed[var_index].to_u16_saturating();
+                }
+            }
+            property_address::SPAWN_INST_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    spawn_instance.health = engine.vars[var_index] as u16;
                 }
             }
             property_address::SPAWN_INST_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
-                    spawn_instance.health_cap = engine.fixed[var_index].to_int().max(0) as u16;
+                    spawn_instance.health_cap = engine.fixed[var_index].to_u16_saturating();
                 }
             }
             property_address::SPAWN_INST_ROTATION => {
@@ -1192,29 +1678,44 @@ pub fn process_spawn_instances(
     Ok(to_spawn)
 }
 
+/// Outcome of a spawn touching a character
+#[derive(Debug, Clone)]
+pub struct SpawnHit {
+    pub result: u8,         // Collision script exit value (see `constants::hit_result`)
+    pub damage: DamageRoll, // Damage of the hit after the target's elemental armor
+    pub to_spawn: Vec<SpawnInstance>, // Spawns created by the collision script
+}
+
 /// Handle collision between spawn and target
+/// Damage is rolled from the spawn's launch damage after distance falloff and reduced by the
+/// target's armor for the spawn's element (see `combat::roll_damage`). The collision script
+/// runs with the target as its target entity.
 pub fn handle_spawn_collision(
     spawn_instance: &mut SpawnInstance,
     spawn_def: &SpawnDefinition,
     target_id: u8,
     target_armor: u8,
     game_state: &mut GameState,
-) -> Result<(u8, Vec<SpawnInstance>), ScriptError> {
+) -> Result<SpawnHit, ScriptError> {
     let mut to_spawn = Vec::new();
 
-    let element_damage = if spawn_def.damage_base > target_armor.into() {
-        (spawn_def.damage_base - target_armor as u16) as u8
-    } else {
-        0
-    };
+    let base =
+        spawn_def.apply_falloff(spawn_instance.damage, spawn_instance.distance_from_origin());
+    let damage = game_state.roll_spawn_damage(spawn_def, base, target_armor);
 
-    spawn_def.execute_collision_script(
+    spawn_instance.core.target_id = Some(target_id);
+    spawn_instance.core.target_type = 1;
+    let result = spawn_def.execute_collision_script(
         game_state,
         spawn_instance,
         &mut to_spawn,
         target_id,
-        element_damage,
+        damage.amount.min(u8::MAX as u16) as u8,
     )?;
 
-    Ok((element_damage, to_spawn))
+    Ok(SpawnHit {
+        result,
+        damage,
+        to_spawn,
+    })
 }
@@ -1,13 +1,17 @@
 //! Status effects system for temporary character modifications
 
 use crate::{
+    combat::DamageRoll,
+    core::{MAX_SIZE_SCALE, MIN_SIZE_SCALE},
     entity::{
-        Character, StatusEffectDefinition, StatusEffectId, StatusEffectInstance,
-        StatusEffectInstanceId,
+        Character, CharacterId, Element, EntityId, StackBehavior, StatusEffectDefinition,
+        StatusEffectId, StatusEffectInstance, StatusEffectInstanceId,
     },
+    event::GameEventKind,
     math::Fixed,
-    script::{ScriptContext, ScriptEngine, ScriptError},
+    script::{ScriptContext, ScriptContextKind, ScriptEngine, ScriptError},
     state::GameState,
+    tilemap::CollisionRect,
 };
 
 extern crate alloc;
@@ -30,6 +34,34 @@ pub struct StatusEffectContext<'a> {
     pub status_def: &'a StatusEffectDefinition,
 }
 
+impl StatusEffectContext<'_> {
+    /// Credit damage dealt to `target_id` to the character that applied this status effect
+    fn credit_damage(&mut self, target_id: u8, amount: u16) {
+        let (Some(source_id), 1) = (
+            self.status_instance.source_id,
+            self.status_instance.source_type,
+        ) else {
+            return;
+        };
+        if amount == 0 || source_id == target_id {
+            return;
+        }
+        self.game_state
+            .record_damage_dealt(source_id, target_id, amount);
+
+        // The source may be the character held outside the state; mirror its updated stats
+        if source_id == self.character.core.id {
+            if let Some(slot) = self.game_state.characters.get(source_id as usize) {
+                self.character.stats = slot.stats;
+            }
+        }
+        // Likewise the target, whose aggro toward the source just rose
+        if target_id == self.character.core.id {
+            self.character.add_aggro(source_id, amount);
+        }
+    }
+}
+
 impl StatusEffectDefinition {
     /// Create a new status effect from definition data
     pub fn from_def(props: Vec<u16>) -> Self {
@@ -37,9 +69,17 @@ impl StatusEffectDefinition {
             duration: props[0],
             stack_limit: props[1] as u8,
             reset_on_stack: props[2] != 0,
+            stack_behavior: StackBehavior::Stack,
             chance: 100, // Default chance
+            category: 0,
+            reflect: 0,
+            taunt: false,
+            scale: 0,
+            priority: 0,
             args: [0; 8],
+            constants: Vec::new(),
             spawns: [0; 4],
+            children: Vec::new(),
             on_script: Vec::new(),
             tick_script: Vec::new(),
             off_script: Vec::new(),
@@ -47,11 +87,31 @@ impl StatusEffectDefinition {
     }
 
     /// Apply this status effect to a character
+    /// `source` is the (entity ID, entity type) that applied the effect, if any
+    ///
+    /// A new instance also applies the definition's child effects, linked to it so they
+    /// share its lifetime. Stacking an existing instance leaves its children untouched.
     pub fn apply_to_character(
         &self,
         character: &mut Character,
         game_state: &mut GameState,
         effect_id: StatusEffectId,
+        source: Option<(EntityId, u8)>,
+    ) -> Result<bool, ScriptError> {
+        let applied = self.apply_linked(character, game_state, effect_id, source, None, 0)?;
+        refresh_modifiers(character, game_state);
+        Ok(applied)
+    }
+
+    /// Apply this effect, linking a newly created instance to the `parent` bundle instance
+    fn apply_linked(
+        &self,
+        character: &mut Character,
+        game_state: &mut GameState,
+        effect_id: StatusEffectId,
+        source: Option<(EntityId, u8)>,
+        parent: Option<StatusEffectInstanceId>,
+        depth: usize,
     ) -> Result<bool, ScriptError> {
         // Check if we can stack this effect
         let existing_instance_id = character.status_effects.iter().find(|&&instance_id| {
@@ -63,34 +123,63 @@ impl StatusEffectDefinition {
         });
 
         if let Some(&existing_id) = existing_instance_id {
-            // Effect already exists, try to stack it
+            // Effect already exists, resolve it with the definition's stacking rule
             if let Some(existing_instance) = game_state.get_status_effect_instance_mut(existing_id)
             {
-                if existing_instance.stack_count < self.stack_limit {
-                    existing_instance.stack_count += 1;
-                    if self.reset_on_stack {
+                match self.stack_behavior {
+                    StackBehavior::Stack if existing_instance.stack_count < self.stack_limit => {
+                        existing_instance.stack_count += 1;
+                        if self.reset_on_stack {
+                            existing_instance.life_span = self.duration;
+                        }
+                    }
+                    StackBehavior::Refresh => {
                         existing_instance.life_span = self.duration;
                     }
-                    return Ok(true);
-                } else {
-                    // Already at stack limit
-                    return Ok(false);
-                }
+                    // Already at stack limit, or re-application is ignored
+                    StackBehavior::Stack | StackBehavior::Ignore => return Ok(false),
+                }
+                let stacks = existing_instance.stack_count;
+                game_state.push_event(GameEventKind::StatusApplied {
+                    character_id: character.core.id,
+                    status_effect_id: effect_id,
+                    instance_id: existing_id,
+                    stacks,
+                    refreshed: true,
+                });
+                return Ok(true);
             }
         } else {
             // Create new instance
-            let new_instance = self.create_instance(effect_id);
+            let mut new_instance = self.create_instance(effect_id);
+            new_instance.character_id = character.core.id;
+            if let Some((source_id, source_type)) = source {
+                new_instance.source_id = Some(source_id);
+                new_instance.source_type = source_type;
+            }
+            if let Some(parent_id) = parent {
+                new_instance.parent = Some(parent_id);
+                if let Some(parent_instance) = game_state.get_status_effect_instance(parent_id) {
+                    new_instance.life_span = parent_instance.life_span;
+                }
+            }
             let instance_id = game_state.status_effect_instances.len() as StatusEffectInstanceId;
+            let stacks = new_instance.stack_count;
             game_state.status_effect_instances.push(new_instance);
             character.status_effects.push(instance_id);
+            game_state.push_event(GameEventKind::StatusApplied {
+                character_id: character.core.id,
+                status_effect_id: effect_id,
+                instance_id,
+                stacks,
+                refreshed: false,
+            });
 
             // Execute on_script for the new instance
-            let character_id = character.core.id;
             match execute_status_effect_script(
                 game_state,
-                character_id,
+                character,
                 instance_id,
-                effect_id,
                 StatusEffectScriptType::On,
             ) {
                 Ok(_) => {
@@ -102,6 +191,23 @@ impl StatusEffectDefinition {
                 }
             }
 
+            // Expand the bundle: child effects are applied with the same source
+            if depth < crate::core::MAX_STATUS_EFFECT_BUNDLE_DEPTH {
+                for &child_id in &self.children {
+                    if let Some(child) = game_state.get_status_effect_definition(child_id) {
+                        let child = child.clone();
+                        child.apply_linked(
+                            character,
+                            game_state,
+                            child_id,
+                            source,
+                            Some(instance_id),
+                            depth + 1,
+                        )?;
+                    }
+                }
+            }
+
             return Ok(true);
         }
 
@@ -188,7 +294,26 @@ impl ScriptContext for StatusEffectContext<'_> {
             }
             property_address::GAME_FRAME => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(self.game_state.frame as i16);
+                    let frame = self.game_state.frame.min(u16::MAX as crate::core::Frame) as u16;
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(frame);
+                }
+            }
+            property_address::GAME_GRAVITY => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = self.game_state.gravity.1;
+                }
+            }
+            property_address::GAME_GRAVITY_X => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = self.game_state.gravity.0;
+                }
+            }
+            property_address::GAME_ARENA_WIDTH
+            | property_address::GAME_ARENA_HEIGHT
+            | property_address::GAME_ARENA_CENTER_X
+            | property_address::GAME_ARENA_CENTER_Y => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = self.game_state.arena_property(prop_address);
                 }
             }
 
@@ -225,7 +350,102 @@ impl ScriptContext for StatusEffectContext<'_> {
             }
             property_address::CHARACTER_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(self.character.health as i16);
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(self.character.health);
+                }
+            }
+            property_address::CHARACTER_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.character.health.min(u8::MAX as u16) as u8;
+                }
+            }
+            // Character match statistics (read-only)
+            property_address::CHARACTER_DAMAGE_DEALT => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] =
+                        Fixed::from_u16_saturating(self.character.stats.damage_dealt);
+                }
+            }
+            property_address::CHARACTER_DAMAGE_TAKEN => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] =
+                        Fixed::from_u16_saturating(self.character.stats.damage_taken);
+                }
+            }
+            property_address::CHARACTER_FRAMES_SINCE_HIT => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(
+                        self.character.frames_since_hit(self.game_state.frame),
+                    );
+                }
+            }
+            property_address::CHARACTER_FRAMES_SINCE_DAMAGED => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(
+                        self.character.frames_since_damaged(self.game_state.frame),
+                    );
+                }
+            }
+            // Character combat state (read-only)
+            property_address::CHARACTER_RECENT_DAMAGE => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] =
+                        Fixed::from_u16_saturating(self.character.recent_damage_taken());
+                }
+            }
+            property_address::CHARACTER_IN_COMBAT => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = if self.character.is_in_combat() { 1 } else { 0 };
+                }
+            }
+            // Character detection
+            property_address::CHARACTER_DETECTION_RADIUS => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.character.detection_radius;
+                }
+            }
+            property_address::CHARACTER_DETECTED_ENEMIES => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.character.detected_enemies;
+                }
+            }
+            property_address::CHARACTER_GROUND_TILE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.character.ground_tile;
+                }
+            }
+            property_address::CHARACTER_GROUND_TILE_X => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.character.ground_tile_pos.0;
+                }
+            }
+            property_address::CHARACTER_GROUND_TILE_Y => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.character.ground_tile_pos.1;
+                }
+            }
+            property_address::CHARACTER_FACING_TILE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.character.facing_tile;
+                }
+            }
+            property_address::CHARACTER_TERRAIN_CONTACT => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.character.terrain_contact;
+                }
+            }
+            property_address::CHARACTER_SHIELD => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(self.character.shield);
+                }
+            }
+            property_address::CHARACTER_CHARGE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.character.charge;
+                }
+            }
+            property_address::CHARACTER_OVERFLOW_MODE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.character.overflow_mode;
                 }
             }
             property_address::CHARACTER_ENERGY => {
@@ -240,7 +460,7 @@ impl ScriptContext for StatusEffectContext<'_> {
             }
             property_address::CHARACTER_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(self.character.health_cap as i16);
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(self.character.health_cap);
                 }
             }
             property_address::CHARACTER_POWER => {
@@ -287,42 +507,47 @@ impl ScriptContext for StatusEffectContext<'_> {
             // Character armor properties
             property_address::CHARACTER_ARMOR_PUNCT => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = self.character.armor[0];
+                    engine.vars[var_index] = self.character.get_armor(Element::Punct);
                 }
             }
             property_address::CHARACTER_ARMOR_BLAST => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = self.character.armor[1];
+                    engine.vars[var_index] = self.character.get_armor(Element::Blast);
                 }
             }
             property_address::CHARACTER_ARMOR_FORCE => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = self.character.armor[2];
+                    engine.vars[var_index] = self.character.get_armor(Element::Force);
                 }
             }
             property_address::CHARACTER_ARMOR_SEVER => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = self.character.armor[3];
+                    engine.vars[var_index] = self.character.get_armor(Element::Sever);
                 }
             }
             property_address::CHARACTER_ARMOR_HEAT => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = self.character.armor[4];
+                    engine.vars[var_index] = self.character.get_armor(Element::Heat);
                 }
             }
             property_address::CHARACTER_ARMOR_CRYO => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = self.character.armor[5];
+                    engine.vars[var_index] = self.character.get_armor(Element::Cryo);
                 }
             }
             property_address::CHARACTER_ARMOR_JOLT => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = self.character.armor[6];
+                    engine.vars[var_index] = self.character.get_armor(Element::Jolt);
+                }
+            }
+            property_address::CHARACTER_ARMOR_ACID => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.character.get_armor(Element::Acid);
                 }
             }
             property_address::CHARACTER_ARMOR_VIRUS => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = self.character.armor[7];
+                    engine.vars[var_index] = self.character.get_armor(Element::Virus);
                 }
             }
 
@@ -347,6 +572,11 @@ impl ScriptContext for StatusEffectContext<'_> {
                     engine.vars[var_index] = self.status_def.chance;
                 }
             }
+            property_address::STATUS_EFFECT_DEF_STACK_BEHAVIOR => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = self.status_def.stack_behavior as u8;
+                }
+            }
             property_address::STATUS_EFFECT_DEF_ARG0 | property_address::STATUS_EFFECT_DEF_ARG1 => {
                 if var_index < engine.vars.len() {
                     let arg_index =
@@ -362,6 +592,23 @@ impl ScriptContext for StatusEffectContext<'_> {
                 }
             }
 
+            // Variables shared by every instance of this status effect
+            property_address::STATUS_EFFECT_DEF_STATIC0
+            | property_address::STATUS_EFFECT_DEF_STATIC1
+            | property_address::STATUS_EFFECT_DEF_STATIC2
+            | property_address::STATUS_EFFECT_DEF_STATIC3 => {
+                let bank = self
+                    .game_state
+                    .static_vars
+                    .status_effects
+                    .get(self.status_instance.definition_id);
+                if let (Some(bank), true) = (bank, var_index < engine.vars.len()) {
+                    let static_idx =
+                        (prop_address - property_address::STATUS_EFFECT_DEF_STATIC0) as usize;
+                    engine.vars[var_index] = bank[static_idx];
+                }
+            }
+
             // Status effect instance properties
             property_address::STATUS_EFFECT_INST_VAR0
             | property_address::STATUS_EFFECT_INST_VAR1
@@ -444,7 +691,22 @@ impl ScriptContext for StatusEffectContext<'_> {
             }
             property_address::CHARACTER_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    self.character.health = engine.fixed[var_index].to_int().max(0) as u16;
+                    let previous = self.character.health;
+                    self.character.health = engine.fixed[var_index].to_u16_saturating();
+
+                    // Credit damage over time to the character that applied this status effect
+                    let damage_dealt = previous.saturating_sub(self.character.health);
+                    self.credit_damage(self.character.core.id, damage_dealt);
+                }
+            }
+            property_address::CHARACTER_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    let previous = self.character.health;
+                    self.character.health = engine.vars[var_index] as u16;
+
+                    // Credit damage over time to the character that applied this status effect
+                    let damage_dealt = previous.saturating_sub(self.character.health);
+                    self.credit_damage(self.character.core.id, damage_dealt);
                 }
             }
             property_address::CHARACTER_ENERGY => {
@@ -452,6 +714,11 @@ impl ScriptContext for StatusEffectContext<'_> {
                     self.character.energy = engine.vars[var_index];
                 }
             }
+            property_address::CHARACTER_DETECTION_RADIUS => {
+                if var_index < engine.vars.len() {
+                    self.character.detection_radius = engine.vars[var_index];
+                }
+            }
             property_address::CHARACTER_ENERGY_CAP => {
                 if var_index < engine.vars.len() {
                     self.character.energy_cap = engine.vars[var_index];
@@ -459,7 +726,7 @@ impl ScriptContext for StatusEffectContext<'_> {
             }
             property_address::CHARACTER_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
-                    self.character.health_cap = engine.fixed[var_index].to_int().max(0) as u16;
+                    self.character.health_cap = engine.fixed[var_index].to_u16_saturating();
                 }
             }
             property_address::CHARACTER_POWER => {
@@ -482,6 +749,22 @@ impl ScriptContext for StatusEffectContext<'_> {
                     self.character.move_speed = engine.fixed[var_index];
                 }
             }
+            property_address::CHARACTER_SHIELD => {
+                if var_index < engine.fixed.len() {
+                    self.character.shield = engine.fixed[var_index].to_u16_saturating();
+                }
+            }
+            property_address::CHARACTER_CHARGE => {
+                if var_index < engine.vars.len() {
+                    self.character.charge =
+                        engine.vars[var_index].min(crate::core::MAX_CHARGE_STACKS);
+                }
+            }
+            property_address::CHARACTER_OVERFLOW_MODE => {
+                if var_index < engine.vars.len() {
+                    self.character.overflow_mode = engine.vars[var_index];
+                }
+            }
             property_address::CHARACTER_ENERGY_REGEN => {
                 if var_index < engine.vars.len() {
                     self.character.energy_regen = engine.vars[var_index];
@@ -506,42 +789,73 @@ impl ScriptContext for StatusEffectContext<'_> {
             // Character armor properties (writable)
             property_address::CHARACTER_ARMOR_PUNCT => {
                 if var_index < engine.vars.len() {
-                    self.character.armor[0] = engine.vars[var_index];
+                    self.character
+                        .set_armor(Element::Punct, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_BLAST => {
                 if var_index < engine.vars.len() {
-                    self.character.armor[1] = engine.vars[var_index];
+                    self.character
+                        .set_armor(Element::Blast, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_FORCE => {
                 if var_index < engine.vars.len() {
-                    self.character.armor[2] = engine.vars[var_index];
+                    self.character
+                        .set_armor(Element::Force, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_SEVER => {
                 if var_index < engine.vars.len() {
-                    self.character.armor[3] = engine.vars[var_index];
+                    self.character
+                        .set_armor(Element::Sever, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_HEAT => {
                 if var_index < engine.vars.len() {
-                    self.character.armor[4] = engine.vars[var_index];
+                    self.character
+                        .set_armor(Element::Heat, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_CRYO => {
                 if var_index < engine.vars.len() {
-                    self.character.armor[5] = engine.vars[var_index];
+                    self.character
+                        .set_armor(Element::Cryo, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_JOLT => {
                 if var_index < engine.vars.len() {
-                    self.character.armor[6] = engine.vars[var_index];
+                    self.character
+                        .set_armor(Element::Jolt, engine.vars[var_index]);
+                }
+            }
+            property_address::CHARACTER_ARMOR_ACID => {
+                if var_index < engine.vars.len() {
+                    self.character
+                        .set_armor(Element::Acid, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_VIRUS => {
                 if var_index < engine.vars.len() {
-                    self.character.armor[7] = engine.vars[var_index];
+                    self.character
+                        .set_armor(Element::Virus, engine.vars[var_index]);
+                }
+            }
+
+            // Variables shared by every instance of this status effect
+            property_address::STATUS_EFFECT_DEF_STATIC0
+            | property_address::STATUS_EFFECT_DEF_STATIC1
+            | property_address::STATUS_EFFECT_DEF_STATIC2
+            | property_address::STATUS_EFFECT_DEF_STATIC3 => {
+                let bank = self
+                    .game_state
+                    .static_vars
+                    .status_effects
+                    .get_mut(self.status_instance.definition_id);
+                if let (Some(bank), true) = (bank, var_index < engine.vars.len()) {
+                    let static_idx =
+                        (prop_address - property_address::STATUS_EFFECT_DEF_STATIC0) as usize;
+                    bank[static_idx] = engine.vars[var_index];
                 }
             }
 
@@ -653,14 +967,32 @@ impl ScriptContext for StatusEffectContext<'_> {
             spawn.runtime_vars = spawn_vars;
         }
 
-        // Assign unique ID
-        spawn.core.id = self.game_state.spawn_instances.len() as u8;
-
         // Set properties from spawn definition
+        spawn.core.size = spawn_def.size;
         spawn.life_span = spawn_def.duration;
         spawn.element = spawn_def.element.unwrap_or(crate::entity::Element::Punct);
 
-        self.game_state.spawn_instances.push(spawn);
+        // Spawns past the pool capacity are dropped without rolling launch variance
+        if !self.game_state.has_spawn_capacity() {
+            return;
+        }
+        self.game_state.apply_launch_variance(spawn_id, &mut spawn);
+        self.game_state.insert_spawn(spawn);
+    }
+
+    fn count_owned_spawns(&self, spawn_id: usize) -> u8 {
+        self.game_state
+            .count_owned_spawns(self.character.core.id, spawn_id)
+    }
+
+    fn despawn_owned_spawns(&mut self, spawn_id: usize) {
+        self.game_state
+            .despawn_owned_spawns(self.character.core.id, spawn_id);
+    }
+
+    fn despawn_owned_spawn(&mut self, instance_id: u8) {
+        self.game_state
+            .despawn_owned_spawn(self.character.core.id, instance_id);
     }
 
     fn log_debug(&self, _message: &str) {
@@ -679,6 +1011,145 @@ impl ScriptContext for StatusEffectContext<'_> {
         // Status effects can't modify action last used data
     }
 
+    fn emit_camera_hint(&mut self, hint: crate::event::CameraHint) {
+        self.game_state.emit_camera_hint(hint);
+    }
+
+    fn drain_energy(&mut self, character_id: u8, amount: u8, steal: bool) -> u8 {
+        let drainer_id = match (
+            self.status_instance.source_id,
+            self.status_instance.source_type,
+        ) {
+            (Some(source_id), 1) => Some(source_id),
+            _ => None,
+        };
+        let steal_percent = if steal { 100 } else { 0 };
+
+        // The afflicted character is held outside the state; sync its energy around the drain
+        let own_id = self.character.core.id as usize;
+        if let Some(slot) = self.game_state.characters.get_mut(own_id) {
+            slot.energy = self.character.energy;
+        }
+        let drained =
+            self.game_state
+                .drain_character_energy(character_id, amount, drainer_id, steal_percent);
+        if let Some(slot) = self.game_state.characters.get(own_id) {
+            self.character.energy = slot.energy;
+        }
+        drained
+    }
+
+    fn teleport(&mut self, mode: u8, offset: (Fixed, Fixed)) -> bool {
+        let core = self.character.core.clone();
+        match self.game_state.teleport_destination(&core, mode, offset) {
+            Some(pos) => {
+                self.character.core.pos = pos;
+                true
+            }
+            None => false,
+        }
+    }
+
+    fn dash(&mut self, direction: u8, distance: Fixed, frames: u8) -> bool {
+        self.character.start_dash(direction, distance, frames)
+    }
+
+    fn wall_jump(&mut self, push: Fixed, lift: Fixed) -> bool {
+        self.character.wall_jump(push, lift)
+    }
+
+    fn offset_to(&self, entity_type: u8, entity_id: u8) -> Option<(Fixed, Fixed)> {
+        self.game_state
+            .offset_between(&self.character.core, 1, entity_type, entity_id)
+    }
+
+    fn has_line_of_sight(&self, entity_type: u8, entity_id: u8) -> bool {
+        self.game_state
+            .line_of_sight_between(&self.character.core, 1, entity_type, entity_id)
+    }
+
+    fn path_step_to(&self, entity_type: u8, entity_id: u8) -> u8 {
+        let reach = self.game_state.jump_reach(self.character);
+        self.game_state
+            .path_step_between(&self.character.core, 1, reach, entity_type, entity_id)
+    }
+
+    fn roll_damage(&mut self, _character_id: u8) -> Option<DamageRoll> {
+        // Only spawns carry hit damage to roll
+        None
+    }
+
+    fn apply_status_effect(&mut self, character_id: u8, effect_id: u8) -> bool {
+        // Effects applied by an effect share its source, as bundle children do
+        let source = self
+            .status_instance
+            .source_id
+            .map(|source_id| (source_id, self.status_instance.source_type));
+        let effect_id = effect_id as StatusEffectId;
+        // The afflicted character is held outside the state
+        let applied = if character_id == self.character.core.id {
+            apply_status_effect_from_source(self.character, self.game_state, effect_id, source)
+        } else {
+            apply_status_effect_to(self.game_state, character_id, effect_id, source)
+        };
+        applied.unwrap_or(false)
+    }
+
+    fn find_target(&self, query: u8) -> Option<u8> {
+        self.game_state
+            .find_target(&self.character.core, &self.character.aggro, query)
+    }
+
+    fn acquire_target(&mut self, query: u8) -> bool {
+        let target = self.find_target(query);
+        // Taunted characters keep the taunter as their target
+        !self.character.is_taunted() && self.character.core.set_character_target(target)
+    }
+
+    fn constant(&self, index: u8) -> Option<u16> {
+        self.status_def.constants.get(index as usize).copied()
+    }
+
+    fn context_kind(&self) -> ScriptContextKind {
+        ScriptContextKind::StatusEffect
+    }
+
+    fn is_strict_mode(&self) -> bool {
+        self.game_state.strict_mode
+    }
+
+    fn subroutine(&self, action_id: u8) -> Option<Vec<u8>> {
+        self.game_state
+            .action_definitions
+            .get(action_id as usize)
+            .map(|definition| definition.script.clone())
+    }
+
+    fn max_call_depth(&self) -> u8 {
+        self.game_state.max_call_depth
+    }
+
+    fn step_budget(&self) -> u16 {
+        self.game_state.script_step_budget
+    }
+
+    fn charge_frame_step(&mut self) -> bool {
+        self.game_state.charge_frame_step()
+    }
+
+    fn charge_quota(&mut self, resource: crate::script::ScriptResource) -> bool {
+        // Status effect scripts are paid for by the afflicted character
+        self.character.charge_script(resource)
+    }
+
+    fn has_character(&self, character_id: u8) -> bool {
+        (character_id as usize) < self.game_state.characters.len()
+    }
+
+    fn has_spawn(&self, spawn_instance_id: u8) -> bool {
+        self.game_state.spawn_index(spawn_instance_id).is_some()
+    }
+
     fn read_character_property_impl(
         &mut self,
         engine: &mut ScriptEngine,
@@ -693,7 +1164,12 @@ impl ScriptContext for StatusEffectContext<'_> {
             return; // Invalid character ID - silent failure
         }
 
-        let character = &self.game_state.characters[character_id as usize];
+        // The character this effect is on is held outside the state while its scripts run
+        let character = if character_id == self.character.core.id {
+            &*self.character
+        } else {
+            &self.game_state.characters[character_id as usize]
+        };
 
         match property_address {
             // Character core properties
@@ -739,7 +1215,12 @@ impl ScriptContext for StatusEffectContext<'_> {
             }
             property_address::CHARACTER_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(character.health as i16);
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(character.health);
+                }
+            }
+            property_address::CHARACTER_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.health.min(u8::MAX as u16) as u8;
                 }
             }
             property_address::CHARACTER_ENERGY => {
@@ -754,7 +1235,7 @@ impl ScriptContext for StatusEffectContext<'_> {
             }
             property_address::CHARACTER_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(character.health_cap as i16);
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(character.health_cap);
                 }
             }
             property_address::CHARACTER_POWER => {
@@ -832,47 +1313,137 @@ impl ScriptContext for StatusEffectContext<'_> {
             // Character armor values
             property_address::CHARACTER_ARMOR_PUNCT => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[0];
+                    engine.vars[var_index] = character.get_armor(Element::Punct);
                 }
             }
             property_address::CHARACTER_ARMOR_BLAST => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[1];
+                    engine.vars[var_index] = character.get_armor(Element::Blast);
                 }
             }
             property_address::CHARACTER_ARMOR_FORCE => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[2];
+                    engine.vars[var_index] = character.get_armor(Element::Force);
                 }
             }
             property_address::CHARACTER_ARMOR_SEVER => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[3];
+                    engine.vars[var_index] = character.get_armor(Element::Sever);
                 }
             }
             property_address::CHARACTER_ARMOR_HEAT => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[4];
+                    engine.vars[var_index] = character.get_armor(Element::Heat);
                 }
             }
             property_address::CHARACTER_ARMOR_CRYO => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[5];
+                    engine.vars[var_index] = character.get_armor(Element::Cryo);
                 }
             }
             property_address::CHARACTER_ARMOR_JOLT => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[6];
+                    engine.vars[var_index] = character.get_armor(Element::Jolt);
                 }
             }
             property_address::CHARACTER_ARMOR_ACID => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[7];
+                    engine.vars[var_index] = character.get_armor(Element::Acid);
                 }
             }
             property_address::CHARACTER_ARMOR_VIRUS => {
                 if var_index < engine.vars.len() {
-                    engine.vars[var_index] = character.armor[8];
+                    engine.vars[var_index] = character.get_armor(Element::Virus);
+                }
+            }
+            // Character match statistics (read-only)
+            property_address::CHARACTER_DAMAGE_DEALT => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] =
+                        Fixed::from_u16_saturating(character.stats.damage_dealt);
+                }
+            }
+            property_address::CHARACTER_DAMAGE_TAKEN => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] =
+                        Fixed::from_u16_saturating(character.stats.damage_taken);
+                }
+            }
+            property_address::CHARACTER_FRAMES_SINCE_HIT => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(
+                        character.frames_since_hit(self.game_state.frame),
+                    );
+                }
+            }
+            property_address::CHARACTER_FRAMES_SINCE_DAMAGED => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(
+                        character.frames_since_damaged(self.game_state.frame),
+                    );
+                }
+            }
+            // Character combat state (read-only)
+            property_address::CHARACTER_RECENT_DAMAGE => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] =
+                        Fixed::from_u16_saturating(character.recent_damage_taken());
+                }
+            }
+            property_address::CHARACTER_IN_COMBAT => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = if character.is_in_combat() { 1 } else { 0 };
+                }
+            }
+            // Character detection
+            property_address::CHARACTER_DETECTION_RADIUS => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.detection_radius;
+                }
+            }
+            property_address::CHARACTER_DETECTED_ENEMIES => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.detected_enemies;
+                }
+            }
+            property_address::CHARACTER_GROUND_TILE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.ground_tile;
+                }
+            }
+            property_address::CHARACTER_GROUND_TILE_X => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.ground_tile_pos.0;
+                }
+            }
+            property_address::CHARACTER_GROUND_TILE_Y => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.ground_tile_pos.1;
+                }
+            }
+            property_address::CHARACTER_FACING_TILE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.facing_tile;
+                }
+            }
+            property_address::CHARACTER_TERRAIN_CONTACT => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.terrain_contact;
+                }
+            }
+            property_address::CHARACTER_SHIELD => {
+                if var_index < engine.fixed.len() {
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(character.shield);
+                }
+            }
+            property_address::CHARACTER_CHARGE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.charge;
+                }
+            }
+            property_address::CHARACTER_OVERFLOW_MODE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = character.overflow_mode;
                 }
             }
             // EntityCore properties
@@ -921,7 +1492,12 @@ impl ScriptContext for StatusEffectContext<'_> {
             return; // Invalid character ID - silent failure
         }
 
-        let character = &mut self.game_state.characters[character_id as usize];
+        let mut damage_dealt = 0;
+        let character = if character_id == self.character.core.id {
+            &mut *self.character
+        } else {
+            &mut self.game_state.characters[character_id as usize]
+        };
 
         match property_address {
             // Character core properties (writable)
@@ -947,7 +1523,16 @@ impl ScriptContext for StatusEffectContext<'_> {
             }
             property_address::CHARACTER_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    character.health = engine.fixed[var_index].to_int().max(0) as u16;
+                    let previous = character.health;
+                    character.health = engine.fixed[var_index].to_u16_saturating();
+                    damage_dealt = previous.saturating_sub(character.health);
+                }
+            }
+            property_address::CHARACTER_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    let previous = character.health;
+                    character.health = engine.vars[var_index] as u16;
+                    damage_dealt = previous.saturating_sub(character.health);
                 }
             }
             property_address::CHARACTER_ENERGY => {
@@ -955,6 +1540,11 @@ impl ScriptContext for StatusEffectContext<'_> {
                     character.energy = engine.vars[var_index];
                 }
             }
+            property_address::CHARACTER_DETECTION_RADIUS => {
+                if var_index < engine.vars.len() {
+                    character.detection_radius = engine.vars[var_index];
+                }
+            }
             property_address::CHARACTER_ENERGY_CAP => {
                 if var_index < engine.vars.len() {
                     character.energy_cap = engine.vars[var_index];
@@ -962,7 +1552,7 @@ impl ScriptContext for StatusEffectContext<'_> {
             }
             property_address::CHARACTER_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
-                    character.health_cap = engine.fixed[var_index].to_int().max(0) as u16;
+                    character.health_cap = engine.fixed[var_index].to_u16_saturating();
                 }
             }
             property_address::CHARACTER_POWER => {
@@ -985,6 +1575,21 @@ impl ScriptContext for StatusEffectContext<'_> {
                     character.move_speed = engine.fixed[var_index];
                 }
             }
+            property_address::CHARACTER_SHIELD => {
+                if var_index < engine.fixed.len() {
+                    character.shield = engine.fixed[var_index].to_u16_saturating();
+                }
+            }
+            property_address::CHARACTER_CHARGE => {
+                if var_index < engine.vars.len() {
+                    character.charge = engine.vars[var_index].min(crate::core::MAX_CHARGE_STACKS);
+                }
+            }
+            property_address::CHARACTER_OVERFLOW_MODE => {
+                if var_index < engine.vars.len() {
+                    character.overflow_mode = engine.vars[var_index];
+                }
+            }
             property_address::CHARACTER_ENERGY_REGEN => {
                 if var_index < engine.vars.len() {
                     character.energy_regen = engine.vars[var_index];
@@ -1008,47 +1613,47 @@ impl ScriptContext for StatusEffectContext<'_> {
             // Character armor values (writable)
             property_address::CHARACTER_ARMOR_PUNCT => {
                 if var_index < engine.vars.len() {
-                    character.armor[0] = engine.vars[var_index];
+                    character.set_armor(Element::Punct, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_BLAST => {
                 if var_index < engine.vars.len() {
-                    character.armor[1] = engine.vars[var_index];
+                    character.set_armor(Element::Blast, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_FORCE => {
                 if var_index < engine.vars.len() {
-                    character.armor[2] = engine.vars[var_index];
+                    character.set_armor(Element::Force, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_SEVER => {
                 if var_index < engine.vars.len() {
-                    character.armor[3] = engine.vars[var_index];
+                    character.set_armor(Element::Sever, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_HEAT => {
                 if var_index < engine.vars.len() {
-                    character.armor[4] = engine.vars[var_index];
+                    character.set_armor(Element::Heat, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_CRYO => {
                 if var_index < engine.vars.len() {
-                    character.armor[5] = engine.vars[var_index];
+                    character.set_armor(Element::Cryo, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_JOLT => {
                 if var_index < engine.vars.len() {
-                    character.armor[6] = engine.vars[var_index];
+                    character.set_armor(Element::Jolt, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_ACID => {
                 if var_index < engine.vars.len() {
-                    character.armor[7] = engine.vars[var_index];
+                    character.set_armor(Element::Acid, engine.vars[var_index]);
                 }
             }
             property_address::CHARACTER_ARMOR_VIRUS => {
                 if var_index < engine.vars.len() {
-                    character.armor[8] = engine.vars[var_index];
+                    character.set_armor(Element::Virus, engine.vars[var_index]);
                 }
             }
             // EntityCore properties (writable)
@@ -1068,7 +1673,7 @@ impl ScriptContext for StatusEffectContext<'_> {
                 }
             }
             property_address::ENTITY_TARGET_ID => {
-                if var_index < engine.vars.len() {
+                if var_index < engine.vars.len() && !character.is_taunted() {
                     character.core.target_id = if engine.vars[var_index] == 255 {
                         None
                     } else {
@@ -1077,12 +1682,15 @@ impl ScriptContext for StatusEffectContext<'_> {
                 }
             }
             property_address::ENTITY_TARGET_TYPE => {
-                if var_index < engine.vars.len() {
+                if var_index < engine.vars.len() && !character.is_taunted() {
                     character.core.target_type = engine.vars[var_index];
                 }
             }
             _ => {} // Property not writable or not supported
         }
+
+        // Credit damage to the character that applied this status effect
+        self.credit_damage(character_id, damage_dealt);
     }
 
     fn read_spawn_property_impl(
@@ -1094,12 +1702,12 @@ impl ScriptContext for StatusEffectContext<'_> {
     ) {
         use crate::constants::property_address;
 
-        // Validate spawn instance ID
-        if spawn_instance_id as usize >= self.game_state.spawn_instances.len() {
+        // Resolve stable spawn instance ID
+        let Some(index) = self.game_state.spawn_index(spawn_instance_id) else {
             return; // Invalid spawn instance ID - silent failure
-        }
+        };
 
-        let spawn_instance = &self.game_state.spawn_instances[spawn_instance_id as usize];
+        let spawn_instance = &self.game_state.spawn_instances()[index];
 
         match property_address {
             // EntityCore properties
@@ -1169,12 +1777,17 @@ impl ScriptContext for StatusEffectContext<'_> {
             // Spawn instance properties
             property_address::SPAWN_INST_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(spawn_instance.health as i16);
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(spawn_instance.health);
+                }
+            }
+            property_address::SPAWN_INST_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    engine.vars[var_index] = spawn_instance.health.min(u8::MAX as u16) as u8;
                 }
             }
             property_address::SPAWN_INST_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
-                    engine.fixed[var_index] = Fixed::from_int(spawn_instance.health_cap as i16);
+                    engine.fixed[var_index] = Fixed::from_u16_saturating(spawn_instance.health_cap);
                 }
             }
             property_address::SPAWN_INST_LIFE_SPAN => {
@@ -1229,12 +1842,12 @@ impl ScriptContext for StatusEffectContext<'_> {
     ) {
         use crate::constants::property_address;
 
-        // Validate spawn instance ID
-        if spawn_instance_id as usize >= self.game_state.spawn_instances.len() {
+        // Resolve stable spawn instance ID
+        let Some(index) = self.game_state.spawn_index(spawn_instance_id) else {
             return; // Invalid spawn instance ID - silent failure
-        }
+        };
 
-        let spawn_instance = &mut self.game_state.spawn_instances[spawn_instance_id as usize];
+        let spawn_instance = &mut self.game_state.spawn_instances_mut()[index];
 
         match property_address {
             // EntityCore properties (writable)
@@ -1292,12 +1905,17 @@ impl ScriptContext for StatusEffectContext<'_> {
             // Spawn instance properties (writable)
             property_address::SPAWN_INST_HEALTH => {
                 if var_index < engine.fixed.len() {
-                    spawn_instance.health = engine.fixed[var_index].to_int().max(0) as u16;
+                    spawn_instance.health = engine.fixed[var_index].to_u16_saturating();
+                }
+            }
+            property_address::SPAWN_INST_HEALTH_BYTE => {
+                if var_index < engine.vars.len() {
+                    spawn_instance.health = engine.vars[var_index] as u16;
                 }
             }
             property_address::SPAWN_INST_HEALTH_CAP => {
                 if var_index < engine.fixed.len() {
-                    spawn_instance.health_cap = engine.fixed[var_index].to_int().max(0) as u16;
+                    spawn_instance.health_cap = engine.fixed[var_index].to_u16_saturating();
                 }
             }
             property_address::SPAWN_INST_ROTATION => {
@@ -1346,73 +1964,45 @@ impl ScriptContext for StatusEffectContext<'_> {
     }
 }
 
-/// Helper function for safe status effect script execution
+/// Run one of a status effect instance's scripts against `character`
 ///
-/// This function properly sequences borrows to avoid borrow checker conflicts
-/// by using unsafe code to work around Rust's borrowing limitations when necessary.
-/// This is safe because we validate all entities exist before accessing them.
+/// The character being processed is held outside `game_state.characters` by the caller
+/// (its slot in the state is a stale copy until the caller writes it back), so scripts see
+/// it through the context rather than the state. The definition and instance are taken out
+/// of the state for the duration of the script and the instance is written back afterwards.
 pub fn execute_status_effect_script(
     game_state: &mut GameState,
-    character_id: u8,
+    character: &mut Character,
     instance_id: StatusEffectInstanceId,
-    definition_id: StatusEffectId,
     script_type: StatusEffectScriptType,
 ) -> Result<u8, ScriptError> {
-    // First, validate that all required entities exist
-    let character_exists = (character_id as usize) < game_state.characters.len();
-    if !character_exists {
-        return Ok(0); // Gracefully handle missing character
-    }
-
-    let instance_exists = game_state.get_status_effect_instance(instance_id).is_some();
-    if !instance_exists {
-        return Ok(0); // Gracefully handle missing instance
-    }
-
-    let definition_exists = game_state
-        .get_status_effect_definition(definition_id)
-        .is_some();
-    if !definition_exists {
-        return Ok(0); // Gracefully handle missing definition
-    }
-
-    // Clone the definition to work around borrow checker issues
-    let definition = game_state
-        .get_status_effect_definition(definition_id)
-        .unwrap()
-        .clone();
-
-    // Use unsafe code to work around the borrow checker limitations
-    // This is safe because we've validated all entities exist above
-    unsafe {
-        let game_state_ptr = game_state as *mut GameState;
-        let character_ptr = (*game_state_ptr)
-            .characters
-            .as_mut_ptr()
-            .add(character_id as usize);
-        let status_instance_ptr = (*game_state_ptr)
-            .get_status_effect_instance_mut(instance_id)
-            .unwrap() as *mut _;
-
-        // Call the appropriate execute method with the raw pointers converted to references
-        match script_type {
-            StatusEffectScriptType::On => definition.execute_on_script(
-                &mut *game_state_ptr,
-                &mut *character_ptr,
-                &mut *status_instance_ptr,
-            ),
-            StatusEffectScriptType::Tick => definition.execute_tick_script(
-                &mut *game_state_ptr,
-                &mut *character_ptr,
-                &mut *status_instance_ptr,
-            ),
-            StatusEffectScriptType::Off => definition.execute_off_script(
-                &mut *game_state_ptr,
-                &mut *character_ptr,
-                &mut *status_instance_ptr,
-            ),
+    // Gracefully handle missing instances and definitions
+    let Some(mut instance) = game_state.get_status_effect_instance(instance_id).cloned() else {
+        return Ok(0);
+    };
+    let Some(definition) = game_state
+        .get_status_effect_definition(instance.definition_id)
+        .cloned()
+    else {
+        return Ok(0);
+    };
+
+    let result = match script_type {
+        StatusEffectScriptType::On => {
+            definition.execute_on_script(game_state, character, &mut instance)
+        }
+        StatusEffectScriptType::Tick => {
+            definition.execute_tick_script(game_state, character, &mut instance)
+        }
+        StatusEffectScriptType::Off => {
+            definition.execute_off_script(game_state, character, &mut instance)
         }
+    };
+
+    if let Some(slot) = game_state.get_status_effect_instance_mut(instance_id) {
+        *slot = instance;
     }
+    result
 }
 
 /// Process all status effects on a character for one frame
@@ -1422,20 +2012,19 @@ pub fn process_character_status_effects(
 ) -> Result<(), ScriptError> {
     let mut effects_to_remove: Vec<StatusEffectInstanceId> = Vec::new();
 
-    // Process each status effect on the character
-    for &effect_instance_id in &character.status_effects {
-        if let Some(instance) = game_state.get_status_effect_instance_mut(effect_instance_id) {
+    // Process each status effect on the character (tick scripts may not add or remove effects)
+    let effect_instance_ids = character.status_effects.clone();
+    for effect_instance_id in effect_instance_ids {
+        if let Some(instance) = game_state.get_status_effect_instance(effect_instance_id) {
             let definition_id = instance.definition_id;
 
             // Get the definition for this instance
             if let Some(_definition) = game_state.get_status_effect_definition(definition_id) {
                 // Execute tick script for the status effect
-                let character_id = character.core.id;
                 match execute_status_effect_script(
                     game_state,
-                    character_id,
+                    character,
                     effect_instance_id,
-                    definition_id,
                     StatusEffectScriptType::Tick,
                 ) {
                     Ok(_) => {
@@ -1447,6 +2036,16 @@ pub fn process_character_status_effects(
                     }
                 }
 
+                // Linked child effects mirror their bundle and are removed along with it
+                if let Some(life_span) = linked_life_span(game_state, effect_instance_id) {
+                    if let Some(instance_mut) =
+                        game_state.get_status_effect_instance_mut(effect_instance_id)
+                    {
+                        instance_mut.life_span = life_span;
+                    }
+                    continue;
+                }
+
                 // Decrease life span
                 if let Some(instance_mut) =
                     game_state.get_status_effect_instance_mut(effect_instance_id)
@@ -1475,12 +2074,192 @@ pub fn process_character_status_effects(
         remove_status_effect_by_instance_id(character, game_state, effect_id)?;
     }
 
+    // Expired taunts, or a defeated taunter, release the forced target; expired size
+    // effects restore the size (and blocked resizes are retried)
+    refresh_modifiers(character, game_state);
+
     // Process passive energy regeneration
     process_passive_energy_regeneration(character, game_state)?;
 
+    // Convert energy above the cap into a shield or charge stacks
+    character.convert_overflow();
+
     Ok(())
 }
 
+/// Character forced as the target by the most recently applied taunt effect on `character`
+///
+/// Only taunts applied by a character that is still standing count.
+pub fn active_taunter(character: &Character, game_state: &GameState) -> Option<CharacterId> {
+    character
+        .status_effects
+        .iter()
+        .rev()
+        .find_map(|&instance_id| {
+            let instance = game_state.get_status_effect_instance(instance_id)?;
+            let definition = game_state.get_status_effect_definition(instance.definition_id)?;
+            if !definition.taunt || instance.source_type != 1 {
+                return None;
+            }
+            let taunter_id = instance.source_id.filter(|&id| id != character.core.id)?;
+            let taunter = game_state.characters.get(taunter_id as usize)?;
+            (taunter.health > 0).then_some(taunter_id)
+        })
+}
+
+/// Re-evaluate the character state derived from its active status effects (taunt and size)
+pub fn refresh_modifiers(character: &mut Character, game_state: &GameState) {
+    refresh_taunt(character, game_state);
+    refresh_size(character, game_state);
+}
+
+/// Combined size scale of the character's active size effects, in percent of the base size
+///
+/// Every stack of every effect with a `scale` multiplies in, clamped to
+/// `MIN_SIZE_SCALE..=MAX_SIZE_SCALE` after each step.
+pub fn active_size_scale(character: &Character, game_state: &GameState) -> u16 {
+    let mut scale = 100u32;
+    for &instance_id in &character.status_effects {
+        let Some(instance) = game_state.get_status_effect_instance(instance_id) else {
+            continue;
+        };
+        let Some(definition) = game_state.get_status_effect_definition(instance.definition_id)
+        else {
+            continue;
+        };
+        if definition.scale == 0 {
+            continue;
+        }
+        for _ in 0..instance.stack_count.max(1) {
+            scale = (scale * definition.scale as u32 / 100)
+                .clamp(MIN_SIZE_SCALE as u32, MAX_SIZE_SCALE as u32);
+        }
+    }
+    scale as u16
+}
+
+/// Resize the character to its active size scale, keeping its feet (bottom center) in place
+///
+/// A grown hitbox that overlaps solid tiles is shifted to the nearest free position that
+/// still contains the previous hitbox, so growing never tunnels through a wall. When there
+/// is no such position the resize is refused and retried on the next refresh.
+pub fn refresh_size(character: &mut Character, game_state: &GameState) {
+    let scale = active_size_scale(character, game_state);
+    if scale == character.size_scale {
+        return;
+    }
+
+    let base = character.unscaled_size();
+    let size = (
+        scale_dimension(base.0, scale),
+        scale_dimension(base.1, scale),
+    );
+    let Some(pos) = resized_position(game_state, character.core.pos, character.core.size, size)
+    else {
+        return;
+    };
+
+    character.core.pos = pos;
+    character.core.size = size;
+    character.size_scale = scale;
+    character.base_size = (scale != 100).then_some(base);
+}
+
+/// Free position for a hitbox resized from `size` to `new_size`, anchored at the feet
+///
+/// Candidates are tried by increasing shift distance, horizontal shifts before downward
+/// ones and left before right.
+fn resized_position(
+    game_state: &GameState,
+    pos: (Fixed, Fixed),
+    size: (u8, u8),
+    new_size: (u8, u8),
+) -> Option<(Fixed, Fixed)> {
+    let grow_x = (new_size.0 as i16 - size.0 as i16).max(0);
+    let grow_y = (new_size.1 as i16 - size.1 as i16).max(0);
+    let anchor_x = (size.0 as i16 - new_size.0 as i16) / 2;
+    let anchor = (
+        pos.0.add(Fixed::from_int(anchor_x)),
+        pos.1
+            .add(Fixed::from_int(size.1 as i16 - new_size.1 as i16)),
+    );
+    // Horizontal shifts that keep the previous hitbox inside the new one
+    let (min_shift_x, max_shift_x) = (-anchor_x - grow_x, -anchor_x);
+
+    for distance in 0..=grow_x + grow_y {
+        for shift_y in 0..=distance.min(grow_y) {
+            let rest = distance - shift_y;
+            for shift_x in [-rest, rest] {
+                if shift_x < min_shift_x.min(0) || shift_x > max_shift_x.max(0) {
+                    continue;
+                }
+                let candidate = (
+                    anchor.0.add(Fixed::from_int(shift_x)),
+                    anchor.1.add(Fixed::from_int(shift_y)),
+                );
+                let rect = CollisionRect::from_entity(candidate, new_size);
+                if !game_state.tile_map.check_collision(rect) {
+                    return Some(candidate);
+                }
+                if rest == 0 {
+                    break;
+                }
+            }
+        }
+    }
+    None
+}
+
+/// Scale one hitbox dimension by a percentage, never below one pixel
+fn scale_dimension(base: u8, scale: u16) -> u8 {
+    (base as u32 * scale as u32 / 100).clamp(1, u8::MAX as u32) as u8
+}
+
+/// Re-evaluate a character's taunt and point its target at the taunter while one is active
+///
+/// Script writes to the target of a taunted character are refused (see `Character::is_taunted`).
+pub fn refresh_taunt(character: &mut Character, game_state: &GameState) {
+    character.taunted_by = active_taunter(character, game_state);
+    if let Some(taunter_id) = character.taunted_by {
+        character.core.target_id = Some(taunter_id);
+        character.core.target_type = 1;
+    }
+}
+
+/// Life span of the bundle a linked child effect belongs to
+///
+/// Returns None for effects that were not applied as part of a bundle, and for children
+/// whose bundle instance no longer exists (those then expire on their own).
+pub fn linked_life_span(
+    game_state: &GameState,
+    effect_instance_id: StatusEffectInstanceId,
+) -> Option<u16> {
+    let parent_id = game_state
+        .get_status_effect_instance(effect_instance_id)?
+        .parent?;
+    game_state
+        .get_status_effect_instance(parent_id)
+        .map(|parent| parent.life_span)
+}
+
+/// Instance IDs on a character that were applied as children of `parent_id`
+pub fn linked_children(
+    character: &Character,
+    game_state: &GameState,
+    parent_id: StatusEffectInstanceId,
+) -> Vec<StatusEffectInstanceId> {
+    character
+        .status_effects
+        .iter()
+        .copied()
+        .filter(|&instance_id| {
+            game_state
+                .get_status_effect_instance(instance_id)
+                .is_some_and(|instance| instance.parent == Some(parent_id))
+        })
+        .collect()
+}
+
 /// Process passive energy regeneration with timing logic handled in Rust
 fn process_passive_energy_regeneration(
     character: &mut Character,
@@ -1491,10 +2270,13 @@ fn process_passive_energy_regeneration(
         return Ok(());
     }
 
-    // Check if it's time to regenerate (frame % rate == 0)
-    if game_state.frame % (character.energy_regen_rate as u16) == 0 {
-        // Add energy with saturation
-        character.energy = character.energy.saturating_add(character.energy_regen);
+    // Check if it's time to regenerate (frame is a multiple of rate)
+    if game_state.frame.is_multiple_of(character.energy_regen_rate as crate::core::Frame) {
+        // Add energy with saturation, never exceeding the energy cap; the excess is banked
+        // for the overflow conversion
+        let new_energy = character.energy.saturating_add(character.energy_regen);
+        character.energy = new_energy.min(character.energy_cap);
+        character.bank_overflow(new_energy.saturating_sub(character.energy_cap));
     }
 
     Ok(())
@@ -1540,23 +2322,20 @@ pub fn remove_status_effect_by_instance_id(
         .position(|&id| id == effect_instance_id);
 
     if let Some(pos) = position {
-        // Get the definition ID before removing the effect
-        let definition_id =
-            if let Some(instance) = game_state.get_status_effect_instance(effect_instance_id) {
-                instance.definition_id
-            } else {
-                // Instance not found, just remove from character's list
-                character.status_effects.remove(pos);
-                return Ok(true);
-            };
+        let Some(definition_id) = game_state
+            .get_status_effect_instance(effect_instance_id)
+            .map(|instance| instance.definition_id)
+        else {
+            // Instance not found, just remove from character's list
+            character.status_effects.remove(pos);
+            return Ok(true);
+        };
 
         // Execute off_script before removing the status effect
-        let character_id = character.core.id;
         match execute_status_effect_script(
             game_state,
-            character_id,
+            character,
             effect_instance_id,
-            definition_id,
             StatusEffectScriptType::Off,
         ) {
             Ok(_) => {
@@ -1569,6 +2348,17 @@ pub fn remove_status_effect_by_instance_id(
         }
 
         character.status_effects.remove(pos);
+        game_state.push_event(GameEventKind::StatusExpired {
+            character_id: character.core.id,
+            status_effect_id: definition_id,
+            instance_id: effect_instance_id,
+        });
+
+        // Child effects of a bundle end with it
+        for child_id in linked_children(character, game_state, effect_instance_id) {
+            remove_status_effect_by_instance_id(character, game_state, child_id)?;
+        }
+        refresh_modifiers(character, game_state);
 
         // Note: We don't remove the instance from the global collection to avoid
         // invalidating other IDs. In a production system, you might want to implement
@@ -1588,9 +2378,17 @@ pub fn create_passive_energy_regen_status_effect() -> StatusEffectDefinition {
         duration: u16::MAX,    // Permanent effect (never expires)
         stack_limit: 1,        // Only one instance allowed
         reset_on_stack: false, // Don't reset life span when reapplied
-        chance: 100,           // Always applies
+        stack_behavior: StackBehavior::Ignore,
+        chance: 100, // Always applies
+        category: 1, // Buff
+        reflect: 0,
+        taunt: false,
+        scale: 0,
+        priority: 0,
         args: [0; 8],
+        constants: Vec::new(),
         spawns: [0; 4],
+        children: Vec::new(),
         on_script: vec![operator_address::EXIT, 1], // Exit with success flag (no initialization needed)
         tick_script: vec![
             // Simple energy regeneration script - timing logic handled in Rust
@@ -1624,15 +2422,44 @@ pub fn apply_status_effect(
     character: &mut Character,
     game_state: &mut GameState,
     effect_definition_id: StatusEffectId,
+) -> Result<bool, ScriptError> {
+    apply_status_effect_from_source(character, game_state, effect_definition_id, None)
+}
+
+/// Apply a status effect to a character by definition ID, recording which entity applied it
+pub fn apply_status_effect_from_source(
+    character: &mut Character,
+    game_state: &mut GameState,
+    effect_definition_id: StatusEffectId,
+    source: Option<(EntityId, u8)>,
 ) -> Result<bool, ScriptError> {
     if let Some(definition) = game_state.get_status_effect_definition(effect_definition_id) {
         let definition_clone = definition.clone(); // Clone to avoid borrow conflicts
-        definition_clone.apply_to_character(character, game_state, effect_definition_id)
+        definition_clone.apply_to_character(character, game_state, effect_definition_id, source)
     } else {
         Ok(false)
     }
 }
 
+/// Apply a status effect to a character of the game state by ID, recording which entity
+/// applied it; returns Ok(false) when the character does not exist
+pub fn apply_status_effect_to(
+    game_state: &mut GameState,
+    character_id: CharacterId,
+    effect_definition_id: StatusEffectId,
+    source: Option<(EntityId, u8)>,
+) -> Result<bool, ScriptError> {
+    let Some(character) = game_state.characters.get(character_id as usize) else {
+        return Ok(false);
+    };
+    // Work on a copy of the character to avoid borrow conflicts with the game state
+    let mut character = character.clone();
+    let applied =
+        apply_status_effect_from_source(&mut character, game_state, effect_definition_id, source)?;
+    game_state.characters[character_id as usize] = character;
+    Ok(applied)
+}
+
 /// Apply passive energy regeneration to all characters in the game
 pub fn apply_passive_energy_regen_to_all_characters(
     characters: &mut [Character],
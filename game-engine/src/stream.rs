@@ -0,0 +1,131 @@
+//! Spectator streaming for broadcasting live matches
+//!
+//! A stream opens with a header and a keyframe (a `GameState::to_bytes` snapshot), then carries
+//! the events of every recorded frame plus a fresh keyframe every `keyframe_interval` frames.
+//! The host drains it in chunks with `take_chunk` while the match runs. A relay only has to keep
+//! the header, the latest keyframe and the events after it: viewers joining mid-game restore
+//! that keyframe and apply the events emitted during its frame and later ones.
+
+use crate::core::Frame;
+use crate::event::GameEvent;
+use crate::state::GameState;
+use alloc::vec::Vec;
+
+/// Stream layout version, bumped whenever the chunk contents change
+pub const STREAM_VERSION: u8 = 1;
+
+/// Opening description of a stream, carried by its first chunk
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StreamHeader {
+    pub version: u8,            // STREAM_VERSION of the producer
+    pub keyframe_interval: u16, // Frames between periodic keyframes (0 = only forced ones)
+    pub start_frame: Frame,     // Frame counter of the opening keyframe
+}
+
+/// Full snapshot a viewer can resume from
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StreamKeyframe {
+    pub frame: Frame, // Frame counter of the snapshot; its events are the ones from this frame on
+    pub state_hash: u32, // GameState::state_hash of the snapshot, for checking a restore
+    pub state: Vec<u8>, // GameState::to_bytes
+}
+
+/// Everything recorded since the previous chunk
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct StreamChunk {
+    pub sequence: u32, // 0 for the chunk opening the stream, then counting up without gaps
+    pub header: Option<StreamHeader>, // Only on the opening chunk
+    pub keyframes: Vec<StreamKeyframe>, // Oldest first
+    pub events: Vec<GameEvent>, // Oldest first
+}
+
+/// Producer side of a spectator stream
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SpectatorStream {
+    header: StreamHeader,
+    sequence: u32,
+    last_keyframe: Frame,
+    // First frame whose events have not been streamed yet
+    event_frame: Frame,
+    keyframes: Vec<StreamKeyframe>,
+    events: Vec<GameEvent>,
+}
+
+impl SpectatorStream {
+    /// Open a stream at the current state, queuing the header and an opening keyframe
+    pub fn new(state: &GameState, keyframe_interval: u16) -> Self {
+        let mut stream = Self {
+            header: StreamHeader {
+                version: STREAM_VERSION,
+                keyframe_interval,
+                start_frame: state.frame,
+            },
+            sequence: 0,
+            last_keyframe: state.frame,
+            event_frame: state.frame,
+            keyframes: Vec::new(),
+            events: Vec::new(),
+        };
+        stream.record_keyframe(state);
+        stream
+    }
+
+    /// Header the stream was opened with
+    pub fn header(&self) -> StreamHeader {
+        self.header
+    }
+
+    /// Queue the events of the frame just advanced, and a keyframe once the interval is up
+    /// Frames advanced with events disabled stream nothing; follow them with `record_keyframe`
+    pub fn record_frame(&mut self, state: &GameState) {
+        let event_frame = self.event_frame;
+        let fresh = state
+            .events
+            .iter()
+            .filter(|event| event.frame >= event_frame);
+        self.events.extend(fresh.cloned());
+        // Events of a frame that ended the match carry the frame counter it stopped at
+        self.event_frame = state
+            .events
+            .iter()
+            .map(|event| event.frame.saturating_add(1))
+            .fold(self.event_frame.max(state.frame), Frame::max);
+
+        let interval = self.header.keyframe_interval as Frame;
+        if interval != 0 && state.frame >= self.last_keyframe.saturating_add(interval) {
+            self.record_keyframe(state);
+        }
+    }
+
+    /// Queue a keyframe of the current state, restarting the keyframe interval
+    pub fn record_keyframe(&mut self, state: &GameState) {
+        self.keyframes.push(StreamKeyframe {
+            frame: state.frame,
+            state_hash: state.state_hash(),
+            state: state.to_bytes(),
+        });
+        self.last_keyframe = state.frame;
+        self.event_frame = self.event_frame.max(state.frame);
+    }
+
+    /// Whether anything was recorded since the previous chunk
+    pub fn has_pending(&self) -> bool {
+        self.sequence == 0 || !self.keyframes.is_empty() || !self.events.is_empty()
+    }
+
+    /// Drain everything recorded since the previous chunk
+    /// Chunks are taken even when empty, so every call advances the sequence
+    pub fn take_chunk(&mut self) -> StreamChunk {
+        let chunk = StreamChunk {
+            sequence: self.sequence,
+            header: (self.sequence == 0).then_some(self.header),
+            keyframes: core::mem::take(&mut self.keyframes),
+            events: core::mem::take(&mut self.events),
+        };
+        self.sequence = self.sequence.wrapping_add(1);
+        chunk
+    }
+}
@@ -0,0 +1,72 @@
+//! Match outcome summaries for backends that only need the result
+//!
+//! A summary is derived entirely from the current state, so summarising a resumed or
+//! replayed match gives the same result as summarising the original.
+
+use crate::core::Frame;
+use crate::entity::{CharacterId, EntityId};
+use crate::state::{GameState, GameStatus};
+use alloc::vec::Vec;
+
+/// Final standing of one character
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct CharacterSummary {
+    pub id: CharacterId,
+    pub group: EntityId,
+    pub health: u16,
+    pub energy: u8,
+    pub damage_dealt: u16,
+    pub damage_taken: u16,
+}
+
+/// Outcome of a match at its current frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatchSummary {
+    pub frames: Frame,
+    pub ended: bool,
+    pub winner_group: Option<EntityId>, // None when nobody survived or surviving groups tie
+    pub survivors: Vec<CharacterId>,    // Characters with health left, in character order
+    pub characters: Vec<CharacterSummary>,
+    pub spawns_created: u32,
+    pub state_hash: u32,
+}
+
+impl GameState {
+    /// Summarise the match as it stands
+    ///
+    /// The winner is decided by the match's victory condition (see `GameState::match_result`);
+    /// by default the group with the most total remaining health wins, and an exact tie has
+    /// no winner.
+    pub fn match_summary(&self) -> MatchSummary {
+        let survivors: Vec<CharacterId> = self
+            .characters
+            .iter()
+            .filter(|character| character.health > 0)
+            .map(|character| character.core.id)
+            .collect();
+        let winner_group = self.match_result().winner_group;
+
+        MatchSummary {
+            frames: self.frame,
+            ended: self.status == GameStatus::Ended,
+            winner_group,
+            survivors,
+            characters: self
+                .characters
+                .iter()
+                .map(|character| CharacterSummary {
+                    id: character.core.id,
+                    group: character.core.group,
+                    health: character.health,
+                    energy: character.energy,
+                    damage_dealt: character.stats.damage_dealt,
+                    damage_taken: character.stats.damage_taken,
+                })
+                .collect(),
+            spawns_created: self.spawns_created,
+            state_hash: self.state_hash(),
+        }
+    }
+}
@@ -0,0 +1,67 @@
+//! Deterministic target queries
+//!
+//! Behaviors pick enemies with `GameState::find_target` (the FIND_TARGET and ACQUIRE_TARGET
+//! operators) instead of looping over character properties. Every query ranks the same
+//! candidates, the standing characters of other groups, and breaks ties the same way, so
+//! replays and verifiers pick the same target.
+
+use crate::constants::target_query;
+use crate::core::MAX_CHARACTERS;
+use crate::entity::{CharacterId, EntityCore, SpawnInstance};
+use crate::state::GameState;
+
+impl GameState {
+    /// Enemy of the entity picked by a `target_query`, given the aggro the entity holds
+    /// toward each character id
+    /// Returns None for unknown queries and when no enemy is standing
+    pub fn find_target(
+        &self,
+        from: &EntityCore,
+        aggro: &[u8; MAX_CHARACTERS],
+        query: u8,
+    ) -> Option<CharacterId> {
+        if query > target_query::HIGHEST_AGGRO {
+            return None;
+        }
+
+        let origin = Self::core_center(from);
+        self.characters
+            .iter()
+            .filter(|character| character.core.group != from.group && character.health > 0)
+            .min_by_key(|character| {
+                let rank = match query {
+                    target_query::LOWEST_HEALTH => character.health as i32,
+                    target_query::HIGHEST_ENMITY => -(character.core.enmity as i32),
+                    target_query::HIGHEST_AGGRO => {
+                        -(aggro.get(character.core.id as usize).copied().unwrap_or(0) as i32)
+                    }
+                    _ => 0,
+                };
+                let center = Self::core_center(&character.core);
+                let dx = center.0.raw() as i32 - origin.0.raw() as i32;
+                let dy = center.1.raw() as i32 - origin.1.raw() as i32;
+                (rank, dx * dx + dy * dy, character.core.id)
+            })
+            .map(|character| character.core.id)
+    }
+
+    /// Aggro table a spawn targets with: its owner character's, or none
+    pub fn owner_aggro(&self, spawn: &SpawnInstance) -> [u8; MAX_CHARACTERS] {
+        match spawn.owner_type {
+            1 => self
+                .characters
+                .get(spawn.owner_id as usize)
+                .map_or([0; MAX_CHARACTERS], |owner| owner.aggro),
+            _ => [0; MAX_CHARACTERS],
+        }
+    }
+}
+
+impl EntityCore {
+    /// Target a character, or clear the target; returns whether a target is set
+    pub fn set_character_target(&mut self, character_id: Option<CharacterId>) -> bool {
+        self.target_id = character_id;
+        self.target_type = if character_id.is_some() { 1 } else { 0 };
+        character_id.is_some()
+    }
+}
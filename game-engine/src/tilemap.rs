@@ -4,19 +4,67 @@
 //! for entity-tilemap interactions.
 
 use crate::core::{TILEMAP_HEIGHT, TILEMAP_WIDTH, TILE_SIZE};
+use crate::entity::{Element, EntityId};
 use crate::math::Fixed;
 
 /// Tile types in the game arena
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
 pub enum TileType {
     Empty = 0,
     Block = 1,
+    Ice = 2,           // Solid, low traction footing
+    Mud = 3,           // Solid, slows horizontal movement
+    ConveyorLeft = 4,  // Solid, pushes grounded characters left
+    ConveyorRight = 5, // Solid, pushes grounded characters right
+    SlopeUp = 6,       // 45° slope rising to the right (/), walkable surface only
+    SlopeDown = 7,     // 45° slope falling to the right (\), walkable surface only
+    Platform = 8,      // One-way platform, only its top edge catches falling characters
+    Ladder = 9,        // Not solid, characters on it are not pulled by gravity
+    Hazard = 10,       // Not solid, marks a hazard zone for scripts
+}
+
+impl TileType {
+    /// Whether the tile blocks movement from every side
+    /// Slopes and platforms only catch characters from above (see `surface_offset`);
+    /// ladders and hazards never block
+    pub fn is_solid(self) -> bool {
+        matches!(
+            self,
+            TileType::Block
+                | TileType::Ice
+                | TileType::Mud
+                | TileType::ConveyorLeft
+                | TileType::ConveyorRight
+        )
+    }
+
+    /// Depth of the walkable surface below the tile top at a pixel column of the tile
+    /// (0-15), for tiles that only catch characters from above
+    pub fn surface_offset(self, column: i32) -> Option<i32> {
+        let column = column.clamp(0, TILE_SIZE as i32 - 1);
+        match self {
+            TileType::SlopeUp => Some(TILE_SIZE as i32 - 1 - column),
+            TileType::SlopeDown => Some(column),
+            TileType::Platform => Some(0),
+            _ => None,
+        }
+    }
 }
 
 impl From<u8> for TileType {
     fn from(value: u8) -> Self {
         match value {
             1 => TileType::Block,
+            2 => TileType::Ice,
+            3 => TileType::Mud,
+            4 => TileType::ConveyorLeft,
+            5 => TileType::ConveyorRight,
+            6 => TileType::SlopeUp,
+            7 => TileType::SlopeDown,
+            8 => TileType::Platform,
+            9 => TileType::Ladder,
+            10 => TileType::Hazard,
             _ => TileType::Empty,
         }
     }
@@ -39,6 +87,36 @@ pub struct Tilemap {
     /// tile_boundaries[y][x] = (left_pixel, top_pixel, right_pixel, bottom_pixel)
     /// This avoids repeated multiplication by TILE_SIZE during collision detection
     tile_boundaries: [[(i32, i32, i32, i32); TILEMAP_WIDTH]; TILEMAP_HEIGHT],
+
+    /// Bumped whenever a tile changes, so cached physics results can tell the map moved on
+    revision: u32,
+}
+
+/// A tile written by a terrain spawn, restored when the spawn is removed
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct TerrainTile {
+    pub spawn_instance_id: EntityId, // Spawn that owns the tile
+    pub x: u8,                       // Tile column
+    pub y: u8,                       // Tile row
+    pub previous: u8,                // Raw tile value before the spawn wrote it
+}
+
+/// Element and damage of a Hazard tile (lava, acid pools), hurting the characters it overlaps
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct HazardTile {
+    pub x: u8,                  // Tile column
+    pub y: u8,                  // Tile row
+    pub element: Element,       // Armor slot that reduces the damage
+    pub damage_per_second: u16, // Damage over one second of contact, before armor
+}
+
+/// First solid tile a ray enters (see `Tilemap::raycast`)
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RayHit {
+    pub tile: (i32, i32), // Tile column and row; tiles outside the map count as solid
+    pub point: (Fixed, Fixed), // Where the ray reaches the tile
 }
 
 /// Rectangle representing an entity's bounding box for collision detection
@@ -56,6 +134,7 @@ impl Tilemap {
         let mut tilemap = Self {
             tiles,
             tile_boundaries: [[(0, 0, 0, 0); TILEMAP_WIDTH]; TILEMAP_HEIGHT],
+            revision: 0,
         };
         tilemap.precalculate_tile_boundaries();
         tilemap
@@ -66,6 +145,7 @@ impl Tilemap {
         let mut tilemap = Self {
             tiles: [[0; TILEMAP_WIDTH]; TILEMAP_HEIGHT],
             tile_boundaries: [[(0, 0, 0, 0); TILEMAP_WIDTH]; TILEMAP_HEIGHT],
+            revision: 0,
         };
         tilemap.precalculate_tile_boundaries();
         tilemap
@@ -85,6 +165,11 @@ impl Tilemap {
         }
     }
 
+    /// Number of tile changes since the map was created (every `get_raw_tiles_mut` counts)
+    pub fn revision(&self) -> u32 {
+        self.revision
+    }
+
     /// Get the tile type at the specified tile coordinates
     pub fn get_tile(&self, tile_x: usize, tile_y: usize) -> TileType {
         if tile_x >= TILEMAP_WIDTH || tile_y >= TILEMAP_HEIGHT {
@@ -96,11 +181,37 @@ impl Tilemap {
     /// Set the tile type at the specified tile coordinates
     pub fn set_tile(&mut self, tile_x: usize, tile_y: usize, tile_type: TileType) {
         if tile_x < TILEMAP_WIDTH && tile_y < TILEMAP_HEIGHT {
-            self.tiles[tile_y][tile_x] = tile_type.into();
+            let value: u8 = tile_type.into();
+            if self.tiles[tile_y][tile_x] != value {
+                self.revision = self.revision.wrapping_add(1);
+            }
+            self.tiles[tile_y][tile_x] = value;
             // Note: tile_boundaries don't need to be recalculated since they're position-based, not type-based
         }
     }
 
+    /// Get the raw tile value at the specified tile coordinates
+    /// Out-of-bounds coordinates read as Block, matching get_tile
+    pub fn get_raw_tile(&self, tile_x: usize, tile_y: usize) -> u8 {
+        if tile_x >= TILEMAP_WIDTH || tile_y >= TILEMAP_HEIGHT {
+            return TileType::Block as u8;
+        }
+        self.tiles[tile_y][tile_x]
+    }
+
+    /// Convert pixel coordinates to tile coordinates, or None outside the map
+    pub fn tile_coords_at_pixel(pixel_x: i32, pixel_y: i32) -> Option<(usize, usize)> {
+        if pixel_x < 0 || pixel_y < 0 {
+            return None;
+        }
+        let tile_x = pixel_x as usize / TILE_SIZE as usize;
+        let tile_y = pixel_y as usize / TILE_SIZE as usize;
+        if tile_x >= TILEMAP_WIDTH || tile_y >= TILEMAP_HEIGHT {
+            return None;
+        }
+        Some((tile_x, tile_y))
+    }
+
     /// Get the tile type at the specified pixel coordinates
     pub fn get_tile_at_pixel(&self, pixel_x: Fixed, pixel_y: Fixed) -> TileType {
         // Convert pixel coordinates to tile coordinates
@@ -161,7 +272,7 @@ impl Tilemap {
         for tile_y in top_tile..=bottom_tile {
             for tile_x in left_tile..=right_tile {
                 // PERFORMANCE: Direct array access instead of get_tile() method call
-                if self.tiles[tile_y][tile_x] == TileType::Block as u8 {
+                if TileType::from(self.tiles[tile_y][tile_x]).is_solid() {
                     return true; // EARLY EXIT: Found collision
                 }
             }
@@ -234,13 +345,186 @@ impl Tilemap {
         self.check_collision(ground_check_rect)
     }
 
+    /// Highest slope or platform surface under the pixel columns `left..=right`, between
+    /// the pixel rows `top..=bottom`, as its pixel y and tile type
+    /// Every column is considered, so a wide character rests on whichever corner of a slope
+    /// is highest
+    pub fn surface_below(
+        &self,
+        left: i32,
+        right: i32,
+        top: i32,
+        bottom: i32,
+    ) -> Option<(i32, TileType)> {
+        let tile = TILE_SIZE as i32;
+        let left = left.max(0);
+        let right = right.min((TILEMAP_WIDTH * TILE_SIZE as usize) as i32 - 1);
+        let first_row = top.div_euclid(tile).max(0);
+        let last_row = bottom.div_euclid(tile).min(TILEMAP_HEIGHT as i32 - 1);
+
+        for row in first_row..=last_row {
+            let mut highest: Option<(i32, TileType)> = None;
+            for column in left.div_euclid(tile)..=right.div_euclid(tile) {
+                let tile_type = self.get_tile(column as usize, row as usize);
+                let span = (
+                    left.max(column * tile) - column * tile,
+                    right.min(column * tile + tile - 1) - column * tile,
+                );
+                let offset = match (
+                    tile_type.surface_offset(span.0),
+                    tile_type.surface_offset(span.1),
+                ) {
+                    (Some(a), Some(b)) => a.min(b),
+                    _ => continue,
+                };
+                let surface = row * tile + offset;
+                if surface >= top
+                    && surface <= bottom
+                    && highest.is_none_or(|(best, _)| surface < best)
+                {
+                    highest = Some((surface, tile_type));
+                }
+            }
+            if highest.is_some() {
+                return highest;
+            }
+        }
+        None
+    }
+
+    /// Check whether any tile of the given type overlaps the rectangle
+    pub fn overlaps_tile_type(&self, rect: CollisionRect, tile_type: TileType) -> bool {
+        let (left, top, right, bottom) = Self::tiles_overlapped(rect);
+        (top..=bottom).any(|tile_y| {
+            (left..=right)
+                .any(|tile_x| self.get_tile(tile_x as usize, tile_y as usize) == tile_type)
+        })
+    }
+
+    /// Check whether a rectangle overlaps the tile at (tile_x, tile_y)
+    pub fn overlaps_tile(rect: CollisionRect, tile_x: usize, tile_y: usize) -> bool {
+        let (left, top, right, bottom) = Self::tiles_overlapped(rect);
+        (left..=right).contains(&(tile_x as i32)) && (top..=bottom).contains(&(tile_y as i32))
+    }
+
+    /// Inclusive (left, top, right, bottom) tile bounds of the map tiles a rectangle overlaps
+    fn tiles_overlapped(rect: CollisionRect) -> (i32, i32, i32, i32) {
+        let tile_raw = Fixed::from_int(TILE_SIZE as i16).raw() as i32;
+        let left = (rect.x.raw() as i32).div_euclid(tile_raw).max(0);
+        let top = (rect.y.raw() as i32).div_euclid(tile_raw).max(0);
+        let right = (rect.right().raw() as i32 - 1)
+            .div_euclid(tile_raw)
+            .min(TILEMAP_WIDTH as i32 - 1);
+        let bottom = (rect.bottom().raw() as i32 - 1)
+            .div_euclid(tile_raw)
+            .min(TILEMAP_HEIGHT as i32 - 1);
+        (left, top, right, bottom)
+    }
+
+    /// Check whether a straight line between two pixel positions is free of solid tiles
+    /// Walks the tiles crossed by the line (Bresenham over the tile grid); the tiles
+    /// containing the two endpoints are not checked
+    pub fn has_line_of_sight(&self, from: (i32, i32), to: (i32, i32)) -> bool {
+        let tile = TILE_SIZE as i32;
+        let (mut x, mut y) = (from.0.div_euclid(tile), from.1.div_euclid(tile));
+        let (end_x, end_y) = (to.0.div_euclid(tile), to.1.div_euclid(tile));
+
+        let dx = (end_x - x).abs();
+        let dy = -(end_y - y).abs();
+        let step_x = if x < end_x { 1 } else { -1 };
+        let step_y = if y < end_y { 1 } else { -1 };
+        let mut error = dx + dy;
+
+        while (x, y) != (end_x, end_y) {
+            let doubled = 2 * error;
+            if doubled >= dy {
+                error += dy;
+                x += step_x;
+            }
+            if doubled <= dx {
+                error += dx;
+                y += step_y;
+            }
+
+            if (x, y) != (end_x, end_y)
+                && x >= 0
+                && y >= 0
+                && self.get_tile(x as usize, y as usize).is_solid()
+            {
+                return false;
+            }
+        }
+
+        true
+    }
+
+    /// Cast a ray from `from` to `to` and return the first solid tile it enters
+    /// A DDA over the tile grid in raw fixed-point units: every tile the segment crosses is
+    /// visited in order using exact integer comparisons, so results never depend on rounding.
+    /// The tile containing `from` is not checked, the one containing `to` is. A ray passing
+    /// exactly through a tile corner enters the horizontal neighbor first, so it cannot slip
+    /// between two diagonal blocks.
+    pub fn raycast(&self, from: (Fixed, Fixed), to: (Fixed, Fixed)) -> Option<RayHit> {
+        let tile = Fixed::from_int(TILE_SIZE as i16).raw() as i64;
+        let (x0, y0) = (from.0.raw() as i64, from.1.raw() as i64);
+        let (dx, dy) = (to.0.raw() as i64 - x0, to.1.raw() as i64 - y0);
+        let (mut tile_x, mut tile_y) = (x0.div_euclid(tile), y0.div_euclid(tile));
+        let mut crossings_x = ((x0 + dx).div_euclid(tile) - tile_x).abs();
+        let mut crossings_y = ((y0 + dy).div_euclid(tile) - tile_y).abs();
+
+        // Distance along each axis from `from` to the next tile boundary in the ray's direction
+        let mut next_x = if dx > 0 {
+            (tile_x + 1) * tile - x0
+        } else {
+            x0 - tile_x * tile
+        };
+        let mut next_y = if dy > 0 {
+            (tile_y + 1) * tile - y0
+        } else {
+            y0 - tile_y * tile
+        };
+
+        while crossings_x + crossings_y > 0 {
+            // The boundary reached first is the one with the smaller next / |delta|
+            let cross_x =
+                crossings_y == 0 || (crossings_x > 0 && next_x * dy.abs() <= next_y * dx.abs());
+            let (travelled, length) = if cross_x {
+                tile_x += dx.signum();
+                crossings_x -= 1;
+                next_x += tile;
+                (next_x - tile, dx.abs())
+            } else {
+                tile_y += dy.signum();
+                crossings_y -= 1;
+                next_y += tile;
+                (next_y - tile, dy.abs())
+            };
+
+            let solid = tile_x < 0
+                || tile_y < 0
+                || self.get_tile(tile_x as usize, tile_y as usize).is_solid();
+            if solid {
+                let along = |start: i64, delta: i64| {
+                    Fixed::from_raw((start + delta * travelled / length) as i16)
+                };
+                return Some(RayHit {
+                    tile: (tile_x as i32, tile_y as i32),
+                    point: (along(x0, dx), along(y0, dy)),
+                });
+            }
+        }
+
+        None
+    }
+
     /// Get the raw tile data as a reference
     pub fn get_raw_tiles(&self) -> &[[u8; TILEMAP_WIDTH]; TILEMAP_HEIGHT] {
         &self.tiles
     }
 
-    /// Get the raw tile data as a mutable reference
+    /// Get the raw tile data as a mutable reference (counts as a change to the revision)
     pub fn get_raw_tiles_mut(&mut self) -> &mut [[u8; TILEMAP_WIDTH]; TILEMAP_HEIGHT] {
+        self.revision = self.revision.wrapping_add(1);
         &mut self.tiles
     }
 }
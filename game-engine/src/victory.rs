@@ -0,0 +1,323 @@
+//! Win/lose conditions evaluated at the end of every frame
+//!
+//! The frame limit always ends a match; a `VictoryCondition` can end it earlier and decides
+//! which group wins. Groups the condition ranks equal at the frame limit are separated by the
+//! match's `TieBreak` rules, tried in order; a tie no rule separates is a draw. Like
+//! `summary`, the result is derived from the state alone, so a resumed or replayed match
+//! reports the same result as the original.
+
+use crate::core::Frame;
+use crate::entity::EntityId;
+use crate::state::{GameState, GameStatus};
+use alloc::vec::Vec;
+use core::cmp::Ordering;
+
+/// How a match is won
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum VictoryCondition {
+    /// Only the frame limit ends the match; the most total health left wins
+    #[default]
+    Timeout,
+    /// Ends once at most one group has characters standing or waiting to revive
+    LastTeamStanding,
+    /// Ends once a group has defeated this many opposing characters; most kills wins
+    KillTarget(u8),
+    /// Only the frame limit ends the match; the highest health percentage wins
+    HealthPercent,
+    /// The group wins by having a character standing at the frame limit and loses when
+    /// eliminated (nobody standing or waiting to revive) before that
+    Survival(EntityId),
+}
+
+impl VictoryCondition {
+    /// Build a condition from its numeric mode and target (kill count or surviving group)
+    pub fn from_parts(mode: u8, target: u8) -> Option<VictoryCondition> {
+        match mode {
+            0 => Some(VictoryCondition::Timeout),
+            1 => Some(VictoryCondition::LastTeamStanding),
+            2 if target > 0 => Some(VictoryCondition::KillTarget(target)),
+            3 => Some(VictoryCondition::HealthPercent),
+            4 => Some(VictoryCondition::Survival(target)),
+            _ => None,
+        }
+    }
+
+    /// Numeric mode (see `from_parts`)
+    pub fn mode(&self) -> u8 {
+        match self {
+            VictoryCondition::Timeout => 0,
+            VictoryCondition::LastTeamStanding => 1,
+            VictoryCondition::KillTarget(_) => 2,
+            VictoryCondition::HealthPercent => 3,
+            VictoryCondition::Survival(_) => 4,
+        }
+    }
+
+    /// Kill count or surviving group, 0 for conditions without a target
+    pub fn target(&self) -> u8 {
+        match self {
+            VictoryCondition::KillTarget(kills) => *kills,
+            VictoryCondition::Survival(group) => *group,
+            _ => 0,
+        }
+    }
+}
+
+/// Why a match ended
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum EndReason {
+    FrameLimit = 0,       // The frame limit was reached
+    LastTeamStanding = 1, // At most one group had characters standing
+    KillTarget = 2,       // A group reached the kill target
+    Eliminated = 3,       // The surviving group was eliminated
+}
+
+impl EndReason {
+    pub fn from_u8(value: u8) -> Option<EndReason> {
+        match value {
+            0 => Some(EndReason::FrameLimit),
+            1 => Some(EndReason::LastTeamStanding),
+            2 => Some(EndReason::KillTarget),
+            3 => Some(EndReason::Eliminated),
+            _ => None,
+        }
+    }
+}
+
+/// Rule separating groups the victory condition ranks equal at the frame limit
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[repr(u8)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub enum TieBreak {
+    HealthPercent = 0, // Highest share of the group's total health cap left
+    DamageDealt = 1,   // Most damage dealt
+    TotalHealth = 2,   // Most total health left
+    Kills = 3,         // Most opposing characters defeated
+    Survivors = 4,     // Most characters standing
+}
+
+impl TieBreak {
+    pub fn from_u8(value: u8) -> Option<TieBreak> {
+        match value {
+            0 => Some(TieBreak::HealthPercent),
+            1 => Some(TieBreak::DamageDealt),
+            2 => Some(TieBreak::TotalHealth),
+            3 => Some(TieBreak::Kills),
+            4 => Some(TieBreak::Survivors),
+            _ => None,
+        }
+    }
+
+    /// Order two standings by this rule, greater ranking higher
+    fn compare(&self, a: &GroupStanding, b: &GroupStanding) -> Ordering {
+        match self {
+            TieBreak::HealthPercent => health_percent(a, b),
+            TieBreak::DamageDealt => a.damage_dealt.cmp(&b.damage_dealt),
+            TieBreak::TotalHealth => a.health.cmp(&b.health),
+            TieBreak::Kills => a.kills.cmp(&b.kills),
+            TieBreak::Survivors => a.survivors.cmp(&b.survivors),
+        }
+    }
+}
+
+/// Standing of one group, in order of first appearance among the characters
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct GroupStanding {
+    pub group: EntityId,
+    pub survivors: u8,     // Characters with health left
+    pub respawning: u8,    // Defeated characters waiting to revive
+    pub health: u32,       // Total health left
+    pub health_cap: u32,   // Total health cap, survivors or not
+    pub kills: u16,        // Opposing characters defeated by the group
+    pub damage_dealt: u32, // Damage dealt by the group's characters
+    pub damage_taken: u32, // Damage taken by the group's characters
+}
+
+/// Result of a match at its current frame
+#[derive(Debug, Clone, PartialEq, Eq)]
+#[cfg_attr(feature = "serde", derive(serde::Serialize, serde::Deserialize))]
+pub struct MatchResult {
+    pub frame: Frame,
+    pub condition: VictoryCondition,
+    pub ended: bool,
+    pub reason: Option<EndReason>, // None while the match is still playing
+    pub winner_group: Option<EntityId>, // Current leader while playing; None on a draw
+    pub tie_break: Option<TieBreak>, // Rule that decided a tie for the winner, if one did
+    pub standings: Vec<GroupStanding>,
+}
+
+impl GameState {
+    /// Standing of every group, in order of first appearance
+    pub fn group_standings(&self) -> Vec<GroupStanding> {
+        let mut standings: Vec<GroupStanding> = Vec::new();
+        for character in &self.characters {
+            let group = character.core.group;
+            let index = match standings
+                .iter()
+                .position(|standing| standing.group == group)
+            {
+                Some(index) => index,
+                None => {
+                    standings.push(GroupStanding {
+                        group,
+                        survivors: 0,
+                        respawning: 0,
+                        health: 0,
+                        health_cap: 0,
+                        kills: 0,
+                        damage_dealt: 0,
+                        damage_taken: 0,
+                    });
+                    standings.len() - 1
+                }
+            };
+            let standing = &mut standings[index];
+            if character.health > 0 {
+                standing.survivors += 1;
+            } else if character.respawn_timer > 0 {
+                standing.respawning += 1;
+            }
+            standing.health += character.health as u32;
+            standing.health_cap += character.health_cap as u32;
+            standing.kills += character.stats.kills as u16;
+            standing.damage_dealt += character.stats.damage_dealt as u32;
+            standing.damage_taken += character.stats.damage_taken as u32;
+        }
+        standings
+    }
+
+    /// Result of the match under its victory condition
+    ///
+    /// While playing, `winner_group` is the group that would win if the frame limit were
+    /// reached now.
+    pub fn match_result(&self) -> MatchResult {
+        let standings = self.group_standings();
+        let (winner_group, tie_break) = self.winning_group(&standings);
+        MatchResult {
+            frame: self.frame,
+            condition: self.victory,
+            ended: self.status == GameStatus::Ended,
+            reason: self.end_reason,
+            winner_group,
+            tie_break,
+            standings,
+        }
+    }
+
+    /// End the match if its victory condition is met; runs after every frame
+    pub(crate) fn check_victory(&mut self) {
+        if self.status != GameStatus::Playing {
+            return;
+        }
+        let standings = self.group_standings();
+        // Groups with characters waiting to revive are still in the match
+        let standing_groups = standings
+            .iter()
+            .filter(|standing| standing.survivors > 0 || standing.respawning > 0)
+            .count();
+
+        let reason = match self.victory {
+            VictoryCondition::Timeout | VictoryCondition::HealthPercent => None,
+            // A match that started with a single group has nobody to outlast
+            VictoryCondition::LastTeamStanding => {
+                (standings.len() > 1 && standing_groups <= 1).then_some(EndReason::LastTeamStanding)
+            }
+            VictoryCondition::KillTarget(target) => standings
+                .iter()
+                .any(|standing| standing.kills >= target as u16)
+                .then_some(EndReason::KillTarget),
+            VictoryCondition::Survival(group) => standings
+                .iter()
+                .any(|standing| {
+                    standing.group == group && standing.survivors == 0 && standing.respawning == 0
+                })
+                .then_some(EndReason::Eliminated),
+        };
+
+        if let Some(reason) = reason {
+            self.end_match(reason);
+        }
+    }
+
+    /// Stop the match for the given reason
+    pub(crate) fn end_match(&mut self, reason: EndReason) {
+        self.status = GameStatus::Ended;
+        self.end_reason = Some(reason);
+    }
+
+    /// Winning (or currently leading) group and the tie-break that decided it: kills decide
+    /// kill target matches, health percentage decides health percentage matches, a standing
+    /// survival group wins its match, and otherwise the most total health left wins
+    /// Ties go through `tie_breaks` while playing and at the frame limit; ties in matches
+    /// ended early, and ties no rule separates, have no winner.
+    fn winning_group(&self, standings: &[GroupStanding]) -> (Option<EntityId>, Option<TieBreak>) {
+        let tie_breaks: &[TieBreak] = match self.end_reason {
+            None | Some(EndReason::FrameLimit) => &self.tie_breaks,
+            Some(_) => &[],
+        };
+        match self.victory {
+            VictoryCondition::KillTarget(_) => {
+                rank_leader(standings, |a, b| a.kills.cmp(&b.kills), tie_breaks)
+            }
+            VictoryCondition::HealthPercent => rank_leader(standings, health_percent, tie_breaks),
+            VictoryCondition::Survival(group)
+                if standings
+                    .iter()
+                    .any(|standing| standing.group == group && standing.survivors > 0) =>
+            {
+                (Some(group), None)
+            }
+            _ => rank_leader(standings, |a, b| a.health.cmp(&b.health), tie_breaks),
+        }
+    }
+}
+
+/// Compare health / health_cap without division
+fn health_percent(a: &GroupStanding, b: &GroupStanding) -> Ordering {
+    (a.health as u64 * b.health_cap as u64).cmp(&(b.health as u64 * a.health_cap as u64))
+}
+
+/// The group ranked strictly above every other group by `compare`, narrowing ties down with
+/// each tie-break in turn; returns the tie-break that left a single group, if one was needed
+fn rank_leader(
+    standings: &[GroupStanding],
+    compare: impl Fn(&GroupStanding, &GroupStanding) -> Ordering,
+    tie_breaks: &[TieBreak],
+) -> (Option<EntityId>, Option<TieBreak>) {
+    let mut leaders = top_standings(standings.iter().collect(), compare);
+    let mut decided_by = None;
+    for tie_break in tie_breaks {
+        if leaders.len() <= 1 {
+            break;
+        }
+        leaders = top_standings(leaders, |a, b| tie_break.compare(a, b));
+        decided_by = Some(*tie_break);
+    }
+    match leaders.as_slice() {
+        [leader] => (Some(leader.group), decided_by),
+        _ => (None, None),
+    }
+}
+
+/// Standings ranked equal to the best one by `compare`, in their original order
+fn top_standings(
+    standings: Vec<&GroupStanding>,
+    compare: impl Fn(&GroupStanding, &GroupStanding) -> Ordering,
+) -> Vec<&GroupStanding> {
+    let mut top: Vec<&GroupStanding> = Vec::new();
+    for standing in standings {
+        match top.first().map(|best| compare(standing, best)) {
+            Some(Ordering::Less) => {}
+            Some(Ordering::Equal) => top.push(standing),
+            _ => {
+                top.clear();
+                top.push(standing);
+            }
+        }
+    }
+    top
+}
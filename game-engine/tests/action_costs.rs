@@ -0,0 +1,76 @@
+//! Scripts and callers read what an action costs a character through one formula
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::api::game_loop;
+use robot_masters_engine::entity::{ActionDefinition, ConditionDefinition};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::script::{ScriptContext, ScriptEngine};
+use robot_masters_engine::state::{ActionContext, ConditionContext, GameState};
+
+const READ_COST: &str = "READ_PROP v0 ACTION_ENERGY_REQUIRED; EXIT_WITH_VAR v0";
+
+/// Character 0 runs action 1 (costing 30 energy) while its condition sees a cost
+fn create_match() -> GameState {
+    Scenario::new()
+        .seed(3)
+        .character(0, 0, 0)
+        .action(ActionDefinition::new(0, 0, compile("EXIT 1")))
+        .behavior_with(
+            ConditionDefinition::new(Fixed::ONE, compile(READ_COST)),
+            ActionDefinition::new(30, 0, compile("APPLY_ENERGY_COST; EXIT 1")),
+        )
+        .build()
+}
+
+/// Read the cost property into var 0 and return it
+fn read_cost(engine: &mut ScriptEngine, context: &mut impl ScriptContext) -> u8 {
+    engine.execute(&compile(READ_COST), context).unwrap();
+    engine.vars[0]
+}
+
+#[test]
+fn actions_and_conditions_read_the_computed_cost() {
+    let mut state = create_match();
+    let mut engine = ScriptEngine::new();
+
+    assert_eq!(
+        read_cost(&mut engine, &mut ActionContext::new(&mut state, 0, 1, 1)),
+        30
+    );
+    assert_eq!(
+        read_cost(&mut engine, &mut ActionContext::new(&mut state, 0, 0, 0)),
+        0
+    );
+
+    let mut context = ConditionContext::new(&mut state, 0, 0, 0).for_action(1);
+    assert_eq!(read_cost(&mut engine, &mut context), 30);
+    let mut context = ConditionContext::new(&mut state, 0, 0, 0);
+    assert_eq!(
+        read_cost(&mut engine, &mut context),
+        0,
+        "no behavior, no cost"
+    );
+}
+
+#[test]
+fn behavior_conditions_see_the_cost_of_their_action() {
+    let mut state = create_match();
+    game_loop(&mut state).expect("frame advances");
+    assert_eq!(state.characters[0].energy, 70);
+}
+
+#[test]
+fn affordability_follows_the_computed_cost() {
+    let mut state = create_match();
+    assert_eq!(state.action_energy_cost(0, 1), Some(30));
+    assert_eq!(state.action_energy_cost(0, 9), None);
+    assert_eq!(state.action_energy_cost(4, 1), None);
+
+    assert!(state.can_afford_action(0, 1));
+    state.characters[0].energy = 29;
+    assert!(!state.can_afford_action(0, 1));
+    assert!(!state.is_action_usable(0, 1));
+    assert!(state.is_action_usable(0, 0));
+}
@@ -0,0 +1,145 @@
+//! Aggro accrues from damage and taunts, decays over time and drives HIGHEST_AGGRO
+
+mod common;
+
+use common::{advance, compile, exec, Scenario};
+use robot_masters_engine::constants::target_query;
+use robot_masters_engine::core::{AGGRO_DECAY_INTERVAL, TAUNT_AGGRO_PER_FRAME};
+use robot_masters_engine::entity::StatusEffectDefinition;
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::status::apply_status_effect_from_source;
+
+/// Character 0 (group 0) facing enemy 1 close by and enemy 2 further away; status effect 0
+/// taunts for 10 frames
+fn create_match() -> GameState {
+    let mut taunt = StatusEffectDefinition::new(10, 1, false, 100, vec![], vec![], vec![]);
+    taunt.taunt = true;
+    Scenario::new()
+        .character(0, 32, 208)
+        .character(1, 80, 208)
+        .character(1, 200, 208)
+        .status_effect(taunt)
+        .build()
+}
+
+#[test]
+fn damage_raises_aggro_toward_the_attacker() {
+    let mut state = create_match();
+    state.record_damage_dealt(2, 0, 30);
+    state.record_damage_dealt(2, 0, 300);
+    state.record_damage_dealt(1, 0, 5);
+    assert_eq!(state.characters[0].aggro[..3], [0, 5, 255]);
+    assert_eq!(
+        state.characters[2].aggro, [0; 8],
+        "dealing damage holds no aggro"
+    );
+}
+
+#[test]
+fn aggro_decays_by_a_quarter_each_interval() {
+    let mut state = create_match();
+    state.record_damage_dealt(1, 0, 40);
+    state.record_damage_dealt(2, 0, 1);
+
+    while state.frame < AGGRO_DECAY_INTERVAL {
+        advance(&mut state, 1);
+    }
+    assert_eq!(state.characters[0].aggro[..3], [0, 40, 1]);
+    advance(&mut state, 1);
+    assert_eq!(state.characters[0].aggro[..3], [0, 30, 0]);
+}
+
+#[test]
+fn taunts_build_aggro_toward_the_taunter() {
+    let mut state = create_match();
+    let mut character = state.characters[0].clone();
+    apply_status_effect_from_source(&mut character, &mut state, 0, Some((2, 1)))
+        .expect("status effect applies");
+    state.characters[0] = character;
+
+    advance(&mut state, 3);
+    assert_eq!(
+        state.characters[0].aggro[2],
+        3 * TAUNT_AGGRO_PER_FRAME as u8
+    );
+}
+
+#[test]
+fn highest_aggro_picks_who_hurt_the_most() {
+    let mut state = create_match();
+    let engine = exec(&mut state, 0, "FIND_TARGET v0 3; EXIT 1");
+    assert_eq!(engine.vars[0], 1, "without aggro the nearest enemy wins");
+
+    state.record_damage_dealt(2, 0, 10);
+    let engine = exec(
+        &mut state,
+        0,
+        "FIND_TARGET v0 3; ACQUIRE_TARGET v1 3; EXIT 1",
+    );
+    assert_eq!(engine.vars[..2], [2, 1]);
+    assert_eq!(state.characters[0].core.target_id, Some(2));
+
+    let from = state.characters[0].core.clone();
+    assert_eq!(
+        state.find_target(
+            &from,
+            &[0, 9, 0, 0, 0, 0, 0, 0],
+            target_query::HIGHEST_AGGRO
+        ),
+        Some(1)
+    );
+}
+
+#[test]
+fn aggro_survives_snapshots_and_changes_the_hash() {
+    let mut state = create_match();
+    let hash = state.state_hash();
+    state.record_damage_dealt(1, 0, 12);
+    assert_ne!(state.state_hash(), hash);
+
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.characters[0].aggro, state.characters[0].aggro);
+    assert_eq!(restored.state_hash(), state.state_hash());
+}
+
+#[test]
+fn effects_applied_by_actions_build_aggro_toward_the_caster() {
+    // Enemy 2 taunts character 0 and makes it bleed one health a tick, from its own action
+    let mut taunt = StatusEffectDefinition::new(10, 1, false, 100, vec![], vec![], vec![]);
+    taunt.taunt = true;
+    let bleed = StatusEffectDefinition::new(
+        10,
+        1,
+        false,
+        100,
+        Vec::new(),
+        compile(
+            "READ_PROP v0 CHARACTER_HEALTH_BYTE; ASSIGN_BYTE v1 1; SUB_BYTE v2 v0 v1
+            WRITE_PROP CHARACTER_HEALTH_BYTE v2",
+        ),
+        Vec::new(),
+    );
+    let mut state = Scenario::new()
+        .character(0, 32, 208)
+        .character(1, 80, 208)
+        .character(1, 200, 208)
+        .behavior(
+            "EXIT 1",
+            "ASSIGN_BYTE v1 0; ASSIGN_BYTE v2 0; APPLY_STATUS_EFFECT v0 v1 v2
+            ASSIGN_BYTE v2 1; APPLY_STATUS_EFFECT v0 v1 v2; EXIT 1",
+        )
+        .status_effect(taunt)
+        .status_effect(bleed)
+        .build();
+
+    advance(&mut state, 5);
+    let character = &state.characters[0];
+    assert_eq!(character.taunted_by, Some(2));
+    let bled = character.health_cap - character.health;
+    assert!(bled > 0);
+    assert_eq!(
+        character.aggro[2] as u16,
+        bled + 5 * TAUNT_AGGRO_PER_FRAME as u16
+    );
+    assert_eq!(character.aggro[1], 0);
+}
@@ -0,0 +1,45 @@
+//! Built-in archetypes give characters baseline stats without tuning every field
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::constants::archetype;
+use robot_masters_engine::entity::Character;
+
+#[test]
+fn archetypes_are_found_by_name() {
+    assert_eq!(archetype::find("bruiser"), Some(&archetype::BRUISER));
+    assert_eq!(archetype::find("sniper"), Some(&archetype::SNIPER));
+    assert_eq!(archetype::find("support"), Some(&archetype::SUPPORT));
+    assert_eq!(archetype::find("Bruiser"), None);
+    assert_eq!(archetype::ALL.len(), 3);
+}
+
+#[test]
+fn characters_start_full_with_the_archetype_stats() {
+    let character = Character::from_archetype(2, 1, &archetype::BRUISER);
+    assert_eq!((character.core.id, character.core.group), (2, 1));
+    assert_eq!(character.core.size, archetype::BRUISER.size);
+    assert_eq!(character.health, character.health_cap);
+    assert_eq!(character.health, archetype::BRUISER.health);
+    assert_eq!(character.energy, character.energy_cap);
+    assert_eq!(character.armor, archetype::BRUISER.armor);
+    assert_eq!(
+        character.detection_radius,
+        archetype::BRUISER.detection_radius
+    );
+}
+
+#[test]
+fn every_archetype_plays_a_match() {
+    let mut scenario = Scenario::new().seed(11);
+    for (id, (_, stats)) in archetype::ALL.iter().enumerate() {
+        scenario = scenario.add_character(Character::from_archetype(id as u8, id as u8, stats));
+    }
+
+    let state = scenario.run(120);
+    assert!(state
+        .characters
+        .iter()
+        .all(|character| character.core.collision.2));
+}
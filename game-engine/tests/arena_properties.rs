@@ -0,0 +1,49 @@
+//! Gravity and arena dimensions readable by scripts for resolution-independent positioning
+
+mod common;
+
+use common::{compile, exec, Scenario};
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::math::Fixed;
+
+const READ_ARENA: &str = "READ_PROP v0 GAME_ARENA_WIDTH; READ_PROP v1 GAME_ARENA_HEIGHT
+    READ_PROP v2 GAME_ARENA_CENTER_X; READ_PROP v3 GAME_ARENA_CENTER_Y";
+const READ_GRAVITY: &str = "READ_PROP v0 GAME_GRAVITY_X; READ_PROP v1 GAME_GRAVITY";
+
+#[test]
+fn actions_read_the_arena_and_gravity() {
+    let mut state = Scenario::new().character(0, 32, 100).build();
+    let engine = exec(&mut state, 0, READ_ARENA);
+    assert_eq!(engine.fixed, [256, 240, 128, 120].map(Fixed::from_int));
+
+    state.gravity = (Fixed::from_frac(1, 4), Fixed::ONE);
+    let engine = exec(&mut state, 0, READ_GRAVITY);
+    assert_eq!(engine.fixed[..2], [Fixed::from_frac(1, 4), Fixed::ONE]);
+
+    // Read-only: writes leave the arena unchanged
+    exec(
+        &mut state,
+        0,
+        "ASSIGN_FIXED f0 9 0; WRITE_PROP GAME_ARENA_WIDTH v0",
+    );
+    assert_eq!(
+        exec(&mut state, 0, READ_ARENA).fixed[0],
+        Fixed::from_int(256)
+    );
+}
+
+#[test]
+fn spawns_read_the_arena_and_gravity() {
+    let mut definition = SpawnDefinition::from_def(Vec::new());
+    definition.behavior_script = compile(&format!(
+        "{READ_ARENA}; WRITE_PROP SPAWN_INST_FIXED0 v2
+        {READ_GRAVITY}; WRITE_PROP SPAWN_INST_FIXED1 v1"
+    ));
+    let mut state = Scenario::new().spawn(definition.clone()).build();
+    let mut instance = SpawnInstance::new(0, 0, (Fixed::ZERO, Fixed::ZERO));
+    definition
+        .execute_behavior_script(&mut state, &mut instance, &mut Vec::new())
+        .unwrap();
+    assert_eq!(instance.runtime_fixed[0], Fixed::from_int(128));
+    assert_eq!(instance.runtime_fixed[1], Fixed::from_frac(1, 2));
+}
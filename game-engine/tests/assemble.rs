@@ -0,0 +1,120 @@
+//! Assembled listings compile back into the bytecode they describe
+
+use robot_masters_engine::constants::{operator_address as op, property_address as prop};
+use robot_masters_engine::script::{assemble, disassemble, AssembleError, AssemblyProblem};
+
+fn problem(source: &str) -> (u16, AssemblyProblem) {
+    let AssembleError { line, problem } = assemble(source).unwrap_err();
+    (line, problem)
+}
+
+#[test]
+fn statements_compile_to_bytecode() {
+    assert_eq!(
+        assemble("READ_PROP v0 CHARACTER_HEALTH; EXIT 1").unwrap(),
+        vec![op::READ_PROP, 0, prop::CHARACTER_HEALTH, op::EXIT, 1]
+    );
+    // Mnemonics, any case, commas, comments, hex and raw operand bytes
+    assert_eq!(
+        assemble("readProp f1, 0x21 # move speed\n  AssignFixed 2 3 4\n").unwrap(),
+        vec![op::READ_PROP, 9, 0x21, op::ASSIGN_FIXED, 2, 3, 4]
+    );
+    assert_eq!(assemble("").unwrap(), Vec::<u8>::new());
+}
+
+#[test]
+fn listings_round_trip() {
+    let script = [
+        op::READ_PROP,
+        0,
+        prop::CHARACTER_HEALTH,
+        op::READ_CHARACTER_PROPERTY,
+        1,
+        2,
+        prop::CHARACTER_ENERGY,
+        op::READ_ARG,
+        3,
+        1,
+        op::SKIP,
+        2,
+        op::GOTO,
+        0,
+        op::EXIT_IF_NO_ENERGY,
+        1,
+    ];
+    assert_eq!(assemble(&disassemble(&script)).unwrap(), script);
+
+    let malformed = [200, op::READ_PROP, 0, 0x0F, op::ADD_BYTE, 0];
+    assert_eq!(assemble(&disassemble(&malformed)).unwrap(), malformed);
+}
+
+#[test]
+fn labels_resolve_jumps() {
+    let source = "
+        top:
+            ASSIGN_RANDOM v0
+            SKIP done
+            GOTO top
+        done: EXIT 1
+    ";
+    assert_eq!(
+        assemble(source).unwrap(),
+        vec![op::ASSIGN_RANDOM, 0, op::SKIP, 2, op::GOTO, 0, op::EXIT, 1]
+    );
+}
+
+#[test]
+fn errors_name_the_line_and_problem() {
+    assert_eq!(
+        problem("EXIT 1\nREAD_PROPS v0 0x21"),
+        (
+            2,
+            AssemblyProblem::UnknownOperator {
+                name: "READ_PROPS".to_string()
+            }
+        )
+    );
+    assert_eq!(
+        problem("EXIT"),
+        (
+            1,
+            AssemblyProblem::OperandCount {
+                expected: 1,
+                found: 0
+            }
+        )
+    );
+    assert_eq!(
+        problem("READ_PROP v0 CHARACTER_HEALTHY"),
+        (
+            1,
+            AssemblyProblem::InvalidOperand {
+                index: 1,
+                text: "CHARACTER_HEALTHY".to_string()
+            }
+        )
+    );
+    assert_eq!(
+        problem("GOTO nowhere"),
+        (
+            1,
+            AssemblyProblem::UnknownLabel {
+                name: "nowhere".to_string()
+            }
+        )
+    );
+    assert_eq!(
+        problem("a: EXIT 0\na: EXIT 1"),
+        (
+            2,
+            AssemblyProblem::DuplicateLabel {
+                name: "a".to_string()
+            }
+        )
+    );
+    // SKIP only jumps forward
+    assert_eq!(
+        problem("EXIT 0; SKIP 0000"),
+        (1, AssemblyProblem::JumpOutOfRange)
+    );
+}
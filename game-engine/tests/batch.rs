@@ -0,0 +1,55 @@
+//! Parallel batch simulation matches sequential runs (requires the `rayon` feature)
+#![cfg(feature = "rayon")]
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::batch::{run_batch, run_batch_sequential};
+use robot_masters_engine::state::GameState;
+
+/// Two characters randomly jumping, so every seed plays out differently
+fn create_match(seed: u16) -> GameState {
+    // 254 is -2 as a signed byte
+    let condition = "ASSIGN_RANDOM v0; EXIT_WITH_VAR v0";
+    let action = "ASSIGN_FIXED f0 254 1; WRITE_PROP CHARACTER_VEL_Y v0; EXIT 1";
+    Scenario::new()
+        .seed(seed)
+        .character(0, 32, 64)
+        .behavior(condition, action)
+        .character(1, 160, 64)
+        .behavior(condition, action)
+        .build()
+}
+
+fn create_batch() -> Vec<GameState> {
+    (1..=7).map(create_match).collect()
+}
+
+#[test]
+fn parallel_batch_equals_sequential_runs() {
+    let mut sequential = create_batch();
+    let expected = run_batch_sequential(&mut sequential, 300);
+
+    for threads in [0, 2, 3, 16] {
+        let mut parallel = create_batch();
+        let outcomes = run_batch(&mut parallel, 300, threads);
+        assert_eq!(outcomes, expected, "{} threads diverged", threads);
+
+        for (a, b) in parallel.iter().zip(&sequential) {
+            assert_eq!(a.state_hash(), b.state_hash());
+            assert_eq!(a.frame, b.frame);
+        }
+    }
+}
+
+#[test]
+fn batch_stops_at_match_end() {
+    let mut states = create_batch();
+    for state in &mut states {
+        state.frame_limit = 10;
+    }
+
+    for outcome in run_batch(&mut states, 100, 2) {
+        assert_eq!(outcome.expect("simulation succeeds").frames_advanced, 10);
+    }
+}
@@ -0,0 +1,52 @@
+//! Broadphase blocks agree with the per-pair entity collision check
+
+use robot_masters_engine::broadphase::{overlap_mask, PixelRect, LANES};
+use robot_masters_engine::entity::EntityCore;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::physics::PhysicsSystem;
+
+/// Entity at a fractional position so pixel truncation is exercised
+fn core(seed: u16) -> EntityCore {
+    let mut core = EntityCore::new(0, 0);
+    core.pos = (
+        Fixed::from_raw((seed % 13) as i16 * 11 - 40),
+        Fixed::from_raw((seed / 13 % 13) as i16 * 9 - 40),
+    );
+    core.size = ((seed % 4) as u8, (seed / 4 % 3) as u8 + 1);
+    core
+}
+
+#[test]
+fn overlap_mask_matches_entity_collision() {
+    for a in 0..169u16 {
+        let subject = core(a);
+        let rect = PixelRect::from_core(&subject);
+        for b in 0..169u16 {
+            let others: Vec<EntityCore> = (0..LANES as u16).map(|k| core(b * 3 + k)).collect();
+            let rects: Vec<PixelRect> = others.iter().map(PixelRect::from_core).collect();
+
+            let expected = others.iter().enumerate().fold(0u8, |mask, (k, other)| {
+                mask | ((PhysicsSystem::check_entity_collision(&subject, other) as u8) << k)
+            });
+            assert_eq!(overlap_mask(&rect, &rects), expected);
+        }
+    }
+}
+
+#[test]
+fn partial_blocks_only_report_given_candidates() {
+    let rect = PixelRect {
+        left: 0,
+        top: 0,
+        right: 8,
+        bottom: 8,
+    };
+    let candidates = [rect; LANES];
+
+    for count in 0..=LANES {
+        assert_eq!(
+            overlap_mask(&rect, &candidates[..count]),
+            (1u8 << count) - 1
+        );
+    }
+}
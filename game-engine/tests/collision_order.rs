@@ -0,0 +1,103 @@
+//! Simultaneous collisions resolve in canonical entity handle order, not storage order
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::entity::{EntityHandle, SpawnDefinition, SpawnInstance};
+use robot_masters_engine::event::{GameEventKind, RemovalReason};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Three idle characters and a spawn definition that destroys itself on any collision
+fn create_match() -> GameState {
+    let mut shield = SpawnDefinition::from_def(Vec::new());
+    shield.collision_mask = 1;
+    shield.collision_script = compile("WRITE_PROP SPAWN_INST_LIFE_SPAN v0; EXIT 0");
+
+    Scenario::new()
+        .seed(3)
+        .character(0, 16, 192)
+        .character(1, 80, 192)
+        .character(2, 144, 192)
+        .spawn(shield)
+        .build()
+}
+
+/// Place overlapping spawns with the given stable IDs and owners, in storage order
+fn push_spawns(state: &mut GameState, ids: &[(u8, u8)]) {
+    let spawns = ids
+        .iter()
+        .map(|&(id, owner)| {
+            let mut spawn =
+                SpawnInstance::new(0, owner, (Fixed::from_int(120), Fixed::from_int(80)));
+            spawn.core.id = id;
+            spawn.core.size = (4, 4);
+            spawn.life_span = 100;
+            spawn
+        })
+        .collect();
+    state.restore_spawns(spawns).expect("distinct spawn IDs");
+}
+
+#[test]
+fn pairs_resolve_by_handle_regardless_of_storage_order() {
+    let mut state = create_match();
+    // Storage order 9, 3, 6: index order would pair 9 with 3 first
+    push_spawns(&mut state, &[(9, 0), (3, 1), (6, 2)]);
+    state.advance_frame().expect("frame advances");
+
+    let survivors: Vec<u8> = state.spawn_instances().iter().map(|s| s.core.id).collect();
+    assert_eq!(survivors, vec![9]);
+
+    let removals: Vec<(u8, RemovalReason)> = state
+        .events
+        .iter()
+        .filter_map(|event| match event.kind {
+            GameEventKind::SpawnRemoved {
+                spawn_instance_id,
+                reason,
+                ..
+            } => Some((spawn_instance_id, reason)),
+            _ => None,
+        })
+        .collect();
+    assert_eq!(
+        removals,
+        vec![
+            (3, RemovalReason::AbsorbedBy(6)),
+            (6, RemovalReason::AbsorbedBy(3)),
+        ]
+    );
+}
+
+#[test]
+fn storage_permutations_produce_identical_states() {
+    let mut first = create_match();
+    let mut second = create_match();
+    push_spawns(&mut first, &[(9, 0), (3, 1), (6, 2)]);
+    push_spawns(&mut second, &[(6, 2), (9, 0), (3, 1)]);
+
+    first.advance_frame().expect("frame advances");
+    second.advance_frame().expect("frame advances");
+    assert_eq!(first.state_hash(), second.state_hash());
+}
+
+#[test]
+fn characters_order_before_spawns_then_by_id() {
+    let mut handles = vec![
+        EntityHandle::Spawn(0),
+        EntityHandle::Character(2),
+        EntityHandle::Spawn(7),
+        EntityHandle::Character(0),
+    ];
+    handles.sort();
+    assert_eq!(
+        handles,
+        vec![
+            EntityHandle::Character(0),
+            EntityHandle::Character(2),
+            EntityHandle::Spawn(0),
+            EntityHandle::Spawn(7),
+        ]
+    );
+}
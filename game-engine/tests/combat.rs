@@ -0,0 +1,135 @@
+//! Spawn hits roll damage through one pipeline: range, crits and elemental armor
+
+mod common;
+
+use common::{compile, exec, Scenario};
+use robot_masters_engine::combat::{roll_damage, DamageRoll};
+use robot_masters_engine::constants::operator_address as op;
+use robot_masters_engine::entity::{Element, SpawnDefinition, SpawnInstance};
+use robot_masters_engine::event::GameEventKind;
+use robot_masters_engine::random::SeededRng;
+use robot_masters_engine::script;
+use robot_masters_engine::state::GameState;
+
+/// Character 1 wears 3 Heat armor; character 0 owns a spawn definition built by `configure`
+fn create_match(configure: impl FnOnce(&mut SpawnDefinition)) -> GameState {
+    let mut bolt = SpawnDefinition::from_def(Vec::new());
+    configure(&mut bolt);
+    Scenario::new()
+        .character(0, 32, 208)
+        .character(1, 160, 208)
+        .with(|character| character.set_armor(Element::Heat, 3))
+        .spawn(bolt)
+        .build()
+}
+
+/// Hit character 1 with a 10 damage Heat spawn and return the hit event's fields
+fn hit(state: &mut GameState) -> (u16, bool, u16) {
+    let pos = state.characters[1].core.pos;
+    let mut spawn = SpawnInstance::with_element(0, 0, pos, Element::Heat);
+    spawn.core.size = (4, 4);
+    spawn.life_span = 100;
+    spawn.damage = 10;
+    state.insert_spawn(spawn).unwrap();
+    state.advance_frame().expect("frame advances");
+    state
+        .events
+        .iter()
+        .find_map(|event| match event.kind {
+            GameEventKind::CharacterHit {
+                amount,
+                critical,
+                mitigated,
+                ..
+            } => Some((amount, critical, mitigated)),
+            _ => None,
+        })
+        .expect("character 1 is hit")
+}
+
+#[test]
+fn plain_hits_subtract_armor() {
+    let mut state = create_match(|_| {});
+    assert_eq!(hit(&mut state), (7, false, 3));
+    assert_eq!(
+        state.characters[1].health,
+        state.characters[1].health_cap - 7
+    );
+}
+
+#[test]
+fn crits_scale_damage_before_armor() {
+    let mut state = create_match(|bolt| {
+        bolt.crit_chance = 255;
+        bolt.crit_multiplier = 200;
+    });
+    assert_eq!(hit(&mut state), (17, true, 3));
+
+    // Armor that absorbs the whole hit reports all of it as mitigated
+    let mut state = create_match(|bolt| bolt.crit_chance = 255);
+    state.characters[1].set_armor(Element::Heat, 40);
+    assert_eq!(hit(&mut state), (0, true, 10));
+}
+
+#[test]
+fn range_rolls_are_seeded() {
+    let mut bolt = SpawnDefinition::from_def(Vec::new());
+    bolt.damage_range = 5;
+    bolt.crit_chance = 128;
+    bolt.crit_multiplier = 150;
+
+    let mut rng = SeededRng::with_stream(7, 2);
+    let rolls: Vec<DamageRoll> = (0..50)
+        .map(|_| roll_damage(&bolt, 10, 2, &mut rng))
+        .collect();
+    for roll in &rolls {
+        let most = if roll.critical { 22 } else { 15 };
+        assert!((10..=most).contains(&roll.raw));
+        assert_eq!((roll.amount, roll.mitigated), (roll.raw - 2, 2));
+    }
+    assert!(rolls.iter().any(|roll| roll.critical));
+    assert!(rolls.iter().any(|roll| !roll.critical));
+
+    rng.seek(0);
+    let replayed: Vec<DamageRoll> = (0..50)
+        .map(|_| roll_damage(&bolt, 10, 2, &mut rng))
+        .collect();
+    assert_eq!(rolls, replayed);
+
+    // Matches with the same seed roll the same hits, and the roll survives snapshots
+    let configure = |bolt: &mut SpawnDefinition| bolt.damage_range = 20;
+    let (mut first, mut second) = (create_match(configure), create_match(configure));
+    assert_eq!(hit(&mut first), hit(&mut second));
+    let restored = GameState::from_bytes(&first.to_bytes()).unwrap();
+    assert_eq!(restored.state_hash(), first.state_hash());
+}
+
+#[test]
+fn collision_scripts_roll_damage_without_dealing_it() {
+    let mut state = create_match(|bolt| {
+        bolt.crit_chance = 255;
+        bolt.crit_multiplier = 150;
+        bolt.collision_script = compile(
+            "ASSIGN_BYTE v2 1; ROLL_DAMAGE v0 v1 v2; \
+             WRITE_PROP SPAWN_DEF_STATIC0 v0; WRITE_PROP SPAWN_DEF_STATIC1 v1; EXIT 2",
+        );
+    });
+    let health = state.characters[1].health;
+    let pos = state.characters[1].core.pos;
+    let mut spawn = SpawnInstance::with_element(0, 0, pos, Element::Heat);
+    spawn.core.size = (4, 4);
+    spawn.life_span = 100;
+    spawn.damage = 10;
+    state.insert_spawn(spawn).unwrap();
+    state.advance_frame().unwrap();
+    assert_eq!(state.static_vars.spawns[0][..2], [12, 1]);
+    assert_eq!(state.characters[1].health, health);
+
+    // Characters have no hit damage to roll
+    let source = "ASSIGN_BYTE v0 9; ASSIGN_BYTE v1 9; ASSIGN_BYTE v2 1; ROLL_DAMAGE v0 v1 v2";
+    assert_eq!(exec(&mut state, 0, source).vars[..2], [0, 0]);
+    assert_eq!(
+        script::disassemble(&[op::ROLL_DAMAGE, 0, 1, 2]),
+        "0000: RollDamage v0, v1, v2"
+    );
+}
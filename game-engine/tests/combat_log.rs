@@ -0,0 +1,79 @@
+//! Game events render as readable combat log lines
+#![cfg(feature = "combat-log")]
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::combat_log::CombatLog;
+use robot_masters_engine::entity::{Element, SpawnDefinition, SpawnInstance};
+use robot_masters_engine::event::{GameEvent, GameEventKind, RemovalReason};
+use robot_masters_engine::state::GameState;
+
+/// Two grounded characters, the second with Heat armor
+fn create_match() -> GameState {
+    Scenario::new()
+        .seed(3)
+        .character(0, 32, 208)
+        .with(|character| character.set_armor(Element::Heat, 6))
+        .character(1, 160, 208)
+        .with(|character| character.set_armor(Element::Heat, 6))
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build()
+}
+
+fn names() -> CombatLog {
+    CombatLog::new(vec!["Gutsman".into(), "Iceman".into()])
+}
+
+#[test]
+fn spawn_hit_reads_as_attack_line() {
+    let mut state = create_match();
+    let pos = state.characters[1].core.pos;
+    let mut spawn = SpawnInstance::new(0, 0, pos);
+    spawn.core.size = (4, 4);
+    spawn.life_span = 100;
+    spawn.damage = 20;
+    spawn.element = Element::Heat;
+    state.insert_spawn(spawn).expect("spawn fits");
+    state.advance_frame().expect("frame advances");
+
+    let lines = names().lines(state.event_history.iter());
+    assert!(lines.contains(&"Frame 0: Gutsman hits Iceman for 14 Heat".to_string()));
+    assert!(lines.contains(&"Frame 0: Iceman enters combat".to_string()));
+}
+
+#[test]
+fn defeats_and_unnamed_characters_fall_back() {
+    let log = names();
+    let event = |kind| GameEvent { frame: 312, kind };
+
+    let defeat = event(GameEventKind::CharacterDefeated {
+        character_id: 1,
+        reason: RemovalReason::KilledBy(Some(0)),
+    });
+    assert_eq!(
+        log.line(&defeat).as_deref(),
+        Some("Frame 312: Iceman is defeated by Gutsman")
+    );
+
+    let stray = event(GameEventKind::CharacterHit {
+        character_id: 4,
+        spawn_instance_id: 9,
+        attacker_id: None,
+        amount: 3,
+        element: Element::Acid,
+        critical: false,
+        mitigated: 0,
+    });
+    assert_eq!(
+        log.line(&stray).as_deref(),
+        Some("Frame 312: Character 4 takes 3 Acid damage")
+    );
+
+    let expired = event(GameEventKind::SpawnRemoved {
+        spawn_instance_id: 9,
+        spawn_id: 0,
+        reason: RemovalReason::Expired,
+    });
+    assert_eq!(log.line(&expired), None);
+}
@@ -0,0 +1,226 @@
+//! Scenario harness for integration tests
+//!
+//! Describe an arena, its characters and their scripts in assembler text, run frames, then
+//! inspect the state:
+//!
+//! ```ignore
+//! let state = Scenario::new()
+//!     .character(0, 64, 32)
+//!     .behavior("EXIT 1", "ASSIGN_FIXED f0 2 0; WRITE_PROP CHARACTER_VEL_X f0; EXIT 1")
+//!     .run(10);
+//! ```
+
+// Every test binary compiles the harness but uses only part of it
+#![allow(dead_code)]
+
+use robot_masters_engine::api::{new_game, GameError};
+use robot_masters_engine::core::ELEMENT_COUNT;
+use robot_masters_engine::entity::{
+    ActionDefinition, Character, ConditionDefinition, SpawnDefinition, SpawnInstance,
+    StatusEffectDefinition,
+};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::script::{self, ScriptEngine};
+use robot_masters_engine::state::{ActionContext, GameState};
+
+/// Match configuration built up one piece at a time
+pub struct Scenario {
+    seed: u16,
+    tilemap: [[u8; 16]; 15],
+    characters: Vec<Character>,
+    actions: Vec<ActionDefinition>,
+    conditions: Vec<ConditionDefinition>,
+    spawns: Vec<SpawnDefinition>,
+    status_effects: Vec<StatusEffectDefinition>,
+}
+
+impl Scenario {
+    /// Empty arena with a solid floor on the bottom row
+    pub fn new() -> Self {
+        let mut tilemap = [[0u8; 16]; 15];
+        tilemap[14] = [1; 16];
+        Self {
+            seed: 1,
+            tilemap,
+            characters: Vec::new(),
+            actions: Vec::new(),
+            conditions: Vec::new(),
+            spawns: Vec::new(),
+            status_effects: Vec::new(),
+        }
+    }
+
+    pub fn seed(mut self, seed: u16) -> Self {
+        self.seed = seed;
+        self
+    }
+
+    /// Replace the arena with a text map, one string per row from the top
+    /// `#` is a block, a hex digit is that tile type and anything else is empty; short rows
+    /// and missing rows are empty.
+    pub fn map(mut self, rows: &[&str]) -> Self {
+        self.tilemap = [[0; 16]; 15];
+        for (row, text) in self.tilemap.iter_mut().zip(rows) {
+            for (tile, symbol) in row.iter_mut().zip(text.chars()) {
+                *tile = match symbol {
+                    '#' => 1,
+                    digit => digit.to_digit(16).unwrap_or(0) as u8,
+                };
+            }
+        }
+        self
+    }
+
+    /// Fill a tile column with blocks above the floor
+    pub fn wall(mut self, column: usize) -> Self {
+        for row in self.tilemap.iter_mut().take(14) {
+            row[column] = 1;
+        }
+        self
+    }
+
+    /// Put blocks in the given columns of one tile row
+    pub fn blocks(mut self, row: usize, columns: &[usize]) -> Self {
+        for &column in columns {
+            self.tilemap[row][column] = 1;
+        }
+        self
+    }
+
+    /// Add a 16x16 character at (x, y) pixels; its id is the number of characters before it
+    pub fn character(mut self, group: u8, x: i16, y: i16) -> Self {
+        let mut character = Character::new(self.characters.len() as u8, group);
+        character.core.pos = (Fixed::from_int(x), Fixed::from_int(y));
+        character.core.size = (16, 16);
+        self.characters.push(character);
+        self
+    }
+
+    /// Add a character like `character` but without armor, so hits land at full damage
+    pub fn unarmored_character(self, group: u8, x: i16, y: i16) -> Self {
+        self.character(group, x, y)
+            .with(|character| character.armor = [0; ELEMENT_COUNT])
+    }
+
+    /// Add a character built elsewhere (an archetype, say) as it is
+    pub fn add_character(mut self, character: Character) -> Self {
+        self.characters.push(character);
+        self
+    }
+
+    /// Adjust the most recently added character
+    pub fn with(mut self, configure: impl FnOnce(&mut Character)) -> Self {
+        configure(self.characters.last_mut().expect("add a character first"));
+        self
+    }
+
+    /// Give the most recently added character a behavior: a new condition and a new action
+    /// (free and without cooldown) written in assembler text
+    pub fn behavior(self, condition: &str, action: &str) -> Self {
+        self.behavior_with(
+            ConditionDefinition::new(Fixed::ONE, compile(condition)),
+            ActionDefinition::new(0, 0, compile(action)),
+        )
+    }
+
+    /// Give the most recently added character a behavior from full definitions
+    pub fn behavior_with(
+        mut self,
+        condition: ConditionDefinition,
+        action: ActionDefinition,
+    ) -> Self {
+        let ids = (self.conditions.len(), self.actions.len());
+        self.conditions.push(condition);
+        self.actions.push(action);
+        self.characters
+            .last_mut()
+            .expect("add a character first")
+            .behaviors
+            .push(ids);
+        self
+    }
+
+    /// Add an action definition no behavior runs (a CALL target or a cost to inspect)
+    pub fn action(mut self, action: ActionDefinition) -> Self {
+        self.actions.push(action);
+        self
+    }
+
+    /// Add a condition definition no behavior runs yet (to pair by id)
+    pub fn condition(mut self, condition: ConditionDefinition) -> Self {
+        self.conditions.push(condition);
+        self
+    }
+
+    pub fn spawn(mut self, spawn: SpawnDefinition) -> Self {
+        self.spawns.push(spawn);
+        self
+    }
+
+    pub fn status_effect(mut self, status_effect: StatusEffectDefinition) -> Self {
+        self.status_effects.push(status_effect);
+        self
+    }
+
+    /// Create the match
+    pub fn build(self) -> GameState {
+        self.try_build().expect("valid scenario")
+    }
+
+    /// Create the match, returning the engine's verdict on an invalid configuration
+    pub fn try_build(self) -> Result<GameState, GameError> {
+        new_game(
+            self.seed,
+            self.tilemap,
+            self.characters,
+            self.actions,
+            self.conditions,
+            self.spawns,
+            self.status_effects,
+        )
+    }
+
+    /// Create the match and advance it `frames` frames
+    pub fn run(self, frames: u32) -> GameState {
+        let mut state = self.build();
+        advance(&mut state, frames);
+        state
+    }
+}
+
+/// Assemble script text, panicking with the failing line
+pub fn compile(source: &str) -> Vec<u8> {
+    script::assemble(source)
+        .unwrap_or_else(|err| panic!("line {}: {:?} in {:?}", err.line, err.problem, source))
+}
+
+/// Advance `frames` frames, panicking on engine errors
+pub fn advance(state: &mut GameState, frames: u32) {
+    for _ in 0..frames {
+        state.advance_frame().expect("frame advances");
+    }
+}
+
+/// Drop a spawn of definition 0 owned by character `owner` onto character `target`, dealing
+/// `damage`, and run one frame
+pub fn drop_spawn(state: &mut GameState, owner: u8, target: usize, damage: u16) {
+    let pos = state.characters[target].core.pos;
+    let mut spawn = SpawnInstance::new(0, owner, pos);
+    spawn.core.size = (4, 4);
+    spawn.life_span = 100;
+    spawn.damage = damage;
+    state.insert_spawn(spawn).expect("spawn fits");
+    state.advance_frame().expect("frame advances");
+}
+
+/// Run script text as action 0 of a character; returns the engine with its final variables
+pub fn exec(state: &mut GameState, character_idx: usize, source: &str) -> ScriptEngine {
+    let mut engine = ScriptEngine::new();
+    engine
+        .execute(
+            &compile(source),
+            &mut ActionContext::new(state, character_idx, 0, 0),
+        )
+        .expect("script runs");
+    engine
+}
@@ -0,0 +1,83 @@
+//! Definition constant pools and 16-bit immediates for values a byte literal cannot hold
+
+mod common;
+
+use common::{compile, exec, Scenario};
+use robot_masters_engine::constants::operator_address as op;
+use robot_masters_engine::entity::ActionDefinition;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::script::{
+    self, validate_with_constants, BytecodeProblem, ScriptContextKind, ScriptEngine, ScriptError,
+};
+use robot_masters_engine::state::{ActionContext, GameState};
+
+/// A match whose action 0 carries `constants`
+fn create_match(constants: Vec<u16>) -> GameState {
+    let mut action = ActionDefinition::new(0, 0, compile("EXIT 0"));
+    action.constants = constants;
+    Scenario::new().character(0, 32, 100).action(action).build()
+}
+
+#[test]
+fn constants_load_as_whole_numbers_or_raw_fixed_bits() {
+    // 600 frames, -1.5 as raw bits and a value past the fixed-point range
+    let mut state = create_match(vec![600, (-48i16) as u16, 40000]);
+    let engine = exec(
+        &mut state,
+        0,
+        "LOAD_CONST f0 const0; LOAD_CONST_FIXED f1 const1; LOAD_CONST f2 const2",
+    );
+    assert_eq!(engine.fixed[0], Fixed::from_int(600));
+    assert_eq!(engine.fixed[1], Fixed::from_frac(-3, 2));
+    assert_eq!(engine.fixed[2], Fixed::from_u16_saturating(40000));
+}
+
+#[test]
+fn immediates_hold_sixteen_bits() {
+    let mut state = create_match(Vec::new());
+    let engine = exec(&mut state, 0, "ASSIGN_FIXED_RAW f3 0xFF 0xD0");
+    assert_eq!(engine.fixed[3], Fixed::from_frac(-3, 2));
+    assert_eq!(
+        compile("AssignFixedRaw f3 255 208"),
+        [op::ASSIGN_FIXED_RAW, 3, 0xFF, 0xD0]
+    );
+}
+
+#[test]
+fn missing_constants_stop_the_script() {
+    let mut state = create_match(vec![1]);
+    let mut engine = ScriptEngine::new();
+    assert_eq!(
+        engine.execute(
+            &[op::LOAD_CONST, 0, 1],
+            &mut ActionContext::new(&mut state, 0, 0, 0)
+        ),
+        Err(ScriptError::InvalidScript)
+    );
+}
+
+#[test]
+fn the_validator_checks_indices_against_the_pool() {
+    let bytes = compile("ASSIGN_BYTE v0 1; LOAD_CONST_FIXED f0 const2");
+    let info = script::validate(&bytes).unwrap();
+    assert_eq!(info.constant_reads, vec![(3, 2)]);
+    assert!(validate_with_constants(&bytes, ScriptContextKind::Action, 3).is_ok());
+    assert_eq!(
+        validate_with_constants(&bytes, ScriptContextKind::Action, 2),
+        Err(ScriptError::InvalidBytecode {
+            offset: 3,
+            problem: BytecodeProblem::UnknownConstant { index: 2 },
+        })
+    );
+    assert_eq!(
+        script::disassemble(&bytes),
+        "0000: AssignByte v0, 1\n0003: LoadConstFixed f0, const2"
+    );
+}
+
+#[test]
+fn constant_pools_survive_snapshots() {
+    let state = create_match(vec![7, 600]);
+    let restored = GameState::from_bytes(&state.to_bytes()).unwrap();
+    assert_eq!(restored.action_definitions[0].constants, vec![7, 600]);
+}
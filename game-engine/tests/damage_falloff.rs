@@ -0,0 +1,97 @@
+//! Spawn damage decays linearly between the falloff distances of its definition
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::math::Fixed;
+
+/// Definition with full damage up to `start` pixels and none from `end` on
+fn falloff(start: u16, end: u16) -> SpawnDefinition {
+    let mut spawn = SpawnDefinition::from_def(Vec::new());
+    spawn.falloff_start = start;
+    spawn.falloff_end = end;
+    spawn
+}
+
+#[test]
+fn damage_decays_linearly_between_start_and_end() {
+    let spawn = falloff(20, 120);
+    assert_eq!(spawn.apply_falloff(100, 0), 100);
+    assert_eq!(spawn.apply_falloff(100, 20), 100);
+    assert_eq!(spawn.apply_falloff(100, 21), 99);
+    assert_eq!(spawn.apply_falloff(100, 70), 50);
+    assert_eq!(spawn.apply_falloff(100, 119), 1);
+    assert_eq!(spawn.apply_falloff(100, 120), 0);
+    assert_eq!(spawn.apply_falloff(100, u16::MAX), 0);
+}
+
+#[test]
+fn degenerate_ranges_switch_falloff_off_or_cut_damage_at_start() {
+    // No end distance: full damage everywhere
+    assert_eq!(falloff(0, 0).apply_falloff(100, u16::MAX), 100);
+    assert_eq!(falloff(50, 0).apply_falloff(100, 500), 100);
+
+    // An end at or before the start drops from full damage to none past the start
+    assert_eq!(falloff(50, 50).apply_falloff(100, 50), 100);
+    assert_eq!(falloff(50, 50).apply_falloff(100, 51), 0);
+    assert_eq!(falloff(50, 10).apply_falloff(100, 30), 100);
+    assert_eq!(falloff(50, 10).apply_falloff(100, 51), 0);
+}
+
+#[test]
+fn extreme_values_do_not_overflow() {
+    let spawn = falloff(0, u16::MAX);
+    assert_eq!(spawn.apply_falloff(u16::MAX, 0), u16::MAX);
+    assert_eq!(spawn.apply_falloff(u16::MAX, 1), u16::MAX - 1);
+    assert_eq!(spawn.apply_falloff(u16::MAX, u16::MAX - 1), 1);
+    assert_eq!(
+        falloff(u16::MAX - 1, u16::MAX).apply_falloff(7, u16::MAX - 1),
+        7
+    );
+}
+
+#[test]
+fn scripts_read_falloff_distances_saturated_to_the_fixed_point_range() {
+    let mut probe = falloff(1023, 4000);
+    probe.behavior_script = compile(
+        "READ_PROP v0 SPAWN_DEF_FALLOFF_START; WRITE_PROP SPAWN_INST_FIXED0 v0
+        READ_PROP v1 SPAWN_DEF_FALLOFF_END; WRITE_PROP SPAWN_INST_FIXED1 v1
+        READ_PROP v2 SPAWN_INST_DAMAGE; WRITE_PROP SPAWN_INST_FIXED2 v2; EXIT 0",
+    );
+    let mut state = Scenario::new().character(0, 32, 208).spawn(probe).build();
+    let mut spawn = SpawnInstance::new(0, 0, (Fixed::from_int(120), Fixed::from_int(64)));
+    spawn.life_span = 100;
+    spawn.damage = 2000;
+    let id = state.insert_spawn(spawn).expect("spawn fits");
+    let mut instance = state.spawn_instances()[state.spawn_index(id).unwrap()].clone();
+
+    let definition = state.spawn_definitions[0].clone();
+    definition
+        .execute_behavior_script(&mut state, &mut instance, &mut Vec::new())
+        .expect("script runs");
+
+    let max = Fixed::from_int(1023);
+    assert_eq!(instance.runtime_fixed[0], max);
+    assert_eq!(instance.runtime_fixed[1], max);
+    assert_eq!(instance.runtime_fixed[2], max);
+}
+
+#[test]
+fn spawn_origin_feeds_the_state_hash_and_spawn_checksum() {
+    let mut state = Scenario::new()
+        .character(0, 32, 208)
+        .spawn(falloff(20, 120))
+        .build();
+    let pos = (Fixed::from_int(120), Fixed::from_int(64));
+    state
+        .insert_spawn(SpawnInstance::new(0, 0, pos))
+        .expect("spawn fits");
+    let before = state.compute_checksum();
+
+    // Same position, different launch point: falloff damage differs, so must the hash
+    state.spawn_instances_mut()[0].origin = (Fixed::from_int(8), Fixed::from_int(8));
+    let after = state.compute_checksum();
+    assert_ne!(after.state, before.state);
+    assert_ne!(after.spawns, before.spawns);
+}
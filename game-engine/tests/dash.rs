@@ -0,0 +1,115 @@
+//! Dashes move characters in equal steps swept against solid tiles, with one event per step
+
+mod common;
+
+use common::{exec, Scenario};
+use robot_masters_engine::constants::dash_direction;
+use robot_masters_engine::event::GameEventKind;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// One character on the floor at tile column 2, with solid tiles in `walls` (columns of
+/// row 13, the row the character stands in)
+fn create_match(walls: &[usize]) -> GameState {
+    Scenario::new()
+        .seed(5)
+        .blocks(13, walls)
+        .character(0, 32, 208)
+        .build()
+}
+
+/// Run DASH for character 0; returns the result variable
+fn dash(state: &mut GameState, direction: u8, distance: u8, frames: u8) -> u8 {
+    let script = format!(
+        "ASSIGN_BYTE v1 {direction}; ASSIGN_FIXED f0 {distance} 0; ASSIGN_BYTE v2 {frames}
+        DASH v0 v1 f0 v2; EXIT 1"
+    );
+    exec(state, 0, &script).vars[0]
+}
+
+fn position(state: &GameState) -> (i32, i32) {
+    let pos = state.characters[0].core.pos;
+    (pos.0.to_int(), pos.1.to_int())
+}
+
+/// (to x, frames_left, blocked) of the dash events emitted during the last frame
+fn dash_steps(state: &GameState) -> Vec<(i32, u8, bool)> {
+    state
+        .events
+        .iter()
+        .filter_map(|event| match event.kind {
+            GameEventKind::Dashed {
+                to,
+                frames_left,
+                blocked,
+                ..
+            } => Some((to.0.to_int(), frames_left, blocked)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn dashes_cover_their_distance_in_equal_steps() {
+    let mut state = create_match(&[]);
+    assert_eq!(dash(&mut state, dash_direction::RIGHT, 64, 4), 1);
+
+    let mut steps = Vec::new();
+    for _ in 0..4 {
+        state.advance_frame().expect("frame advances");
+        steps.extend(dash_steps(&state));
+    }
+    assert_eq!(
+        steps,
+        vec![
+            (48, 3, false),
+            (64, 2, false),
+            (80, 1, false),
+            (96, 0, false)
+        ]
+    );
+    assert_eq!(position(&state), (96, 208));
+    assert_eq!(state.characters[0].dash, None);
+}
+
+#[test]
+fn dashes_stop_at_walls() {
+    let mut state = create_match(&[5]);
+    assert_eq!(dash(&mut state, dash_direction::RIGHT, 64, 1), 1);
+
+    state.advance_frame().expect("frame advances");
+    assert_eq!(dash_steps(&state), vec![(64, 0, true)]);
+    assert_eq!(position(&state), (64, 208));
+    assert_eq!(state.characters[0].dash, None);
+}
+
+#[test]
+fn gravity_is_suspended_while_dashing() {
+    let mut state = create_match(&[]);
+    assert_eq!(dash(&mut state, dash_direction::UP, 32, 2), 1);
+
+    state.advance_frame().expect("frame advances");
+    // Mid-dash state survives a snapshot round trip
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.characters[0].dash, state.characters[0].dash);
+    assert_eq!(restored.state_hash(), state.state_hash());
+
+    state.advance_frame().expect("frame advances");
+    assert_eq!(position(&state), (32, 176));
+    assert_eq!(state.characters[0].core.vel, (Fixed::ZERO, Fixed::ZERO));
+}
+
+#[test]
+fn invalid_dashes_do_not_start_and_facing_follows_dir() {
+    let mut state = create_match(&[]);
+    assert_eq!(dash(&mut state, dash_direction::RIGHT, 64, 0), 0);
+    assert_eq!(dash(&mut state, dash_direction::RIGHT, 0, 4), 0);
+    assert_eq!(dash(&mut state, 9, 64, 4), 0);
+    state.characters[0].core.dir.0 = 1;
+    assert_eq!(dash(&mut state, dash_direction::FACING, 64, 4), 0);
+    assert_eq!(state.characters[0].dash, None);
+
+    // Characters start facing right
+    state.characters[0].core.dir.0 = 2;
+    assert_eq!(dash(&mut state, dash_direction::FACING, 64, 4), 1);
+}
@@ -0,0 +1,59 @@
+//! Characters detect living enemies within their detection radius, optionally only in sight
+
+mod common;
+
+use common::{advance, exec, Scenario};
+use robot_masters_engine::state::GameState;
+
+/// Character 0 (group 0, 64 pixel radius) at x = 32; character 1 (enemy) 48 pixels away,
+/// character 2 (enemy) 168 pixels away and character 3 (ally) 24 pixels behind
+fn scenario(requires_los: bool) -> Scenario {
+    Scenario::new()
+        .character(0, 32, 208)
+        .with(|observer| {
+            observer.detection_radius = 64;
+            observer.detection_requires_los = requires_los;
+        })
+        .character(1, 80, 208)
+        .character(1, 200, 208)
+        .character(0, 8, 208)
+}
+
+fn detected(state: &mut GameState) -> u8 {
+    exec(state, 0, "READ_PROP v0 CHARACTER_DETECTED_ENEMIES; EXIT 1").vars[0]
+}
+
+#[test]
+fn enemies_within_the_radius_are_detected() {
+    let mut state = scenario(false).run(1);
+    assert_eq!(detected(&mut state), 0b0010);
+    // Characters without a radius detect nothing
+    assert_eq!(state.characters[1].detected_enemies, 0);
+
+    // Defeated enemies drop out of the mask on the next frame
+    state.characters[1].health = 0;
+    advance(&mut state, 1);
+    assert_eq!(detected(&mut state), 0);
+}
+
+#[test]
+fn scripts_can_widen_the_radius() {
+    let mut state = scenario(false).build();
+    exec(
+        &mut state,
+        0,
+        "ASSIGN_BYTE v0 200; WRITE_PROP CHARACTER_DETECTION_RADIUS v0; EXIT 1",
+    );
+    advance(&mut state, 1);
+    assert_eq!(detected(&mut state), 0b0110);
+}
+
+#[test]
+fn walls_block_detection_only_when_sight_is_required() {
+    // A wall column between the observer and character 1
+    let mut state = scenario(true).wall(4).run(1);
+    assert_eq!(detected(&mut state), 0);
+
+    let mut state = scenario(false).wall(4).run(1);
+    assert_eq!(detected(&mut state), 0b0010);
+}
@@ -0,0 +1,199 @@
+//! Determinism checks for bulk frame advancement
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::api::{advance_frames, game_loop};
+use robot_masters_engine::entity::{ActionInstance, ConditionInstance, Element, SpawnInstance};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::victory::EndReason;
+
+fn create_match(seed: u16) -> GameState {
+    // Randomly true condition so the RNG state feeds into the hash
+    let condition = "ASSIGN_RANDOM v0; EXIT_WITH_VAR v0";
+    // Push the character sideways whenever the condition passes
+    let action = "ASSIGN_FIXED f0 3 1; WRITE_PROP CHARACTER_VEL_X v0; EXIT 1";
+    Scenario::new()
+        .seed(seed)
+        .character(0, 32, 64)
+        .behavior(condition, action)
+        .character(1, 160, 64)
+        .behavior(condition, action)
+        .build()
+}
+
+#[test]
+fn advance_frames_matches_step_by_step_hash() {
+    let mut stepped = create_match(12345);
+    let mut bulk = create_match(12345);
+    assert_eq!(stepped.state_hash(), bulk.state_hash());
+
+    for _ in 0..600 {
+        game_loop(&mut stepped).expect("frame advances");
+    }
+    let advanced = advance_frames(&mut bulk, 600).expect("frames advance");
+
+    assert_eq!(advanced, 600);
+    assert_eq!(stepped.frame, bulk.frame);
+    assert_eq!(stepped.state_hash(), bulk.state_hash());
+}
+
+#[test]
+fn state_hash_differs_between_seeds() {
+    let mut first = create_match(1);
+    let mut second = create_match(2);
+
+    advance_frames(&mut first, 120).expect("frames advance");
+    advance_frames(&mut second, 120).expect("frames advance");
+
+    assert_ne!(first.state_hash(), second.state_hash());
+}
+
+#[test]
+fn checksum_sections_locate_divergence() {
+    let mut local = create_match(321);
+    let mut remote = create_match(321);
+    advance_frames(&mut local, 30).expect("frames advance");
+    advance_frames(&mut remote, 30).expect("frames advance");
+
+    let checksum = local.compute_checksum();
+    assert_eq!(checksum, remote.compute_checksum());
+    assert_eq!(checksum.state, local.state_hash());
+    assert_eq!(checksum.characters.len(), 2);
+
+    // Only the diverged character's section changes
+    remote.characters[1].energy ^= 1;
+    let diverged = remote.compute_checksum();
+    assert_ne!(diverged.state, checksum.state);
+    assert_eq!(diverged.characters[0], checksum.characters[0]);
+    assert_ne!(diverged.characters[1], checksum.characters[1]);
+    assert_eq!(diverged.rng, checksum.rng);
+    assert_eq!(diverged.spawns, checksum.spawns);
+}
+
+#[test]
+fn rng_seeks_directly_to_any_frame() {
+    use robot_masters_engine::random::SeededRng;
+
+    // Walking the generator frame by frame matches seeking straight to the frame
+    let mut walked = SeededRng::new(77);
+    for frame in 0..10 {
+        walked.seek(frame);
+        walked.next_u16();
+        walked.next_u16();
+    }
+    walked.seek(10);
+    let walked_values = [walked.next_u16(), walked.next_u16()];
+
+    let mut sought = SeededRng::new(77);
+    sought.seek(10);
+    assert_eq!(walked_values, [sought.next_u16(), sought.next_u16()]);
+    assert_eq!(walked_values[1], SeededRng::value_at(77, 0, 10, 1));
+
+    // Streams sharing a seed produce different sequences
+    assert_ne!(
+        SeededRng::value_at(77, 0, 10, 0),
+        SeededRng::value_at(77, 1, 10, 0)
+    );
+}
+
+#[test]
+fn unlimited_sessions_run_past_u16_frames() {
+    use robot_masters_engine::core::MAX_FRAMES;
+    use robot_masters_engine::state::GameStatus;
+
+    // Default matches still end at MAX_FRAMES
+    let mut timed = create_match(9);
+    timed.frame = MAX_FRAMES;
+    game_loop(&mut timed).expect("frame advances");
+    assert_eq!(timed.status, GameStatus::Ended);
+
+    // Training sessions keep counting where a u16 frame would have wrapped
+    let mut first = create_match(9);
+    let mut second = create_match(9);
+    for state in [&mut first, &mut second] {
+        state.frame_limit = 0;
+        state.frame = u16::MAX as u32 - 5;
+        advance_frames(state, 10).expect("frames advance");
+    }
+
+    assert_eq!(first.status, GameStatus::Playing);
+    assert_eq!(first.frame, u16::MAX as u32 + 5);
+    assert_eq!(first.state_hash(), second.state_hash());
+
+    // Randomness past frame 65535 does not repeat the start of the match
+    use robot_masters_engine::random::SeededRng;
+    assert_ne!(
+        SeededRng::value_at(9, 0, first.frame, 0),
+        SeededRng::value_at(9, 0, first.frame - 65_536, 0)
+    );
+}
+
+#[test]
+fn state_hash_covers_every_simulation_field() {
+    // One spawn and one action and condition instance, so their fields are in the state
+    fn populated_match() -> GameState {
+        let mut state = create_match(55);
+        let pos = (Fixed::from_int(96), Fixed::from_int(64));
+        state.insert_spawn(SpawnInstance::new(0, 0, pos)).unwrap();
+        state.action_instances.push(ActionInstance::new(0, 0));
+        state.condition_instances.push(ConditionInstance::new(0, 0));
+        state
+    }
+
+    let mutations: [(&str, fn(&mut GameState)); 17] = [
+        ("power", |state| state.characters[0].power += 1),
+        ("weight", |state| state.characters[0].weight += 1),
+        ("jump_force", |state| {
+            state.characters[0].jump_force = Fixed::from_int(77)
+        }),
+        ("move_speed", |state| {
+            state.characters[0].move_speed = Fixed::from_int(77)
+        }),
+        ("wall_slide_speed", |state| {
+            state.characters[0].wall_slide_speed = Fixed::from_int(77)
+        }),
+        ("energy_regen", |state| {
+            state.characters[0].energy_regen += 1
+        }),
+        ("energy_regen_rate", |state| {
+            state.characters[0].energy_regen_rate += 1
+        }),
+        ("energy_charge", |state| {
+            state.characters[0].energy_charge += 1
+        }),
+        ("energy_charge_rate", |state| {
+            state.characters[0].energy_charge_rate += 1
+        }),
+        ("base_size", |state| {
+            state.characters[0].base_size = Some(state.characters[0].core.size)
+        }),
+        ("spawn element", |state| {
+            state.spawn_instances_mut()[0].element = Element::Heat
+        }),
+        ("spawn health_cap", |state| {
+            state.spawn_instances_mut()[0].health_cap += 1
+        }),
+        ("action runtime_fixed", |state| {
+            state.action_instances[0].runtime_fixed[3] = Fixed::from_int(1)
+        }),
+        ("action last_used_frame", |state| {
+            state.action_instances[0].last_used_frame = 0
+        }),
+        ("condition runtime_fixed", |state| {
+            state.condition_instances[0].runtime_fixed[3] = Fixed::from_int(1)
+        }),
+        ("spawns_created", |state| state.spawns_created += 1),
+        ("end_reason", |state| {
+            state.end_reason = Some(EndReason::FrameLimit)
+        }),
+    ];
+
+    let baseline = populated_match().state_hash();
+    for (field, mutate) in mutations {
+        let mut state = populated_match();
+        mutate(&mut state);
+        assert_ne!(state.state_hash(), baseline, "{field} is not hashed");
+    }
+}
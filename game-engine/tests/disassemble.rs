@@ -0,0 +1,52 @@
+//! Disassembled scripts list one mnemonic instruction per line
+
+use robot_masters_engine::constants::{operator_address as op, property_address as prop};
+use robot_masters_engine::script::disassemble;
+
+#[test]
+fn instructions_are_listed_with_named_operands() {
+    let script = [
+        op::READ_PROP,
+        0,
+        prop::CHARACTER_HEALTH,
+        op::READ_PROP,
+        9,
+        prop::CHARACTER_MOVE_SPEED,
+        op::READ_CHARACTER_PROPERTY,
+        1,
+        2,
+        prop::CHARACTER_ENERGY,
+        op::READ_ARG,
+        3,
+        1,
+        op::SKIP,
+        2,
+        op::GOTO,
+        0,
+        op::EXIT_IF_NO_ENERGY,
+        1,
+    ];
+
+    assert_eq!(
+        disassemble(&script),
+        "0000: ReadProp v0, CHARACTER_HEALTH\n\
+         0003: ReadProp f1, CHARACTER_MOVE_SPEED\n\
+         0006: ReadCharacterProperty 1, r2, CHARACTER_ENERGY\n\
+         0010: ReadArg v3, arg1\n\
+         0013: Skip 0017\n\
+         0015: Goto 0000\n\
+         0017: ExitIfNoEnergy 1"
+    );
+    assert_eq!(disassemble(&[]), "");
+}
+
+#[test]
+fn malformed_bytes_are_listed_raw() {
+    assert_eq!(
+        disassemble(&[200, op::READ_PROP, 0, 0x0F, op::ADD_BYTE, 0]),
+        "0000: .byte 200\n\
+         0001: ReadProp v0, 0x0F\n\
+         0004: .byte 40\n\
+         0005: .byte 0"
+    );
+}
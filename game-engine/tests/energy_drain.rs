@@ -0,0 +1,94 @@
+//! Energy damage drains a hit character's energy; energy steal hands part of it to the owner
+
+mod common;
+
+use common::{drop_spawn, exec, Scenario};
+use robot_masters_engine::entity::SpawnDefinition;
+use robot_masters_engine::event::GameEventKind;
+use robot_masters_engine::state::GameState;
+
+/// Two grounded characters without armor or energy regen; spawn 0 drains 30 energy and
+/// steals half of it
+fn create_match() -> GameState {
+    let mut disruptor = SpawnDefinition::from_def(Vec::new());
+    disruptor.energy_damage = 30;
+    disruptor.energy_steal = 50;
+
+    let mut state = Scenario::new()
+        .seed(3)
+        .unarmored_character(0, 32, 208)
+        .unarmored_character(1, 160, 208)
+        .spawn(disruptor)
+        .build();
+    for character in &mut state.characters {
+        character.energy_regen = 0;
+    }
+    state
+}
+
+fn drains(state: &GameState) -> Vec<(u8, Option<u8>, u8, u8)> {
+    state
+        .events
+        .iter()
+        .filter_map(|event| match event.kind {
+            GameEventKind::EnergyDrained {
+                character_id,
+                drainer_id,
+                amount,
+                stolen,
+            } => Some((character_id, drainer_id, amount, stolen)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn spawn_hits_drain_and_steal_energy() {
+    let mut state = create_match();
+    state.characters[0].energy = 40;
+    state.characters[1].energy = 20;
+
+    drop_spawn(&mut state, 0, 1, 0);
+    // Only 20 energy was left to drain, so the owner steals 10 of it
+    assert_eq!(state.characters[1].energy, 0);
+    assert_eq!(state.characters[0].energy, 50);
+    assert_eq!(drains(&state), vec![(1, Some(0), 20, 10)]);
+}
+
+#[test]
+fn stolen_energy_saturates_at_the_cap() {
+    let mut state = create_match();
+    state.characters[0].energy = 95;
+
+    drop_spawn(&mut state, 0, 1, 0);
+    assert_eq!(state.characters[1].energy, 70);
+    assert_eq!(state.characters[0].energy, 100);
+    assert_eq!(drains(&state), vec![(1, Some(0), 30, 5)]);
+}
+
+#[test]
+fn empty_characters_emit_no_drain() {
+    let mut state = create_match();
+    state.characters[1].energy = 0;
+
+    drop_spawn(&mut state, 0, 1, 0);
+    assert!(drains(&state).is_empty());
+}
+
+#[test]
+fn steal_energy_opcode_transfers_to_the_caller() {
+    let mut state = create_match();
+    state.characters[0].energy = 10;
+
+    // var0 = target, var1 = amount; var2 receives the drained energy
+    let engine = exec(
+        &mut state,
+        0,
+        "ASSIGN_BYTE v0 1; ASSIGN_BYTE v1 25; STEAL_ENERGY 2 v0 v1; EXIT 1",
+    );
+    assert_eq!(engine.vars[2], 25);
+
+    assert_eq!(state.characters[1].energy, 75);
+    assert_eq!(state.characters[0].energy, 35);
+    assert_eq!(drains(&state), vec![(1, Some(0), 25, 25)]);
+}
@@ -0,0 +1,126 @@
+//! Energy gained above the cap converts into an overheal shield or charge stacks
+
+mod common;
+
+use common::{advance, drop_spawn, exec, Scenario};
+use robot_masters_engine::constants::energy_overflow;
+use robot_masters_engine::core::{ENERGY_PER_CHARGE, MAX_CHARGE_STACKS};
+use robot_masters_engine::entity::SpawnDefinition;
+use robot_masters_engine::event::GameEventKind;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Two armorless characters; the first one sits at full energy regenerating `regen` a frame
+fn create_match(overflow_mode: u8, regen: u8) -> GameState {
+    let mut state = Scenario::new()
+        .unarmored_character(0, 32, 208)
+        .unarmored_character(1, 160, 208)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build();
+    for character in &mut state.characters {
+        character.energy_regen = 0;
+    }
+    let battery = &mut state.characters[0];
+    battery.overflow_mode = overflow_mode;
+    battery.energy = battery.energy_cap;
+    battery.energy_regen = regen;
+    battery.energy_regen_rate = 1;
+    state
+}
+
+#[test]
+fn overflow_is_lost_without_a_mode() {
+    let mut state = create_match(energy_overflow::NONE, 5);
+    advance(&mut state, 10);
+    let character = &state.characters[0];
+    assert_eq!(character.energy, character.energy_cap);
+    assert_eq!(
+        (
+            character.overflow_energy,
+            character.shield,
+            character.charge
+        ),
+        (0, 0, 0)
+    );
+}
+
+#[test]
+fn overflow_builds_a_shield_that_absorbs_hits() {
+    let mut state = create_match(energy_overflow::SHIELD, 5);
+    advance(&mut state, 4);
+    assert_eq!(state.characters[0].shield, 20);
+    assert_eq!(state.characters[0].energy, state.characters[0].energy_cap);
+
+    state.characters[0].energy_regen = 0;
+    let health = state.characters[0].health;
+    drop_spawn(&mut state, 1, 0, 30);
+    assert_eq!(state.characters[0].shield, 0);
+    assert_eq!(state.characters[0].health, health - 10);
+    assert!(state.events.iter().any(|event| matches!(
+        event.kind,
+        GameEventKind::CharacterHit {
+            character_id: 0,
+            amount: 10,
+            mitigated: 20,
+            ..
+        }
+    )));
+
+    // The shield never grows past the health cap
+    state.characters[0].energy_regen = 255;
+    advance(&mut state, 10);
+    assert_eq!(state.characters[0].shield, state.characters[0].health_cap);
+}
+
+#[test]
+fn overflow_charges_stacks_up_to_the_limit() {
+    let mut state = create_match(energy_overflow::CHARGE, 3);
+    advance(&mut state, 7);
+    assert_eq!(state.characters[0].charge, 21 / ENERGY_PER_CHARGE);
+    assert_eq!(state.characters[0].overflow_energy, 21 % ENERGY_PER_CHARGE);
+
+    advance(&mut state, 100);
+    assert_eq!(state.characters[0].charge, MAX_CHARGE_STACKS);
+    assert_eq!(state.characters[0].overflow_energy, 0);
+
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.characters[0].charge, MAX_CHARGE_STACKS);
+    assert_eq!(
+        restored.characters[0].overflow_mode,
+        energy_overflow::CHARGE
+    );
+    assert_eq!(restored.state_hash(), state.state_hash());
+}
+
+#[test]
+fn scripts_read_and_spend_overflow_resources() {
+    let mut state = create_match(energy_overflow::CHARGE, 0);
+    state.characters[0].charge = 4;
+    state.characters[0].shield = 12;
+
+    let engine = exec(
+        &mut state,
+        1,
+        "READ_CHARACTER_PROPERTY 0 r0 CHARACTER_CHARGE
+        READ_CHARACTER_PROPERTY 0 r1 CHARACTER_SHIELD
+        READ_CHARACTER_PROPERTY 0 r2 CHARACTER_OVERFLOW_MODE",
+    );
+    assert_eq!(engine.vars[0], 4);
+    assert_eq!(engine.fixed[1], Fixed::from_int(12));
+    assert_eq!(engine.vars[2], energy_overflow::CHARGE);
+
+    exec(
+        &mut state,
+        0,
+        "ASSIGN_BYTE v0 1; WRITE_PROP CHARACTER_CHARGE v0
+        ASSIGN_BYTE v1 255; WRITE_PROP CHARACTER_OVERFLOW_MODE v1",
+    );
+    assert_eq!(state.characters[0].charge, 1);
+    assert_eq!(state.characters[0].overflow_mode, 255);
+
+    // Unknown modes discard overflow like NONE
+    state.characters[0].energy_regen = 50;
+    advance(&mut state, 2);
+    assert_eq!(state.characters[0].overflow_energy, 0);
+    assert_eq!(state.characters[0].charge, 1);
+}
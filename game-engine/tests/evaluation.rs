@@ -0,0 +1,67 @@
+//! Heuristic per-group evaluation for spectator win bars and lookahead experiments
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Two groups standing the same distance from the arena center
+fn create_match() -> GameState {
+    Scenario::new()
+        .character(0, 64, 208)
+        .character(1, 176, 208)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build()
+}
+
+#[test]
+fn even_groups_split_the_win_chance() {
+    let state = create_match();
+    let evaluations = state.evaluate();
+    assert_eq!(evaluations.len(), 2);
+    assert_eq!(evaluations[0].health, 1000);
+    assert_eq!(evaluations[0].position, 387);
+    assert_eq!(evaluations[0].spawns, 0);
+    assert_eq!(evaluations[0].score, 677);
+    assert_eq!(evaluations[0].win_chance, 500);
+    assert_eq!(
+        (evaluations[1].score, evaluations[1].win_chance),
+        (677, 500)
+    );
+}
+
+#[test]
+fn health_position_and_spawns_shift_the_balance() {
+    let mut state = create_match();
+    let cap = state.characters[1].health_cap;
+    state.characters[1].health = cap / 2;
+    let evaluations = state.evaluate();
+    assert_eq!(evaluations[1].health, 500);
+    assert!(evaluations[0].win_chance > evaluations[1].win_chance);
+
+    // Group 1 wins back ground by holding the center and owning every spawn
+    state.characters[1].core.pos = (Fixed::from_int(120), Fixed::from_int(112));
+    for _ in 0..2 {
+        let spawn = SpawnInstance::new(0, 1, (Fixed::from_int(100), Fixed::from_int(40)));
+        state.insert_spawn(spawn).expect("spawn fits");
+    }
+    let evaluations = state.evaluate();
+    assert_eq!(evaluations[1].position, 1000);
+    assert_eq!((evaluations[0].spawns, evaluations[1].spawns), (0, 1000));
+    assert!(evaluations[1].win_chance > evaluations[0].win_chance);
+}
+
+#[test]
+fn eliminated_groups_have_no_chance() {
+    let mut state = create_match();
+    state.characters[0].health = 0;
+    let evaluations = state.evaluate();
+    assert_eq!(evaluations[0].score, 0);
+    assert_eq!(evaluations[0].win_chance, 0);
+    assert_eq!(evaluations[1].win_chance, 1000);
+
+    state.characters[1].health = 0;
+    assert!(state.evaluate().iter().all(|group| group.win_chance == 0));
+}
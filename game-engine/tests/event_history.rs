@@ -0,0 +1,81 @@
+//! Recent events stay queryable by frame range after the frame that emitted them
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::event::{GameEvent, GameEventKind};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Idle match with three spawns, ids 0..3
+fn create_match() -> GameState {
+    let mut state = Scenario::new()
+        .seed(11)
+        .character(0, 32, 208)
+        .character(1, 160, 208)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build();
+
+    for slot in 0..3i16 {
+        let pos = (Fixed::from_int(16 + slot * 24), Fixed::from_int(64));
+        let mut spawn = SpawnInstance::new(0, 0, pos);
+        spawn.core.size = (4, 4);
+        spawn.life_span = 100;
+        state.insert_spawn(spawn).expect("spawn fits");
+    }
+    state
+}
+
+/// Advance 20 frames, removing one spawn during frames 3, 8 and 12
+fn run(state: &mut GameState) {
+    for frame in 0..20 {
+        if let Some(slot) = [3, 8, 12].iter().position(|&at| at == frame) {
+            let index = state.spawn_index(slot as u8).expect("spawn alive");
+            state.spawn_instances_mut()[index].life_span = 0;
+        }
+        state.advance_frame().expect("frame advances");
+    }
+}
+
+/// Frames of the spawn removals in `events`
+fn removal_frames<'a>(events: impl Iterator<Item = &'a GameEvent>) -> Vec<u32> {
+    events
+        .filter(|event| matches!(event.kind, GameEventKind::SpawnRemoved { .. }))
+        .map(|event| event.frame)
+        .collect()
+}
+
+#[test]
+fn past_events_are_queryable_by_frame_range() {
+    let mut state = create_match();
+    run(&mut state);
+
+    // The per-frame queue only holds the last frame; the history holds all three removals
+    assert!(state.events.is_empty());
+    let all = removal_frames(state.event_history.iter());
+    assert_eq!(all, vec![3, 8, 12]);
+    assert_eq!(removal_frames(state.event_history.range(0, 19)), all);
+    assert_eq!(
+        removal_frames(state.event_history.range(all[1], all[2])),
+        all[1..]
+    );
+    assert!(removal_frames(state.event_history.range(all[0] + 1, all[1] - 1)).is_empty());
+}
+
+#[test]
+fn frames_outside_the_window_are_dropped() {
+    let mut state = create_match();
+    let mut reference = create_match();
+    state.event_history.set_frames(10);
+    run(&mut state);
+    run(&mut reference);
+
+    // Only frames 10..=19 remain; the history never influences the simulation
+    assert_eq!(removal_frames(state.event_history.iter()), vec![12]);
+    assert_eq!(state.state_hash(), reference.state_hash());
+
+    state.event_history.set_frames(0);
+    state.advance_frame().expect("frame advances");
+    assert!(state.event_history.is_empty());
+}
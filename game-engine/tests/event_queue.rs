@@ -0,0 +1,147 @@
+//! Frontends drain the events of every frame, and of API calls between frames, in one queue
+
+mod common;
+
+use common::{advance, Scenario};
+use robot_masters_engine::api::advance_frames;
+use robot_masters_engine::core::MAX_QUEUED_EVENTS;
+use robot_masters_engine::entity::{SpawnDefinition, StatusEffectDefinition};
+use robot_masters_engine::event::{CameraHint, GameEventKind};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::status::apply_status_effect;
+
+/// Character 0 launches spawn 0 every frame; status effect 0 lasts 3 frames
+fn create_match() -> GameState {
+    Scenario::new()
+        .character(0, 32, 208)
+        .behavior("EXIT 1", "ASSIGN_BYTE v0 0; SPAWN v0; EXIT 1")
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .status_effect(StatusEffectDefinition::new(
+            3,
+            2,
+            false,
+            100,
+            vec![],
+            vec![],
+            vec![],
+        ))
+        .build()
+}
+
+fn apply(state: &mut GameState) {
+    let mut character = state.characters[0].clone();
+    assert!(apply_status_effect(&mut character, state, 0).unwrap());
+    state.characters[0] = character;
+}
+
+#[test]
+fn drained_events_cover_every_frame_since_the_last_drain() {
+    let mut state = create_match();
+    advance(&mut state, 2);
+
+    let drained = state.drain_events();
+    assert_eq!(drained.dropped, 0);
+    let kinds: Vec<(u32, &GameEventKind)> = drained
+        .events
+        .iter()
+        .map(|event| (event.frame, &event.kind))
+        .collect();
+    assert_eq!(
+        kinds[..2],
+        [
+            (
+                0,
+                &GameEventKind::ActionExecuted {
+                    character_id: 0,
+                    action_id: 0
+                }
+            ),
+            (
+                0,
+                &GameEventKind::SpawnCreated {
+                    spawn_instance_id: 0,
+                    spawn_id: 0,
+                    owner_id: Some(0)
+                }
+            ),
+        ]
+    );
+    assert!(kinds
+        .iter()
+        .any(|&(frame, kind)| frame == 1 && matches!(kind, GameEventKind::SpawnCreated { .. })));
+    // Only the last frame is still in the per-frame buffer
+    assert!(state.events.iter().all(|event| event.frame == 1));
+    assert!(state.drain_events().events.is_empty());
+}
+
+#[test]
+fn status_effects_report_application_and_expiry() {
+    let mut state = create_match();
+    apply(&mut state);
+    apply(&mut state);
+    advance(&mut state, 4);
+
+    let statuses: Vec<GameEventKind> = state
+        .drain_events()
+        .events
+        .into_iter()
+        .map(|event| event.kind)
+        .filter(|kind| {
+            matches!(
+                kind,
+                GameEventKind::StatusApplied { .. } | GameEventKind::StatusExpired { .. }
+            )
+        })
+        .collect();
+    assert_eq!(
+        statuses,
+        vec![
+            GameEventKind::StatusApplied {
+                character_id: 0,
+                status_effect_id: 0,
+                instance_id: 0,
+                stacks: 1,
+                refreshed: false,
+            },
+            GameEventKind::StatusApplied {
+                character_id: 0,
+                status_effect_id: 0,
+                instance_id: 0,
+                stacks: 2,
+                refreshed: true,
+            },
+            GameEventKind::StatusExpired {
+                character_id: 0,
+                status_effect_id: 0,
+                instance_id: 0,
+            },
+        ]
+    );
+}
+
+#[test]
+fn full_queues_drop_the_oldest_events() {
+    let mut state = create_match();
+    let hint = |zoom| {
+        GameEventKind::CameraHint(CameraHint {
+            focus_type: 0,
+            focus_id: 0,
+            zoom: Fixed::from_int(zoom),
+            shake: 0,
+        })
+    };
+    for zoom in 0..MAX_QUEUED_EVENTS as i16 + 3 {
+        state.push_event(hint(zoom));
+    }
+    let drained = state.drain_events();
+    assert_eq!(
+        (drained.events.len(), drained.dropped),
+        (MAX_QUEUED_EVENTS, 3)
+    );
+    assert_eq!(drained.events[0].kind, hint(3));
+
+    // Batches run with events disabled and queue nothing
+    advance_frames(&mut state, 5).unwrap();
+    assert!(state.drain_events().events.is_empty());
+}
@@ -0,0 +1,62 @@
+//! CLAMP_FIXED and LERP for speed caps and smooth acceleration toward a target speed
+
+mod common;
+
+use common::{compile, exec, Scenario};
+use robot_masters_engine::constants::operator_address as op;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::script;
+
+#[test]
+fn clamping_accepts_bounds_in_either_order() {
+    let (low, high) = (Fixed::from_int(-2), Fixed::from_int(3));
+    assert_eq!(Fixed::from_int(5).clamp_between(low, high), high);
+    assert_eq!(Fixed::from_int(5).clamp_between(high, low), high);
+    assert_eq!(Fixed::from_int(-9).clamp_between(high, low), low);
+    assert_eq!(Fixed::ONE.clamp_between(low, high), Fixed::ONE);
+}
+
+#[test]
+fn lerp_approaches_the_target_without_stalling() {
+    let half = Fixed::from_frac(1, 2);
+    assert_eq!(
+        Fixed::ZERO.lerp(Fixed::from_int(4), half),
+        Fixed::from_int(2)
+    );
+    assert_eq!(
+        Fixed::ZERO.lerp(Fixed::from_int(-4), half),
+        Fixed::from_int(-2)
+    );
+    assert_eq!(
+        Fixed::ZERO.lerp(Fixed::from_int(4), Fixed::from_int(3)),
+        Fixed::from_int(4)
+    );
+    assert_eq!(Fixed::ONE.lerp(Fixed::from_int(4), Fixed::ZERO), Fixed::ONE);
+
+    // A tiny fraction still gets there one raw step at a time
+    let (mut speed, target) = (Fixed::ZERO, Fixed::from_raw(5));
+    for _ in 0..5 {
+        speed = speed.lerp(target, Fixed::from_raw(1));
+    }
+    assert_eq!(speed, target);
+}
+
+#[test]
+fn scripts_ease_toward_a_capped_speed() {
+    // f0 = current speed 0, f1 = requested speed 6 clamped to 3, f2 = ease factor 1/2
+    let source = "ASSIGN_FIXED f1 6 0; ASSIGN_FIXED f2 0 0; ASSIGN_FIXED f3 3 0
+        CLAMP_FIXED f1 f2 f3; ASSIGN_FIXED f2 1 2; LERP f0 f1 f2; EXIT 1";
+    let bytes = compile(source);
+    assert!(script::validate(&bytes).is_ok());
+
+    let mut state = Scenario::new().character(0, 32, 100).build();
+    let engine = exec(&mut state, 0, source);
+    assert_eq!(engine.fixed[1], Fixed::from_int(3));
+    assert_eq!(engine.fixed[0], Fixed::from_frac(3, 2));
+
+    assert!(script::validate(&[op::LERP, 0, 1, 4]).is_err());
+    assert_eq!(
+        script::disassemble(&[op::CLAMP_FIXED, 0, 2, 3]),
+        "0000: ClampFixed f0, f2, f3"
+    );
+}
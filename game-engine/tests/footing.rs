@@ -0,0 +1,92 @@
+//! Ice, mud and conveyor tiles modify how grounded characters move
+
+mod common;
+
+use common::{advance, Scenario};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Character 0 standing at x = 64 on a floor of `tile`, walking right at `speed` pixels per
+/// frame
+fn create_match(tile: char, speed: i16) -> GameState {
+    let floor = tile.to_string().repeat(16);
+    let mut map = vec![""; 15];
+    map[14] = &floor;
+    // Fixed property writes take the fixed variable's index
+    let action = format!("ASSIGN_FIXED f0 {speed} 0; WRITE_PROP CHARACTER_VEL_X v0; EXIT 1");
+    Scenario::new()
+        .map(&map)
+        .character(0, 64, 208)
+        .behavior("EXIT 1", &action)
+        .build()
+}
+
+fn x_after(state: &mut GameState, frames: u32) -> Fixed {
+    advance(state, frames);
+    state.characters[0].core.pos.0
+}
+
+#[test]
+fn mud_halves_walking_speed() {
+    assert_eq!(x_after(&mut create_match('#', 2), 6), Fixed::from_int(76));
+    assert_eq!(x_after(&mut create_match('3', 2), 6), Fixed::from_int(70));
+    assert_eq!(x_after(&mut create_match('3', 0), 6), Fixed::from_int(64));
+}
+
+#[test]
+fn conveyors_push_grounded_characters() {
+    assert_eq!(x_after(&mut create_match('4', 0), 6), Fixed::from_int(58));
+    assert_eq!(x_after(&mut create_match('5', 0), 6), Fixed::from_int(70));
+
+    // The push adds to walking, against or along the belt
+    assert_eq!(x_after(&mut create_match('4', 2), 6), Fixed::from_int(70));
+    assert_eq!(x_after(&mut create_match('5', 2), 6), Fixed::from_int(82));
+}
+
+#[test]
+fn ice_eases_velocity_changes() {
+    // Starting to walk takes a few frames to reach full speed
+    let mut state = create_match('2', 2);
+    let mut speeds = Vec::new();
+    for _ in 0..6 {
+        advance(&mut state, 1);
+        speeds.push(state.characters[0].core.vel.0);
+    }
+    assert_eq!(speeds[0], Fixed::from_frac(1, 4));
+    assert!(speeds.windows(2).all(|pair| pair[0] < pair[1]));
+    assert!(speeds[5] < Fixed::from_int(2));
+
+    // Stopping slides on, where solid ground stops at once
+    let sliding = |tile| {
+        let mut state = create_match(tile, 0);
+        state.characters[0].core.vel.0 = Fixed::from_int(2);
+        x_after(&mut state, 6)
+    };
+    assert_eq!(sliding('#'), Fixed::from_int(64));
+    assert!(sliding('2') > Fixed::from_int(70));
+}
+
+#[test]
+fn airborne_characters_ignore_the_tile_below() {
+    let map = [
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "",
+        "5555555555555555",
+    ];
+    let mut state = Scenario::new().map(&map).character(0, 64, 100).build();
+    advance(&mut state, 4);
+    assert!(!state.characters[0].core.collision.2);
+    assert_eq!(state.characters[0].core.pos.0, Fixed::from_int(64));
+}
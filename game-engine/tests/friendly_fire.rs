@@ -0,0 +1,46 @@
+//! Friendly fire decides whether spawns can hit allies of their owner
+
+mod common;
+
+use common::{drop_spawn, Scenario};
+use robot_masters_engine::entity::SpawnDefinition;
+use robot_masters_engine::state::GameState;
+
+/// Characters 0 and 1 share group 0, character 2 is in group 1; nobody has armor
+fn create_match() -> GameState {
+    Scenario::new()
+        .seed(3)
+        .unarmored_character(0, 32, 208)
+        .unarmored_character(0, 96, 208)
+        .unarmored_character(1, 160, 208)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build()
+}
+
+#[test]
+fn friendly_fire_is_on_by_default() {
+    let mut state = create_match();
+    assert!(state.friendly_fire);
+
+    drop_spawn(&mut state, 0, 1, 10);
+    assert_eq!(state.characters[1].health, 90);
+}
+
+#[test]
+fn disabling_friendly_fire_spares_allies_only() {
+    let mut state = create_match();
+    state.friendly_fire = false;
+
+    drop_spawn(&mut state, 0, 1, 10);
+    assert_eq!(state.characters[1].health, 100);
+    drop_spawn(&mut state, 0, 2, 10);
+    assert_eq!(state.characters[2].health, 90);
+
+    let standings = state.group_standings();
+    assert_eq!(standings[0].damage_dealt, 10);
+    assert_eq!(standings[0].health, 200);
+    assert_eq!(standings[1].damage_taken, 10);
+
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert!(!restored.friendly_fire);
+}
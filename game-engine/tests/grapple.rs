@@ -0,0 +1,166 @@
+//! Grapple spawns pull their owner to a caught point, or pull a caught character to the owner
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::constants::grapple_mode;
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::event::GameEventKind;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+const HOOK: u8 = 0; // Pulls the owner at 8 pixels per frame
+const HARPOON: u8 = 1; // Pulls the hit character at 8 pixels per frame
+const SHORT_HARPOON: u8 = 2; // Harpoon that breaks after 3 frames
+
+/// Two characters on the floor at tile columns 2 and 10, a solid tile at column 5 of row 5
+/// and solid tiles in `walls` (columns of row 13, the row both characters stand in)
+fn create_match(walls: &[usize]) -> GameState {
+    let grapple = |mode, frames| {
+        let mut definition = SpawnDefinition::from_def(Vec::new());
+        definition.grapple = mode;
+        definition.grapple_speed = Fixed::from_int(8);
+        definition.grapple_frames = frames;
+        definition
+    };
+
+    Scenario::new()
+        .seed(3)
+        .blocks(5, &[5])
+        .blocks(13, walls)
+        .character(0, 32, 208)
+        .character(1, 160, 208)
+        .spawn(grapple(grapple_mode::PULL_OWNER, 0))
+        .spawn(grapple(grapple_mode::PULL_TARGET, 0))
+        .spawn(grapple(grapple_mode::PULL_TARGET, 3))
+        .build()
+}
+
+/// Place a grapple spawn owned by character 0, without gravity so it stays where it was placed
+fn throw(state: &mut GameState, spawn_id: u8, pos: (i16, i16)) {
+    let mut spawn = SpawnInstance::new(
+        spawn_id,
+        0,
+        (Fixed::from_int(pos.0), Fixed::from_int(pos.1)),
+    );
+    spawn.core.size = (4, 4);
+    spawn.core.dir.1 = 1;
+    spawn.life_span = 100;
+    state.insert_spawn(spawn).unwrap();
+}
+
+/// Advance up to `frames` frames until a grapple is released; returns the release event's
+/// (character_id, arrived) and the frames it took
+fn pull(state: &mut GameState, frames: u32) -> Option<((u8, bool), u32)> {
+    for frame in 1..=frames {
+        state.advance_frame().expect("frame advances");
+        let released = state.events.iter().find_map(|event| match event.kind {
+            GameEventKind::GrappleReleased {
+                character_id,
+                arrived,
+                ..
+            } => Some((character_id, arrived)),
+            _ => None,
+        });
+        if let Some(released) = released {
+            return Some((released, frame));
+        }
+    }
+    None
+}
+
+fn attached(state: &GameState) -> Vec<(u8, u8, (i32, i32))> {
+    state
+        .events
+        .iter()
+        .filter_map(|event| match event.kind {
+            GameEventKind::GrappleAttached {
+                character_id,
+                source_id,
+                anchor,
+            } => Some((
+                character_id,
+                source_id,
+                (anchor.0.to_int(), anchor.1.to_int()),
+            )),
+            _ => None,
+        })
+        .collect()
+}
+
+fn position(state: &GameState, index: usize) -> (i32, i32) {
+    let pos = state.characters[index].core.pos;
+    (pos.0.to_int(), pos.1.to_int())
+}
+
+#[test]
+fn hooks_pull_the_owner_to_the_terrain_they_catch() {
+    let mut state = create_match(&[]);
+    // Just under the tile at column 5 of row 5
+    throw(&mut state, HOOK, (86, 96));
+
+    state.advance_frame().expect("frame advances");
+    assert_eq!(attached(&state), vec![(0, 0, (88, 98))]);
+    assert!(state.spawn_instances().is_empty());
+
+    let (released, _) = pull(&mut state, 30).expect("pull ends");
+    assert_eq!(released, (0, true));
+    let (x, y) = position(&state, 0);
+    assert!(
+        (73..=88).contains(&x) && (96..=98).contains(&y),
+        "{:?}",
+        (x, y)
+    );
+    assert_eq!(state.characters[0].grapple, None);
+}
+
+#[test]
+fn harpoons_pull_the_hit_character_to_the_owner() {
+    let mut state = create_match(&[]);
+    throw(&mut state, HARPOON, (164, 212));
+
+    state.advance_frame().expect("frame advances");
+    assert_eq!(attached(&state), vec![(1, 0, (40, 216))]);
+
+    // Hitboxes only touch after 120 pixels, once the harpoon hits the owner's center
+    let (released, frames) = pull(&mut state, 30).expect("pull ends");
+    assert_eq!((released, frames), ((1, true), 15));
+    assert_eq!(position(&state, 1), (40, 208));
+    assert_eq!(position(&state, 0), (32, 208));
+}
+
+#[test]
+fn pulls_break_on_walls_time_limits_and_defeat() {
+    let mut state = create_match(&[6]);
+    throw(&mut state, HARPOON, (164, 212));
+    state.advance_frame().expect("frame advances");
+    assert_eq!(pull(&mut state, 30), Some(((1, false), 7)));
+    assert_eq!(position(&state, 1), (112, 208));
+
+    let mut state = create_match(&[]);
+    throw(&mut state, SHORT_HARPOON, (164, 212));
+    state.advance_frame().expect("frame advances");
+    assert_eq!(pull(&mut state, 30), Some(((1, false), 3)));
+    assert_eq!(position(&state, 1), (136, 208));
+
+    let mut state = create_match(&[]);
+    throw(&mut state, HARPOON, (164, 212));
+    state.advance_frame().expect("frame advances");
+    state.characters[0].health = 0;
+    assert_eq!(pull(&mut state, 30), Some(((1, false), 1)));
+    assert_eq!(position(&state, 1), (160, 208));
+}
+
+#[test]
+fn pulls_survive_snapshots() {
+    let mut state = create_match(&[]);
+    throw(&mut state, HARPOON, (164, 212));
+    state.advance_frame().expect("frame advances");
+    state.advance_frame().expect("frame advances");
+    assert!(state.characters[1].grapple.is_some());
+
+    let mut restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.characters[1].grapple, state.characters[1].grapple);
+    assert_eq!(restored.state_hash(), state.state_hash());
+    assert_eq!(pull(&mut restored, 30), pull(&mut state, 30));
+}
@@ -0,0 +1,67 @@
+//! Hazard tiles with an element and damage per second hurt the characters standing in them
+
+mod common;
+
+use common::{advance, Scenario};
+use robot_masters_engine::entity::Element;
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::tilemap::TileType;
+
+/// A character standing in a two-tile hazard pool on columns 2-3 of row 13
+fn create_match() -> GameState {
+    let mut map = [""; 15];
+    map[13] = "..aa";
+    map[14] = "################";
+    Scenario::new()
+        .map(&map)
+        .character(0, 40, 208)
+        .with(|character| {
+            character.health = 500;
+            character.health_cap = 500;
+        })
+        .build()
+}
+
+/// Health lost over `frames` frames
+fn health_lost(state: &mut GameState, frames: u32) -> u16 {
+    let before = state.characters[0].health;
+    advance(state, frames);
+    before - state.characters[0].health
+}
+
+#[test]
+fn hazards_deal_their_damage_per_second_after_armor() {
+    let mut state = create_match();
+    assert!(state.place_hazard(2, 13, Element::Heat, 160));
+    assert_eq!(state.characters[0].get_armor(Element::Heat), 100);
+
+    // 60 per second after armor, spread evenly over the frames of the second
+    assert_eq!(health_lost(&mut state, 30), 30);
+    assert_eq!(health_lost(&mut state, 60), 60);
+
+    // Only the strongest hazard touched applies
+    assert!(state.place_hazard(3, 13, Element::Acid, 130));
+    assert_eq!(health_lost(&mut state, 60), 60);
+    state.characters[0].set_armor(Element::Heat, 160);
+    assert_eq!(health_lost(&mut state, 60), 30);
+}
+
+#[test]
+fn hazards_need_hazard_tiles() {
+    let mut state = create_match();
+    assert!(!state.place_hazard(4, 13, Element::Heat, 160));
+    assert!(!state.place_hazard(2, 20, Element::Heat, 160));
+    assert!(state.place_hazard(2, 13, Element::Heat, 160));
+
+    // Replacing the tile turns the hazard off
+    state.tile_map.set_tile(2, 13, TileType::Empty);
+    assert_eq!(health_lost(&mut state, 60), 0);
+}
+
+#[test]
+fn hazards_survive_snapshots() {
+    let mut state = create_match();
+    state.place_hazard(2, 13, Element::Acid, 300);
+    let restored = GameState::from_bytes(&state.to_bytes()).unwrap();
+    assert_eq!(restored.hazards, state.hazards);
+}
@@ -0,0 +1,50 @@
+//! Health property representations across scripts
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::entity::ActionDefinition;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+fn create_match(health: u16, script: &str) -> GameState {
+    Scenario::new()
+        .character(0, 32, 208)
+        .with(|character| {
+            character.health = health;
+            character.health_cap = health;
+        })
+        .action(ActionDefinition::new(0, 0, compile(script)))
+        .build()
+}
+
+/// Read health as both Fixed and byte, then persist them in the action instance
+fn read_health_script() -> &'static str {
+    "READ_PROP v0 CHARACTER_HEALTH; READ_PROP v0 CHARACTER_HEALTH_BYTE; EXIT 1"
+}
+
+#[test]
+fn health_reads_saturate_instead_of_wrapping() {
+    let mut state = create_match(2000, read_health_script());
+    state.execute_action(0, 0).expect("action runs");
+
+    let instance = &state.action_instances[0];
+    assert_eq!(instance.runtime_fixed[0], Fixed::from_int(1023));
+    assert_eq!(instance.runtime_vars[0], u8::MAX);
+
+    let mut state = create_match(100, read_health_script());
+    state.execute_action(0, 0).expect("action runs");
+
+    let instance = &state.action_instances[0];
+    assert_eq!(instance.runtime_fixed[0], Fixed::from_int(100));
+    assert_eq!(instance.runtime_vars[0], 100);
+}
+
+#[test]
+fn byte_health_writes_set_health() {
+    let script = "ASSIGN_BYTE v0 42; WRITE_PROP CHARACTER_HEALTH_BYTE v0; EXIT 1";
+    let mut state = create_match(2000, script);
+    state.execute_action(0, 0).expect("action runs");
+
+    assert_eq!(state.characters[0].health, 42);
+}
@@ -0,0 +1,80 @@
+//! Instance views list the script VM state of each character without touching storage
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::entity::StatusEffectDefinition;
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::status::apply_status_effect;
+
+/// Character 0 runs a behavior that locks itself in; character 1's behavior never passes
+/// and it carries a 30 frame status effect
+fn create_match() -> GameState {
+    let mut state = Scenario::new()
+        .character(0, 32, 208)
+        .behavior(
+            "ASSIGN_BYTE v1 9; EXIT 1",
+            "LOCK_ACTION; ASSIGN_BYTE v2 4; EXIT 1",
+        )
+        .character(1, 160, 208)
+        .behavior("EXIT 0", "EXIT 1")
+        .status_effect(StatusEffectDefinition::new(
+            30,
+            1,
+            false,
+            100,
+            vec![],
+            vec![],
+            vec![],
+        ))
+        .build();
+
+    let mut character = state.characters[1].clone();
+    assert!(apply_status_effect(&mut character, &mut state, 0).unwrap());
+    state.characters[1] = character;
+
+    state.advance_frame().expect("frame advances");
+    state
+}
+
+#[test]
+fn action_instances_report_their_variables_and_lock() {
+    let state = create_match();
+    let actions: Vec<_> = state.action_instances_of(0).collect();
+    assert_eq!(actions.len(), 1);
+    assert_eq!(actions[0].instance.definition_id, 0);
+    assert_eq!(actions[0].instance.runtime_vars[2], 4);
+    assert!(actions[0].locked);
+    assert!(actions[0].definition.is_some());
+
+    let active = state.active_action_of(0).expect("character 0 is locked in");
+    assert_eq!(active.id, actions[0].id);
+
+    // Character 1's condition never passed, so its action never ran
+    assert_eq!(state.action_instances_of(1).count(), 0);
+    assert!(state.active_action_of(1).is_none());
+}
+
+#[test]
+fn condition_instances_keep_their_runtime_variables() {
+    let state = create_match();
+    let conditions: Vec<_> = state.condition_instances_of(0).collect();
+    assert_eq!(conditions.len(), 1);
+    assert_eq!(conditions[0].instance.runtime_vars[1], 9);
+
+    let conditions: Vec<_> = state.condition_instances_of(1).collect();
+    assert_eq!(conditions.len(), 1);
+    assert_eq!(conditions[0].instance.definition_id, 1);
+}
+
+#[test]
+fn status_effects_are_listed_per_character() {
+    let state = create_match();
+    let effects: Vec<_> = state.status_effect_instances_of(1).collect();
+    assert_eq!(effects.len(), 1);
+    assert_eq!(effects[0].instance.stack_count, 1);
+    assert_eq!(effects[0].definition.map(|def| def.duration), Some(30));
+
+    assert_eq!(state.status_effect_instances_of(0).count(), 0);
+    assert_eq!(state.status_effect_instances_of(9).count(), 0);
+}
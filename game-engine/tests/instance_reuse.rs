@@ -0,0 +1,64 @@
+//! Each character keeps one instance per action and condition, so script variables persist
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::api::advance_frames;
+use robot_masters_engine::state::GameState;
+
+/// Load the instance counter from `counter`, add one, and exit with 1
+///
+/// Working variables start at zero on every run; the instance keeps them afterwards.
+fn counter_script(counter: &str) -> String {
+    format!("READ_PROP v0 {counter}; ASSIGN_BYTE v1 1; ADD_BYTE v0 v0 v1; EXIT 1")
+}
+
+/// Two characters whose only behavior is a counting condition and a counting action
+fn create_match() -> GameState {
+    Scenario::new()
+        .character(0, 32, 208)
+        .behavior(
+            &counter_script("CONDITION_INST_VAR0"),
+            &counter_script("ACTION_INST_VAR0"),
+        )
+        .character(1, 96, 208)
+        .with(|character| character.behaviors = vec![(0, 0)])
+        .build()
+}
+
+#[test]
+fn action_variables_persist_per_character() {
+    let mut state = create_match();
+    state.characters[0].behaviors.clear();
+    state.characters[1].behaviors.clear();
+
+    for _ in 0..3 {
+        state.execute_action(0, 0).expect("action runs");
+    }
+    state.execute_action(1, 0).expect("action runs");
+
+    assert_eq!(state.action_instances.len(), 2);
+    assert_eq!(state.action_instances[0].character_id, 0);
+    assert_eq!(state.action_instances[0].runtime_vars[0], 3);
+    assert_eq!(state.action_instances[1].character_id, 1);
+    assert_eq!(state.action_instances[1].runtime_vars[0], 1);
+}
+
+#[test]
+fn instances_do_not_grow_over_a_match() {
+    let mut state = create_match();
+    advance_frames(&mut state, 30).expect("frames advance");
+
+    assert_eq!(state.action_instances.len(), 2);
+    assert_eq!(state.condition_instances.len(), 2);
+    for instance in &state.action_instances {
+        assert_eq!(instance.runtime_vars[0], 30);
+    }
+    for instance in &state.condition_instances {
+        assert_eq!(instance.runtime_vars[0], 30);
+    }
+
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.action_instances[1].character_id, 1);
+    assert_eq!(restored.state_hash(), state.state_hash());
+}
@@ -0,0 +1,89 @@
+//! Knockback pushes characters hit by spawns away and holds their behaviors in hitstun
+
+mod common;
+
+use common::{advance, Scenario};
+use robot_masters_engine::entity::{Element, SpawnDefinition, SpawnInstance};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Character 1 stands at x 64 with an action that stops it every frame; character 0 owns
+/// a spawn definition pushing at 4 pixels per frame with 10 frames of hitstun
+fn create_match() -> GameState {
+    let mut blast = SpawnDefinition::from_def(Vec::new());
+    blast.knockback = Fixed::from_int(4);
+    blast.hitstun = 10;
+    Scenario::new()
+        .character(0, 200, 208)
+        .character(1, 64, 208)
+        .behavior(
+            "EXIT 1",
+            "ASSIGN_FIXED f0 0 0; WRITE_PROP CHARACTER_VEL_X v0; EXIT 1",
+        )
+        .spawn(blast)
+        .build()
+}
+
+/// Hit character 1 from its left with a spawn of the given element
+fn hit(state: &mut GameState, element: Element) {
+    let pos = (Fixed::from_int(62), Fixed::from_int(212));
+    let mut spawn = SpawnInstance::with_element(0, 0, pos, element);
+    spawn.core.size = (4, 4);
+    spawn.core.dir.1 = 1;
+    spawn.life_span = 100;
+    state.insert_spawn(spawn).unwrap();
+    state.advance_frame().expect("frame advances");
+}
+
+#[test]
+fn hits_push_away_from_the_spawn_and_up() {
+    let mut state = create_match();
+    hit(&mut state, Element::Punct);
+    let character = &state.characters[1];
+    assert_eq!(
+        character.core.vel,
+        (Fixed::from_int(4), Fixed::from_int(-2))
+    );
+    assert_eq!(character.hitstun, 10);
+}
+
+#[test]
+fn weight_and_force_scale_the_push() {
+    let mut state = create_match();
+    state.characters[1].weight = 200;
+    hit(&mut state, Element::Punct);
+    assert_eq!(state.characters[1].core.vel.0, Fixed::from_int(2));
+
+    let mut state = create_match();
+    hit(&mut state, Element::Force);
+    assert_eq!(state.characters[1].core.vel.0, Fixed::from_int(6));
+
+    // Featherweights are capped
+    let mut state = create_match();
+    state.characters[1].weight = 1;
+    hit(&mut state, Element::Force);
+    assert_eq!(state.characters[1].core.vel.0, Fixed::from_int(12));
+}
+
+#[test]
+fn hitstun_suspends_behaviors() {
+    let mut state = create_match();
+    hit(&mut state, Element::Punct);
+    advance(&mut state, 10);
+    assert_eq!(state.characters[1].hitstun, 0);
+    assert_eq!(state.characters[1].core.vel.0, Fixed::from_int(4));
+
+    // The stop action runs again once hitstun is over
+    advance(&mut state, 1);
+    assert_eq!(state.characters[1].core.vel.0, Fixed::ZERO);
+}
+
+#[test]
+fn hitstun_survives_snapshots() {
+    let mut state = create_match();
+    hit(&mut state, Element::Punct);
+    let restored = GameState::from_bytes(&state.to_bytes()).unwrap();
+    assert_eq!(restored.characters[1].hitstun, 10);
+    assert_eq!(restored.spawn_definitions[0].knockback, Fixed::from_int(4));
+    assert_eq!(restored.state_hash(), state.state_hash());
+}
@@ -0,0 +1,84 @@
+//! Spawns roll their launch variance from a dedicated stream of the match seed
+
+mod common;
+
+use common::{exec, Scenario};
+use robot_masters_engine::entity::SpawnDefinition;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+const VOLLEY: &str = "ASSIGN_BYTE v0 0
+    SPAWN v0; SPAWN v0; SPAWN v0; SPAWN v0; SPAWN v0; SPAWN v0; SPAWN v0; SPAWN v0";
+
+/// Spawn 0 is a 20 damage, 60 frame bullet with the given variance
+fn create_match(
+    seed: u16,
+    spread: Fixed,
+    duration_variance: u16,
+    damage_variance: u16,
+) -> GameState {
+    let mut bullet = SpawnDefinition::from_def(Vec::new());
+    bullet.damage_base = 20;
+    bullet.duration = 60;
+    bullet.velocity_spread = spread;
+    bullet.duration_variance = duration_variance;
+    bullet.damage_variance = damage_variance;
+    Scenario::new()
+        .seed(seed)
+        .character(0, 32, 208)
+        .spawn(bullet)
+        .build()
+}
+
+/// (damage, life span, velocity) of each spawn launched by a volley
+fn volley(state: &mut GameState) -> Vec<(u16, u16, (Fixed, Fixed))> {
+    exec(state, 0, VOLLEY);
+    state
+        .spawn_instances()
+        .iter()
+        .map(|spawn| (spawn.damage, spawn.life_span, spawn.core.vel))
+        .collect()
+}
+
+#[test]
+fn definitions_without_variance_launch_exactly() {
+    let mut state = create_match(7, Fixed::ZERO, 0, 0);
+    let launched = volley(&mut state);
+    assert_eq!(launched.len(), 8);
+    assert!(launched
+        .iter()
+        .all(|&launch| launch == (20, 60, (Fixed::ZERO, Fixed::ZERO))));
+}
+
+#[test]
+fn variance_stays_within_the_definition_bounds() {
+    let spread = Fixed::from_raw(8);
+    let mut state = create_match(7, spread, 10, 5);
+    let launched = volley(&mut state);
+
+    for &(damage, life_span, (vel_x, vel_y)) in &launched {
+        assert!((15..=25).contains(&damage));
+        assert!((50..=70).contains(&life_span));
+        assert!(vel_x.raw().abs() <= spread.raw() && vel_y.raw().abs() <= spread.raw());
+    }
+    // The volley spreads out instead of repeating one roll
+    assert!(launched.iter().any(|&launch| launch != launched[0]));
+}
+
+#[test]
+fn launch_rolls_are_deterministic_per_seed() {
+    let roll = |seed| volley(&mut create_match(seed, Fixed::from_raw(8), 10, 5));
+    assert_eq!(roll(7), roll(7));
+    assert_ne!(roll(7), roll(8));
+}
+
+#[test]
+fn launch_rolls_leave_the_script_random_stream_alone() {
+    let mut plain = create_match(7, Fixed::ZERO, 0, 0);
+    let mut jittered = create_match(7, Fixed::from_raw(8), 10, 5);
+    volley(&mut plain);
+    volley(&mut jittered);
+    for _ in 0..4 {
+        assert_eq!(plain.next_random(), jittered.next_random());
+    }
+}
@@ -0,0 +1,80 @@
+//! Tilemap raycasts and the HAS_LINE_OF_SIGHT operator for "shoot only when visible" logic
+
+mod common;
+
+use common::{compile, exec, Scenario};
+use robot_masters_engine::constants::operator_address as op;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::script;
+use robot_masters_engine::tilemap::{RayHit, TileType, Tilemap};
+
+fn point(x: i16, y: i16) -> (Fixed, Fixed) {
+    (Fixed::from_int(x), Fixed::from_int(y))
+}
+
+#[test]
+fn rays_stop_at_the_first_solid_tile() {
+    let mut map = Tilemap::empty();
+    map.set_tile(8, 12, TileType::Block);
+    map.set_tile(0, 3, TileType::Block);
+
+    assert_eq!(
+        map.raycast(point(40, 200), point(200, 200)),
+        Some(RayHit {
+            tile: (8, 12),
+            point: point(128, 200),
+        })
+    );
+    assert_eq!(map.raycast(point(40, 150), point(200, 170)), None);
+    assert_eq!(
+        map.raycast(point(8, 8), point(8, 100)).map(|hit| hit.point),
+        Some(point(8, 48))
+    );
+
+    // Moving left, a ray ending on a tile's left edge stays inside that tile
+    map.set_tile(1, 0, TileType::Block);
+    assert_eq!(map.raycast(point(40, 8), point(32, 8)), None);
+    assert_eq!(
+        map.raycast(point(40, 8), point(31, 8)).map(|hit| hit.point),
+        Some(point(32, 8))
+    );
+}
+
+#[test]
+fn rays_cannot_slip_between_diagonal_blocks() {
+    let mut map = Tilemap::empty();
+    map.set_tile(1, 0, TileType::Block);
+    map.set_tile(0, 1, TileType::Block);
+    assert_eq!(
+        map.raycast(point(8, 8), point(24, 24)),
+        Some(RayHit {
+            tile: (1, 0),
+            point: point(16, 16),
+        })
+    );
+}
+
+#[test]
+fn scripts_check_line_of_sight_to_an_entity() {
+    let source = "ASSIGN_BYTE v1 1; ASSIGN_BYTE v2 1; HAS_LINE_OF_SIGHT v0 v1 v2";
+    let mut state = Scenario::new()
+        .wall(8)
+        .character(0, 32, 192)
+        .character(1, 192, 192)
+        .build();
+    assert_eq!(exec(&mut state, 0, source).vars[0], 0);
+
+    state.tile_map.set_tile(8, 12, TileType::Empty);
+    assert_eq!(exec(&mut state, 0, source).vars[0], 1);
+
+    // Missing entities are never visible
+    let missing = "ASSIGN_BYTE v1 1; ASSIGN_BYTE v2 7; HAS_LINE_OF_SIGHT v0 v1 v2";
+    assert_eq!(exec(&mut state, 0, missing).vars[0], 0);
+
+    let bytes = compile(source);
+    assert!(script::validate(&bytes).is_ok());
+    assert_eq!(
+        script::disassemble(&[op::HAS_LINE_OF_SIGHT, 0, 1, 2]),
+        "0000: HasLineOfSight v0, v1, v2"
+    );
+}
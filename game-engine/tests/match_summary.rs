@@ -0,0 +1,65 @@
+//! Match summaries report the outcome without per-frame data
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::api::advance_frames;
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::state::GameState;
+
+/// Three grounded characters with baseline armor (100): ids 0 and 1 in group 0, id 2 in group 1
+fn create_match() -> GameState {
+    let mut state = Scenario::new()
+        .seed(17)
+        .character(0, 32, 208)
+        .character(0, 96, 208)
+        .character(1, 160, 208)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build();
+    state.frame_limit = 30;
+    state
+}
+
+/// Drop a spawn owned by `owner` onto `target`
+fn fire_at(state: &mut GameState, owner: u8, target: usize, damage: u16) {
+    let pos = state.characters[target].core.pos;
+    let mut spawn = SpawnInstance::new(0, owner, pos);
+    spawn.core.size = (4, 4);
+    spawn.life_span = 100;
+    spawn.damage = damage;
+    state.insert_spawn(spawn).expect("spawn fits");
+}
+
+#[test]
+fn last_surviving_group_wins() {
+    let mut state = create_match();
+    fire_at(&mut state, 0, 2, 200);
+    fire_at(&mut state, 2, 1, 130);
+    advance_frames(&mut state, 100).expect("frames advance");
+
+    let summary = state.match_summary();
+    assert!(summary.ended);
+    assert_eq!(summary.frames, 30);
+    assert_eq!(summary.winner_group, Some(0));
+    assert_eq!(summary.survivors, vec![0, 1]);
+    assert_eq!(summary.spawns_created, 2);
+    assert_eq!(summary.characters[2].health, 0);
+    assert_eq!(summary.characters[1].health, 70);
+    assert_eq!(summary.characters[0].damage_dealt, 100);
+    assert_eq!(summary.state_hash, state.state_hash());
+}
+
+#[test]
+fn surviving_groups_are_ranked_by_remaining_health() {
+    let mut state = create_match();
+    state.characters[1].health = 0;
+    state.characters[0].health = 60;
+    state.characters[2].health = 60;
+    assert_eq!(state.match_summary().winner_group, None);
+
+    state.characters[2].health = 61;
+    let summary = state.match_summary();
+    assert!(!summary.ended);
+    assert_eq!(summary.winner_group, Some(1));
+    assert_eq!(summary.survivors, vec![0, 2]);
+}
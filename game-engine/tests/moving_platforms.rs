@@ -0,0 +1,84 @@
+//! Platform spawns carry the characters standing on them and move by script or velocity
+
+mod common;
+
+use common::{advance, compile, Scenario};
+use robot_masters_engine::constants::terrain_contact;
+use robot_masters_engine::entity::SpawnDefinition;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// A 32x8 platform definition steered by `script`
+fn platform(script: &str) -> SpawnDefinition {
+    let mut definition = SpawnDefinition::from_def(Vec::new());
+    definition.platform = true;
+    definition.size = (32, 8);
+    definition.damage_base = 10;
+    definition.behavior_script = compile(script);
+    definition
+}
+
+/// A match with one character dropped at (72, 100) and a platform at (64, 160)
+fn create_match(script: &str) -> GameState {
+    let mut state = Scenario::new()
+        .character(0, 72, 100)
+        .spawn(platform(script))
+        .build();
+    state
+        .place_platform(0, (Fixed::from_int(64), Fixed::from_int(160)))
+        .expect("platform definition");
+    state
+}
+
+/// Character position relative to the platform
+fn offset(state: &GameState) -> (Fixed, Fixed) {
+    let (character, platform) = (&state.characters[0].core, &state.spawn_instances()[0].core);
+    (
+        character.pos.0.sub(platform.pos.0),
+        character.pos.1.sub(platform.pos.1),
+    )
+}
+
+#[test]
+fn characters_land_on_and_ride_platforms() {
+    let mut state = create_match("EXIT 0");
+    advance(&mut state, 40);
+    assert_eq!(offset(&state), (Fixed::from_int(8), Fixed::from_int(-16)));
+    assert!(state.characters[0].has_terrain_contact(terrain_contact::PLATFORM));
+    assert!(state.characters[0].core.collision.2);
+
+    // Moving sideways and up, the rider keeps its place on top
+    state.spawn_instances_mut()[0].core.vel = (Fixed::from_int(1), Fixed::from_raw(-16));
+    advance(&mut state, 30);
+    assert_eq!(state.spawn_instances()[0].core.pos.0, Fixed::from_int(94));
+    assert_eq!(state.spawn_instances()[0].core.pos.1, Fixed::from_int(145));
+    assert_eq!(offset(&state), (Fixed::from_int(8), Fixed::from_int(-16)));
+
+    // Platforms never hit the characters they carry
+    assert_eq!(state.characters[0].health, state.characters[0].health_cap);
+}
+
+#[test]
+fn platform_scripts_steer_the_platform() {
+    let mut state = create_match("ASSIGN_FIXED f0 2 0; WRITE_PROP SPAWN_VEL_X v0; EXIT 0");
+    advance(&mut state, 40);
+    let x = state.spawn_instances()[0].core.pos.0;
+    advance(&mut state, 10);
+    assert_eq!(
+        state.spawn_instances()[0].core.pos.0,
+        x.add(Fixed::from_int(20))
+    );
+}
+
+#[test]
+fn platforms_need_a_platform_definition() {
+    let mut state = Scenario::new()
+        .character(0, 72, 100)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build();
+    assert_eq!(
+        state.place_platform(0, (Fixed::from_int(64), Fixed::from_int(160))),
+        None
+    );
+    assert_eq!(state.place_platform(1, (Fixed::ZERO, Fixed::ZERO)), None);
+}
@@ -0,0 +1,68 @@
+//! Scripts written for older opcode table versions migrate to the current table
+
+use robot_masters_engine::constants::operator_address as op;
+use robot_masters_engine::core::OPCODE_VERSION;
+use robot_masters_engine::script::{
+    migrate, migrate_with, operator_version, BytecodeProblem, OpcodeAlias, ScriptError,
+};
+
+fn problem_at(result: Result<Vec<u8>, ScriptError>) -> (u16, BytecodeProblem) {
+    match result {
+        Err(ScriptError::InvalidBytecode { offset, problem }) => (offset, problem),
+        other => panic!("expected a bytecode error, got {:?}", other),
+    }
+}
+
+#[test]
+fn every_operator_belongs_to_a_table_version() {
+    for &(name, opcode) in op::ALL {
+        let version = operator_version(opcode).unwrap_or_else(|| panic!("{name} has no version"));
+        assert!((1..=OPCODE_VERSION).contains(&version), "{name}");
+    }
+    assert_eq!(operator_version(op::ADD), Some(1));
+    assert_eq!(operator_version(op::ROLL_DAMAGE), Some(2));
+    assert_eq!(operator_version(255), None);
+}
+
+#[test]
+fn scripts_may_only_use_operators_of_their_version() {
+    let script = [op::ASSIGN_BYTE, 0, 90, op::SIN, 0, 0, op::EXIT, 1];
+    assert_eq!(migrate(&script, OPCODE_VERSION), Ok(script.to_vec()));
+    assert_eq!(
+        problem_at(migrate(&script, 1)),
+        (
+            3,
+            BytecodeProblem::UnavailableOperator {
+                opcode: op::SIN,
+                version: 1
+            }
+        )
+    );
+    assert_eq!(
+        problem_at(migrate(&[op::EXIT], 1)),
+        (0, BytecodeProblem::Truncated)
+    );
+}
+
+#[test]
+fn retired_operators_are_rewritten_until_their_removal() {
+    let aliases = [OpcodeAlias {
+        opcode: 200,
+        replacement: op::ADD,
+        removed_in: 2,
+    }];
+    // The retired opcode takes the replacement's operands, so jump targets stay put
+    let script = [op::SKIP, 4, 200, 0, 1, 2, op::EXIT, 1];
+    assert_eq!(
+        migrate_with(&script, 1, &aliases),
+        Ok(vec![op::SKIP, 4, op::ADD, 0, 1, 2, op::EXIT, 1])
+    );
+    assert_eq!(
+        problem_at(migrate_with(&script, 2, &aliases)),
+        (2, BytecodeProblem::UnknownOperator { opcode: 200 })
+    );
+    assert_eq!(
+        problem_at(migrate_with(&[200, 0, 1], 1, &aliases)),
+        (0, BytecodeProblem::Truncated)
+    );
+}
@@ -0,0 +1,159 @@
+//! Grounded pathfinding and the PATH_DIRECTION_TO operator for walkers that must get around terrain
+
+mod common;
+
+use common::{compile, exec, Scenario};
+use robot_masters_engine::constants::{operator_address as op, path_step};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::pathfinding::JumpReach;
+use robot_masters_engine::script;
+use robot_masters_engine::tilemap::Tilemap;
+
+/// Reach of the default character: 5 px jump force, 3 px move speed, 1/2 px gravity
+const REACH: JumpReach = JumpReach {
+    height: 1,
+    distance: 3,
+};
+
+/// Reach of a 7 px jump force under the same gravity
+const STRONG: JumpReach = JumpReach {
+    height: 2,
+    distance: 4,
+};
+
+/// Tilemap of a text map with a floor on the bottom row
+fn tilemap(rows: &[&str]) -> Tilemap {
+    let mut map = [""; 15];
+    map[..rows.len()].copy_from_slice(rows);
+    map[14] = "################";
+    Scenario::new().map(&map).build().tile_map
+}
+
+#[test]
+fn jump_reach_follows_jump_force_and_gravity() {
+    let (force, speed) = (Fixed::from_int(5), Fixed::from_int(3));
+    assert_eq!(JumpReach::of(force, speed, Fixed::from_frac(1, 2)), REACH);
+    assert_eq!(
+        JumpReach::of(Fixed::from_int(7), speed, Fixed::from_frac(1, 2)),
+        STRONG
+    );
+    assert_eq!(
+        JumpReach::of(force, speed, Fixed::from_int(5)),
+        JumpReach::NONE
+    );
+}
+
+#[test]
+fn paths_walk_on_open_ground() {
+    let map = tilemap(&[]);
+    assert_eq!(map.path_step((3, 13), (10, 13), 1, REACH), path_step::RIGHT);
+    assert_eq!(map.path_step((3, 13), (0, 13), 1, REACH), path_step::LEFT);
+    assert_eq!(
+        map.path_step((3, 13), (3, 13), 1, REACH),
+        path_step::ARRIVED
+    );
+
+    // Airborne ends fall onto the floor first
+    assert_eq!(map.path_step((3, 2), (3, 9), 1, REACH), path_step::ARRIVED);
+}
+
+#[test]
+fn paths_jump_walls_within_reach() {
+    let mut rows = [""; 14];
+    rows[13] = "....#";
+    let map = tilemap(&rows);
+    assert_eq!(
+        map.path_step((3, 13), (7, 13), 1, REACH),
+        path_step::JUMP_RIGHT
+    );
+    assert_eq!(
+        map.path_step((5, 13), (1, 13), 1, REACH),
+        path_step::JUMP_LEFT
+    );
+
+    // A wall two tiles tall needs a stronger jump
+    rows[12] = "....#";
+    let map = tilemap(&rows);
+    assert_eq!(
+        map.path_step((3, 13), (7, 13), 1, REACH),
+        path_step::NO_PATH
+    );
+    assert_eq!(
+        map.path_step((3, 13), (7, 13), 1, STRONG),
+        path_step::JUMP_RIGHT
+    );
+
+    // Taller bodies do not fit under a block over the wall
+    rows[9] = "....#";
+    let map = tilemap(&rows);
+    assert_eq!(
+        map.path_step((3, 13), (4, 11), 1, STRONG),
+        path_step::JUMP_RIGHT
+    );
+    assert_eq!(
+        map.path_step((3, 13), (4, 11), 3, STRONG),
+        path_step::NO_PATH
+    );
+}
+
+#[test]
+fn paths_cross_gaps_and_drop_off_ledges() {
+    // Ledges on row 8 with a two-tile gap on columns 5-6
+    let mut rows = [""; 9];
+    rows[8] = "#####..#########";
+    let map = tilemap(&rows);
+    assert_eq!(
+        map.path_step((4, 7), (10, 7), 1, REACH),
+        path_step::JUMP_RIGHT
+    );
+    assert_eq!(
+        map.path_step((4, 7), (10, 7), 1, JumpReach::NONE),
+        path_step::NO_PATH
+    );
+
+    // Dropping into the gap is a walk, but the ledges are out of reach from the floor
+    assert_eq!(map.path_step((4, 7), (5, 13), 1, REACH), path_step::RIGHT);
+    assert_eq!(map.path_step((5, 13), (4, 7), 1, REACH), path_step::NO_PATH);
+
+    // Platforms above are reached straight up
+    let mut rows = [""; 13];
+    rows[12] = "...8";
+    let map = tilemap(&rows);
+    assert_eq!(
+        map.path_step((3, 13), (3, 11), 1, STRONG),
+        path_step::JUMP_UP
+    );
+    assert_eq!(
+        map.path_step((3, 13), (3, 11), 1, REACH),
+        path_step::NO_PATH
+    );
+}
+
+#[test]
+fn scripts_ask_for_the_next_move_toward_an_entity() {
+    let mut map = [""; 15];
+    map[13] = "....#";
+    map[14] = "################";
+    let source = "ASSIGN_BYTE v1 1; ASSIGN_BYTE v2 1; PATH_DIRECTION_TO v0 v1 v2";
+    let mut state = Scenario::new()
+        .map(&map)
+        .character(0, 48, 208)
+        .character(1, 160, 208)
+        .build();
+    assert_eq!(exec(&mut state, 0, source).vars[0], path_step::JUMP_RIGHT);
+    assert_eq!(exec(&mut state, 1, source).vars[0], path_step::ARRIVED);
+
+    // Weaker jumps cannot clear the block
+    state.characters[0].jump_force = Fixed::from_int(2);
+    assert_eq!(exec(&mut state, 0, source).vars[0], path_step::NO_PATH);
+
+    let missing = "ASSIGN_BYTE v1 2; ASSIGN_BYTE v2 9; PATH_DIRECTION_TO v0 v1 v2";
+    assert_eq!(exec(&mut state, 0, missing).vars[0], path_step::NO_PATH);
+
+    let bytes = compile(source);
+    assert!(script::validate(&bytes).is_ok());
+    assert_eq!(
+        script::disassemble(&[op::PATH_DIRECTION_TO, 0, 1, 2]),
+        "0000: PathDirectionTo v0, v1, v2"
+    );
+}
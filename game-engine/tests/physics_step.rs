@@ -0,0 +1,48 @@
+//! The per-frame physics phases drive gravity, movement and script-visible collision flags
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// One character dropped above a solid floor; it walks right only while grounded
+fn create_match() -> GameState {
+    Scenario::new()
+        .seed(5)
+        .character(0, 32, 64)
+        .behavior(
+            "READ_PROP v0 CHARACTER_COLLISION_BOTTOM; EXIT_WITH_VAR v0",
+            "ASSIGN_FIXED f0 1 1; WRITE_PROP CHARACTER_VEL_X v0; EXIT 1",
+        )
+        .build()
+}
+
+#[test]
+fn gravity_lands_character_on_floor() {
+    let mut state = create_match();
+    for _ in 0..120 {
+        state.advance_frame().expect("frame advances");
+    }
+
+    let core = &state.characters[0].core;
+    assert_eq!(core.pos.1, Fixed::from_int(14 * 16 - 16));
+    assert_eq!(core.vel.1, Fixed::ZERO);
+    assert!(core.collision.2, "bottom collision flag set on the floor");
+}
+
+#[test]
+fn scripts_see_bottom_collision_flag() {
+    let mut state = create_match();
+    let start_x = state.characters[0].core.pos.0;
+
+    // Falling: the grounded condition fails, so the character does not walk
+    state.advance_frame().expect("frame advances");
+    assert!(!state.characters[0].core.collision.2);
+    assert_eq!(state.characters[0].core.pos.0, start_x);
+
+    for _ in 0..120 {
+        state.advance_frame().expect("frame advances");
+    }
+    assert!(state.characters[0].core.pos.0 > start_x);
+}
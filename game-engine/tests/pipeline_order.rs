@@ -0,0 +1,52 @@
+//! Frame pipeline ordering assumptions that determinism depends on
+
+use robot_masters_engine::pipeline::{EntityOrder, FramePhase, FRAME_PIPELINE};
+
+fn position(phase: FramePhase) -> usize {
+    FRAME_PIPELINE
+        .iter()
+        .position(|&candidate| candidate == phase)
+        .expect("phase is part of the pipeline")
+}
+
+#[test]
+fn phases_run_status_behaviors_physics_collisions_cleanup() {
+    let order = [
+        FramePhase::StatusEffects,
+        FramePhase::Behaviors,
+        FramePhase::Gravity,
+        FramePhase::Movement,
+        FramePhase::SpawnCollisions,
+        FramePhase::SpawnHits,
+        FramePhase::Cleanup,
+    ];
+    for pair in order.windows(2) {
+        assert!(
+            position(pair[0]) < position(pair[1]),
+            "{} must run before {}",
+            pair[0].name(),
+            pair[1].name()
+        );
+    }
+
+    // Scripts read collision flags computed earlier in the same frame
+    assert!(position(FramePhase::CollisionFlags) < position(FramePhase::Behaviors));
+}
+
+#[test]
+fn phases_are_unique_and_described() {
+    for (index, phase) in FRAME_PIPELINE.iter().enumerate() {
+        assert_eq!(position(*phase), index, "{} runs twice", phase.name());
+        assert_eq!(*phase as usize, index);
+        assert!(!phase.name().is_empty());
+    }
+
+    assert_eq!(
+        FramePhase::Behaviors.entity_order(),
+        EntityOrder::CharacterBehaviors
+    );
+    assert_eq!(
+        FramePhase::SpawnCollisions.entity_order(),
+        EntityOrder::SpawnPairs
+    );
+}
@@ -0,0 +1,84 @@
+//! Per-context property permission enforcement
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::api::game_loop;
+use robot_masters_engine::constants::{property_address, property_permission};
+use robot_masters_engine::script::{ScriptContextKind, ScriptEngine, ScriptError};
+use robot_masters_engine::state::{ConditionContext, GameState};
+
+/// Script writing 7 into the executing character's health, then exiting with 1
+fn write_health_script() -> &'static str {
+    "ASSIGN_FIXED f0 7 1; WRITE_PROP CHARACTER_HEALTH v0; EXIT 1"
+}
+
+fn create_match(condition_script: &str, action_script: &str) -> GameState {
+    Scenario::new()
+        .character(0, 32, 208)
+        .behavior(condition_script, action_script)
+        .build()
+}
+
+#[test]
+fn conditions_are_read_only() {
+    for address in [
+        property_address::GAME_GRAVITY,
+        property_address::CHARACTER_HEALTH,
+        property_address::ENTITY_DIR_HORIZONTAL,
+        property_address::SPAWN_INST_LIFE_SPAN,
+    ] {
+        assert!(!property_permission::is_writable(
+            ScriptContextKind::Condition,
+            address
+        ));
+        assert!(property_permission::is_writable(
+            ScriptContextKind::Action,
+            address
+        ));
+    }
+    assert!(!property_permission::is_writable(
+        ScriptContextKind::Spawn,
+        property_address::STATUS_EFFECT_INST_STACK_COUNT
+    ));
+}
+
+#[test]
+fn condition_writes_are_rejected() {
+    let mut state = create_match(write_health_script(), "EXIT 1");
+    let health = state.characters[0].health;
+
+    game_loop(&mut state).expect("frame advances");
+    assert_eq!(state.characters[0].health, health);
+
+    // The same write from an action goes through
+    let mut state = create_match("EXIT 1", write_health_script());
+    game_loop(&mut state).expect("frame advances");
+    assert_eq!(state.characters[0].health, 7);
+}
+
+#[test]
+fn condition_entity_writes_are_rejected() {
+    let script = "ASSIGN_FIXED f0 7 1; WRITE_CHARACTER_PROPERTY 0 CHARACTER_HEALTH r0; EXIT 1";
+    let mut state = create_match(script, "EXIT 1");
+    let health = state.characters[0].health;
+
+    game_loop(&mut state).expect("frame advances");
+    assert_eq!(state.characters[0].health, health);
+}
+
+#[test]
+fn strict_mode_reports_read_only_writes() {
+    let mut state = create_match(write_health_script(), "EXIT 1");
+    state.strict_mode = true;
+
+    let mut context = ConditionContext::new(&mut state, 0, 0, 0);
+    let mut engine = ScriptEngine::new();
+    assert_eq!(
+        engine.execute(&compile(write_health_script()), &mut context),
+        Err(ScriptError::ReadOnlyProperty {
+            property_address: property_address::CHARACTER_HEALTH
+        })
+    );
+    assert!(game_loop(&mut state).is_err());
+}
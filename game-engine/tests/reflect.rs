@@ -0,0 +1,89 @@
+//! Reflect status effects return part of incoming spawn damage to the attacker
+
+mod common;
+
+use common::{drop_spawn, Scenario};
+use robot_masters_engine::entity::{SpawnDefinition, StatusEffectDefinition};
+use robot_masters_engine::event::{GameEventKind, RemovalReason};
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::status::apply_status_effect;
+
+/// Two grounded characters without armor; status effect 0 reflects 25% per stack
+fn create_match() -> GameState {
+    let mut thorns = StatusEffectDefinition::new(600, 2, false, 100, vec![], vec![], vec![]);
+    thorns.reflect = 25;
+
+    Scenario::new()
+        .seed(3)
+        .unarmored_character(0, 32, 208)
+        .unarmored_character(1, 160, 208)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .status_effect(thorns)
+        .build()
+}
+
+fn add_thorns(state: &mut GameState, target: usize) {
+    let mut character = state.characters[target].clone();
+    apply_status_effect(&mut character, state, 0).expect("status effect applies");
+    state.characters[target] = character;
+}
+
+fn reflections(state: &GameState) -> Vec<(u8, u8, u16)> {
+    state
+        .events
+        .iter()
+        .filter_map(|event| match event.kind {
+            GameEventKind::DamageReflected {
+                character_id,
+                reflector_id,
+                amount,
+            } => Some((character_id, reflector_id, amount)),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn reflected_damage_is_attributed_to_the_reflector() {
+    let mut state = create_match();
+    add_thorns(&mut state, 1);
+    add_thorns(&mut state, 1);
+    assert_eq!(state.reflect_percent(1), 50);
+
+    drop_spawn(&mut state, 0, 1, 40);
+    assert_eq!(state.characters[1].health, 60);
+    assert_eq!(state.characters[0].health, 80);
+    assert_eq!(reflections(&state), vec![(0, 1, 20)]);
+    assert_eq!(state.characters[0].stats.damage_dealt, 40);
+    assert_eq!(state.characters[1].stats.damage_dealt, 20);
+}
+
+#[test]
+fn reflection_bounces_are_capped() {
+    let mut state = create_match();
+    for target in [0, 1, 1] {
+        add_thorns(&mut state, target);
+    }
+
+    // 80 in, then 40 back, 10 forward, 5 back and no further
+    drop_spawn(&mut state, 0, 1, 80);
+    assert_eq!(reflections(&state), vec![(0, 1, 40), (1, 0, 10), (0, 1, 5)]);
+    assert_eq!(state.characters[0].health, 55);
+    assert_eq!(state.characters[1].health, 10);
+}
+
+#[test]
+fn reflection_can_defeat_the_attacker() {
+    let mut state = create_match();
+    add_thorns(&mut state, 1);
+    add_thorns(&mut state, 1);
+    state.characters[0].health = 10;
+
+    drop_spawn(&mut state, 0, 1, 40);
+    assert_eq!(state.characters[0].health, 0);
+    assert!(state.events.iter().any(|event| event.kind
+        == GameEventKind::CharacterDefeated {
+            character_id: 0,
+            reason: RemovalReason::KilledBy(Some(1)),
+        }));
+}
@@ -0,0 +1,82 @@
+//! Removal events report why spawns and characters left the match
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::entity::SpawnInstance;
+use robot_masters_engine::event::{removal_reason, GameEventKind, RemovalReason};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::physics::BoundaryPolicy;
+use robot_masters_engine::state::GameState;
+
+/// Character 0 zeroes character 1's health every frame; character 1 idles
+fn create_match() -> GameState {
+    Scenario::new()
+        .seed(7)
+        .character(0, 32, 64)
+        .behavior(
+            "EXIT 1",
+            "ASSIGN_BYTE v0 0; WRITE_CHARACTER_PROPERTY 1 CHARACTER_HEALTH_BYTE r0; EXIT 1",
+        )
+        .character(1, 160, 64)
+        .build()
+}
+
+fn push_spawn(state: &mut GameState, pos: (Fixed, Fixed), life_span: u16) -> u8 {
+    let mut spawn = SpawnInstance::new(0, 0, pos);
+    spawn.core.size = (4, 4);
+    spawn.life_span = life_span;
+    state.insert_spawn(spawn).expect("spawn fits")
+}
+
+fn spawn_removal(state: &GameState, spawn_instance_id: u8) -> Option<RemovalReason> {
+    state.events.iter().find_map(|event| match event.kind {
+        GameEventKind::SpawnRemoved {
+            spawn_instance_id: id,
+            reason,
+            ..
+        } if id == spawn_instance_id => Some(reason),
+        _ => None,
+    })
+}
+
+#[test]
+fn defeat_credits_the_final_attacker() {
+    let mut state = create_match();
+    state.advance_frame().expect("frame advances");
+
+    let defeat = state.events.iter().find_map(|event| match event.kind {
+        GameEventKind::CharacterDefeated {
+            character_id,
+            reason,
+        } => Some((character_id, reason)),
+        _ => None,
+    });
+    assert_eq!(defeat, Some((1, RemovalReason::KilledBy(Some(0)))));
+    assert_eq!(
+        RemovalReason::KilledBy(Some(0)).code(),
+        removal_reason::KILLED
+    );
+}
+
+#[test]
+fn spawn_removals_report_expired_and_out_of_bounds() {
+    let mut state = create_match();
+    state.spawn_boundary = BoundaryPolicy::Despawn;
+
+    let inside = (Fixed::from_int(64), Fixed::from_int(64));
+    let outside = (Fixed::from_int(-200), Fixed::from_int(64));
+    let expired = push_spawn(&mut state, inside, 0);
+    let escaped = push_spawn(&mut state, outside, 100);
+    let alive = push_spawn(&mut state, inside, 100);
+
+    state.advance_frame().expect("frame advances");
+
+    assert_eq!(spawn_removal(&state, expired), Some(RemovalReason::Expired));
+    assert_eq!(
+        spawn_removal(&state, escaped),
+        Some(RemovalReason::OutOfBounds)
+    );
+    assert_eq!(spawn_removal(&state, alive), None);
+    assert_eq!(state.spawn_instances().len(), 1);
+}
@@ -0,0 +1,148 @@
+//! REPEAT runs a script body a bounded number of times
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::constants::operator_address as op;
+use robot_masters_engine::core::MAX_REPEAT_COUNT;
+use robot_masters_engine::entity::ActionDefinition;
+use robot_masters_engine::script::{self, ScriptEngine, ScriptError};
+use robot_masters_engine::state::{ActionContext, GameState};
+
+/// A match whose action 1 counts its own REPEAT of 2 iterations into var 3
+fn create_match() -> GameState {
+    let counted_twice = vec![
+        op::ASSIGN_BYTE,
+        5,
+        2,
+        op::REPEAT,
+        6,
+        5,
+        4,
+        op::ADD_BYTE,
+        3,
+        3,
+        7,
+    ];
+
+    Scenario::new()
+        .seed(9)
+        .character(0, 32, 208)
+        .action(ActionDefinition::new(0, 0, vec![op::EXIT, 1]))
+        .action(ActionDefinition::new(0, 0, counted_twice))
+        .build()
+}
+
+/// Run `script` for character 0 with var 7 set to 1; returns the byte variables
+fn run(state: &mut GameState, script: &[u8]) -> Result<[u8; 8], ScriptError> {
+    let mut prologue = vec![op::ASSIGN_BYTE, 7, 1];
+    prologue.extend_from_slice(script);
+    let mut engine = ScriptEngine::new();
+    engine.execute(&prologue, &mut ActionContext::new(state, 0, 0, 0))?;
+    Ok(engine.vars)
+}
+
+/// Set var 1 to `count`, then REPEAT `body` with the iteration in var 0
+fn repeat(count: u8, body: &[u8]) -> Vec<u8> {
+    let mut script = vec![
+        op::ASSIGN_BYTE,
+        1,
+        count,
+        op::REPEAT,
+        0,
+        1,
+        body.len() as u8,
+    ];
+    script.extend_from_slice(body);
+    script
+}
+
+#[test]
+fn bodies_run_count_times_with_the_iteration_index() {
+    let mut state = create_match();
+    // Sum of the iteration indices 0 + 1 + 2 + 3 into var 2
+    let vars = run(&mut state, &repeat(4, &[op::ADD_BYTE, 2, 2, 0])).unwrap();
+    assert_eq!((vars[0], vars[2]), (3, 6));
+
+    let vars = run(&mut state, &repeat(0, &[op::ADD_BYTE, 2, 2, 7])).unwrap();
+    assert_eq!(vars[2], 0, "a count of 0 skips the body");
+
+    // Nested loops: 3 outer iterations of a 4 iteration inner loop
+    let inner = [
+        op::ASSIGN_BYTE,
+        4,
+        4,
+        op::REPEAT,
+        5,
+        4,
+        4,
+        op::ADD_BYTE,
+        2,
+        2,
+        7,
+    ];
+    let vars = run(&mut state, &repeat(3, &inner)).unwrap();
+    assert_eq!(vars[2], 12);
+}
+
+#[test]
+fn counts_are_capped() {
+    let body = [op::ADD_BYTE, 2, 2, 7];
+    let mut state = create_match();
+    let vars = run(&mut state, &repeat(200, &body)).unwrap();
+    assert_eq!(vars[2], MAX_REPEAT_COUNT);
+
+    state.strict_mode = true;
+    assert_eq!(
+        run(&mut state, &repeat(200, &body)),
+        Err(ScriptError::RepeatLimit { count: 200 })
+    );
+    assert!(run(&mut state, &repeat(MAX_REPEAT_COUNT, &body)).is_ok());
+}
+
+#[test]
+fn jumps_and_exits_leave_the_loop() {
+    let mut state = create_match();
+    // A SKIP to the end of the body still runs the next iteration
+    let vars = run(
+        &mut state,
+        &repeat(5, &[op::SKIP, 4, op::ADD_BYTE, 2, 2, 7]),
+    )
+    .unwrap();
+    assert_eq!((vars[0], vars[2]), (4, 0));
+
+    // A GOTO past the end of the body ends the loop (the prologue shifts offsets by 3)
+    let mut script = repeat(5, &[op::ADD_BYTE, 2, 2, 7, op::GOTO, 0]);
+    let past_end = script.len() as u8 + 3 + 4;
+    *script.last_mut().unwrap() = past_end;
+    script.extend_from_slice(&[op::ADD_BYTE, 4, 4, 7, op::ADD_BYTE, 3, 3, 7]);
+    let vars = run(&mut state, &script).unwrap();
+    assert_eq!((vars[2], vars[3], vars[4]), (1, 1, 0));
+
+    let mut engine = ScriptEngine::new();
+    let script = repeat(5, &[op::EXIT, 1]);
+    let exit = engine.execute(&script, &mut ActionContext::new(&mut state, 0, 0, 0));
+    assert_eq!((exit, engine.vars[0]), (Ok(1), 0));
+}
+
+#[test]
+fn subroutines_keep_their_own_loops() {
+    let mut state = create_match();
+    // Each of 3 outer iterations calls action 1, which counts 2 into var 3
+    let vars = run(&mut state, &repeat(3, &[op::CALL, 1])).unwrap();
+    assert_eq!((vars[0], vars[3]), (2, 6));
+}
+
+#[test]
+fn bodies_must_end_on_an_instruction() {
+    let script = repeat(2, &[op::ADD_BYTE, 2, 2, 7]);
+    assert!(script::validate(&script).is_ok());
+
+    let mut truncated = script.clone();
+    truncated[6] = 2;
+    assert!(script::validate(&truncated).is_err());
+
+    let listing = script::disassemble(&script);
+    assert!(listing.contains("0011"), "{}", listing);
+    assert_eq!(script::assemble(&listing), Ok(script));
+}
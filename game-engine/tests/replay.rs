@@ -0,0 +1,98 @@
+//! Recorded matches re-simulate to the same per-frame checksums
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::api::GameError;
+use robot_masters_engine::entity::{ActionDefinition, ConditionDefinition};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::replay::Replay;
+use robot_masters_engine::state::GameState;
+
+/// Characters jump sideways on random frames
+fn create_match() -> GameState {
+    // Both characters share behavior 0: a random condition and a sideways push with a
+    // five frame cooldown
+    let condition =
+        ConditionDefinition::new(Fixed::ONE, compile("ASSIGN_RANDOM v0; EXIT_WITH_VAR v0"));
+    let action = ActionDefinition::new(
+        0,
+        5,
+        compile("ASSIGN_FIXED f0 3 1; WRITE_PROP CHARACTER_VEL_X v0; EXIT 1"),
+    );
+    Scenario::new()
+        .seed(2024)
+        .character(0, 32, 64)
+        .behavior_with(condition, action)
+        .character(1, 160, 64)
+        .with(|character| character.behaviors = vec![(0, 0)])
+        .build()
+}
+
+/// Record 60 frames one at a time and 90 more in bulk
+fn record_match() -> (GameState, Replay) {
+    let mut state = create_match();
+    let mut replay = Replay::new(&state);
+    for _ in 0..60 {
+        replay.advance_frame(&mut state).expect("frame advances");
+    }
+    replay
+        .advance_frames(&mut state, 90)
+        .expect("frames advance");
+    (state, replay)
+}
+
+#[test]
+fn shared_replay_verifies_and_reaches_the_live_state() {
+    let (live, replay) = record_match();
+    assert_eq!(replay.frame_count(), 150);
+
+    let shared = Replay::from_bytes(&replay.to_bytes()).expect("replay decodes");
+    assert_eq!(shared, replay);
+
+    let outcome = GameState::verify_replay(&shared).expect("replay re-simulates");
+    assert!(outcome.is_verified());
+    assert_eq!(outcome.frames_verified, 150);
+    assert_eq!(outcome.state.frame, live.frame);
+    assert_eq!(outcome.state.state_hash(), live.state_hash());
+}
+
+#[test]
+fn tampered_checksum_reports_first_desync() {
+    let (_, mut replay) = record_match();
+    let original = replay.checksums[41];
+    replay.checksums[41] ^= 1;
+    replay.checksums[100] ^= 1;
+
+    let outcome = GameState::verify_replay(&replay).expect("replay re-simulates");
+    assert!(!outcome.is_verified());
+    assert_eq!(outcome.frames_verified, 41);
+    let desync = outcome.desync.expect("desync reported");
+    assert_eq!(desync.frame, 42);
+    assert_eq!(desync.actual, original);
+    assert_eq!(desync.expected, original ^ 1);
+}
+
+#[test]
+fn malformed_replays_are_rejected() {
+    let bytes = record_match().1.to_bytes();
+
+    assert_eq!(
+        Replay::from_bytes(&bytes[..bytes.len() - 1]).err(),
+        Some(GameError::InvalidInput)
+    );
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        Replay::from_bytes(&trailing).err(),
+        Some(GameError::InvalidInput)
+    );
+
+    let mut foreign = bytes;
+    foreign[0] ^= 0xFF;
+    assert_eq!(
+        Replay::from_bytes(&foreign).err(),
+        Some(GameError::InvalidInput)
+    );
+}
@@ -0,0 +1,96 @@
+//! Defeated characters stay idle and revive once the match's respawn delay runs out
+
+mod common;
+
+use common::{advance, drop_spawn, Scenario};
+use robot_masters_engine::entity::SpawnDefinition;
+use robot_masters_engine::event::GameEventKind;
+use robot_masters_engine::state::{GameState, GameStatus};
+use robot_masters_engine::victory::VictoryCondition;
+
+/// Two armorless characters; the first one acts every frame
+fn create_match(respawn_delay: u16) -> GameState {
+    let mut state = Scenario::new()
+        .unarmored_character(0, 32, 208)
+        .behavior("EXIT 1", "EXIT 1")
+        .unarmored_character(1, 160, 208)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build();
+    state.respawn_delay = respawn_delay;
+    state
+}
+
+/// Drop a lethal spawn owned by `owner` onto `target` and run one frame
+fn defeat(state: &mut GameState, owner: u8, target: usize) {
+    drop_spawn(state, owner, target, 500);
+    assert_eq!(state.characters[target].health, 0);
+}
+
+fn acted(state: &GameState, character_id: u8) -> bool {
+    state.events.iter().any(|event| {
+        matches!(event.kind, GameEventKind::ActionExecuted { character_id: id, .. } if id == character_id)
+    })
+}
+
+#[test]
+fn defeated_characters_idle_until_they_revive() {
+    let mut state = create_match(30);
+    advance(&mut state, 1);
+    assert!(acted(&state, 0));
+
+    defeat(&mut state, 1, 0);
+    assert_eq!(state.characters[0].respawn_timer, 30);
+
+    advance(&mut state, 29);
+    assert!(!acted(&state, 0));
+    assert_eq!(state.characters[0].health, 0);
+    assert_eq!(state.characters[0].respawn_timer, 1);
+
+    advance(&mut state, 1);
+    let character = &state.characters[0];
+    assert_eq!(character.health, character.health_cap);
+    assert_eq!(character.energy, character.energy_cap);
+    assert!(state
+        .events
+        .iter()
+        .any(|event| event.kind == GameEventKind::CharacterRevived { character_id: 0 }));
+
+    advance(&mut state, 1);
+    assert!(acted(&state, 0));
+}
+
+#[test]
+fn characters_stay_down_without_a_respawn_delay() {
+    let mut state = create_match(0);
+    defeat(&mut state, 1, 0);
+    advance(&mut state, 120);
+    assert_eq!(state.characters[0].health, 0);
+    assert_eq!(state.characters[0].respawn_timer, 0);
+}
+
+#[test]
+fn groups_waiting_to_revive_are_not_eliminated() {
+    let mut state = create_match(10);
+    state.victory = VictoryCondition::LastTeamStanding;
+
+    defeat(&mut state, 0, 1);
+    assert_eq!(state.status, GameStatus::Playing);
+    let result = state.match_result();
+    assert_eq!(result.standings[1].survivors, 0);
+    assert_eq!(result.standings[1].respawning, 1);
+
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.respawn_delay, 10);
+    assert_eq!(restored.characters[1].respawn_timer, 10);
+    assert_eq!(restored.state_hash(), state.state_hash());
+
+    advance(&mut state, 10);
+    assert_eq!(state.status, GameStatus::Playing);
+    assert_eq!(state.match_result().standings[1].survivors, 1);
+
+    // Without a delay the same defeat ends the match
+    let mut state = create_match(0);
+    state.victory = VictoryCondition::LastTeamStanding;
+    defeat(&mut state, 0, 1);
+    assert_eq!(state.status, GameStatus::Ended);
+}
@@ -0,0 +1,93 @@
+//! Reward rolls are a pure function of the state hash and follow the table's weights
+
+use robot_masters_engine::api::GameError;
+use robot_masters_engine::reward::{roll_rewards, RewardDrop, RewardEntry, RewardTable};
+
+/// Three entries weighted 70 / 25 / 5 with different quantity ranges
+fn create_table(rolls: u8) -> RewardTable {
+    let entry = |item_id, weight, min_quantity, max_quantity| RewardEntry {
+        item_id,
+        weight,
+        min_quantity,
+        max_quantity,
+    };
+    RewardTable {
+        rolls,
+        entries: vec![entry(1, 70, 1, 3), entry(2, 25, 2, 2), entry(3, 5, 0, 9)],
+    }
+}
+
+#[test]
+fn same_hash_rolls_the_same_drops() {
+    let table = create_table(8);
+    for hash in [0, 1, 0xDEAD_BEEF, u32::MAX] {
+        let drops = roll_rewards(hash, &table).expect("valid table");
+        assert_eq!(drops.len(), 8);
+        assert_eq!(roll_rewards(hash, &table), Ok(drops));
+    }
+}
+
+#[test]
+fn every_hash_bit_changes_the_drops() {
+    // Hashes whose halves XOR to the same value used to share one seed
+    let table = create_table(16);
+    let base = roll_rewards(0x1234_5678, &table).unwrap();
+    let twin = roll_rewards(0x1234_5678 ^ 0x0001_0001, &table).unwrap();
+    assert_ne!(base, twin);
+
+    for bit in 0..32 {
+        let flipped = roll_rewards(0x1234_5678 ^ (1 << bit), &table).unwrap();
+        assert_ne!(base, flipped, "bit {}", bit);
+    }
+}
+
+#[test]
+fn drops_follow_the_table_weights() {
+    let table = create_table(1);
+    let mut picks = [0u32; 3];
+    let mut quantities = [[false; 10]; 3];
+    let samples = 20_000u32;
+    for hash in (0..samples).map(|n| n.wrapping_mul(0x9E37_79B9)) {
+        let drops: Vec<RewardDrop> = roll_rewards(hash, &table).unwrap();
+        let drop = &drops[0];
+        let entry = &table.entries[drop.item_id as usize - 1];
+        assert!((entry.min_quantity..=entry.max_quantity).contains(&drop.quantity));
+        picks[drop.item_id as usize - 1] += 1;
+        quantities[drop.item_id as usize - 1][drop.quantity as usize] = true;
+    }
+
+    // Within 1.5 percentage points of the 70 / 25 / 5 split
+    for (count, weight) in picks.iter().zip([70u32, 25, 5]) {
+        let expected = samples * weight / 100;
+        assert!(
+            count.abs_diff(expected) < samples * 15 / 1000,
+            "{:?}",
+            picks
+        );
+    }
+    // Every quantity in each range comes up
+    assert_eq!(
+        quantities[0],
+        [false, true, true, true, false, false, false, false, false, false]
+    );
+    assert!(quantities[2].iter().all(|&seen| seen));
+}
+
+#[test]
+fn invalid_tables_are_rejected() {
+    let mut table = create_table(1);
+    for entry in &mut table.entries {
+        entry.weight = 0;
+    }
+    assert_eq!(roll_rewards(7, &table), Err(GameError::InvalidInput));
+
+    let mut table = create_table(1);
+    table.entries[0].min_quantity = 4;
+    assert_eq!(roll_rewards(7, &table), Err(GameError::InvalidInput));
+
+    let table = RewardTable {
+        rolls: 1,
+        entries: Vec::new(),
+    };
+    assert_eq!(roll_rewards(7, &table), Err(GameError::InvalidInput));
+}
@@ -0,0 +1,100 @@
+//! Byte snapshots resume a match with bit-identical results
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::api::{advance_frames, GameError};
+use robot_masters_engine::entity::{ActionDefinition, ConditionDefinition, SpawnInstance};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::{GameState, SNAPSHOT_VERSION};
+
+/// Characters jump sideways on random frames while a spawn drifts across the arena
+fn create_match(seed: u16) -> GameState {
+    let condition =
+        ConditionDefinition::new(Fixed::ONE, compile("ASSIGN_RANDOM v0; EXIT_WITH_VAR v0"));
+    let action = ActionDefinition::new(
+        0,
+        5,
+        compile("ASSIGN_FIXED f0 3 1; WRITE_PROP CHARACTER_VEL_X v0; EXIT 1"),
+    );
+    let mut state = Scenario::new()
+        .seed(seed)
+        .character(0, 32, 64)
+        .behavior_with(condition, action)
+        .character(1, 160, 64)
+        .with(|character| character.behaviors = vec![(0, 0)])
+        .build();
+
+    let mut spawn = SpawnInstance::new(0, 0, (Fixed::from_int(16), Fixed::from_int(32)));
+    spawn.core.size = (4, 4);
+    spawn.core.vel = (Fixed::ONE, Fixed::ZERO);
+    spawn.life_span = 400;
+    state.insert_spawn(spawn).expect("spawn fits");
+    state
+}
+
+#[test]
+fn resumed_match_matches_continuous_run() {
+    let mut continuous = create_match(4242);
+    let mut first_half = create_match(4242);
+
+    // Draw mid-frame so the saved RNG position is not at a frame boundary
+    for state in [&mut continuous, &mut first_half] {
+        advance_frames(state, 150).expect("frames advance");
+        state.next_random();
+    }
+
+    let bytes = first_half.to_bytes();
+    let mut resumed = GameState::from_bytes(&bytes).expect("snapshot restores");
+    assert_eq!(resumed.state_hash(), first_half.state_hash());
+    assert_eq!(resumed.to_bytes(), bytes);
+
+    advance_frames(&mut resumed, 300).expect("frames advance");
+    advance_frames(&mut continuous, 300).expect("frames advance");
+    assert_eq!(resumed.frame, continuous.frame);
+    assert_eq!(resumed.state_hash(), continuous.state_hash());
+}
+
+#[test]
+fn corrupted_snapshots_are_rejected() {
+    let bytes = create_match(7).to_bytes();
+
+    let truncated = &bytes[..bytes.len() - 1];
+    assert_eq!(
+        GameState::from_bytes(truncated).err(),
+        Some(GameError::InvalidGameState)
+    );
+
+    let mut trailing = bytes.clone();
+    trailing.push(0);
+    assert_eq!(
+        GameState::from_bytes(&trailing).err(),
+        Some(GameError::InvalidGameState)
+    );
+
+    let mut foreign = bytes;
+    foreign[0] ^= 0xFF;
+    assert_eq!(
+        GameState::from_bytes(&foreign).err(),
+        Some(GameError::InvalidGameState)
+    );
+}
+
+#[test]
+fn snapshots_from_other_layout_versions_are_rejected() {
+    let mut bytes = create_match(7).to_bytes();
+    assert_eq!(bytes[4], SNAPSHOT_VERSION);
+
+    // The version byte follows the four magic bytes
+    bytes[4] = SNAPSHOT_VERSION - 1;
+    assert_eq!(
+        GameState::from_bytes(&bytes).err(),
+        Some(GameError::InvalidGameState)
+    );
+
+    bytes[4] = SNAPSHOT_VERSION + 1;
+    assert_eq!(
+        GameState::from_bytes(&bytes).err(),
+        Some(GameError::InvalidGameState)
+    );
+}
@@ -0,0 +1,88 @@
+//! Per-character script quotas keep one runaway character from starving the rest
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::api::GameError;
+use robot_masters_engine::entity::{
+    ActionDefinition, ConditionDefinition, ScriptQuota, SpawnDefinition,
+};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Two grounded characters running the given behaviors, each with its own quota
+/// Condition 0 loops forever, condition 1 always passes; action 0 requests three spawns,
+/// action 1 writes energy twice
+fn create_match(behaviors: [(usize, usize); 2], quotas: [ScriptQuota; 2]) -> GameState {
+    let condition = |source| ConditionDefinition::new(Fixed::ONE, compile(source));
+    let action = |source| ActionDefinition::new(0, 0, compile(source));
+    Scenario::new()
+        .seed(5)
+        .character(0, 32, 208)
+        .with(|character| {
+            character.behaviors = vec![behaviors[0]];
+            character.quota = quotas[0];
+        })
+        .character(1, 160, 208)
+        .with(|character| {
+            character.behaviors = vec![behaviors[1]];
+            character.quota = quotas[1];
+        })
+        .condition(condition("GOTO 0"))
+        .condition(condition("EXIT 1"))
+        .action(action("SPAWN v0; SPAWN v0; SPAWN v0; EXIT 1"))
+        .action(action(
+            "ASSIGN_BYTE v0 10; WRITE_PROP CHARACTER_ENERGY v0
+            ASSIGN_BYTE v0 20; WRITE_PROP CHARACTER_ENERGY v0; EXIT 1",
+        ))
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build()
+}
+
+#[test]
+fn runaway_script_halts_at_instruction_quota() {
+    let looping = ScriptQuota {
+        max_instructions: 100,
+        ..ScriptQuota::default()
+    };
+    let mut state = create_match([(0, 0), (1, 1)], [looping, ScriptQuota::default()]);
+
+    for _ in 0..3 {
+        state.advance_frame().expect("frame advances");
+        assert_eq!(state.characters[0].script_usage.instructions, 100);
+    }
+    // The looping character never acts; its neighbour keeps running its behavior
+    assert!(state.spawn_instances().is_empty());
+    assert_eq!(state.characters[1].energy, 20);
+}
+
+#[test]
+fn spawns_and_writes_beyond_quota_are_skipped() {
+    let limited = ScriptQuota {
+        max_spawns: 2,
+        max_property_writes: 1,
+        ..ScriptQuota::default()
+    };
+    let mut state = create_match([(1, 0), (1, 1)], [limited, limited]);
+    state.advance_frame().expect("frame advances");
+
+    assert_eq!(state.spawn_instances().len(), 2);
+    assert_eq!(state.characters[0].script_usage.spawns, 2);
+    assert_eq!(state.characters[1].energy, 10);
+    assert_eq!(state.characters[1].script_usage.property_writes, 1);
+}
+
+#[test]
+fn strict_mode_reports_exceeded_quota() {
+    let looping = ScriptQuota {
+        max_instructions: 100,
+        ..ScriptQuota::default()
+    };
+    let mut state = create_match([(0, 0), (1, 1)], [looping, ScriptQuota::default()]);
+    state.strict_mode = true;
+
+    assert_eq!(
+        state.advance_frame().err(),
+        Some(GameError::ScriptExecutionError)
+    );
+}
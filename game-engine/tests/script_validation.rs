@@ -0,0 +1,125 @@
+//! Static script validation reports the offset of the first malformed instruction
+
+use robot_masters_engine::constants::{operator_address as op, property_address as prop};
+use robot_masters_engine::script::{
+    validate, validate_for, BytecodeProblem, ScriptContextKind, ScriptError, ScriptInfo,
+};
+
+fn problem_at(script: &[u8]) -> (u16, BytecodeProblem) {
+    match validate(script) {
+        Err(ScriptError::InvalidBytecode { offset, problem }) => (offset, problem),
+        other => panic!("expected a bytecode error, got {:?}", other),
+    }
+}
+
+#[test]
+fn well_formed_scripts_are_summarized() {
+    // Loop: move right, spawn, and jump back to the start until grounded
+    let script = [
+        op::READ_PROP,
+        8,
+        prop::CHARACTER_MOVE_SPEED,
+        op::WRITE_PROP,
+        prop::CHARACTER_VEL_X,
+        8,
+        op::READ_SPAWN,
+        0,
+        0,
+        op::SPAWN,
+        0,
+        op::EXIT_IF_NOT_GROUNDED,
+        1,
+        op::SKIP,
+        2,
+        op::GOTO,
+        0,
+        op::EXIT,
+        1,
+    ];
+
+    assert_eq!(
+        validate(&script),
+        Ok(ScriptInfo {
+            instructions: 8,
+            has_loops: true,
+            creates_spawns: true,
+            property_writes: vec![(3, prop::CHARACTER_VEL_X)],
+            constant_reads: vec![],
+        })
+    );
+    assert_eq!(validate(&[]), Ok(ScriptInfo::default()));
+}
+
+#[test]
+fn malformed_instructions_are_located() {
+    assert_eq!(
+        problem_at(&[op::EXIT, 0, 200]),
+        (2, BytecodeProblem::UnknownOperator { opcode: 200 })
+    );
+    assert_eq!(
+        problem_at(&[op::ASSIGN_BYTE, 0, 1, op::ADD_BYTE, 0, 1]),
+        (3, BytecodeProblem::Truncated)
+    );
+    assert_eq!(
+        problem_at(&[op::ASSIGN_BYTE, 8, 1]),
+        (0, BytecodeProblem::IndexOutOfRange { index: 8 })
+    );
+    assert_eq!(
+        problem_at(&[op::NEGATE, 4]),
+        (0, BytecodeProblem::IndexOutOfRange { index: 4 })
+    );
+    assert_eq!(
+        problem_at(&[op::READ_PROP, 0, 0x0F]),
+        (0, BytecodeProblem::UnknownProperty { address: 0x0F })
+    );
+    assert_eq!(
+        problem_at(&[op::READ_SPAWN_PROPERTY, 0, 0, prop::CHARACTER_HEALTH]),
+        (
+            0,
+            BytecodeProblem::IncompatibleProperty {
+                address: prop::CHARACTER_HEALTH
+            }
+        )
+    );
+}
+
+#[test]
+fn jumps_must_land_on_an_instruction() {
+    assert_eq!(
+        problem_at(&[op::GOTO, 2]),
+        (0, BytecodeProblem::JumpOutOfRange { target: 2 })
+    );
+    assert_eq!(
+        problem_at(&[op::SKIP, 3, op::EXIT, 1]),
+        (0, BytecodeProblem::JumpOutOfRange { target: 5 })
+    );
+    assert_eq!(
+        problem_at(&[op::SKIP, 1, op::EXIT, 1]),
+        (0, BytecodeProblem::JumpIntoInstruction { target: 3 })
+    );
+    // Skipping to the end of the script is an exit
+    assert!(validate(&[op::SKIP, 2, op::EXIT, 1]).is_ok());
+}
+
+#[test]
+fn writes_are_checked_against_the_context() {
+    let script = [
+        op::ASSIGN_BYTE,
+        0,
+        5,
+        op::WRITE_PROP,
+        prop::CHARACTER_ENERGY,
+        0,
+    ];
+
+    assert!(validate_for(&script, ScriptContextKind::Action).is_ok());
+    assert_eq!(
+        validate_for(&script, ScriptContextKind::Condition),
+        Err(ScriptError::InvalidBytecode {
+            offset: 3,
+            problem: BytecodeProblem::ReadOnlyProperty {
+                address: prop::CHARACTER_ENERGY
+            },
+        })
+    );
+}
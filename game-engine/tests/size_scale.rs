@@ -0,0 +1,98 @@
+//! Size status effects scale the character hitbox, anchored at the feet
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::api::advance_frames;
+use robot_masters_engine::entity::StatusEffectDefinition;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::status::apply_status_effect;
+
+const GROW: usize = 0;
+const SHRINK: usize = 1;
+
+/// One 16x16 character standing on the floor; GROW doubles, SHRINK halves per stack
+fn create_match(arena: Scenario) -> GameState {
+    let mut grow = StatusEffectDefinition::new(10, 1, false, 100, vec![], vec![], vec![]);
+    grow.scale = 200;
+    let mut shrink = StatusEffectDefinition::new(10, 2, false, 100, vec![], vec![], vec![]);
+    shrink.scale = 50;
+
+    arena
+        .seed(3)
+        .character(0, 64, 208)
+        .status_effect(grow)
+        .status_effect(shrink)
+        .build()
+}
+
+fn apply(state: &mut GameState, effect_id: usize) {
+    let mut character = state.characters[0].clone();
+    apply_status_effect(&mut character, state, effect_id).expect("status effect applies");
+    state.characters[0] = character;
+}
+
+fn feet(state: &GameState) -> (i32, i32) {
+    let core = &state.characters[0].core;
+    (
+        core.pos.0.to_int() + core.size.0 as i32 / 2,
+        core.pos.1.to_int() + core.size.1 as i32,
+    )
+}
+
+#[test]
+fn grow_keeps_the_feet_and_restores_on_expiry() {
+    let mut state = create_match(Scenario::new());
+    let before = feet(&state);
+
+    apply(&mut state, GROW);
+    assert_eq!(state.characters[0].core.size, (32, 32));
+    assert_eq!(state.characters[0].size_scale, 200);
+    assert_eq!(state.characters[0].unscaled_size(), (16, 16));
+    assert_eq!(feet(&state), before);
+
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.characters[0].base_size, Some((16, 16)));
+
+    advance_frames(&mut state, 10).expect("frames advance");
+    assert_eq!(state.characters[0].core.size, (16, 16));
+    assert_eq!(state.characters[0].size_scale, 100);
+    assert_eq!(state.characters[0].base_size, None);
+}
+
+#[test]
+fn shrink_stacks_multiply() {
+    let mut state = create_match(Scenario::new());
+    apply(&mut state, SHRINK);
+    assert_eq!(state.characters[0].core.size, (8, 8));
+    apply(&mut state, SHRINK);
+    assert_eq!(state.characters[0].core.size, (4, 4));
+    assert_eq!(state.characters[0].size_scale, 25);
+}
+
+#[test]
+fn growing_under_a_low_ceiling_waits_for_room() {
+    let mut map = vec![""; 15];
+    map[12] = "################";
+    map[14] = "################";
+    let mut state = create_match(Scenario::new().map(&map));
+
+    apply(&mut state, GROW);
+    assert_eq!(state.characters[0].core.size, (16, 16));
+    assert_eq!(state.characters[0].size_scale, 100);
+
+    state.tile_map.get_raw_tiles_mut()[12] = [0; 16];
+    advance_frames(&mut state, 1).expect("frames advance");
+    assert_eq!(state.characters[0].core.size, (32, 32));
+}
+
+#[test]
+fn growing_against_a_wall_shifts_away_from_it() {
+    let mut state = create_match(Scenario::new().wall(5));
+
+    apply(&mut state, GROW);
+    assert_eq!(state.characters[0].core.size, (32, 32));
+    assert_eq!(state.characters[0].core.pos.0, Fixed::from_int(48));
+    assert_eq!(feet(&state).1, 224);
+}
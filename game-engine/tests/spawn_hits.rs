@@ -0,0 +1,78 @@
+//! Spawns hit overlapping characters through their collision scripts
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::constants::hit_result;
+use robot_masters_engine::entity::{Element, SpawnDefinition, SpawnInstance};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Two grounded characters and one spawn definition with the given collision script
+fn create_match(collision_script: &str) -> GameState {
+    let mut bolt = SpawnDefinition::from_def(Vec::new());
+    bolt.collision_script = compile(collision_script);
+
+    Scenario::new()
+        .seed(9)
+        .character(0, 32, 208)
+        .with(|character| character.set_armor(Element::Punct, 5))
+        .character(1, 160, 208)
+        .with(|character| character.set_armor(Element::Punct, 5))
+        .spawn(bolt)
+        .build()
+}
+
+/// Place a Punct spawn owned by character 0 on top of `target`
+fn fire_at(state: &mut GameState, target: usize) -> u8 {
+    let pos = state.characters[target].core.pos;
+    let mut spawn = SpawnInstance::new(0, 0, pos);
+    spawn.core.size = (4, 4);
+    spawn.core.vel = (Fixed::from_int(2), Fixed::ZERO);
+    spawn.life_span = 100;
+    spawn.damage = 30;
+    state.insert_spawn(spawn).expect("spawn fits")
+}
+
+#[test]
+fn hit_applies_armor_reduced_damage_and_consumes_spawn() {
+    let mut state = create_match("");
+    fire_at(&mut state, 1);
+    state.advance_frame().expect("frame advances");
+
+    assert_eq!(state.characters[1].health, 75);
+    assert_eq!(state.characters[0].stats.damage_dealt, 25);
+    assert!(state.spawn_instances().is_empty());
+}
+
+#[test]
+fn spawns_never_hit_their_owner() {
+    let mut state = create_match("");
+    fire_at(&mut state, 0);
+    state.advance_frame().expect("frame advances");
+
+    assert_eq!(state.characters[0].health, 100);
+    assert_eq!(state.spawn_instances().len(), 1);
+}
+
+#[test]
+fn bounce_keeps_spawn_and_reverses_velocity() {
+    let mut state = create_match(&format!("EXIT {}", hit_result::BOUNCE));
+    let id = fire_at(&mut state, 1);
+    state.advance_frame().expect("frame advances");
+
+    assert_eq!(state.characters[1].health, 75);
+    let spawn = &state.spawn_instances()[state.spawn_index(id).expect("spawn survives")];
+    assert_eq!(spawn.core.vel.0, Fixed::from_int(-2));
+    assert_eq!(spawn.core.target_id, Some(1));
+}
+
+#[test]
+fn pass_skips_damage_and_removal() {
+    let mut state = create_match(&format!("EXIT {}", hit_result::PASS));
+    fire_at(&mut state, 1);
+    state.advance_frame().expect("frame advances");
+
+    assert_eq!(state.characters[1].health, 100);
+    assert_eq!(state.spawn_instances().len(), 1);
+}
@@ -0,0 +1,134 @@
+//! Spawns whose collision masks share a layer collide with spawns of other owners
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::event::{GameEventKind, RemovalReason};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Spawn definition on the given collision layers
+/// Its collision script records the definition's mask and a hit flag, then ends the spawn
+/// when `fragile`
+fn layered(collision_mask: u8, fragile: bool) -> SpawnDefinition {
+    let mut spawn = SpawnDefinition::from_def(Vec::new());
+    spawn.collision_mask = collision_mask;
+    let mut source = String::from(
+        "READ_PROP v0 SPAWN_DEF_COLLISION_MASK; WRITE_PROP SPAWN_INST_VAR0 v0
+        ASSIGN_BYTE v1 1; WRITE_PROP SPAWN_INST_VAR1 v1",
+    );
+    if fragile {
+        source.push_str("; ASSIGN_FIXED f2 0 0; WRITE_PROP SPAWN_INST_LIFE_SPAN v2");
+    }
+    spawn.collision_script = compile(&source);
+    spawn
+}
+
+/// Two characters far apart; spawn definitions: 0 interceptor (layers 0b011), 1 missile
+/// (layer 0b001), 2 decoy (layer 0b100), 3 ghost (no layers), 4 armored missile (layer 0b001,
+/// survives its collisions)
+fn create_match() -> GameState {
+    Scenario::new()
+        .character(0, 16, 208)
+        .character(1, 224, 208)
+        .spawn(layered(0b011, true))
+        .spawn(layered(0b001, true))
+        .spawn(layered(0b100, true))
+        .spawn(layered(0, true))
+        .spawn(layered(0b001, false))
+        .build()
+}
+
+/// Place a spawn of a definition, owned by a character, in the middle of the arena
+fn launch(state: &mut GameState, spawn_id: u8, owner_id: u8) -> u8 {
+    let mut spawn = SpawnInstance::new(
+        spawn_id,
+        owner_id,
+        (Fixed::from_int(120), Fixed::from_int(64)),
+    );
+    spawn.core.size = (8, 8);
+    spawn.life_span = 100;
+    state.insert_spawn(spawn).expect("spawn fits")
+}
+
+fn vars(state: &GameState, id: u8) -> [u8; 4] {
+    let index = state.spawn_index(id).expect("spawn alive");
+    state.spawn_instances()[index].runtime_vars
+}
+
+fn removal(state: &GameState, id: u8) -> Option<RemovalReason> {
+    state.events.iter().find_map(|event| match event.kind {
+        GameEventKind::SpawnRemoved {
+            spawn_instance_id,
+            reason,
+            ..
+        } if spawn_instance_id == id => Some(reason),
+        _ => None,
+    })
+}
+
+#[test]
+fn shared_layers_destroy_both_spawns() {
+    let mut state = create_match();
+    let interceptor = launch(&mut state, 0, 0);
+    let missile = launch(&mut state, 1, 1);
+    state.advance_frame().expect("frame advances");
+
+    assert!(state.spawn_instances().is_empty());
+    assert_eq!(
+        removal(&state, interceptor),
+        Some(RemovalReason::AbsorbedBy(missile))
+    );
+    assert_eq!(
+        removal(&state, missile),
+        Some(RemovalReason::AbsorbedBy(interceptor))
+    );
+}
+
+#[test]
+fn both_collision_scripts_run() {
+    let mut state = create_match();
+    let first = launch(&mut state, 4, 0);
+    let second = launch(&mut state, 4, 1);
+    state.advance_frame().expect("frame advances");
+
+    assert_eq!(vars(&state, first), [0b001, 1, 0, 0]);
+    assert_eq!(vars(&state, second), [0b001, 1, 0, 0]);
+}
+
+#[test]
+fn spawns_without_a_shared_layer_pass_through() {
+    let mut state = create_match();
+    launch(&mut state, 0, 0);
+    launch(&mut state, 2, 1);
+    launch(&mut state, 3, 1);
+    state.advance_frame().expect("frame advances");
+
+    assert_eq!(state.spawn_instances().len(), 3);
+    assert!(state
+        .spawn_instances()
+        .iter()
+        .all(|spawn| spawn.runtime_vars == [0; 4]));
+}
+
+#[test]
+fn spawns_of_the_same_owner_never_intercept_each_other() {
+    let mut state = create_match();
+    launch(&mut state, 0, 0);
+    launch(&mut state, 1, 0);
+    state.advance_frame().expect("frame advances");
+    assert_eq!(state.spawn_instances().len(), 2);
+
+    // A third spawn from the other owner collides with both
+    let missile = launch(&mut state, 4, 1);
+    state.advance_frame().expect("frame advances");
+    assert_eq!(
+        state
+            .spawn_instances()
+            .iter()
+            .map(|spawn| spawn.core.id)
+            .collect::<Vec<_>>(),
+        vec![missile]
+    );
+}
@@ -0,0 +1,133 @@
+//! Spawn storage is reserved once, capped at MAX_SPAWNS, and handles detect removed spawns
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::api::{advance_frames, GameError};
+use robot_masters_engine::core::MAX_SPAWNS;
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::event::GameEventKind;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// One character and a spawn definition, no scripts
+fn create_match() -> GameState {
+    Scenario::new()
+        .seed(3)
+        .character(0, 32, 208)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build()
+}
+
+/// A spawn owned by character 0, well away from it
+fn spawn() -> SpawnInstance {
+    let mut spawn = SpawnInstance::new(0, 0, (Fixed::from_int(160), Fixed::from_int(64)));
+    spawn.core.size = (4, 4);
+    spawn.life_span = 100;
+    spawn
+}
+
+#[test]
+fn spawn_churn_reuses_the_reserved_storage() {
+    let mut state = create_match();
+    let storage = state.spawn_instances().as_ptr();
+
+    for _ in 0..10 {
+        while state.has_spawn_capacity() {
+            state.insert_spawn(spawn()).expect("capacity left");
+        }
+        assert_eq!(state.spawn_instances().len(), MAX_SPAWNS);
+        assert_eq!(state.insert_spawn(spawn()), None);
+        state.despawn_owned_spawns(0, 0);
+
+        advance_frames(&mut state, 1).expect("frames advance");
+        assert!(state.spawn_instances().is_empty());
+    }
+
+    assert_eq!(state.spawn_instances().as_ptr(), storage);
+    assert_eq!(state.spawns_created, 10 * MAX_SPAWNS as u32);
+}
+
+#[test]
+fn handles_go_stale_when_the_spawn_is_removed() {
+    let mut state = create_match();
+    let removed = state.insert_spawn(spawn()).unwrap();
+    let kept = state.insert_spawn(spawn()).unwrap();
+    let removed_handle = state.spawn_handle(removed).unwrap();
+    let kept_handle = state.spawn_handle(kept).unwrap();
+    assert_eq!(state.resolve_spawn_handle(removed_handle), Some(0));
+
+    state.despawn_owned_spawn(0, removed);
+    advance_frames(&mut state, 1).expect("frames advance");
+    assert_eq!(state.resolve_spawn_handle(removed_handle), None);
+    assert_eq!(state.resolve_spawn_handle(kept_handle), Some(0));
+    assert_ne!(state.spawn_handle(removed), Some(removed_handle));
+
+    // Generations survive a snapshot, so stale handles stay stale after resuming
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.resolve_spawn_handle(removed_handle), None);
+    assert_eq!(restored.resolve_spawn_handle(kept_handle), Some(0));
+}
+
+#[test]
+fn exhausted_pools_drop_new_spawns_without_reusing_ids() {
+    let mut state = create_match();
+    let ids: Vec<u8> = (0..MAX_SPAWNS)
+        .map(|_| state.insert_spawn(spawn()).expect("capacity left"))
+        .collect();
+    let created = |state: &GameState| {
+        state
+            .events
+            .iter()
+            .filter(|event| matches!(event.kind, GameEventKind::SpawnCreated { .. }))
+            .count()
+    };
+    assert_eq!(created(&state), MAX_SPAWNS);
+
+    // A full pool takes no ID, counts no creation and reports nothing
+    assert!(!state.has_spawn_capacity());
+    assert_eq!(state.insert_spawn(spawn()), None);
+    assert_eq!(state.spawns_created, MAX_SPAWNS as u32);
+    assert_eq!(created(&state), MAX_SPAWNS);
+    let live: Vec<u8> = state.spawn_instances().iter().map(|s| s.core.id).collect();
+    assert_eq!(live, ids);
+
+    // Once a spawn leaves, the next one gets an ID no live spawn holds
+    state.despawn_owned_spawn(0, ids[10]);
+    advance_frames(&mut state, 1).expect("frames advance");
+    let live: Vec<u8> = state.spawn_instances().iter().map(|s| s.core.id).collect();
+    let id = state.insert_spawn(spawn()).expect("capacity left");
+    assert!(!live.contains(&id));
+    assert_eq!(state.spawn_instances().len(), MAX_SPAWNS);
+}
+
+#[test]
+fn restored_spawns_keep_their_ids_and_fit_the_pool() {
+    let mut state = create_match();
+    let with_id = |id: u8| {
+        let mut spawn = spawn();
+        spawn.core.id = id;
+        spawn
+    };
+    state
+        .restore_spawns(vec![with_id(9), with_id(3)])
+        .expect("distinct IDs");
+    let live: Vec<u8> = state.spawn_instances().iter().map(|s| s.core.id).collect();
+    assert_eq!(live, vec![9, 3]);
+
+    // New spawns skip the restored IDs
+    let ids: Vec<u8> = (0..4)
+        .map(|_| state.insert_spawn(spawn()).unwrap())
+        .collect();
+    assert_eq!(ids, vec![0, 1, 2, 4]);
+
+    assert_eq!(
+        state.restore_spawns(vec![with_id(5), with_id(5)]),
+        Err(GameError::InvalidGameState)
+    );
+    let too_many = (0..=MAX_SPAWNS as u8).map(with_id).collect();
+    assert_eq!(
+        state.restore_spawns(too_many),
+        Err(GameError::InvalidGameState)
+    );
+}
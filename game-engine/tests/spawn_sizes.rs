@@ -0,0 +1,72 @@
+//! Spawns created by scripts take their hitbox size from their definition
+
+mod common;
+
+use common::{advance, compile, exec, Scenario};
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance, StatusEffectDefinition};
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::status::apply_status_effect;
+
+/// Spawn 0 is a 6x10 shell; spawn 1 (4x4) launches a shell when it hits a character; status
+/// effect 0 launches a shell when applied
+fn create_match() -> GameState {
+    let mut shell = SpawnDefinition::from_def(Vec::new());
+    shell.size = (6, 10);
+    shell.duration = 100;
+    let mut cluster = SpawnDefinition::from_def(Vec::new());
+    cluster.size = (4, 4);
+    cluster.duration = 100;
+    cluster.collision_script = compile("ASSIGN_BYTE v0 0; SPAWN v0; EXIT 0");
+    let launcher = StatusEffectDefinition::new(
+        10,
+        1,
+        false,
+        100,
+        compile("ASSIGN_BYTE v0 0; SPAWN v0"),
+        Vec::new(),
+        Vec::new(),
+    );
+    Scenario::new()
+        .character(0, 32, 208)
+        .character(1, 160, 208)
+        .spawn(shell)
+        .spawn(cluster)
+        .status_effect(launcher)
+        .build()
+}
+
+fn sizes(state: &GameState) -> Vec<(u8, (u8, u8))> {
+    state
+        .spawn_instances()
+        .iter()
+        .map(|spawn| (spawn.spawn_id, spawn.core.size))
+        .collect()
+}
+
+#[test]
+fn action_spawns_use_the_definition_size() {
+    let mut state = create_match();
+    exec(&mut state, 0, "ASSIGN_BYTE v0 0; SPAWN v0");
+    assert_eq!(sizes(&state), vec![(0, (6, 10))]);
+}
+
+#[test]
+fn status_effect_spawns_use_the_definition_size() {
+    let mut state = create_match();
+    let mut character = state.characters[0].clone();
+    assert!(apply_status_effect(&mut character, &mut state, 0).unwrap());
+    state.characters[0] = character;
+    assert_eq!(sizes(&state), vec![(0, (6, 10))]);
+}
+
+#[test]
+fn spawn_script_spawns_use_the_definition_size() {
+    let mut state = create_match();
+    let mut cluster = SpawnInstance::new(1, 0, state.characters[1].core.pos);
+    cluster.core.size = (4, 4);
+    cluster.life_span = 100;
+    state.insert_spawn(cluster).expect("spawn fits");
+    advance(&mut state, 1);
+    // The cluster is used up by its hit
+    assert_eq!(sizes(&state), vec![(0, (6, 10))]);
+}
@@ -0,0 +1,85 @@
+//! Spawns whose physics inputs stop changing sleep through the physics passes, and the
+//! skipped work never changes the simulation
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::api::advance_frames;
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance, SpawnSleep};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::tilemap::TileType;
+
+/// One character and a spawn definition, no scripts
+fn create_match() -> GameState {
+    Scenario::new()
+        .seed(3)
+        .character(0, 32, 208)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build()
+}
+
+/// A spawn owned by character 0 at `pos`, falling when `gravity` is set
+fn spawn(pos: (i16, i16), vel: (i16, i16), gravity: bool) -> SpawnInstance {
+    let mut spawn = SpawnInstance::new(0, 0, (Fixed::from_int(pos.0), Fixed::from_int(pos.1)));
+    spawn.core.size = (8, 8);
+    spawn.core.vel = (Fixed::from_int(vel.0), Fixed::from_int(vel.1));
+    spawn.core.dir.1 = if gravity { 2 } else { 1 };
+    spawn.life_span = 500;
+    spawn
+}
+
+/// A hovering turret, a spawn dropped onto a ledge and a moving projectile
+fn populate(state: &mut GameState) {
+    state.tile_map.set_tile(8, 10, TileType::Block);
+    state.insert_spawn(spawn((160, 40), (0, 0), false)).unwrap();
+    state.insert_spawn(spawn((132, 100), (0, 0), true)).unwrap();
+    state.insert_spawn(spawn((16, 60), (1, 0), false)).unwrap();
+}
+
+#[test]
+fn sleeping_spawns_simulate_identically() {
+    let mut sleeping = create_match();
+    let mut awake = create_match();
+    populate(&mut sleeping);
+    populate(&mut awake);
+
+    for _ in 0..120 {
+        // Forgetting the rest state every frame runs every physics pass on every spawn
+        for spawn in awake.spawn_instances_mut() {
+            spawn.sleep = SpawnSleep::default();
+        }
+        advance_frames(&mut sleeping, 1).unwrap();
+        advance_frames(&mut awake, 1).unwrap();
+        assert_eq!(sleeping.to_bytes(), awake.to_bytes());
+    }
+
+    // The turret settled, the projectile never does
+    assert!(sleeping.spawn_instances()[0].is_resting());
+    assert!(!sleeping.spawn_instances()[2].is_resting());
+}
+
+#[test]
+fn changed_inputs_wake_a_resting_spawn() {
+    let mut state = create_match();
+    state.insert_spawn(spawn((160, 40), (0, 0), false)).unwrap();
+    advance_frames(&mut state, 2).unwrap();
+    assert!(state.spawn_instances()[0].is_resting());
+
+    state.spawn_instances_mut()[0].core.vel.0 = Fixed::from_int(1);
+    advance_frames(&mut state, 1).unwrap();
+    assert_eq!(state.spawn_instances()[0].core.pos.0, Fixed::from_int(161));
+    assert!(!state.spawn_instances()[0].is_resting());
+
+    state.spawn_instances_mut()[0].core.vel.0 = Fixed::ZERO;
+    advance_frames(&mut state, 1).unwrap();
+    assert!(state.spawn_instances()[0].is_resting());
+
+    // A tile change invalidates every rest state
+    state.tile_map.set_tile(0, 0, TileType::Block);
+    let rest = state.spawn_instances()[0].sleep.rest.unwrap();
+    advance_frames(&mut state, 1).unwrap();
+    let woken = state.spawn_instances()[0].sleep.rest.unwrap();
+    assert_ne!(woken.tiles, rest.tiles);
+    assert_eq!(woken.pos, rest.pos);
+}
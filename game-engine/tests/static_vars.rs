@@ -0,0 +1,64 @@
+//! Static variables shared by every instance of a definition, such as a global turret count
+
+mod common;
+
+use common::{compile, exec, Scenario};
+use robot_masters_engine::entity::{ActionDefinition, SpawnDefinition, SpawnInstance};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Adds 1 to `bank` every run
+fn counter(bank: &str) -> String {
+    format!("READ_PROP v0 {bank}; ASSIGN_BYTE v1 1; ADD_BYTE v0 v0 v1; WRITE_PROP {bank} v0")
+}
+
+/// Two characters sharing action 0, and a turret spawn definition counting its behavior runs
+fn create_match() -> GameState {
+    let mut turret = SpawnDefinition::from_def(Vec::new());
+    turret.behavior_script = compile(&counter("SPAWN_DEF_STATIC0"));
+    Scenario::new()
+        .character(0, 32, 100)
+        .character(1, 160, 100)
+        .action(ActionDefinition::new(0, 0, compile("EXIT 0")))
+        .spawn(turret)
+        .build()
+}
+
+#[test]
+fn characters_share_the_bank_of_an_action() {
+    let mut state = create_match();
+    assert!(state.static_vars.is_zeroed());
+
+    exec(&mut state, 0, &counter("ACTION_DEF_STATIC1"));
+    let engine = exec(&mut state, 1, &counter("ACTION_DEF_STATIC1"));
+    assert_eq!(engine.vars[0], 2);
+    assert_eq!(state.static_vars.actions[0], [0, 2, 0, 0]);
+}
+
+#[test]
+fn spawns_share_the_bank_of_their_definition() {
+    let mut state = create_match();
+    let turret = state.spawn_definitions[0].clone();
+    for x in [64, 96] {
+        let mut instance = SpawnInstance::new(0, 0, (Fixed::from_int(x), Fixed::from_int(40)));
+        for _ in 0..3 {
+            turret
+                .execute_behavior_script(&mut state, &mut instance, &mut Vec::new())
+                .unwrap();
+        }
+    }
+    assert_eq!(state.static_vars.spawns[0], [6, 0, 0, 0]);
+}
+
+#[test]
+fn banks_survive_snapshots_and_start_zeroed_in_new_matches() {
+    let mut state = create_match();
+    let untouched = state.state_hash();
+    exec(&mut state, 0, &counter("ACTION_DEF_STATIC3"));
+    assert_ne!(state.state_hash(), untouched);
+
+    let restored = GameState::from_bytes(&state.to_bytes()).unwrap();
+    assert_eq!(restored.static_vars, state.static_vars);
+    assert_eq!(restored.state_hash(), state.state_hash());
+    assert!(create_match().static_vars.is_zeroed());
+}
@@ -0,0 +1,112 @@
+//! Status effect bundles apply their child effects together and end them together
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::api::{advance_frames, GameError};
+use robot_masters_engine::entity::StatusEffectDefinition;
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::status::{apply_status_effect, remove_status_effect};
+
+const FROZEN: usize = 0;
+const SLOW: usize = 1;
+const VULNERABLE: usize = 2;
+
+fn effect(duration: u16, children: Vec<usize>) -> StatusEffectDefinition {
+    let exit = compile("EXIT 1");
+    let mut definition =
+        StatusEffectDefinition::new(duration, 1, false, 100, exit.clone(), exit.clone(), exit);
+    definition.children = children;
+    definition
+}
+
+/// One grounded character; "Frozen" (id 0) bundles "Slow" (1) and "Vulnerable" (2)
+fn create_match(definitions: Vec<StatusEffectDefinition>) -> Result<GameState, GameError> {
+    definitions
+        .into_iter()
+        .fold(
+            Scenario::new().seed(3).character(0, 64, 208),
+            Scenario::status_effect,
+        )
+        .try_build()
+}
+
+fn frozen_match() -> GameState {
+    create_match(vec![
+        effect(10, vec![SLOW, VULNERABLE]),
+        effect(300, Vec::new()),
+        effect(300, Vec::new()),
+    ])
+    .expect("valid bundle configuration")
+}
+
+fn apply(state: &mut GameState, effect_id: usize) {
+    let mut character = state.characters[0].clone();
+    assert!(apply_status_effect(&mut character, state, effect_id).unwrap());
+    state.characters[0] = character;
+}
+
+fn active_definitions(state: &GameState) -> Vec<usize> {
+    state.characters[0]
+        .status_effects
+        .iter()
+        .map(|&id| state.status_effect_instances[id as usize].definition_id)
+        .collect()
+}
+
+#[test]
+fn bundle_applies_linked_children() {
+    let mut state = frozen_match();
+    apply(&mut state, FROZEN);
+
+    assert_eq!(active_definitions(&state), vec![FROZEN, SLOW, VULNERABLE]);
+    let instances = &state.status_effect_instances;
+    assert_eq!(instances[0].parent, None);
+    assert_eq!(instances[1].parent, Some(0));
+    assert_eq!(instances[2].parent, Some(0));
+    // Children take the bundle's lifetime, not their own duration
+    assert_eq!(instances[1].life_span, 10);
+
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.status_effect_instances[2].parent, Some(0));
+    assert_eq!(
+        restored.status_effect_definitions[FROZEN].children,
+        vec![SLOW, VULNERABLE]
+    );
+}
+
+#[test]
+fn children_expire_with_their_bundle() {
+    let mut state = frozen_match();
+    apply(&mut state, FROZEN);
+
+    advance_frames(&mut state, 4).expect("frames advance");
+    assert_eq!(state.status_effect_instances[1].life_span, 6);
+    assert_eq!(active_definitions(&state).len(), 3);
+
+    advance_frames(&mut state, 6).expect("frames advance");
+    assert!(active_definitions(&state).is_empty());
+}
+
+#[test]
+fn removing_a_bundle_removes_its_children_only() {
+    let mut state = frozen_match();
+    apply(&mut state, SLOW);
+    apply(&mut state, FROZEN);
+    // Slow was already active, so it stacks instead of joining the bundle
+    assert_eq!(active_definitions(&state), vec![SLOW, FROZEN, VULNERABLE]);
+
+    let mut character = state.characters[0].clone();
+    assert!(remove_status_effect(&mut character, &mut state, FROZEN).unwrap());
+    state.characters[0] = character;
+    assert_eq!(active_definitions(&state), vec![SLOW]);
+}
+
+#[test]
+fn invalid_bundles_are_rejected() {
+    let cyclic = create_match(vec![effect(10, vec![1]), effect(10, vec![0])]);
+    assert_eq!(cyclic.err(), Some(GameError::CircularReference));
+
+    let missing = create_match(vec![effect(10, vec![5])]);
+    assert_eq!(missing.err(), Some(GameError::InvalidStatusEffectId));
+}
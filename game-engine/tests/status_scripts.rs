@@ -0,0 +1,110 @@
+//! The frame pipeline runs status effect on, tick and off scripts
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::api::advance_frames;
+use robot_masters_engine::entity::{Element, StatusEffectDefinition};
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::status::apply_status_effect_from_source;
+
+/// Write a byte constant to one of the character's own byte properties
+fn set_own(property: &str, value: u8) -> Vec<u8> {
+    compile(&format!(
+        "ASSIGN_BYTE v0 {value}; WRITE_PROP {property} v0; EXIT 1"
+    ))
+}
+
+/// Subtract one health from `character_id` every tick
+fn drain(character_id: u8) -> Vec<u8> {
+    compile(&format!(
+        "READ_CHARACTER_PROPERTY {character_id} r0 CHARACTER_HEALTH_BYTE
+        ASSIGN_BYTE v1 1; SUB_BYTE v2 v0 v1
+        WRITE_CHARACTER_PROPERTY {character_id} CHARACTER_HEALTH_BYTE r2; EXIT 1"
+    ))
+}
+
+/// Two grounded characters; effect 0 drains its own character, effect 1 drains character 1
+fn create_match() -> GameState {
+    let poison = StatusEffectDefinition::new(
+        5,
+        1,
+        false,
+        100,
+        set_own("CHARACTER_ENERGY", 7),
+        drain(0),
+        set_own("CHARACTER_ENERGY", 42),
+    );
+    let aura = StatusEffectDefinition::new(3, 1, false, 100, Vec::new(), drain(1), Vec::new());
+
+    Scenario::new()
+        .seed(5)
+        .character(0, 32, 208)
+        .character(1, 160, 208)
+        .status_effect(poison)
+        .status_effect(aura)
+        .build()
+}
+
+fn apply(state: &mut GameState, target: usize, effect_id: usize, source: u8) {
+    let mut character = state.characters[target].clone();
+    apply_status_effect_from_source(&mut character, state, effect_id, Some((source, 1)))
+        .expect("status effect applies");
+    state.characters[target] = character;
+}
+
+#[test]
+fn scripts_run_on_apply_every_tick_and_on_expiry() {
+    let mut state = create_match();
+    apply(&mut state, 0, 0, 1);
+    assert_eq!(state.characters[0].energy, 7);
+
+    advance_frames(&mut state, 3).expect("frames advance");
+    assert_eq!(state.characters[0].health, 97);
+    assert_eq!(state.status_effect_instances[0].life_span, 2);
+
+    advance_frames(&mut state, 2).expect("frames advance");
+    assert_eq!(state.characters[0].health, 95);
+    assert!(state.characters[0].status_effects.is_empty());
+    assert_eq!(state.characters[0].energy, 42);
+    // Damage over time is credited to the character that applied the effect
+    assert_eq!(state.characters[1].stats.damage_dealt, 5);
+
+    advance_frames(&mut state, 2).expect("frames advance");
+    assert_eq!(state.characters[0].health, 95);
+}
+
+#[test]
+fn scripts_can_affect_other_characters() {
+    let mut state = create_match();
+    apply(&mut state, 0, 1, 0);
+
+    advance_frames(&mut state, 5).expect("frames advance");
+    assert_eq!(state.characters[1].health, 97);
+    assert_eq!(state.characters[0].health, 100);
+    // The credit survives writing the processed character back into the state
+    assert_eq!(state.characters[0].stats.damage_dealt, 3);
+}
+
+#[test]
+fn scripts_address_armor_by_element() {
+    let mut state = create_match();
+    state
+        .status_effect_definitions
+        .push(StatusEffectDefinition::new(
+            5,
+            1,
+            false,
+            100,
+            set_own("CHARACTER_ARMOR_ACID", 7),
+            Vec::new(),
+            set_own("CHARACTER_ARMOR_VIRUS", 9),
+        ));
+    apply(&mut state, 0, 2, 1);
+    assert_eq!(state.characters[0].get_armor(Element::Acid), 7);
+    assert_eq!(state.characters[0].get_armor(Element::Virus), 100);
+
+    advance_frames(&mut state, 5).expect("frames advance");
+    assert_eq!(state.characters[0].get_armor(Element::Acid), 7);
+    assert_eq!(state.characters[0].get_armor(Element::Virus), 9);
+}
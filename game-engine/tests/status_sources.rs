@@ -0,0 +1,81 @@
+//! Status effects applied by scripts record the character that applied them
+
+mod common;
+
+use common::{advance, compile, drop_spawn, exec, Scenario};
+use robot_masters_engine::constants::operator_address as op;
+use robot_masters_engine::entity::{SpawnDefinition, StatusEffectDefinition};
+use robot_masters_engine::script;
+use robot_masters_engine::state::GameState;
+
+/// Two characters; status effect 0 takes one health from its character every tick, and
+/// spawn 0 applies it to whoever it hits
+fn create_match() -> GameState {
+    let bleed = StatusEffectDefinition::new(
+        10,
+        1,
+        false,
+        100,
+        Vec::new(),
+        compile(
+            "READ_PROP v0 CHARACTER_HEALTH_BYTE; ASSIGN_BYTE v1 1; SUB_BYTE v2 v0 v1
+            WRITE_PROP CHARACTER_HEALTH_BYTE v2",
+        ),
+        Vec::new(),
+    );
+    let mut dart = SpawnDefinition::from_def(Vec::new());
+    dart.collision_script = compile(
+        "READ_PROP v1 ENTITY_TARGET_ID; ASSIGN_BYTE v2 0; APPLY_STATUS_EFFECT v0 v1 v2; EXIT 0",
+    );
+    Scenario::new()
+        .character(0, 32, 208)
+        .character(1, 160, 208)
+        .spawn(dart)
+        .status_effect(bleed)
+        .build()
+}
+
+#[test]
+fn action_scripts_apply_effects_as_their_character() {
+    let mut state = create_match();
+    let engine = exec(
+        &mut state,
+        0,
+        "ASSIGN_BYTE v1 1; ASSIGN_BYTE v2 0; APPLY_STATUS_EFFECT v0 v1 v2",
+    );
+    assert_eq!(engine.vars[0], 1);
+    let instance = &state.status_effect_instances[0];
+    assert_eq!(instance.character_id, 1);
+    assert_eq!((instance.source_id, instance.source_type), (Some(0), 1));
+
+    // The damage over time is credited to the applying character
+    advance(&mut state, 3);
+    assert_eq!(state.characters[1].health, 97);
+    assert_eq!(state.characters[0].stats.damage_dealt, 3);
+
+    // Missing characters and definitions apply nothing
+    let engine = exec(
+        &mut state,
+        0,
+        "ASSIGN_BYTE v1 9; ASSIGN_BYTE v2 0; APPLY_STATUS_EFFECT v0 v1 v2
+        ASSIGN_BYTE v1 1; ASSIGN_BYTE v2 9; APPLY_STATUS_EFFECT v3 v1 v2",
+    );
+    assert_eq!((engine.vars[0], engine.vars[3]), (0, 0));
+    assert_eq!(
+        script::disassemble(&[op::APPLY_STATUS_EFFECT, 0, 1, 2]),
+        "0000: ApplyStatusEffect v0, v1, v2"
+    );
+}
+
+#[test]
+fn spawn_hits_apply_effects_as_the_owning_character() {
+    let mut state = create_match();
+    drop_spawn(&mut state, 0, 1, 0);
+
+    let instance = &state.status_effect_instances[0];
+    assert_eq!(instance.character_id, 1);
+    assert_eq!((instance.source_id, instance.source_type), (Some(0), 1));
+    advance(&mut state, 4);
+    assert_eq!(state.characters[1].health, 96);
+    assert_eq!(state.characters[0].stats.damage_dealt, 4);
+}
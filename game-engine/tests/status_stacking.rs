@@ -0,0 +1,82 @@
+//! Re-applying a status effect follows its definition's stacking rule
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::api::advance_frames;
+use robot_masters_engine::entity::{StackBehavior, StatusEffectDefinition};
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::status::apply_status_effect;
+
+const STACK: usize = 0;
+const REFRESH: usize = 1;
+const IGNORE: usize = 2;
+
+/// One grounded character; every effect lasts 10 frames and allows 2 stacks
+fn create_match() -> GameState {
+    let effect = |behavior| {
+        let mut definition = StatusEffectDefinition::new(10, 2, false, 100, vec![], vec![], vec![]);
+        definition.stack_behavior = behavior;
+        definition
+    };
+
+    Scenario::new()
+        .seed(3)
+        .character(0, 64, 208)
+        .status_effect(effect(StackBehavior::Stack))
+        .status_effect(effect(StackBehavior::Refresh))
+        .status_effect(effect(StackBehavior::Ignore))
+        .build()
+}
+
+fn apply(state: &mut GameState, effect_id: usize) -> bool {
+    let mut character = state.characters[0].clone();
+    let applied = apply_status_effect(&mut character, state, effect_id).unwrap();
+    state.characters[0] = character;
+    applied
+}
+
+fn life_span(state: &GameState, effect_id: usize) -> u16 {
+    state
+        .status_effect_instances
+        .iter()
+        .find(|instance| instance.definition_id == effect_id)
+        .map_or(0, |instance| instance.life_span)
+}
+
+#[test]
+fn stack_adds_up_to_the_limit() {
+    let mut state = create_match();
+    assert_eq!(state.get_status_effect_stacks(0, STACK), 0);
+    assert!(apply(&mut state, STACK));
+    advance_frames(&mut state, 4).expect("frames advance");
+
+    assert!(apply(&mut state, STACK));
+    assert!(!apply(&mut state, STACK));
+    assert_eq!(state.get_status_effect_stacks(0, STACK), 2);
+    // reset_on_stack is off, so stacking keeps the remaining life span
+    assert_eq!(life_span(&state, STACK), 6);
+}
+
+#[test]
+fn refresh_resets_the_life_span_only() {
+    let mut state = create_match();
+    assert!(apply(&mut state, REFRESH));
+    advance_frames(&mut state, 4).expect("frames advance");
+
+    assert!(apply(&mut state, REFRESH));
+    assert_eq!(state.get_status_effect_stacks(0, REFRESH), 1);
+    assert_eq!(life_span(&state, REFRESH), 10);
+}
+
+#[test]
+fn ignore_keeps_the_existing_instance() {
+    let mut state = create_match();
+    assert!(apply(&mut state, IGNORE));
+    advance_frames(&mut state, 4).expect("frames advance");
+
+    assert!(!apply(&mut state, IGNORE));
+    assert_eq!(state.get_status_effect_stacks(0, IGNORE), 1);
+    assert_eq!(life_span(&state, IGNORE), 6);
+    assert_eq!(state.characters[0].status_effects.len(), 1);
+}
@@ -0,0 +1,103 @@
+//! Step budgets stop runaway scripts per script run and per frame
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::api::{game_loop, GameError};
+use robot_masters_engine::constants::operator_address as op;
+use robot_masters_engine::core::DEFAULT_SCRIPT_STEP_BUDGET;
+use robot_masters_engine::script::{BudgetScope, ScriptEngine, ScriptError};
+use robot_masters_engine::state::{ActionContext, GameState};
+
+/// Character 0's behavior checks a 3 instruction condition, then runs a 2 instruction action
+fn create_match() -> GameState {
+    Scenario::new()
+        .seed(13)
+        .character(0, 0, 0)
+        .behavior(
+            "ASSIGN_BYTE v0 1; ASSIGN_BYTE v1 1; EXIT 1",
+            "ASSIGN_BYTE v0 1; EXIT 1",
+        )
+        .build()
+}
+
+/// Run `script` for character 0; returns the result and the steps the engine counted
+fn run(state: &mut GameState, script: &[u8]) -> (Result<u8, ScriptError>, u32) {
+    let mut engine = ScriptEngine::new();
+    let result = engine.execute(script, &mut ActionContext::new(state, 0, 0, 0));
+    (result, engine.steps)
+}
+
+#[test]
+fn endless_loops_run_out_of_steps() {
+    let mut state = create_match();
+    assert_eq!(
+        run(&mut state, &[op::GOTO, 0]),
+        (
+            Err(ScriptError::BudgetExceeded {
+                scope: BudgetScope::Script
+            }),
+            DEFAULT_SCRIPT_STEP_BUDGET as u32 + 1
+        )
+    );
+}
+
+#[test]
+fn script_budgets_count_every_instruction() {
+    let script = [op::ASSIGN_BYTE, 0, 1, op::ASSIGN_BYTE, 1, 1, op::EXIT, 1];
+    let mut state = create_match();
+    state.script_step_budget = 3;
+    assert_eq!(run(&mut state, &script), (Ok(1), 3));
+
+    state.script_step_budget = 2;
+    let (result, steps) = run(&mut state, &script);
+    assert_eq!(
+        result,
+        Err(ScriptError::BudgetExceeded {
+            scope: BudgetScope::Script
+        })
+    );
+    assert_eq!(steps, 3);
+
+    // 32 × 32 × 32 iterations of a 1 instruction body pass an unlimited budget
+    state.script_step_budget = 0;
+    let script = compile(
+        "
+            ASSIGN_BYTE v1 32
+            REPEAT v0 v1 outer
+            REPEAT v2 v1 middle
+            REPEAT v4 v1 inner
+            ADD_BYTE v3 v3 v1
+        inner: middle: outer:
+            EXIT 1
+        ",
+    );
+    let (result, steps) = run(&mut state, &script);
+    assert_eq!(result, Ok(1));
+    assert!(steps > DEFAULT_SCRIPT_STEP_BUDGET as u32);
+}
+
+#[test]
+fn frame_budgets_cover_every_script_of_the_frame() {
+    // The condition and the action take 5 instructions together
+    let mut state = create_match();
+    state.frame_step_budget = 5;
+    game_loop(&mut state).expect("frame advances");
+    assert_eq!(state.frame_steps(), 5);
+    game_loop(&mut state).expect("the budget renews every frame");
+
+    state.frame_step_budget = 4;
+    assert_eq!(game_loop(&mut state), Err(GameError::ScriptExecutionError));
+}
+
+#[test]
+fn budgets_survive_snapshots() {
+    let mut state = create_match();
+    state.script_step_budget = 100;
+    state.frame_step_budget = 70_000;
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(
+        (restored.script_step_budget, restored.frame_step_budget),
+        (100, 70_000)
+    );
+}
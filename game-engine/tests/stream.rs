@@ -0,0 +1,113 @@
+//! Spectator streams carry keyframes and per-frame events for viewers joining mid-game
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::entity::SpawnInstance;
+use robot_masters_engine::event::GameEventKind;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::physics::BoundaryPolicy;
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::stream::{SpectatorStream, STREAM_VERSION};
+
+/// Two characters and a spawn drifting out of the arena
+fn create_match() -> GameState {
+    let mut state = Scenario::new()
+        .character(0, 32, 208)
+        .character(1, 160, 208)
+        .behavior(
+            "EXIT 1",
+            "ASSIGN_FIXED f0 1 0; WRITE_PROP CHARACTER_VEL_X v0; EXIT 1",
+        )
+        .build();
+    state.spawn_boundary = BoundaryPolicy::Despawn;
+    let mut spawn = SpawnInstance::new(0, 0, (Fixed::from_int(4), Fixed::from_int(64)));
+    spawn.core.size = (4, 4);
+    spawn.core.vel.0 = Fixed::from_int(-2);
+    spawn.life_span = 100;
+    state.insert_spawn(spawn).expect("spawn fits");
+    state
+}
+
+/// Advance `frames` frames, recording each into the stream
+fn advance(state: &mut GameState, stream: &mut SpectatorStream, frames: u32) {
+    for _ in 0..frames {
+        state.advance_frame().expect("frame advances");
+        stream.record_frame(state);
+    }
+}
+
+#[test]
+fn streams_open_with_a_header_and_keyframe() {
+    let mut state = create_match();
+    let mut stream = SpectatorStream::new(&state, 4);
+    advance(&mut state, &mut stream, 10);
+
+    let chunk = stream.take_chunk();
+    assert_eq!(chunk.sequence, 0);
+    let header = chunk.header.expect("opening chunk carries the header");
+    assert_eq!(
+        (header.version, header.keyframe_interval, header.start_frame),
+        (STREAM_VERSION, 4, 0)
+    );
+    let frames: Vec<u32> = chunk.keyframes.iter().map(|key| key.frame).collect();
+    assert_eq!(frames, [0, 4, 8]);
+    assert!(chunk
+        .events
+        .iter()
+        .any(|event| event.frame == 3 && matches!(event.kind, GameEventKind::SpawnRemoved { .. })));
+
+    // Later chunks only carry what happened since
+    assert!(!stream.has_pending());
+    let chunk = stream.take_chunk();
+    assert_eq!(chunk.sequence, 1);
+    assert!(chunk.header.is_none() && chunk.keyframes.is_empty() && chunk.events.is_empty());
+}
+
+#[test]
+fn late_viewers_resume_from_the_latest_keyframe() {
+    let mut state = create_match();
+    let mut stream = SpectatorStream::new(&state, 4);
+    advance(&mut state, &mut stream, 6);
+
+    let chunk = stream.take_chunk();
+    let keyframe = chunk.keyframes.last().unwrap();
+    let mut viewer = GameState::from_bytes(&keyframe.state).unwrap();
+    assert_eq!(viewer.state_hash(), keyframe.state_hash);
+
+    // Events from the keyframe's frame on are the ones the viewer has not seen
+    let mut seen = Vec::new();
+    while viewer.frame < state.frame {
+        viewer.advance_frame().unwrap();
+        seen.extend(viewer.events.iter().cloned());
+    }
+    let streamed: Vec<_> = chunk
+        .events
+        .into_iter()
+        .filter(|event| event.frame >= keyframe.frame)
+        .collect();
+    assert_eq!(seen, streamed);
+    assert_eq!(viewer.state_hash(), state.state_hash());
+}
+
+#[test]
+fn forced_keyframes_cover_frames_without_events() {
+    let mut state = create_match();
+    let mut stream = SpectatorStream::new(&state, 0);
+    state.events_enabled = false;
+    advance(&mut state, &mut stream, 5);
+    state.events_enabled = true;
+    stream.record_keyframe(&state);
+
+    let chunk = stream.take_chunk();
+    let frames: Vec<u32> = chunk.keyframes.iter().map(|key| key.frame).collect();
+    assert_eq!(frames, [0, 5]);
+    assert!(chunk.events.is_empty());
+
+    // Frames that did not advance stream nothing twice
+    advance(&mut state, &mut stream, 1);
+    stream.record_frame(&state);
+    let chunk = stream.take_chunk();
+    assert!(chunk.keyframes.is_empty());
+    assert!(chunk.events.iter().all(|event| event.frame == 5));
+}
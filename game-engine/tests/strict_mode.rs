@@ -0,0 +1,83 @@
+//! Strict mode checks for entity property access
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::constants::{operator_address, property_address};
+use robot_masters_engine::entity::ActionDefinition;
+use robot_masters_engine::script::ScriptError;
+use robot_masters_engine::state::GameState;
+
+fn create_match(script: Vec<u8>) -> GameState {
+    Scenario::new()
+        .character(0, 32, 208)
+        .character(1, 160, 208)
+        .action(ActionDefinition::new(0, 0, script))
+        .build()
+}
+
+fn read_character(character_id: u8, address: u8) -> Vec<u8> {
+    vec![
+        operator_address::READ_CHARACTER_PROPERTY,
+        character_id,
+        0,
+        address,
+        operator_address::EXIT,
+        1,
+    ]
+}
+
+#[test]
+fn invalid_accesses_are_ignored_by_default() {
+    for script in [
+        read_character(9, property_address::CHARACTER_HEALTH),
+        read_character(0, property_address::SPAWN_INST_LIFE_SPAN),
+    ] {
+        let mut state = create_match(script);
+        assert_eq!(state.execute_action(0, 0), Ok(()));
+    }
+}
+
+#[test]
+fn strict_mode_reports_entity_and_address() {
+    let mut state = create_match(read_character(9, property_address::CHARACTER_HEALTH));
+    state.strict_mode = true;
+    assert_eq!(
+        state.execute_action(0, 0),
+        Err(ScriptError::InvalidEntity {
+            entity_id: 9,
+            property_address: property_address::CHARACTER_HEALTH,
+        })
+    );
+
+    let mut state = create_match(read_character(1, property_address::SPAWN_INST_LIFE_SPAN));
+    state.strict_mode = true;
+    assert_eq!(
+        state.execute_action(0, 0),
+        Err(ScriptError::IncompatibleProperty {
+            entity_id: 1,
+            property_address: property_address::SPAWN_INST_LIFE_SPAN,
+        })
+    );
+
+    let mut state = create_match(vec![
+        operator_address::READ_SPAWN_PROPERTY,
+        7,
+        0,
+        property_address::SPAWN_INST_LIFE_SPAN,
+        operator_address::EXIT,
+        1,
+    ]);
+    state.strict_mode = true;
+    assert_eq!(
+        state.execute_action(0, 0),
+        Err(ScriptError::InvalidEntity {
+            entity_id: 7,
+            property_address: property_address::SPAWN_INST_LIFE_SPAN,
+        })
+    );
+
+    let mut state = create_match(read_character(1, property_address::CHARACTER_HEALTH));
+    state.strict_mode = true;
+    assert_eq!(state.execute_action(0, 0), Ok(()));
+}
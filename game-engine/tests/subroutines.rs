@@ -0,0 +1,147 @@
+//! CALL runs another action's script as a subroutine, sharing the caller's variables
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::constants::operator_address as op;
+use robot_masters_engine::entity::ActionDefinition;
+use robot_masters_engine::script::{ScriptEngine, ScriptError};
+use robot_masters_engine::state::{ActionContext, GameState};
+
+const INCREMENT: u8 = 1; // Adds 1 to var 0
+const EARLY_RETURN: u8 = 2; // Returns before setting var 0 to 99
+const QUIT: u8 = 3; // Exits with 0
+const RECURSE: u8 = 4; // Adds 1 to var 0, then calls itself
+
+fn create_match() -> GameState {
+    let scripts = [
+        vec![op::EXIT, 1],
+        vec![op::ASSIGN_BYTE, 1, 1, op::ADD_BYTE, 0, 0, 1],
+        vec![op::RETURN, op::ASSIGN_BYTE, 0, 99],
+        vec![op::EXIT, 0],
+        vec![
+            op::ASSIGN_BYTE,
+            1,
+            1,
+            op::ADD_BYTE,
+            0,
+            0,
+            1,
+            op::CALL,
+            RECURSE,
+        ],
+    ];
+
+    scripts
+        .into_iter()
+        .fold(
+            Scenario::new()
+                .seed(7)
+                .character(0, 32, 208)
+                .character(1, 160, 208),
+            |scenario, script| scenario.action(ActionDefinition::new(0, 0, script)),
+        )
+        .build()
+}
+
+/// Run `script` for character 0; returns the exit flag and var 0
+fn run(state: &mut GameState, script: &[u8]) -> Result<(u8, u8), ScriptError> {
+    let mut engine = ScriptEngine::new();
+    let exit_flag = engine.execute(script, &mut ActionContext::new(state, 0, 0, 0))?;
+    Ok((exit_flag, engine.vars[0]))
+}
+
+#[test]
+fn calls_share_variables_and_resume_after_the_call() {
+    let mut state = create_match();
+    let script = [
+        op::ASSIGN_BYTE,
+        0,
+        5,
+        op::CALL,
+        INCREMENT,
+        op::CALL,
+        INCREMENT,
+        op::EXIT_WITH_VAR,
+        0,
+    ];
+    assert_eq!(run(&mut state, &script), Ok((1, 7)));
+
+    let script = [op::ASSIGN_BYTE, 0, 3, op::CALL, EARLY_RETURN, op::EXIT, 1];
+    assert_eq!(run(&mut state, &script), Ok((1, 3)));
+
+    // RETURN outside a subroutine ends the script without an exit value
+    let script = [op::ASSIGN_BYTE, 0, 1, op::RETURN, op::EXIT, 9];
+    assert_eq!(run(&mut state, &script), Ok((0, 1)));
+}
+
+#[test]
+fn exits_inside_a_subroutine_end_the_whole_script() {
+    let mut state = create_match();
+    let script = [op::CALL, QUIT, op::ASSIGN_BYTE, 0, 42, op::EXIT, 1];
+    assert_eq!(run(&mut state, &script), Ok((0, 0)));
+}
+
+#[test]
+fn calls_past_the_depth_limit_are_skipped_or_rejected() {
+    let script = [op::CALL, RECURSE, op::EXIT_WITH_VAR, 0];
+    let mut state = create_match();
+    assert_eq!(run(&mut state, &script), Ok((1, 4)));
+
+    state.max_call_depth = 1;
+    assert_eq!(run(&mut state, &script), Ok((1, 1)));
+
+    state.max_call_depth = 0;
+    assert_eq!(run(&mut state, &script), Ok((0, 0)));
+    assert_eq!(
+        run(&mut state, &[op::CALL, 9, op::EXIT, 1]),
+        Ok((1, 0)),
+        "unknown actions are skipped"
+    );
+
+    state.strict_mode = true;
+    state.max_call_depth = 2;
+    assert_eq!(
+        run(&mut state, &script),
+        Err(ScriptError::InvalidCall { action_id: RECURSE })
+    );
+    assert_eq!(
+        run(&mut state, &[op::CALL, 9, op::EXIT, 1]),
+        Err(ScriptError::InvalidCall { action_id: 9 })
+    );
+}
+
+#[test]
+fn traces_record_the_call_depth_of_each_step() {
+    let mut state = create_match();
+    let mut trace = Vec::new();
+    ScriptEngine::new()
+        .execute_traced(
+            &[op::CALL, INCREMENT, op::EXIT, 1],
+            &mut ActionContext::new(&mut state, 0, 0, 0),
+            &mut trace,
+        )
+        .unwrap();
+
+    let steps: Vec<_> = trace
+        .iter()
+        .map(|step| (step.pos, step.depth, step.opcode))
+        .collect();
+    assert_eq!(
+        steps,
+        vec![
+            (0, 0, op::CALL),
+            (0, 1, op::ASSIGN_BYTE),
+            (3, 1, op::ADD_BYTE),
+            (2, 0, op::EXIT),
+        ]
+    );
+}
+
+#[test]
+fn call_depth_survives_snapshots() {
+    let mut state = create_match();
+    state.max_call_depth = 2;
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.max_call_depth, 2);
+}
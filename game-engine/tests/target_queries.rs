@@ -0,0 +1,99 @@
+//! FIND_TARGET and ACQUIRE_TARGET pick enemies deterministically
+
+mod common;
+
+use common::{exec, Scenario};
+use robot_masters_engine::constants::target_query;
+use robot_masters_engine::state::GameState;
+
+/// Character 0 (group 0) with an ally, a defeated enemy next to it, enemy 1 close by and
+/// enemy 2 further away with less health and more enmity
+fn create_match() -> GameState {
+    Scenario::new()
+        .character(0, 32, 208)
+        .character(0, 48, 208)
+        .with(|ally| ally.health = 1)
+        .character(1, 80, 208)
+        .with(|enemy| enemy.health = 50)
+        .character(1, 200, 208)
+        .with(|enemy| {
+            enemy.health = 20;
+            enemy.core.enmity = 9;
+        })
+        .character(1, 40, 208)
+        .with(|enemy| enemy.health = 0)
+        .build()
+}
+
+/// Run each query through FIND_TARGET for character 0
+fn find(state: &mut GameState) -> [u8; 3] {
+    let engine = exec(
+        state,
+        0,
+        "FIND_TARGET v0 0; FIND_TARGET v1 1; FIND_TARGET v2 2; EXIT 1",
+    );
+    [engine.vars[0], engine.vars[1], engine.vars[2]]
+}
+
+#[test]
+fn queries_rank_standing_enemies() {
+    let mut state = create_match();
+    assert_eq!(find(&mut state), [2, 3, 3]);
+
+    let from = state.characters[0].core.clone();
+    let aggro = state.characters[0].aggro;
+    assert_eq!(
+        state.find_target(&from, &aggro, target_query::NEAREST),
+        Some(2)
+    );
+    assert_eq!(
+        state.find_target(&from, &aggro, 4),
+        None,
+        "unknown queries find nothing"
+    );
+}
+
+#[test]
+fn ties_go_to_the_nearest_then_the_lowest_id() {
+    let mut state = create_match();
+    state.characters[2].health = 20;
+    state.characters[2].core.enmity = 9;
+    assert_eq!(find(&mut state), [2, 2, 2]);
+
+    // Same distance on both sides of character 1's center
+    let mut state = Scenario::new()
+        .character(0, 100, 208)
+        .character(1, 60, 208)
+        .character(1, 140, 208)
+        .build();
+    assert_eq!(find(&mut state), [1, 1, 1]);
+}
+
+#[test]
+fn acquiring_sets_and_clears_the_target() {
+    let mut state = create_match();
+    let engine = exec(&mut state, 0, "ACQUIRE_TARGET v0 1; EXIT 1");
+    assert_eq!(engine.vars[0], 1);
+    assert_eq!(state.characters[0].core.target_id, Some(3));
+    assert_eq!(state.characters[0].core.target_type, 1);
+
+    state.characters[2].health = 0;
+    state.characters[3].health = 0;
+    assert_eq!(find(&mut state), [255, 255, 255]);
+    let engine = exec(&mut state, 0, "ACQUIRE_TARGET v0 0; EXIT 1");
+    assert_eq!(engine.vars[0], 0);
+    assert_eq!(state.characters[0].core.target_id, None);
+    assert_eq!(state.characters[0].core.target_type, 0);
+}
+
+#[test]
+fn taunted_characters_keep_the_taunter() {
+    let mut state = create_match();
+    state.characters[0].taunted_by = Some(3);
+    state.characters[0].core.target_id = Some(3);
+    state.characters[0].core.target_type = 1;
+
+    let engine = exec(&mut state, 0, "ACQUIRE_TARGET v0 0; EXIT 1");
+    assert_eq!(engine.vars[0], 0);
+    assert_eq!(state.characters[0].core.target_id, Some(3));
+}
@@ -0,0 +1,90 @@
+//! DISTANCE_TO and DIRECTION_TO measure from the script's entity to another entity's center
+
+mod common;
+
+use common::{exec, Scenario};
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::script;
+use robot_masters_engine::state::GameState;
+
+/// Character 1's center sits 30 pixels right of and 40 above character 0's; spawn 7 (8x8)
+/// is centered 60 pixels left of character 0's center
+fn create_match() -> GameState {
+    let mut state = Scenario::new()
+        .character(0, 96, 192)
+        .character(1, 126, 152)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build();
+
+    let mut spawn = SpawnInstance::new(0, 1, (Fixed::from_int(40), Fixed::from_int(196)));
+    spawn.core.id = 7;
+    spawn.core.size = (8, 8);
+    spawn.life_span = 100;
+    state.restore_spawns(vec![spawn]).expect("spawn fits");
+    state
+}
+
+/// Measure from character 0 to an entity; returns the distance and direction
+fn measure(state: &mut GameState, entity_type: u8, entity_id: u8) -> (Fixed, (Fixed, Fixed)) {
+    let source = format!(
+        "ASSIGN_BYTE v0 {}; ASSIGN_BYTE v1 {}; DISTANCE_TO f0 v0 v1; DIRECTION_TO f1 f2 v0 v1; EXIT 1",
+        entity_type, entity_id
+    );
+    let engine = exec(state, 0, &source);
+    (engine.fixed[0], (engine.fixed[1], engine.fixed[2]))
+}
+
+#[test]
+fn characters_are_measured_between_centers() {
+    let mut state = create_match();
+    let (distance, direction) = measure(&mut state, 1, 1);
+    assert_eq!(distance, Fixed::from_int(50));
+    // (0.6, -0.8) rounded towards zero to 1/32 steps
+    assert_eq!(direction, (Fixed::from_raw(19), Fixed::from_raw(-25)));
+}
+
+#[test]
+fn spawns_are_found_by_stable_id() {
+    let mut state = create_match();
+    let (distance, direction) = measure(&mut state, 2, 7);
+    assert_eq!(distance, Fixed::from_int(60));
+    assert_eq!(direction, (Fixed::from_int(-1), Fixed::ZERO));
+}
+
+#[test]
+fn missing_entities_and_the_script_entity_measure_zero() {
+    let mut state = create_match();
+    for (entity_type, entity_id) in [(1, 9), (2, 3), (0, 1), (5, 0), (1, 0)] {
+        assert_eq!(
+            measure(&mut state, entity_type, entity_id),
+            (Fixed::ZERO, (Fixed::ZERO, Fixed::ZERO)),
+            "entity type {} id {}",
+            entity_type,
+            entity_id
+        );
+    }
+}
+
+#[test]
+fn offsets_across_the_whole_screen_do_not_overflow() {
+    let (x, y) = (Fixed::from_int(255), Fixed::from_int(-239));
+    assert_eq!(Fixed::length(x, y).to_int(), 349);
+    let (dir_x, dir_y) = Fixed::normalize(x, y);
+    assert_eq!((dir_x.raw(), dir_y.raw()), (23, -21));
+    assert_eq!(
+        Fixed::normalize(Fixed::ZERO, Fixed::ZERO),
+        (Fixed::ZERO, Fixed::ZERO)
+    );
+}
+
+#[test]
+fn targeting_operators_round_trip_through_the_assembler() {
+    let source = "DISTANCE_TO f0 v0 v1\nDIRECTION_TO f1 f2 v0 v1\nEXIT 1";
+    let bytes = common::compile(source);
+    assert!(script::validate(&bytes).is_ok());
+    assert_eq!(script::assemble(&script::disassemble(&bytes)), Ok(bytes));
+
+    // Fixed-point destinations must be in range
+    assert!(script::validate(&[120, 9, 0, 1, 0, 1]).is_err());
+}
@@ -0,0 +1,87 @@
+//! Taunt status effects force the afflicted character's target and refuse retargets
+
+mod common;
+
+use common::{compile, drop_spawn, exec, Scenario};
+use robot_masters_engine::api::advance_frames;
+use robot_masters_engine::entity::{SpawnDefinition, StatusEffectDefinition};
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::status::apply_status_effect_from_source;
+
+/// Three grounded characters; status effect 0 taunts for 10 frames and spawn 0 applies it to
+/// whoever it hits
+fn create_match() -> GameState {
+    let mut taunt = StatusEffectDefinition::new(10, 1, false, 100, vec![], vec![], vec![]);
+    taunt.taunt = true;
+    let mut provoke = SpawnDefinition::from_def(Vec::new());
+    provoke.collision_script = compile(
+        "READ_PROP v1 ENTITY_TARGET_ID; ASSIGN_BYTE v2 0; APPLY_STATUS_EFFECT v0 v1 v2; EXIT 0",
+    );
+
+    Scenario::new()
+        .seed(3)
+        .character(0, 32, 208)
+        .character(1, 96, 208)
+        .character(2, 160, 208)
+        .spawn(provoke)
+        .status_effect(taunt)
+        .build()
+}
+
+fn taunt(state: &mut GameState, target: usize, taunter: u8) {
+    let mut character = state.characters[target].clone();
+    apply_status_effect_from_source(&mut character, state, 0, Some((taunter, 1)))
+        .expect("status effect applies");
+    state.characters[target] = character;
+}
+
+/// Run a script on character 0 that points its target at character `target_id`
+fn retarget(state: &mut GameState, target_id: u8) {
+    exec(
+        state,
+        0,
+        &format!(
+            "ASSIGN_BYTE v0 {target_id}; WRITE_CHARACTER_PROPERTY 0 ENTITY_TARGET_ID r0; EXIT 1"
+        ),
+    );
+}
+
+#[test]
+fn taunt_forces_the_target_until_it_expires() {
+    let mut state = create_match();
+    taunt(&mut state, 0, 1);
+    assert_eq!(state.characters[0].taunted_by, Some(1));
+    assert_eq!(state.characters[0].core.target_id, Some(1));
+    assert_eq!(state.characters[0].core.target_type, 1);
+
+    retarget(&mut state, 2);
+    assert_eq!(state.characters[0].core.target_id, Some(1));
+
+    advance_frames(&mut state, 10).expect("frames advance");
+    assert_eq!(state.characters[0].taunted_by, None);
+    retarget(&mut state, 2);
+    assert_eq!(state.characters[0].core.target_id, Some(2));
+}
+
+#[test]
+fn defeating_the_taunter_releases_the_target() {
+    let mut state = create_match();
+    taunt(&mut state, 0, 1);
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.characters[0].taunted_by, Some(1));
+
+    state.characters[1].health = 0;
+    advance_frames(&mut state, 1).expect("frames advance");
+    assert!(!state.characters[0].is_taunted());
+}
+
+#[test]
+fn spawn_hits_taunt_toward_the_owner() {
+    let mut state = create_match();
+    drop_spawn(&mut state, 2, 0, 0);
+    assert_eq!(state.characters[0].taunted_by, Some(2));
+    assert_eq!(state.characters[0].core.target_id, Some(2));
+
+    retarget(&mut state, 1);
+    assert_eq!(state.characters[0].core.target_id, Some(2));
+}
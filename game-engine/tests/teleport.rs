@@ -0,0 +1,77 @@
+//! The TELEPORT operator only lands entities on spots free of solid tiles
+
+mod common;
+
+use common::{exec, Scenario};
+use robot_masters_engine::constants::teleport_mode;
+use robot_masters_engine::state::GameState;
+
+/// Two characters on the floor at tile columns 2 and 10, with solid tiles in `walls`
+/// (columns of row 13, the row both characters stand in)
+fn create_match(walls: &[usize]) -> GameState {
+    Scenario::new()
+        .seed(5)
+        .blocks(13, walls)
+        .character(0, 32, 208)
+        .character(1, 160, 208)
+        .build()
+}
+
+/// Run TELEPORT for character 0 with an (x, 0) offset; returns the result variable
+fn teleport(state: &mut GameState, mode: u8, x: u8) -> u8 {
+    let script = format!("ASSIGN_FIXED f0 {x} 0; TELEPORT {mode} v0 f0 f1; EXIT 1");
+    exec(state, 0, &script).vars[0]
+}
+
+fn position(state: &GameState) -> (i32, i32) {
+    let pos = state.characters[0].core.pos;
+    (pos.0.to_int(), pos.1.to_int())
+}
+
+#[test]
+fn offsets_stop_short_of_walls_and_clamp_to_the_world() {
+    let mut state = create_match(&[6, 7]);
+    // 112 would be inside the wall; the blink backs off in 10 pixel steps to 72
+    assert_eq!(teleport(&mut state, teleport_mode::OFFSET, 80), 1);
+    assert_eq!(position(&state), (72, 208));
+
+    let mut state = create_match(&[]);
+    assert_eq!(teleport(&mut state, teleport_mode::OFFSET, 250), 1);
+    assert_eq!(position(&state), (240, 208));
+}
+
+#[test]
+fn blocked_teleports_fail_in_place() {
+    // A wall directly ahead leaves no free spot along the way
+    let mut state = create_match(&[3]);
+    assert_eq!(teleport(&mut state, teleport_mode::OFFSET, 16), 0);
+    assert_eq!(position(&state), (32, 208));
+
+    // Unknown modes and teleports without a target do nothing
+    assert_eq!(teleport(&mut state, 9, 16), 0);
+    assert_eq!(teleport(&mut state, teleport_mode::TARGET, 16), 0);
+    assert_eq!(position(&state), (32, 208));
+}
+
+#[test]
+fn target_teleports_land_next_to_the_target() {
+    let mut state = create_match(&[]);
+    state.characters[0].core.target_id = Some(1);
+    state.characters[0].core.target_type = 1;
+
+    assert_eq!(teleport(&mut state, teleport_mode::TARGET, 16), 1);
+    assert_eq!(position(&state), (176, 208));
+}
+
+#[test]
+fn random_teleports_pick_a_free_tile_in_range() {
+    // Within 3 tiles of column 2 only columns 0 and 5 are free
+    let mut state = create_match(&[1, 3, 4]);
+    let mut replay = create_match(&[1, 3, 4]);
+
+    assert_eq!(teleport(&mut state, teleport_mode::RANDOM, 3), 1);
+    assert!([(0, 208), (80, 208)].contains(&position(&state)));
+
+    assert_eq!(teleport(&mut replay, teleport_mode::RANDOM, 3), 1);
+    assert_eq!(position(&replay), position(&state));
+}
@@ -0,0 +1,83 @@
+//! Terrain spawns write solid tiles for their lifetime and restore the map when removed
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::api::advance_frames;
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+use robot_masters_engine::tilemap::{TerrainTile, TileType};
+
+const ICE: u8 = TileType::Ice as u8;
+
+/// One character on the floor at tile column 2 and an ice wall spawn definition
+fn create_match() -> GameState {
+    let mut wall = SpawnDefinition::from_def(Vec::new());
+    wall.terrain = ICE;
+
+    Scenario::new()
+        .seed(3)
+        .character(0, 32, 208)
+        .spawn(wall)
+        .build()
+}
+
+/// A terrain spawn owned by character 0, without gravity so it stays where it was placed
+fn wall(pos: (i16, i16), size: (u8, u8)) -> SpawnInstance {
+    let mut spawn = SpawnInstance::new(0, 0, (Fixed::from_int(pos.0), Fixed::from_int(pos.1)));
+    spawn.core.size = size;
+    spawn.core.dir.1 = 1;
+    spawn.life_span = 100;
+    spawn
+}
+
+#[test]
+fn terrain_is_written_for_the_spawn_lifetime() {
+    let mut state = create_match();
+    // Unaligned hitbox: covers columns 4-5 of rows 11-12
+    let id = state.insert_spawn(wall((72, 184), (16, 16))).unwrap();
+
+    for (x, y) in [(4, 11), (5, 11), (4, 12), (5, 12)] {
+        assert_eq!(state.tile_map.get_tile(x, y), TileType::Ice);
+    }
+    assert_eq!(state.terrain_tiles.len(), 4);
+    assert_eq!(
+        state.terrain_tiles[0],
+        TerrainTile {
+            spawn_instance_id: id,
+            x: 4,
+            y: 11,
+            previous: 0,
+        }
+    );
+
+    // Tiles stay solid while the spawn lives, and the spawn is not pushed out of them
+    advance_frames(&mut state, 5).expect("frames advance");
+    assert_eq!(state.tile_map.get_tile(4, 11), TileType::Ice);
+    assert_eq!(state.spawn_instances()[0].core.pos.0, Fixed::from_int(72));
+
+    state.despawn_owned_spawn(0, id);
+    advance_frames(&mut state, 1).expect("frames advance");
+    assert!(state.terrain_tiles.is_empty());
+    assert_eq!(state.tile_map.get_raw_tiles()[11], [0; 16]);
+    assert_eq!(state.tile_map.get_raw_tiles()[12], [0; 16]);
+}
+
+#[test]
+fn terrain_leaves_characters_and_existing_tiles_alone() {
+    let mut state = create_match();
+    // Columns 1-4 of the two bottom rows: the character stands in column 2, row 14 is floor
+    state.insert_spawn(wall((16, 208), (64, 32))).unwrap();
+
+    assert_eq!(state.tile_map.get_tile(2, 13), TileType::Empty);
+    assert_eq!(state.tile_map.get_tile(1, 13), TileType::Ice);
+    assert_eq!(state.tile_map.get_tile(3, 13), TileType::Ice);
+    assert_eq!(state.tile_map.get_tile(2, 14), TileType::Block);
+    assert_eq!(state.terrain_tiles.len(), 3);
+
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.terrain_tiles, state.terrain_tiles);
+    assert_eq!(restored.tile_map.get_tile(1, 13), TileType::Ice);
+    assert_eq!(restored.state_hash(), state.state_hash());
+}
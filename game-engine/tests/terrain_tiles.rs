@@ -0,0 +1,87 @@
+//! Slopes, one-way platforms, ladders and hazard tiles, and the terrain contact property
+
+mod common;
+
+use common::{advance, exec, Scenario};
+use robot_masters_engine::constants::terrain_contact;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Arena rows from the top with a solid floor on row 14
+fn arena(rows: &[(usize, &'static str)]) -> Vec<&'static str> {
+    let mut map = vec![""; 15];
+    for &(row, text) in rows {
+        map[row] = text;
+    }
+    map[14] = "################";
+    map
+}
+
+/// Walk character 0 right at `speed` pixels per frame
+fn walking_right(scenario: Scenario, speed: i16) -> Scenario {
+    // Fixed property writes take the fixed variable's index
+    let action = format!("ASSIGN_FIXED f0 {speed} 0; WRITE_PROP CHARACTER_VEL_X v0; EXIT 1");
+    scenario.behavior("EXIT 1", &action)
+}
+
+fn contact(state: &mut GameState) -> u8 {
+    exec(state, 0, "READ_PROP v0 CHARACTER_TERRAIN_CONTACT; EXIT 1").vars[0]
+}
+
+#[test]
+fn platforms_catch_falling_characters_and_let_jumps_through() {
+    let map = arena(&[(12, "..8888")]);
+    let mut state = Scenario::new().map(&map).character(0, 48, 100).run(40);
+    assert_eq!(state.characters[0].core.pos.1, Fixed::from_int(192 - 16));
+    assert!(state.characters[0].core.collision.2);
+    assert_eq!(contact(&mut state), terrain_contact::PLATFORM);
+
+    // From the floor below, a jump passes up through the platform and lands on top of it
+    let mut state = Scenario::new().map(&map).character(0, 48, 208).build();
+    advance(&mut state, 2);
+    state.characters[0].core.vel.1 = Fixed::from_int(-8);
+    advance(&mut state, 60);
+    assert_eq!(state.characters[0].core.pos.1, Fixed::from_int(192 - 16));
+}
+
+#[test]
+fn characters_walk_up_and_down_slopes() {
+    // A one-tile hill: a ramp rising to the right, then one falling back to the floor
+    let map = arena(&[(13, ".....67")]);
+    let mut state = walking_right(Scenario::new().map(&map).character(0, 40, 208), 1).build();
+
+    let mut highest = Fixed::from_int(208);
+    let mut touched_slope = false;
+    for _ in 0..120 {
+        state.advance_frame().expect("frame advances");
+        highest = highest.min(state.characters[0].core.pos.1);
+        touched_slope |= state.characters[0].has_terrain_contact(terrain_contact::SLOPE);
+    }
+
+    // The character climbed to the peak, came down the far side and kept walking
+    assert!(touched_slope);
+    assert_eq!(highest, Fixed::from_int(208 - 16));
+    assert_eq!(state.characters[0].core.pos.1, Fixed::from_int(208));
+    assert!(state.characters[0].core.pos.0 > Fixed::from_int(7 * 16));
+}
+
+#[test]
+fn ladders_hold_characters_against_gravity() {
+    let map = arena(&[(6, "...9"), (7, "...9"), (8, "...9")]);
+    let mut state = Scenario::new().map(&map).character(0, 48, 112).run(30);
+    assert_eq!(state.characters[0].core.pos.1, Fixed::from_int(112));
+    assert_eq!(contact(&mut state), terrain_contact::LADDER);
+
+    // Climbing is up to scripts: the ladder only removes gravity
+    state.characters[0].core.vel.1 = Fixed::from_int(-1);
+    advance(&mut state, 4);
+    assert_eq!(state.characters[0].core.pos.1, Fixed::from_int(108));
+}
+
+#[test]
+fn hazard_tiles_are_reported_but_not_solid() {
+    let map = arena(&[(13, "..aa")]);
+    let mut state = Scenario::new().map(&map).character(0, 40, 100).run(40);
+    assert_eq!(state.characters[0].core.pos.1, Fixed::from_int(208));
+    assert_eq!(contact(&mut state), terrain_contact::HAZARD);
+}
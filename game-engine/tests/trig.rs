@@ -0,0 +1,117 @@
+//! Table-based trigonometry and square roots for aimed and curved trajectories
+
+mod common;
+
+use common::Scenario;
+use robot_masters_engine::constants::operator_address as op;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::script::{self, ScriptEngine};
+use robot_masters_engine::state::{ActionContext, GameState};
+
+fn create_match() -> GameState {
+    Scenario::new().seed(21).character(0, 32, 208).build()
+}
+
+#[test]
+fn sine_and_cosine_follow_the_binary_angle() {
+    // Right, down, left and up: y grows downward
+    let quarter_turns = [0u8, 64, 128, 192];
+    let cos: Vec<i16> = quarter_turns.iter().map(|&a| Fixed::cos(a).raw()).collect();
+    let sin: Vec<i16> = quarter_turns.iter().map(|&a| Fixed::sin(a).raw()).collect();
+    assert_eq!(cos, [32, 0, -32, 0]);
+    assert_eq!(sin, [0, 32, 0, -32]);
+
+    // 45 degrees: 32 / sqrt(2) = 22.6
+    assert_eq!(Fixed::sin(32).raw(), 23);
+    assert_eq!(Fixed::cos(224).raw(), 23);
+    assert_eq!(Fixed::sin(224).raw(), -23);
+
+    for angle in 0..=255u8 {
+        assert_eq!(Fixed::sin(angle), Fixed::sin(128u8.wrapping_sub(angle)));
+        assert_eq!(Fixed::sin(angle), Fixed::sin(angle.wrapping_add(128)).neg());
+    }
+}
+
+#[test]
+fn atan2_inverts_sine_and_cosine() {
+    let at = |x: i16, y: i16| Fixed::atan2(Fixed::from_int(y), Fixed::from_int(x));
+    assert_eq!(
+        [at(5, 0), at(0, 5), at(-5, 0), at(0, -5), at(0, 0)],
+        [0, 64, 128, 192, 0]
+    );
+    assert_eq!(
+        [at(3, 3), at(-3, 3), at(-3, -3), at(3, -3)],
+        [32, 96, 160, 224]
+    );
+
+    // Round trips land within one step of the table resolution
+    for angle in 0..=255u8 {
+        let back = Fixed::atan2(
+            Fixed::sin(angle).mul(Fixed::from_int(64)),
+            Fixed::cos(angle).mul(Fixed::from_int(64)),
+        );
+        let error = back.wrapping_sub(angle).min(angle.wrapping_sub(back));
+        assert!(error <= 2, "angle {} came back as {}", angle, back);
+    }
+}
+
+#[test]
+fn square_roots_floor() {
+    let sqrt = |raw: i16| Fixed::from_raw(raw).sqrt().raw();
+    assert_eq!(Fixed::from_int(16).sqrt(), Fixed::from_int(4));
+    assert_eq!(Fixed::from_int(2).sqrt().raw(), 45); // 1.414 * 32 = 45.25
+    assert_eq!(sqrt(8), 16); // sqrt(0.25) = 0.5
+    assert_eq!(sqrt(-32), 0);
+    assert_eq!(Fixed::MAX.sqrt().raw(), 1023);
+}
+
+#[test]
+fn scripts_aim_with_the_math_operators() {
+    // Aim at an offset of (-4, 4), then rebuild the unit direction and its length
+    let script = [
+        op::ASSIGN_FIXED,
+        0,
+        4,
+        0,
+        op::ASSIGN_FIXED,
+        1,
+        4,
+        0,
+        op::NEGATE,
+        1,
+        op::ATAN2,
+        0,
+        0,
+        1,
+        op::COS,
+        2,
+        0,
+        op::SIN,
+        3,
+        0,
+        op::MUL,
+        0,
+        0,
+        0,
+        op::SQRT,
+        1,
+        0,
+        op::EXIT,
+        1,
+    ];
+    assert!(script::validate(&script).is_ok());
+
+    let mut state = create_match();
+    let mut engine = ScriptEngine::new();
+    engine
+        .execute(&script, &mut ActionContext::new(&mut state, 0, 0, 0))
+        .unwrap();
+    assert_eq!(engine.vars[0], 96);
+    assert_eq!(engine.fixed[2].raw(), -23);
+    assert_eq!(engine.fixed[3].raw(), 23);
+    assert_eq!(engine.fixed[1], Fixed::from_int(4));
+
+    // Out-of-range operands are rejected before execution
+    assert!(script::validate(&[op::SIN, 4, 0]).is_err());
+    assert!(script::validate(&[op::ATAN2, 8, 0, 1]).is_err());
+}
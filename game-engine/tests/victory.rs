@@ -0,0 +1,147 @@
+//! Victory conditions end matches early and decide the winner
+
+mod common;
+
+use common::{drop_spawn, Scenario};
+use robot_masters_engine::api::advance_frames;
+use robot_masters_engine::entity::SpawnDefinition;
+use robot_masters_engine::state::{GameState, GameStatus};
+use robot_masters_engine::victory::{EndReason, TieBreak, VictoryCondition};
+
+/// One grounded character without armor per group
+fn create_match(groups: u8, victory: VictoryCondition) -> GameState {
+    let mut scenario = Scenario::new().seed(3);
+    for group in 0..groups {
+        scenario = scenario.unarmored_character(group, 32 + group as i16 * 64, 208);
+    }
+
+    let mut state = scenario
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build();
+    state.victory = victory;
+    state
+}
+
+/// Drop a lethal spawn owned by `owner` onto `target` and run one frame
+fn defeat(state: &mut GameState, owner: u8, target: usize) {
+    drop_spawn(state, owner, target, 500);
+    assert_eq!(state.characters[target].health, 0);
+}
+
+#[test]
+fn last_team_standing_ends_when_one_group_remains() {
+    let mut state = create_match(2, VictoryCondition::LastTeamStanding);
+    advance_frames(&mut state, 10).expect("frames advance");
+    assert_eq!(state.status, GameStatus::Playing);
+
+    defeat(&mut state, 0, 1);
+    let result = state.match_result();
+    assert!(result.ended);
+    assert_eq!(result.reason, Some(EndReason::LastTeamStanding));
+    assert_eq!(result.winner_group, Some(0));
+    assert_eq!(state.characters[0].stats.kills, 1);
+    assert_eq!(state.match_summary().winner_group, Some(0));
+}
+
+#[test]
+fn kill_target_counts_kills_without_events() {
+    let mut state = create_match(3, VictoryCondition::KillTarget(2));
+    state.events_enabled = false;
+
+    defeat(&mut state, 2, 0);
+    assert_eq!(state.status, GameStatus::Playing);
+    defeat(&mut state, 2, 1);
+
+    let result = state.match_result();
+    assert_eq!(result.reason, Some(EndReason::KillTarget));
+    assert_eq!(result.winner_group, Some(2));
+    assert_eq!(result.standings[2].kills, 2);
+}
+
+#[test]
+fn timeout_scoring_depends_on_the_condition() {
+    for (victory, winner) in [
+        (VictoryCondition::Timeout, 1),
+        (VictoryCondition::HealthPercent, 0),
+        (VictoryCondition::Survival(0), 0),
+    ] {
+        let mut state = create_match(2, victory);
+        state.frame_limit = 5;
+        state.characters[0].health = 60;
+        state.characters[1].health_cap = 200;
+        state.characters[1].health = 100;
+
+        advance_frames(&mut state, 10).expect("frames advance");
+        let result = state.match_result();
+        assert_eq!(result.reason, Some(EndReason::FrameLimit));
+        assert_eq!(result.winner_group, Some(winner), "{victory:?}");
+    }
+}
+
+#[test]
+fn survival_fails_when_the_group_is_eliminated() {
+    let mut state = create_match(3, VictoryCondition::Survival(1));
+    defeat(&mut state, 0, 1);
+
+    let result = state.match_result();
+    assert_eq!(result.reason, Some(EndReason::Eliminated));
+    assert_ne!(result.winner_group, Some(1));
+
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.match_result(), result);
+}
+
+#[test]
+fn tie_breaks_decide_timeouts_in_order() {
+    // Equal total health: group 0 has more of its cap left, group 1 dealt more damage
+    let timeout = |tie_breaks: Vec<TieBreak>| {
+        let mut state = create_match(2, VictoryCondition::Timeout);
+        state.frame_limit = 5;
+        state.tie_breaks = tie_breaks;
+        state.characters[0].health = 80;
+        state.characters[1].health_cap = 200;
+        state.characters[1].health = 80;
+        state.characters[1].stats.damage_dealt = 40;
+        advance_frames(&mut state, 10).expect("frames advance");
+        state
+    };
+
+    let draw = timeout(Vec::new()).match_result();
+    assert_eq!((draw.winner_group, draw.tie_break), (None, None));
+
+    let result = timeout(vec![TieBreak::HealthPercent, TieBreak::DamageDealt]).match_result();
+    assert_eq!(result.winner_group, Some(0));
+    assert_eq!(result.tie_break, Some(TieBreak::HealthPercent));
+
+    // Rules that still tie fall through to the next one
+    let state = timeout(vec![TieBreak::Kills, TieBreak::DamageDealt]);
+    let result = state.match_result();
+    assert_eq!(result.winner_group, Some(1));
+    assert_eq!(result.tie_break, Some(TieBreak::DamageDealt));
+    assert_eq!(state.match_summary().winner_group, Some(1));
+
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.tie_breaks, state.tie_breaks);
+    assert_eq!(restored.match_result(), result);
+
+    let draw = timeout(vec![TieBreak::Kills, TieBreak::Survivors]).match_result();
+    assert_eq!((draw.winner_group, draw.tie_break), (None, None));
+}
+
+#[test]
+fn tie_breaks_skip_matches_that_end_early() {
+    let mut state = create_match(2, VictoryCondition::LastTeamStanding);
+    state.tie_breaks = vec![TieBreak::DamageDealt];
+    state.characters[0].stats.damage_dealt = 10;
+
+    // Both groups fall on the same frame, so the match ends without a winner
+    for character in &mut state.characters {
+        character.health = 0;
+    }
+    state.advance_frame().expect("frame advances");
+
+    let result = state.match_result();
+    assert!(result.ended);
+    assert_eq!(result.reason, Some(EndReason::LastTeamStanding));
+    assert_eq!((result.winner_group, result.tie_break), (None, None));
+}
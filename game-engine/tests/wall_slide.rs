@@ -0,0 +1,93 @@
+//! Characters pressing into a wall in the air slide down it, and WALL_JUMP kicks off it
+
+mod common;
+
+use common::{exec, Scenario};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// One character in the air at (64, 32), right against a wall filling tile column 5
+fn create_match(wall_slide_speed: Fixed) -> GameState {
+    Scenario::new()
+        .seed(5)
+        .wall(5)
+        .character(0, 64, 32)
+        .with(|character| character.wall_slide_speed = wall_slide_speed)
+        .build()
+}
+
+/// Fall for `frames` frames; returns the fastest fall speed seen
+fn fall(state: &mut GameState, frames: u32) -> Fixed {
+    let mut fastest = Fixed::ZERO;
+    for _ in 0..frames {
+        state.advance_frame().expect("frame advances");
+        fastest = fastest.max(state.characters[0].core.vel.1);
+    }
+    fastest
+}
+
+/// Run WALL_JUMP for character 0 with a push of 3 and a lift of 5; returns the result variable
+fn wall_jump(state: &mut GameState) -> u8 {
+    exec(
+        state,
+        0,
+        "ASSIGN_FIXED f0 3 0; ASSIGN_FIXED f1 5 0; WALL_JUMP v0 f0 f1; EXIT 1",
+    )
+    .vars[0]
+}
+
+#[test]
+fn pressing_into_a_wall_caps_the_fall_speed() {
+    let mut state = create_match(Fixed::ONE);
+    assert_eq!(fall(&mut state, 20), Fixed::ONE);
+    assert!(state.characters[0].core.collision.1);
+
+    // Facing away from the wall, or without a slide speed, the character falls freely
+    let mut state = create_match(Fixed::ONE);
+    state.characters[0].core.dir.0 = 0;
+    assert!(fall(&mut state, 20) > Fixed::ONE);
+
+    let mut state = create_match(Fixed::ZERO);
+    assert!(fall(&mut state, 20) > Fixed::ONE);
+}
+
+#[test]
+fn wall_jumps_kick_away_from_the_wall() {
+    let mut state = create_match(Fixed::ONE);
+    fall(&mut state, 2);
+
+    assert_eq!(wall_jump(&mut state), 1);
+    let character = &state.characters[0];
+    assert_eq!(
+        character.core.vel,
+        (Fixed::from_int(-3), Fixed::from_int(-5))
+    );
+    assert_eq!(character.core.dir.0, 0);
+
+    state.advance_frame().expect("frame advances");
+    assert!(state.characters[0].core.pos.0 < Fixed::from_int(64));
+}
+
+#[test]
+fn wall_jumps_need_a_wall_and_open_air() {
+    // Away from the wall
+    let mut state = create_match(Fixed::ONE);
+    state.characters[0].core.pos.0 = Fixed::from_int(32);
+    fall(&mut state, 1);
+    assert_eq!(wall_jump(&mut state), 0);
+
+    // Standing on the floor next to the wall
+    let mut state = create_match(Fixed::ONE);
+    state.characters[0].core.pos.1 = Fixed::from_int(208);
+    fall(&mut state, 1);
+    assert!(state.characters[0].core.collision.1);
+    assert_eq!(wall_jump(&mut state), 0);
+    assert_eq!(state.characters[0].core.vel.0, Fixed::ZERO);
+}
+
+#[test]
+fn wall_slide_speed_survives_snapshots() {
+    let state = create_match(Fixed::from_raw(40));
+    let restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.characters[0].wall_slide_speed, Fixed::from_raw(40));
+}
@@ -0,0 +1,147 @@
+//! A frame work budget defers spawn interception to the next frame instead of failing
+
+mod common;
+
+use common::{compile, Scenario};
+use robot_masters_engine::constants::{operator_address, property_address};
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::event::GameEventKind;
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::state::GameState;
+
+/// Two idle characters and two overlapping pairs of self-destroying shields (ids 1+2 and 3+4)
+fn create_match(frame_work_budget: u32) -> GameState {
+    let mut shield = SpawnDefinition::from_def(Vec::new());
+    shield.collision_mask = 1;
+    shield.collision_script = vec![
+        operator_address::WRITE_PROP,
+        property_address::SPAWN_INST_LIFE_SPAN,
+        0,
+        operator_address::EXIT,
+        0,
+    ];
+
+    let mut state = Scenario::new()
+        .character(0, 16, 192)
+        .character(1, 208, 192)
+        .spawn(shield)
+        .build();
+    state.frame_work_budget = frame_work_budget;
+
+    let spawns = [(1, 0, 64), (2, 1, 64), (3, 0, 160), (4, 1, 160)]
+        .into_iter()
+        .map(|(id, owner, x)| {
+            let mut spawn = SpawnInstance::new(0, owner, (Fixed::from_int(x), Fixed::from_int(80)));
+            spawn.core.id = id;
+            spawn.core.size = (4, 4);
+            spawn.life_span = 100;
+            spawn
+        })
+        .collect();
+    state.restore_spawns(spawns).expect("distinct spawn IDs");
+    state
+}
+
+fn survivors(state: &GameState) -> Vec<u8> {
+    state.spawn_instances().iter().map(|s| s.core.id).collect()
+}
+
+fn deferrals(state: &GameState) -> Vec<u8> {
+    state
+        .events
+        .iter()
+        .filter_map(|event| match event.kind {
+            GameEventKind::SpawnCollisionsDeferred { remaining, .. } => Some(remaining),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn unbudgeted_frames_check_every_pair() {
+    let mut state = create_match(0);
+    state.advance_frame().expect("frame advances");
+    assert_eq!(survivors(&state), Vec::<u8>::new());
+    assert!(deferrals(&state).is_empty());
+    assert_eq!(state.spawn_collision_resume(), None);
+}
+
+#[test]
+fn spent_budgets_defer_the_remaining_spawns_to_the_next_frame() {
+    let mut state = create_match(1);
+    state.advance_frame().expect("frame advances");
+
+    // Spawn 1 still resolves its pair; the checks it spent defer spawns 2, 3 and 4
+    assert_eq!(survivors(&state), vec![3, 4]);
+    assert_eq!(deferrals(&state), vec![3]);
+    assert_eq!(state.spawn_collision_resume(), Some(2));
+    assert!(state.frame_work() >= 1);
+
+    // The next frame starts from spawn 3, which resolves the second pair
+    state.advance_frame().expect("frame advances");
+    assert_eq!(survivors(&state), Vec::<u8>::new());
+
+    state.advance_frame().expect("frame advances");
+    assert!(deferrals(&state).is_empty());
+    assert_eq!(state.spawn_collision_resume(), None);
+}
+
+#[test]
+fn deferred_matches_resume_identically_from_snapshots() {
+    let mut state = create_match(1);
+    state.advance_frame().expect("frame advances");
+
+    let mut restored = GameState::from_bytes(&state.to_bytes()).expect("snapshot decodes");
+    assert_eq!(restored.frame_work_budget, 1);
+    assert_eq!(restored.spawn_collision_resume(), Some(2));
+
+    state.advance_frame().expect("frame advances");
+    restored.advance_frame().expect("frame advances");
+    assert_eq!(restored.state_hash(), state.state_hash());
+    assert_eq!(survivors(&restored), survivors(&state));
+}
+
+#[test]
+fn deferred_passes_keep_the_canonical_pair_order() {
+    // Four overlapping spawns of different owners; every collision script folds its spawn id
+    // into a shared checksum (static0 = static0 * 3 + id) and counts its runs in static1
+    let mut witness = SpawnDefinition::from_def(Vec::new());
+    witness.collision_mask = 1;
+    witness.collision_script = compile(
+        "READ_PROP v0 SPAWN_DEF_STATIC0; READ_PROP v1 SPAWN_CORE_ID
+        WRAPPING_ADD v2 v0 v0; WRAPPING_ADD v2 v2 v0; WRAPPING_ADD v2 v2 v1
+        WRITE_PROP SPAWN_DEF_STATIC0 v2
+        READ_PROP v0 SPAWN_DEF_STATIC1; ASSIGN_BYTE v1 1; ADD_BYTE v0 v0 v1
+        WRITE_PROP SPAWN_DEF_STATIC1 v0; EXIT 1",
+    );
+    let run = |frame_work_budget: u32| {
+        let mut scenario = Scenario::new().spawn(witness.clone());
+        for owner in 0..4 {
+            scenario = scenario.character(owner, 16 + owner as i16 * 48, 192);
+        }
+        let mut state = scenario.build();
+        state.frame_work_budget = frame_work_budget;
+        for owner in 0..4 {
+            let mut spawn =
+                SpawnInstance::new(0, owner, (Fixed::from_int(120), Fixed::from_int(80)));
+            spawn.core.size = (4, 4);
+            spawn.life_span = 100;
+            state.insert_spawn(spawn).expect("spawn fits");
+        }
+        // Run until a pass completes (a pass never takes more frames than there are spawns)
+        state.advance_frame().expect("frame advances");
+        let mut frames = 1;
+        while state.spawn_collision_resume().is_some() && frames < 8 {
+            state.advance_frame().expect("frame advances");
+            frames += 1;
+        }
+        (state.static_vars.spawns[0], frames)
+    };
+
+    let (unbudgeted, frames) = run(0);
+    assert_eq!(frames, 1);
+    assert_eq!(unbudgeted[1], 12, "six pairs, two scripts each");
+    let (budgeted, frames) = run(1);
+    assert!(frames > 1);
+    assert_eq!(budgeted, unbudgeted);
+}
@@ -0,0 +1,118 @@
+//! Entities leaving the world follow the boundary policy configured for their kind
+
+mod common;
+
+use common::{advance, Scenario};
+use robot_masters_engine::entity::{SpawnDefinition, SpawnInstance};
+use robot_masters_engine::event::{GameEventKind, RemovalReason};
+use robot_masters_engine::math::Fixed;
+use robot_masters_engine::physics::BoundaryPolicy;
+use robot_masters_engine::state::GameState;
+
+/// Empty, weightless arena; character 0 drifts left from x = 4, spawn 0 (8x8) drifts right
+/// from x = 244, both 2 pixels a frame
+fn create_match(character_boundary: BoundaryPolicy, spawn_boundary: BoundaryPolicy) -> GameState {
+    let mut state = Scenario::new()
+        .map(&[])
+        .character(0, 4, 100)
+        .with(|character| character.core.vel = (Fixed::from_int(-2), Fixed::ZERO))
+        .character(1, 120, 100)
+        .spawn(SpawnDefinition::from_def(Vec::new()))
+        .build();
+    state.gravity = (Fixed::ZERO, Fixed::ZERO);
+    state.character_boundary = character_boundary;
+    state.spawn_boundary = spawn_boundary;
+    state.boundary_damage = 5;
+
+    let mut spawn = SpawnInstance::new(0, 1, (Fixed::from_int(244), Fixed::from_int(40)));
+    spawn.core.size = (8, 8);
+    spawn.core.vel = (Fixed::from_int(2), Fixed::ZERO);
+    spawn.life_span = 200;
+    spawn.health = 12;
+    state.insert_spawn(spawn).expect("spawn fits");
+    state
+}
+
+fn spawn_x(state: &GameState) -> Option<i32> {
+    state
+        .spawn_instances()
+        .first()
+        .map(|spawn| spawn.core.pos.0.to_int())
+}
+
+fn removals(state: &GameState) -> Vec<RemovalReason> {
+    state
+        .events
+        .iter()
+        .filter_map(|event| match event.kind {
+            GameEventKind::SpawnRemoved { reason, .. } => Some(reason),
+            _ => None,
+        })
+        .collect()
+}
+
+#[test]
+fn clamped_entities_stop_at_the_edge() {
+    let mut state = create_match(BoundaryPolicy::Clamp, BoundaryPolicy::Clamp);
+    advance(&mut state, 10);
+
+    let character = &state.characters[0].core;
+    assert_eq!(character.pos.0, Fixed::ZERO);
+    assert_eq!(character.vel.0, Fixed::ZERO);
+    assert_eq!(spawn_x(&state), Some(248));
+    assert_eq!(state.characters[0].health, 100);
+}
+
+#[test]
+fn wrapped_entities_reenter_from_the_opposite_edge() {
+    let mut state = create_match(BoundaryPolicy::Wrap, BoundaryPolicy::Wrap);
+    // The spawn is fully outside once it reaches x = 256, six frames in
+    advance(&mut state, 5);
+    assert_eq!(spawn_x(&state), Some(254));
+    advance(&mut state, 1);
+    assert_eq!(spawn_x(&state), Some(0));
+
+    // The 16 pixel wide character leaves past x = -16, ten frames in
+    advance(&mut state, 4);
+    assert_eq!(state.characters[0].core.pos.0, Fixed::from_int(240));
+    assert_eq!(state.characters[0].core.vel.0, Fixed::from_int(-2));
+}
+
+#[test]
+fn despawned_spawns_leave_once_fully_outside() {
+    let mut state = create_match(BoundaryPolicy::Despawn, BoundaryPolicy::Despawn);
+    advance(&mut state, 5);
+    assert_eq!(spawn_x(&state), Some(254));
+    advance(&mut state, 1);
+    assert_eq!(spawn_x(&state), None);
+    assert_eq!(removals(&state), vec![RemovalReason::OutOfBounds]);
+
+    // Characters are never removed by the boundary; they are clamped instead
+    advance(&mut state, 4);
+    assert_eq!(state.characters[0].core.pos.0, Fixed::ZERO);
+}
+
+#[test]
+fn damaging_boundaries_hurt_entities_touching_outside() {
+    let mut state = create_match(BoundaryPolicy::Damage, BoundaryPolicy::Damage);
+    // Both poke outside on the third frame, the character past x = 0 and the spawn past
+    // x = 248, and are clamped back and damaged
+    advance(&mut state, 2);
+    assert_eq!(state.characters[0].health, 100);
+    advance(&mut state, 1);
+    assert_eq!(state.characters[0].health, 95);
+    assert_eq!(state.characters[0].core.pos.0, Fixed::ZERO);
+    assert_eq!(spawn_x(&state), Some(248));
+    assert_eq!(state.spawn_instances()[0].health, 7);
+
+    // Velocity into the edge is cancelled, so the damage stops
+    advance(&mut state, 3);
+    assert_eq!(state.characters[0].health, 95);
+
+    // A spawn run down to zero health by the boundary is removed
+    let mut state = create_match(BoundaryPolicy::Damage, BoundaryPolicy::Damage);
+    state.boundary_damage = 12;
+    advance(&mut state, 3);
+    assert_eq!(spawn_x(&state), None);
+    assert_eq!(removals(&state), vec![RemovalReason::OutOfBounds]);
+}
@@ -29,6 +29,18 @@ declare module 'robot-masters-wasm' {
      */
     static validateConfig(configJson: string): string
 
+    /**
+     * Get engine constants (timing, arena size, limits, opcodes, property addresses)
+     * @returns JSON string of EngineConstantsJson
+     */
+    static getEngineConstantsJson(): string
+
+    /**
+     * Get the per-frame phase order and per-phase entity iteration order
+     * @returns JSON string of PipelineInfoJson
+     */
+    static getPipelineInfoJson(): string
+
     /**
      * Check if the wrapper has been properly initialized with a configuration
      * @returns true if initialized, false otherwise
@@ -44,6 +56,60 @@ declare module 'robot-masters-wasm' {
      */
     newGame(): void
 
+    /**
+     * Rebuild the game from the stored configuration with a different seed
+     * Skips JSON parsing and validation; the stored configuration keeps its original seed
+     * @param seed u16 seed for the new game
+     * @throws Error if game initialization fails
+     */
+    resetWithSeed(seed: number): void
+
+    /**
+     * Save the complete match (definitions, instances, cooldowns, RNG position) as bytes
+     * @returns Versioned binary snapshot for loadState()
+     * @throws Error if game is not initialized
+     */
+    saveState(): Uint8Array
+
+    /**
+     * Resume a match saved with saveState(), replacing the current game
+     * The stored configuration is left untouched; the previous game is kept on failure
+     * @param bytes Snapshot returned by saveState()
+     * @throws Error if the snapshot is malformed
+     */
+    loadState(bytes: Uint8Array): void
+
+    /**
+     * Export the current game's recording: starting snapshot plus per-frame state hashes
+     * @returns Versioned replay bytes for importReplay()
+     * @throws Error if game is not initialized
+     */
+    exportReplay(): Uint8Array
+
+    /**
+     * Re-simulate a replay and compare every frame's state hash with the recording
+     * A verified replay replaces the current game with its final state
+     * @param bytes Replay returned by exportReplay()
+     * @returns JSON string of ReplayVerificationJson
+     * @throws Error if the replay is malformed
+     */
+    importReplay(bytes: Uint8Array): string
+
+    /**
+     * Start streaming the current game for spectators (restarts a running stream)
+     * New games, loaded states and imported replays reopen a started stream
+     * @param keyframeInterval Frames between periodic keyframes (0 = only at the start and after batch advances)
+     * @throws Error if game is not initialized
+     */
+    startStream(keyframeInterval: number): void
+
+    /**
+     * Drain everything the spectator stream recorded since the previous call
+     * @returns JSON string of StreamChunkJson
+     * @throws Error if no stream was started
+     */
+    takeStreamChunk(): string
+
     /**
      * Check if the game has been initialized and is ready for frame execution
      * @returns true if game is initialized, false otherwise
@@ -57,6 +123,58 @@ declare module 'robot-masters-wasm' {
      */
     stepFrame(): void
 
+    /**
+     * Advance the game state by up to `frames` frames without per-frame JSON caching
+     * Produces the same state as calling stepFrame() the same number of times
+     * @param frames Number of frames to advance (u16)
+     * @returns Number of frames actually advanced (stops early if the game ends)
+     * @throws Error if game is not initialized or frame stepping fails
+     */
+    advanceFramesUnchecked(frames: number): number
+
+    /**
+     * Advance the game until it ends and return the match summary
+     * @returns JSON string of MatchSummaryJson
+     * @throws Error if game is not initialized, frame_limit is 0 or frame stepping fails
+     */
+    runToEnd(): string
+
+    /**
+     * Get the match result under the configured victory condition
+     * While playing, winner_group is the group that would win if time ran out now
+     * @returns JSON string of MatchResultJson
+     * @throws Error if game is not initialized
+     */
+    getGameResultJson(): string
+
+    /**
+     * Get aggregate stats (survivors, health totals, kills, damage) for every group
+     * @returns JSON string of GroupStandingJson[]
+     * @throws Error if game is not initialized
+     */
+    getTeamStatsJson(): string
+
+    /**
+     * Get a heuristic score and win chance for every group (per mille), for win bars
+     * @returns JSON string of GroupEvaluationJson[]
+     * @throws Error if game is not initialized
+     */
+    getEvaluationJson(): string
+
+    /**
+     * Get a deterministic 32-bit hash of the current game state
+     * @returns FNV-1a hash of all simulation-relevant state
+     * @throws Error if game is not initialized
+     */
+    getStateHash(): number
+
+    /**
+     * Get the state hash with per-section checksums for locating divergence
+     * @returns JSON string of StateChecksumJson
+     * @throws Error if game is not initialized
+     */
+    getStateChecksum(): string
+
     /**
      * Check if the game has ended (reached maximum frames or other end condition)
      * @returns true if game has ended, false otherwise
@@ -95,6 +213,22 @@ declare module 'robot-masters-wasm' {
      */
     getStateJson(): string
 
+    /**
+     * Get the render-relevant state as a compact little-endian binary frame
+     * Read with a DataView; see the layout table in API.md (Fixed values are raw i16, / 32)
+     * @returns Binary frame bytes
+     * @throws Error if game is not initialized
+     */
+    getStateBinary(): Uint8Array
+
+    /**
+     * Get the binary render frame as a standalone ArrayBuffer for transfer to a worker
+     * Hydrate it on the receiving side with StateView.fromTransferable
+     * @returns Binary frame buffer
+     * @throws Error if game is not initialized
+     */
+    getStateTransferable(): ArrayBuffer
+
     /**
      * Get characters data as JSON string
      * Returns detailed character information including position, health, energy, and status effects
@@ -119,6 +253,141 @@ declare module 'robot-masters-wasm' {
      */
     getStatusEffectsJson(): string
 
+    /**
+     * Get a character's action, condition and status effect instances as JSON string
+     * Includes the script variables each instance keeps between runs, for debugging tools
+     * @param characterId - Character id (unknown characters return empty lists)
+     * @returns CharacterInstancesJson as string
+     * @throws Error if game is not initialized
+     */
+    getInstancesJson(characterId: number): string
+
+    /**
+     * Get presentation events emitted during the most recent frame as JSON string
+     * Events never affect gameplay (e.g. camera hints for spectator mode)
+     * @returns JSON array of GameEventJson as string
+     * @throws Error if game is not initialized
+     */
+    getEventsJson(): string
+
+    /**
+     * Take every event emitted since the last call, oldest first, emptying the queue
+     * The queue keeps at most 4096 events; the oldest past that are counted in `dropped`
+     * @returns DrainedEventsJson as string
+     * @throws Error if game is not initialized
+     */
+    drainEvents(): string
+
+    /**
+     * Get retained events emitted during frames firstFrame..=lastFrame as JSON string
+     * Covers the configured event_history window so late-mounting views can backfill
+     * @returns JSON array of GameEventJson as string
+     * @throws Error if game is not initialized
+     */
+    getEventHistoryJson(firstFrame: number, lastFrame: number): string
+
+    /**
+     * Get readable combat log lines for retained events in frames firstFrame..=lastFrame
+     * Characters are named by the `name` field of their configuration
+     * @returns JSON array of strings as string
+     * @throws Error if game is not initialized
+     */
+    getCombatLogJson(firstFrame: number, lastFrame: number): string
+
+    // Reward methods
+
+    /**
+     * Deterministically roll post-match rewards from a final state hash and a reward table
+     * The same hash and table always yield the same drops, so results can be verified on-chain
+     * @param stateHash - 32-bit hash of the final game state
+     * @param rewardTableJson - JSON string of a RewardTableJson
+     * @returns JSON string of RewardDropJson[]
+     * @throws Error if the table is malformed or has no positive weights
+     */
+    static rollRewards(stateHash: number, rewardTableJson: string): string
+
+    // Content authoring methods
+
+    /**
+     * Run a single action in a sandbox (all behaviors cleared) and simulate the aftermath
+     * @param configJson - JSON configuration to build the sandbox from
+     * @param characterIndex - Character performing the action
+     * @param actionIndex - Action definition to force once
+     * @param frames - Frames to simulate after the action
+     * @returns JSON string of ActionTestResultJson
+     * @throws Error if the configuration is invalid or an index is out of range
+     */
+    static testAction(
+      configJson: string,
+      characterIndex: number,
+      actionIndex: number,
+      frames: number
+    ): string
+
+    /**
+     * Evaluate a condition against a captured state and trace every executed instruction
+     * @param stateBytes - Snapshot returned by saveState()
+     * @param characterIndex - Character evaluating the condition
+     * @param conditionIndex - Condition definition to evaluate
+     * @returns JSON string of ConditionEvaluationJson
+     * @throws Error if the snapshot is invalid or an index is out of range
+     */
+    static evaluateConditionAgainstState(
+      stateBytes: Uint8Array,
+      characterIndex: number,
+      conditionIndex: number
+    ): string
+
+    /**
+     * Evaluate a condition against a getStateJson() capture (no RNG, static or instance vars)
+     * @param configJson - JSON configuration the captured match was built from
+     * @param stateJson - getStateJson() capture
+     * @param characterIndex - Character evaluating the condition
+     * @param conditionIndex - Condition definition to evaluate
+     * @returns JSON string of ConditionEvaluationJson
+     * @throws Error if the configuration or capture is invalid or an index is out of range
+     */
+    static evaluateConditionAgainstStateJson(
+      configJson: string,
+      stateJson: string,
+      characterIndex: number,
+      conditionIndex: number
+    ): string
+
+    /**
+     * Disassemble a configured action script into a mnemonic listing
+     * @param actionId - Action definition to disassemble
+     * @returns One instruction per line, e.g. "0003: ReadProp v0, CHARACTER_HEALTH"
+     * @throws Error if the id is out of range
+     */
+    disassembleAction(actionId: number): string
+
+    /**
+     * Disassemble a configured condition script into a mnemonic listing
+     * @param conditionId - Condition definition to disassemble
+     * @returns One instruction per line, e.g. "0000: Exit 1"
+     * @throws Error if the id is out of range
+     */
+    disassembleCondition(conditionId: number): string
+
+    /**
+     * Export a configured character with exactly the definitions it references
+     * @param characterIndex - Character to export
+     * @returns JSON string of CharacterCardJson
+     * @throws Error if the index or one of its references is out of range
+     */
+    exportCharacterCard(characterIndex: number): string
+
+    /**
+     * Import a character card, appending its definitions and remapping its references
+     * @param configJson - JSON configuration to import into
+     * @param cardJson - Card from exportCharacterCard()
+     * @param group - Group (team) the character joins
+     * @returns Validated JSON configuration with the character appended
+     * @throws Error if the card is invalid or the result fails validation
+     */
+    static importCharacterCard(configJson: string, cardJson: string, group: number): string
+
     // Error handling and recovery methods
 
     /**
@@ -148,13 +417,116 @@ declare module 'robot-masters-wasm' {
     getHealthInfo(): string
   }
 
+  /**
+   * Read-only view of a binary render frame received from getStateTransferable
+   */
+  export class StateView {
+    /**
+     * Hydrate a view from a transferred frame buffer
+     * @throws Error if the buffer is truncated or uses another layout version
+     */
+    static fromTransferable(buffer: ArrayBuffer): StateView
+    frame(): number
+    isEnded(): boolean
+    characterCount(): number
+    spawnCount(): number
+    statusEffectCount(): number
+    /** Record at index (not id), undefined past the end */
+    character(index: number): CharacterRecord | undefined
+    spawn(index: number): SpawnRecord | undefined
+    statusEffect(index: number): StatusEffectRecord | undefined
+    free(): void
+  }
+
+  /** Character record of a render frame; Fixed values are raw (divide by 32) */
+  export interface CharacterRecord {
+    readonly id: number
+    readonly group: number
+    readonly facing: number
+    readonly gravity_dir: number
+    readonly pos_x: number
+    readonly pos_y: number
+    readonly vel_x: number
+    readonly vel_y: number
+    readonly health: number
+    readonly health_cap: number
+    readonly energy: number
+    readonly energy_cap: number
+    readonly width: number
+    readonly height: number
+    /** 255 = none */
+    readonly locked_action: number
+    /** 1=top, 2=right, 4=bottom, 8=left */
+    readonly collision: number
+    /** 1=in combat */
+    readonly flags: number
+  }
+
+  /** Spawn record of a render frame; Fixed values are raw (divide by 32) */
+  export interface SpawnRecord {
+    readonly id: number
+    readonly spawn_id: number
+    readonly owner_id: number
+    readonly element: number
+    readonly pos_x: number
+    readonly pos_y: number
+    readonly vel_x: number
+    readonly vel_y: number
+    readonly rotation: number
+    readonly life_span: number
+    readonly health: number
+    readonly width: number
+    readonly height: number
+  }
+
+  /** Status effect record of a render frame */
+  export interface StatusEffectRecord {
+    readonly definition_id: number
+    readonly character_id: number
+    readonly stack_count: number
+    readonly life_span: number
+    readonly duration: number
+  }
+
   // Type definitions for JSON data structures
 
   export type GameStatus = 'not_initialized' | 'playing' | 'ended'
 
   export interface GameConfig {
     seed: number
+    /** Vertical gravity as [num, den] (legacy) or a per-axis vector [[x_num, x_den], [y_num, y_den]] */
+    gravity?: [number, number] | [[number, number], [number, number]]
     tilemap: number[][] // 15x16 tilemap as nested arrays
+    /** Elemental damage for hazard tiles (10) */
+    hazards?: HazardJson[]
+    /** World boundary policies, defaults to clamp characters / despawn spawns */
+    boundary?: BoundaryConfigJson
+    /** Recent-damage window in frames for "in combat" tracking (default 180, 0 = disabled) */
+    damage_window?: number
+    /** Frames a defeated character stays down before reviving (default 0 = never) */
+    respawn_delay?: number
+    /** Raise script errors for invalid entity IDs or incompatible property addresses (default false) */
+    strict_mode?: boolean
+    /** Script CALLs that may be in progress at once (default 4) */
+    max_call_depth?: number
+    /** Instructions one script run may execute, CALLs and loops included (default 4096, 0 = unlimited) */
+    script_step_budget?: number
+    /** Script instructions per frame across all scripts (default 0 = unlimited) */
+    frame_step_budget?: number
+    /** Script steps plus collision checks per frame before spawn interception defers to the next frame (default 0 = never) */
+    frame_work_budget?: number
+    /** Frames of events kept for getEventHistoryJson (default 300, 0 = disabled) */
+    event_history?: number
+    /** Opcode table version the scripts were written for (default: the current one) */
+    opcode_version?: number
+    /** Match length in frames (default 3840, 0 = unlimited training session) */
+    frame_limit?: number
+    /** Victory condition (default timeout: most health left wins) */
+    victory?: VictoryConfigJson
+    /** Spawns can hit allies of their owning character (default true) */
+    friendly_fire?: boolean
+    /** Platform spawns placed by the arena at match start */
+    platforms?: PlatformPlacementJson[]
     characters: CharacterDefinitionJson[]
     actions: ActionDefinitionJson[]
     conditions: ConditionDefinition[]
@@ -162,10 +534,29 @@ declare module 'robot-masters-wasm' {
     status_effects: StatusEffectDefinitionJson[]
   }
 
+  /**
+   * World boundary policy: 0=Clamp, 1=Wrap, 2=Despawn, 3=Damage
+   */
+  export type BoundaryPolicy = 0 | 1 | 2 | 3
+
+  /**
+   * Policies applied when entities leave the 256x240 world
+   */
+  export interface BoundaryConfigJson {
+    /** Policy for characters (Despawn behaves like Clamp) */
+    character: BoundaryPolicy
+    /** Policy for spawns */
+    spawn: BoundaryPolicy
+    /** Damage per frame applied by the Damage policy (u16) */
+    damage: number
+  }
+
   /**
    * Character definition with enhanced properties for combat and movement
    */
   export interface CharacterDefinitionJson {
+    /** Built-in template ('bruiser', 'sniper' or 'support') supplying unspecified fields (parse time only) */
+    archetype?: 'bruiser' | 'sniper' | 'support'
     id: number
     group: number
     /** Position as [[x_numerator, x_denominator], [y_numerator, y_denominator]] for deterministic Fixed-point values */
@@ -186,6 +577,8 @@ declare module 'robot-masters-wasm' {
     jump_force: [number, number]
     /** Movement speed as [numerator, denominator] for deterministic Fixed-point value */
     move_speed: [number, number]
+    /** Max fall speed while pressing into a wall in the air as [numerator, denominator] (default: no wall slide) */
+    wall_slide_speed?: [number, number]
     /** Armor values for all 9 elements */
     armor: [
       number,
@@ -212,8 +605,33 @@ declare module 'robot-masters-wasm' {
     target_type: number
     /** Behavior pairs as [condition_id, action_id] */
     behaviors: [number, number][]
+    /** Enemy detection range in pixels (default 0 = detects nothing) */
+    detection_radius?: number
+    /** Solid tiles block detection (default false) */
+    detection_requires_los?: boolean
+    /** What energy above the cap turns into, see `energy_overflow_modes` (default 0 = lost) */
+    overflow_mode?: number
+    /** Per-frame script quota shared by conditions, actions, owned spawns and status effects */
+    quota?: ScriptQuotaJson
+    /** Display name for getCombatLogJson and state JSON (default "Character {id}") */
+    name?: string
+    /** Sprite key echoed into state JSON (ignored by the engine) */
+    sprite?: string
   }
 
+  /** Per-frame script limits (0 = unlimited) */
+  export interface ScriptQuotaJson {
+    max_instructions?: number
+    max_property_writes?: number
+    max_spawns?: number
+  }
+
+  /**
+   * Script bytecode, or assembler text compiled when the configuration is parsed
+   * (e.g. "READ_PROP v0 CHARACTER_HEALTH; EXIT 1"); serialized configurations use bytes
+   */
+  export type Script = number[] | string
+
   /**
    * Action definition with simplified structure (interval and duration removed)
    */
@@ -224,29 +642,34 @@ declare module 'robot-masters-wasm' {
     cooldown: number
     /** Action arguments array */
     args: [number, number, number, number, number, number, number, number]
+    /** Constant pool read by LOAD_CONST operators (u16 entries, default empty) */
+    constants?: number[]
     /** Spawn IDs that this action can create */
     spawns: [number, number, number, number]
     /** Script bytecode for action execution */
-    script: number[]
+    script: Script
   }
 
   export interface ConditionDefinition {
     energy_mul: number // Fixed-point value as float for JSON
     args: [number, number, number, number, number, number, number, number]
-    script: number[]
+    constants?: number[] // Constant pool read by LOAD_CONST operators
+    script: Script
   }
 
   /**
    * Spawn definition with enhanced combat properties
    */
   export interface SpawnDefinitionJson {
+    /** Id of an earlier spawn definition to inherit unspecified fields from (parse time only) */
+    extends?: number
     /** Base damage value (u16 type, 0-65535) */
     damage_base: number
-    /** Damage range for random variation (u16 type, 0-65535) */
+    /** Up to this much damage is added to each hit, rolled per hit (u16 type, 0-65535) */
     damage_range: number
-    /** Critical hit chance percentage (u8 type, 0-100) */
+    /** Critical hit chance out of 255 (u8 type, 255 = every hit) */
     crit_chance: number
-    /** Critical hit damage multiplier (u8 type, 1-100) */
+    /** Percent of the damage dealt on a critical hit (u8 type, 150 = 1.5x) */
     crit_multiplier: number
     /** Maximum health for this spawn type */
     health_cap: number
@@ -256,16 +679,411 @@ declare module 'robot-masters-wasm' {
     element?: number
     /** Application chance percentage (u8 type, 0-100) */
     chance: number
+    /** Spawn-vs-spawn collision layers; spawns sharing a bit intercept each other (default 0) */
+    collision_mask?: number
+    /** Full damage up to this distance in pixels from the spawn origin (default 0) */
+    falloff_start?: number
+    /** Damage decays linearly to zero at this distance (default 0 = no falloff) */
+    falloff_end?: number
+    /** Max per-axis velocity jitter as [numerator, denominator], rolled at creation (default none) */
+    velocity_spread?: [number, number]
+    /** Max lifetime jitter in frames (+/-), rolled at creation (default 0) */
+    duration_variance?: number
+    /** Max base damage jitter (+/-), rolled at creation (default 0) */
+    damage_variance?: number
+    /** Energy drained from a hit character; armor does not apply (default 0) */
+    energy_damage?: number
+    /** Percent of drained energy given to the owning character, up to its energy cap (default 0) */
+    energy_steal?: number
+    /** Tile type written into the empty tiles the spawn covers at creation and restored when it is removed; tiles overlapping a character are skipped (default 0 = none) */
+    terrain?: number
+    /** Grapple mode, see `grapple_modes`; grapples pull their owner to a caught point or a hit character to the owner (default 0 = none) */
+    grapple?: number
+    /** Grapple pull speed in pixels per frame as [numerator, denominator]; grapples need a positive speed */
+    grapple_speed?: [number, number]
+    /** Frames before a grapple pull breaks (default 0 = no limit) */
+    grapple_frames?: number
+    /** Moving platform: characters land on its top edge and ride along, and it never hits them (default false) */
+    platform?: boolean
+    /** Speed in pixels per frame a hit character is pushed away at, as [numerator, denominator], scaled by 100 / weight (default 0 = none) */
+    knockback?: [number, number]
+    /** Frames a hit character's behaviors are suspended (default 0 = none) */
+    hitstun?: number
     /** Spawn arguments array */
     args: [number, number, number, number, number, number, number, number]
+    /** Constant pool read by LOAD_CONST operators (u16 entries, default empty) */
+    constants?: number[]
     /** Nested spawn IDs that this spawn can create */
     spawns: [number, number, number, number]
     /** Script for spawn behavior logic */
-    behavior_script: number[]
+    behavior_script: Script
     /** Script for collision handling */
-    collision_script: number[]
+    collision_script: Script
     /** Script for despawn cleanup */
-    despawn_script: number[]
+    despawn_script: Script
+    /** Display name echoed into spawn state JSON (ignored by the engine) */
+    name?: string
+    /** Sprite key echoed into spawn state JSON (ignored by the engine) */
+    sprite?: string
+  }
+
+  /**
+   * Weighted entry in a post-match reward table
+   */
+  export interface RewardEntryJson {
+    /** Item identifier (u16) */
+    item_id: number
+    /** Relative pick weight (u16, 0 = never) */
+    weight: number
+    /** Inclusive minimum quantity (u8) */
+    min_quantity: number
+    /** Inclusive maximum quantity (u8) */
+    max_quantity: number
+  }
+
+  /**
+   * Post-match reward table
+   */
+  export interface RewardTableJson {
+    /** Number of picks made from the table (u8) */
+    rolls: number
+    /** Weighted entries */
+    entries: RewardEntryJson[]
+  }
+
+  /**
+   * Single rolled reward
+   */
+  export interface RewardDropJson {
+    item_id: number
+    quantity: number
+  }
+
+  /**
+   * Platform spawn placed by the arena (owner type 0) at match start
+   */
+  export interface HazardJson {
+    /** [x, y] of a hazard tile */
+    position: [number, number]
+    /** Element (0-8) checked against the armor of the characters it hurts */
+    element: number
+    /** u16 - Damage per second before armor */
+    damage_per_second: number
+  }
+
+  export interface PlatformPlacementJson {
+    /** Spawn definition with `platform` set */
+    spawn_id: number
+    /** [[x_num, x_den], [y_num, y_den]] */
+    position: [[number, number], [number, number]]
+  }
+
+  /**
+   * Victory condition: 0=Timeout, 1=LastTeamStanding, 2=KillTarget, 3=HealthPercent, 4=Survival
+   */
+  export interface VictoryConfigJson {
+    condition: number
+    /** Kills needed for KillTarget (1+), group that must survive for Survival */
+    target?: number
+    /**
+     * Rules tried in order on a tie at the frame limit (default [] = draw)
+     * 0=HealthPercent, 1=DamageDealt, 2=TotalHealth, 3=Kills, 4=Survivors
+     */
+    tie_breaks?: number[]
+  }
+
+  /**
+   * Match result returned by GameWrapper.getGameResultJson()
+   */
+  export interface MatchResultJson {
+    frame: number
+    condition: number
+    /** Kill target or surviving group (0 when unused) */
+    target: number
+    ended: boolean
+    /** 0=FrameLimit, 1=LastTeamStanding, 2=KillTarget, 3=Eliminated (null while playing) */
+    reason: number | null
+    /** Winning (or currently leading) group, null on a draw */
+    winner_group: number | null
+    /** Tie-break rule that decided the winner (null when none was needed) */
+    tie_break: number | null
+    standings: GroupStandingJson[]
+  }
+
+  /**
+   * Standing of one group in a MatchResultJson or GameWrapper.getTeamStatsJson()
+   */
+  export interface GroupStandingJson {
+    group: number
+    /** Characters with health left */
+    survivors: number
+    /** Defeated characters waiting to revive */
+    respawning: number
+    health: number
+    health_cap: number
+    /** Characters of other groups defeated by the group */
+    kills: number
+    damage_dealt: number
+    damage_taken: number
+  }
+
+  /**
+   * Heuristic standing of one group returned by GameWrapper.getEvaluationJson(); shares are
+   * per mille (0-1000)
+   */
+  export interface GroupEvaluationJson {
+    group: number
+    /** Health left out of the group's total health cap */
+    health: number
+    /** Closeness of the standing characters to the arena center */
+    position: number
+    /** Share of the live spawns owned by characters */
+    spawns: number
+    /** Health, position and spawns weighted 6:2:2 */
+    score: number
+    /** Share of the scores of all groups, rounded down; 0 once eliminated */
+    win_chance: number
+  }
+
+  /**
+   * Match outcome returned by GameWrapper.runToEnd()
+   */
+  export interface MatchSummaryJson {
+    frames: number
+    ended: boolean
+    /** Winning group, null for a draw (no survivors or tied remaining health) */
+    winner_group: number | null
+    /** Ids of characters with health left */
+    survivors: number[]
+    characters: CharacterSummaryJson[]
+    /** Spawn instances created during the match */
+    spawns_created: number
+    /** Final state hash (e.g. for rollRewards) */
+    state_hash: number
+  }
+
+  /**
+   * Final standing of one character in a MatchSummaryJson
+   */
+  export interface CharacterSummaryJson {
+    id: number
+    group: number
+    health: number
+    energy: number
+    damage_dealt: number
+    damage_taken: number
+  }
+
+  /**
+   * Per-section checksums returned by GameWrapper.getStateChecksum()
+   */
+  export interface StateChecksumJson {
+    frame: number
+    /** Same value as getStateHash() */
+    state: number
+    /** Seed and RNG stream positions */
+    rng: number
+    /** One checksum per character, in character order */
+    characters: number[]
+    spawns: number
+    status_effects: number
+  }
+
+  /**
+   * Verification result returned by GameWrapper.importReplay()
+   */
+  export interface ReplayVerificationJson {
+    /** Every recorded checksum was reproduced */
+    verified: boolean
+    /** Frames recorded in the replay */
+    frame_count: number
+    /** Frames matched before the first desync */
+    frames_verified: number
+    /** Frame counter after the first diverging frame */
+    desync_frame: number | null
+    /** Recorded state hash at the desync */
+    expected_hash: number | null
+    /** Re-simulated state hash at the desync */
+    actual_hash: number | null
+  }
+
+  /**
+   * Opening description of a spectator stream
+   */
+  export interface StreamHeaderJson {
+    /** Stream layout version of the producer */
+    version: number
+    /** Frames between periodic keyframes (0 = only forced ones) */
+    keyframe_interval: number
+    /** Frame counter of the opening keyframe */
+    start_frame: number
+  }
+
+  /**
+   * Snapshot a spectator can resume from with GameWrapper.loadState()
+   */
+  export interface StreamKeyframeJson {
+    /** Frame counter of the snapshot; events from this frame on follow it */
+    frame: number
+    /** State hash of the snapshot, for checking a restore */
+    state_hash: number
+    /** Snapshot bytes */
+    state: number[]
+  }
+
+  /**
+   * Spectator stream chunk returned by GameWrapper.takeStreamChunk()
+   */
+  export interface StreamChunkJson {
+    /** 0 for the chunk opening the stream, then counting up without gaps */
+    sequence: number
+    /** Only on the opening chunk */
+    header: StreamHeaderJson | null
+    /** Oldest first */
+    keyframes: StreamKeyframeJson[]
+    /** Oldest first */
+    events: GameEventJson[]
+  }
+
+  /**
+   * Self-contained character returned by GameWrapper.exportCharacterCard()
+   */
+  export interface CharacterCardJson {
+    /** Card layout version (1) */
+    version: number
+    /** Behaviors index the card's conditions and actions */
+    character: CharacterDefinitionJson
+    conditions: ConditionDefinition[]
+    /** Spawn slot n references spawns[n - 1] (0 = empty) */
+    actions: ActionDefinitionJson[]
+    spawns: SpawnDefinitionJson[]
+  }
+
+  /**
+   * Sandbox result returned by GameWrapper.testAction()
+   */
+  export interface ActionTestResultJson {
+    /** Script error raised by the forced action, if any */
+    script_error: string | null
+    /** Frames simulated after the action (stops early if the match ends) */
+    frames_advanced: number
+    state: GameState
+    /** Events from the action and every simulated frame, in order */
+    events: GameEventJson[]
+  }
+
+  /**
+   * Engine state after one script instruction
+   */
+  export interface ScriptTraceStepJson {
+    /** Offset of the instruction in the script */
+    pos: number
+    /** CALLs in progress (0 = the evaluated script, 1+ = a subroutine's script) */
+    depth: number
+    opcode: number
+    /** Operator name (empty for unknown opcodes) */
+    operator: string
+    /** Byte variables after the instruction */
+    vars: number[]
+    /** Fixed-point variables after the instruction as [numerator, denominator] */
+    fixed: [number, number][]
+  }
+
+  /**
+   * Condition evaluation returned by GameWrapper.evaluateConditionAgainstState()
+   */
+  export interface ConditionEvaluationJson {
+    /** Condition passed (non-zero exit value) */
+    result: boolean
+    exit_value: number
+    /** Script error raised during evaluation, if any */
+    script_error: string | null
+    /** Executed instructions, in order */
+    trace: ScriptTraceStepJson[]
+  }
+
+  /**
+   * Entity iteration order within a frame phase
+   */
+  export type EntityOrder =
+    | 'world'
+    | 'characters_by_index'
+    | 'characters_then_spawns'
+    | 'character_effects'
+    | 'character_behaviors'
+    | 'spawn_pairs'
+    | 'spawns_by_index'
+    | 'character_spawn_pairs'
+
+  /**
+   * Single phase of the per-frame pipeline
+   */
+  export interface PipelinePhaseJson {
+    /** Position in the frame pipeline */
+    index: number
+    name: string
+    entity_order: EntityOrder
+    /** Condition, action, spawn or status effect scripts run in this phase */
+    runs_scripts: boolean
+  }
+
+  /**
+   * Pipeline description returned by GameWrapper.getPipelineInfoJson()
+   */
+  export interface PipelineInfoJson {
+    /** Phases in execution order */
+    phases: PipelinePhaseJson[]
+  }
+
+  /**
+   * Engine constants exported by GameWrapper.getEngineConstantsJson()
+   */
+  export interface EngineConstantsJson {
+    frames_per_second: number
+    game_duration_seconds: number
+    max_frames: number
+    default_damage_window: number
+    default_event_history: number
+    screen_width: number
+    screen_height: number
+    tile_size: number
+    tilemap_width: number
+    tilemap_height: number
+    element_count: number
+    max_characters: number
+    max_spawns: number
+    max_status_effects: number
+    max_script_length: number
+    max_script_variables: number
+    max_script_stack: number
+    /** Current opcode table version */
+    opcode_version: number
+    /** Most iterations a REPEAT runs */
+    max_repeat_count: number
+    /** Instructions per script run when script_step_budget is not configured */
+    default_script_step_budget: number
+    /** Overflow energy converted into one charge stack */
+    energy_per_charge: number
+    /** Most charge stacks a character holds */
+    max_charge_stacks: number
+    /** Operator name -> opcode */
+    operators: Record<string, number>
+    /** Property name -> address */
+    properties: Record<string, number>
+    /** Spawn collision script exit name -> value (CONSUME, BOUNCE, PASS) */
+    hit_results: Record<string, number>
+    /** TELEPORT operator mode name -> value (OFFSET, TARGET, RANDOM) */
+    teleport_modes: Record<string, number>
+    /** DASH operator direction name -> value (RIGHT .. UP_RIGHT clockwise, FACING) */
+    dash_directions: Record<string, number>
+    /** FIND_TARGET/ACQUIRE_TARGET query name -> value (NEAREST, LOWEST_HEALTH, HIGHEST_ENMITY, HIGHEST_AGGRO) */
+    target_queries: Record<string, number>
+    /** Spawn definition grapple mode name -> value (NONE, PULL_OWNER, PULL_TARGET) */
+    grapple_modes: Record<string, number>
+    /** PATH_DIRECTION_TO move name -> value (ARRIVED, LEFT, RIGHT, JUMP_LEFT, JUMP_RIGHT, JUMP_UP, NO_PATH) */
+    path_steps: Record<string, number>
+    /** Character energy overflow mode name -> value (NONE, SHIELD, CHARGE) */
+    energy_overflow_modes: Record<string, number>
   }
 
   /**
@@ -278,37 +1096,75 @@ declare module 'robot-masters-wasm' {
     stack_limit: number
     /** Whether to reset duration when stacking */
     reset_on_stack: boolean
+    /** Re-application rule: 0=Stack, 1=Refresh, 2=Ignore (default 0) */
+    stack_behavior?: number
     /** Application chance percentage (u8 type, 0-100) */
     chance: number
+    /** UI grouping: 0=neutral, 1=buff, 2=debuff (default 0) */
+    category?: number
+    /** Percent of incoming spawn damage returned to the attacker, per stack (default 0) */
+    reflect?: number
+    /** Force the afflicted character to target the character that applied it (default false) */
+    taunt?: boolean
+    /** Display priority, higher is listed first in status JSON (default 0, ignored by the simulation) */
+    priority?: number
+    /** Percent of the base hitbox size per stack while active (default 0 = no size change) */
+    scale?: number
     /** Status effect arguments array */
     args: [number, number, number, number, number, number, number, number]
+    /** Constant pool read by LOAD_CONST operators (u16 entries, default empty) */
+    constants?: number[]
     /** Spawn IDs that this status effect can create */
     spawns: [number, number, number, number]
+    /** Status effect IDs applied together with this one, sharing its lifetime (max 4) */
+    children?: number[]
     /** Script executed when status effect is applied */
-    on_script: number[]
+    on_script: Script
     /** Script executed each frame while active */
-    tick_script: number[]
+    tick_script: Script
     /** Script executed when status effect expires */
-    off_script: number[]
+    off_script: Script
   }
 
   export interface FrameInfo {
     frame: number
     status: GameStatus
+    /** Configured frame limit (0 = unlimited training session) */
     max_frames: number
     fps: number
     elapsed_seconds: number
-    remaining_seconds: number
+    /** null for unlimited sessions */
+    remaining_seconds: number | null
   }
 
   export interface GameState {
     frame: number
     seed: number
+    /** Vertical gravity as [numerator, denominator] */
+    gravity: [number, number]
+    /** Gravity vector as [[x_num, x_den], [y_num, y_den]] */
+    gravity_vector: [[number, number], [number, number]]
     status: GameStatus
     characters: CharacterStateJson[]
     spawns: SpawnStateJson[]
     status_effects: StatusEffectStateJson[]
+    /** Current tiles, including tiles written by terrain spawns */
     tilemap: number[][]
+    /** Tiles currently written by terrain spawns */
+    terrain: TerrainTileJson[]
+  }
+
+  /**
+   * Tile written by a terrain spawn for its lifetime
+   */
+  export interface TerrainTileJson {
+    spawn_instance_id: number
+    x: number
+    y: number
+    /** Tile type written by the spawn */
+    tile: number
+    /** Tile type restored when the spawn is removed */
+    previous: number
   }
 
   /**
@@ -361,16 +1217,100 @@ declare module 'robot-masters-wasm' {
     target_id?: number
     /** Target type: 1=Character, 2=Spawn */
     target_type: number
-    /** Entity size as [width, height] */
+    /** Taunting character forcing target_id; script retargets are ignored while set */
+    taunted_by?: number
+    /** Aggro toward each character id (index = id), raised by damage and taunts */
+    aggro: number[]
+    /** Current (scaled) hitbox size as [width, height] */
     size: [number, number]
+    /** Percent of base_size applied by size status effects (100 = unscaled) */
+    size_scale: number
+    /** Hitbox size without status effect scaling */
+    base_size: [number, number]
     /** Collision flags as [top, right, bottom, left] */
     collision: [boolean, boolean, boolean, boolean]
+    /** Dash in progress; velocity is ignored while dashing */
+    dash?: DashStateJson
+    /** Grapple pull in progress; velocity is ignored while pulled */
+    grapple?: GrappleStateJson
+    /** Falling while pressing into a wall, capped at the wall slide speed */
+    wall_sliding: boolean
+    /** Frames left in which behaviors are suspended after a knockback hit */
+    hitstun: number
+    /** Health reached zero; behaviors are skipped until the character revives */
+    dead: boolean
+    /** Frames until a defeated character revives (0 = not reviving) */
+    respawn_timer: number
+    /** What energy above the cap turns into, see `energy_overflow_modes` */
+    overflow_mode: number
+    /** Energy above the cap waiting for conversion */
+    overflow_energy: number
+    /** Overheal shield absorbing hit damage before health */
+    shield: number
+    /** Charge stacks built from overflow energy */
+    charge: number
     /** Currently locked action ID if any */
     locked_action?: number
     /** Active status effect instance IDs */
     status_effects: number[]
     /** Behavior pairs as [condition_id, action_id] */
     behaviors: [number, number][]
+    /** Cooldown state per behavior, same order as behaviors */
+    cooldowns: ActionCooldownJson[]
+    /** Running match statistics */
+    stats: CharacterStatsJson
+    /** Enemy detection range in pixels */
+    detection_radius: number
+    /** Bitmask of enemies detected this frame (bit n = character id n) */
+    detected_enemies: number
+    /** Tile type under the character's feet */
+    ground_tile: number
+    /** [x, y] tile coordinates under the feet (255 = outside the map) */
+    ground_tile_pos: [number, number]
+    /** Tile type just beyond the facing edge */
+    facing_tile: number
+    /** Terrain contact bits (1 slope, 2 platform, 4 ladder, 8 hazard) */
+    terrain_contact: number
+    /** Display name from the character configuration */
+    name: string | null
+    /** Sprite key from the character configuration */
+    sprite: string | null
+  }
+
+  /**
+   * Running per-character match statistics
+   */
+  export interface CharacterStatsJson {
+    /** Total damage dealt to other characters */
+    damage_dealt: number
+    /** Total damage taken from any source */
+    damage_taken: number
+    /** Frames since the character last dealt damage (or since match start) */
+    frames_since_hit: number
+    /** Frames since the character last took damage (or since match start) */
+    frames_since_damaged: number
+    /** Damage taken within the damage window */
+    recent_damage: number
+    /** Damage was taken within the damage window */
+    in_combat: boolean
+  }
+
+  /**
+   * Cooldown state of a behavior's action
+   */
+  export interface ActionCooldownJson {
+    /** Action definition ID */
+    action_id: number
+    /** Frames until the action is off cooldown (0 = ready) */
+    frames_remaining: number
+    /** Total cooldown from the action definition */
+    cooldown: number
+    /** Off cooldown and enough energy to use right now */
+    usable: boolean
+    /** Energy the action costs this character right now (scripts read it via 0x8A) */
+    energy_cost: number
+    /** Enough energy for energy_cost, ignoring the cooldown */
+    affordable: boolean
   }
 
   /**
@@ -381,7 +1321,7 @@ declare module 'robot-masters-wasm' {
     spawn_id: number
     /** Owner entity ID (supports both Character and Spawn entities) */
     owner_id: number
-    /** Owner type: 1=Character, 2=Spawn */
+    /** Owner type: 0=Arena, 1=Character, 2=Spawn */
     owner_type: number
     /** Position as [[x_numerator, x_denominator], [y_numerator, y_denominator]] for deterministic Fixed-point values */
     position: [[number, number], [number, number]]
@@ -395,6 +1335,10 @@ declare module 'robot-masters-wasm' {
     rotation: [number, number]
     /** Remaining lifespan in frames (renamed from lifespan) */
     life_span: number
+    /** Creation position as [[x_num, x_den], [y_num, y_den]] used for damage falloff */
+    origin: [[number, number], [number, number]]
+    /** Base damage rolled at creation (before distance falloff) */
+    damage: number
     /** Element type as number value (0-8) */
     element?: number
     /** Direction as [x_dir, y_dir] replacing facing and gravity_dir */
@@ -418,6 +1362,41 @@ declare module 'robot-masters-wasm' {
       [number, number],
       [number, number]
     ]
+    /** Display name from the spawn definition */
+    name: string | null
+    /** Sprite key from the spawn definition */
+    sprite: string | null
+  }
+
+  /**
+   * Script instances of one character (getInstancesJson)
+   */
+  export interface CharacterInstancesJson {
+    character_id: number
+    /** Actions the character has run, in creation order */
+    actions: ActionInstanceJson[]
+    /** Conditions the character has evaluated, in creation order */
+    conditions: ConditionInstanceJson[]
+    /** Status effects active on the character, in application order */
+    status_effects: StatusEffectStateJson[]
+  }
+
+  export interface ActionInstanceJson {
+    instance_id: number
+    action_id: number
+    /** Frame the action last ran */
+    last_used_frame: number
+    /** The character is locked into this action */
+    locked: boolean
+    runtime_vars: [number, number, number, number]
+    runtime_fixed: [[number, number], [number, number], [number, number], [number, number]]
+  }
+
+  export interface ConditionInstanceJson {
+    instance_id: number
+    condition_id: number
+    runtime_vars: [number, number, number, number]
+    runtime_fixed: [[number, number], [number, number], [number, number], [number, number]]
   }
 
   /**
@@ -426,9 +1405,29 @@ declare module 'robot-masters-wasm' {
   export interface StatusEffectStateJson {
     instance_id: number
     definition_id: number
+    /** Character the effect is applied to */
+    character_id: number
+    /** Entity that applied the effect (undefined = game/system) */
+    source_id?: number
+    /** Source entity type (0=None, 1=Character, 2=Spawn) */
+    source_type: number
+    /** UI grouping from definition (0=neutral, 1=buff, 2=debuff) */
+    category: number
+    /** Display priority from definition (higher first) */
+    priority: number
+    /** Rank among the character's effects by priority (0 = most important); show slots below N for N icons */
+    icon_slot: number
     /** Remaining duration in frames (renamed from remaining_duration) */
     life_span: number
+    /** Total duration in frames from definition */
+    duration: number
     stack_count: number
+    /** Maximum stacks from definition */
+    stack_limit: number
+    /** Re-application rule from definition: 0=Stack, 1=Refresh, 2=Ignore */
+    stack_behavior: number
+    /** Bundle instance this effect was applied with (null = applied directly) */
+    parent?: number
     /** Runtime variables array (renamed from vars) */
     runtime_vars: [number, number, number, number]
     /** Runtime Fixed-point values as [[num1, den1], [num2, den2], [num3, den3], [num4, den4]] (renamed from fixed) */
@@ -440,6 +1439,264 @@ declare module 'robot-masters-wasm' {
     ]
   }
 
+  /**
+   * Camera direction hint for spectator clients
+   */
+  export interface CameraHintJson {
+    /** Focus target type (0=None, 1=Character, 2=Spawn) */
+    focus_type: number
+    /** Character or spawn instance id */
+    focus_id: number
+    /** Zoom level as [numerator, denominator] (1.0 = default, 0 = keep current) */
+    zoom: [number, number]
+    /** Screen shake magnitude (0 = none) */
+    shake: number
+  }
+
+  /**
+   * Presentation event emitted during a frame
+   */
+  export interface GameEventJson {
+    /** Frame during which the event was emitted */
+    frame: number
+    /** Event type discriminator */
+    event_type:
+      | 'camera_hint'
+      | 'combat_state'
+      | 'spawn_removed'
+      | 'character_hit'
+      | 'damage_reflected'
+      | 'energy_drained'
+      | 'dash'
+      | 'grapple_attached'
+      | 'grapple_released'
+      | 'character_defeated'
+      | 'spawn_collisions_deferred'
+      | 'spawn_created'
+      | 'status_applied'
+      | 'status_expired'
+      | 'action_executed'
+      | 'character_revived'
+    /** Present when event_type is "camera_hint" */
+    camera_hint?: CameraHintJson
+    /** Present when event_type is "combat_state" */
+    combat_state?: CombatStateJson
+    /** Present when event_type is "spawn_removed" or "character_defeated" */
+    removal?: RemovalJson
+    /** Present when event_type is "character_hit" */
+    hit?: HitJson
+    /** Present when event_type is "damage_reflected" */
+    reflect?: ReflectJson
+    /** Present when event_type is "energy_drained" */
+    energy?: EnergyJson
+    /** Present when event_type is "dash" */
+    dash?: DashJson
+    /** Present when event_type is "grapple_attached" or "grapple_released" */
+    grapple?: GrappleJson
+    /** Present when event_type is "spawn_collisions_deferred" */
+    deferred?: DeferredWorkJson
+    /** Present when event_type is "spawn_created" */
+    spawn?: SpawnCreatedJson
+    /** Present when event_type is "status_applied" or "status_expired" */
+    status?: StatusEventJson
+    /** Present when event_type is "action_executed" */
+    action?: ActionEventJson
+    /** Present when event_type is "character_revived" */
+    revival?: RevivalJson
+  }
+
+  /**
+   * Events taken from the queue by drainEvents
+   */
+  export interface DrainedEventsJson {
+    /** Events emitted since the last drain, oldest first */
+    events: GameEventJson[]
+    /** Oldest events discarded because the queue was full */
+    dropped: number
+  }
+
+  /**
+   * Spawn instance that entered the match
+   */
+  export interface SpawnCreatedJson {
+    /** New spawn instance id */
+    spawn_instance_id: number
+    /** Spawn definition id */
+    spawn_id: number
+    /** Owning character (null for ownerless spawns) */
+    owner_id: number | null
+  }
+
+  /**
+   * Status effect applied to or removed from a character
+   */
+  export interface StatusEventJson {
+    /** Character carrying the status effect */
+    character_id: number
+    /** Status effect definition id */
+    status_effect_id: number
+    /** Status effect instance id */
+    instance_id: number
+    /** Stack count after the application (status_applied only) */
+    stacks: number | null
+    /** Stacked onto or refreshed an existing instance (status_applied only) */
+    refreshed: boolean | null
+  }
+
+  /**
+   * Defeated character whose respawn timer ran out
+   */
+  export interface RevivalJson {
+    /** Character back at full health and energy */
+    character_id: number
+  }
+
+  /**
+   * Action script that started running
+   */
+  export interface ActionEventJson {
+    /** Character running the action */
+    character_id: number
+    /** Action definition id */
+    action_id: number
+  }
+
+  /**
+   * Spawn interception checks moved to the next frame by frame_work_budget
+   */
+  export interface DeferredWorkJson {
+    /** Spawns whose interception checks were deferred */
+    remaining: number
+    /** Script steps plus collision checks spent this frame when the pass stopped */
+    work: number
+  }
+
+  /**
+   * One frame of a dash; renderers draw an after-image per step
+   */
+  export interface DashJson {
+    character_id: number
+    /** Position before this frame's step as [[x_num, x_den], [y_num, y_den]] */
+    from: [[number, number], [number, number]]
+    /** Position after the step */
+    to: [[number, number], [number, number]]
+    /** Dash frames still to come (0 = the dash ended) */
+    frames_left: number
+    /** Solid tiles stopped the step short, ending the dash */
+    blocked: boolean
+  }
+
+  /**
+   * Dash in progress on a character
+   */
+  export interface DashStateJson {
+    /** Displacement still to cover as [[x_num, x_den], [y_num, y_den]] */
+    remaining: [[number, number], [number, number]]
+    /** Frames until the dash ends */
+    frames_left: number
+  }
+
+  /**
+   * Grapple pull starting (grapple_attached) or ending (grapple_released)
+   */
+  export interface GrappleJson {
+    /** Character being pulled */
+    character_id: number
+    /** Owner of the grapple spawn */
+    source_id: number
+    /** Point pulled toward as [[x_num, x_den], [y_num, y_den]] (grapple_attached only) */
+    anchor?: [[number, number], [number, number]]
+    /** Reached the anchor rather than broke early (grapple_released only) */
+    arrived?: boolean
+  }
+
+  /**
+   * Grapple pull in progress on a character
+   */
+  export interface GrappleStateJson {
+    /** Owner of the grapple spawn */
+    source_id: number
+    /** Point the character's center is pulled toward as [[x_num, x_den], [y_num, y_den]] */
+    anchor: [[number, number], [number, number]]
+    /** Character whose center the anchor follows (set when pulled toward the owner) */
+    anchor_id?: number
+    /** Pull speed in pixels per frame as [numerator, denominator] */
+    speed: [number, number]
+    /** Frames until the pull breaks (0 = no limit) */
+    frames_left: number
+  }
+
+  /**
+   * Damage returned to an attacker by a reflect status effect (armor does not apply)
+   */
+  export interface ReflectJson {
+    /** Character that took the reflected damage */
+    character_id: number
+    /** Character whose status effect reflected it */
+    reflector_id: number
+    amount: number
+  }
+
+  /**
+   * Energy removed from a character by a spawn hit or a drain/steal operator
+   */
+  export interface EnergyJson {
+    /** Character that lost the energy */
+    character_id: number
+    /** Character responsible for the drain (null for ownerless spawns) */
+    drainer_id: number | null
+    amount: number
+    /** Part of the amount the drainer gained, capped by its energy cap */
+    stolen: number
+  }
+
+  /**
+   * Spawn hit a character, after armor
+   */
+  export interface HitJson {
+    character_id: number
+    spawn_instance_id: number
+    /** Owning character of the spawn, null for ownerless spawns */
+    attacker_id: number | null
+    /** Damage after armor (0 = fully absorbed) */
+    amount: number
+    /** Element index (0=Punct .. 8=Virus) */
+    element: number
+    /** The damage roll was a critical hit */
+    critical: boolean
+    /** Damage absorbed by armor */
+    mitigated: number
+  }
+
+  /**
+   * Removal reason: 0=Expired, 1=Killed, 2=OutOfBounds, 3=Absorbed
+   */
+  export type RemovalReason = 0 | 1 | 2 | 3
+
+  /**
+   * Spawn removed or character defeated, with the reason
+   */
+  export interface RemovalJson {
+    /** 1=Character, 2=Spawn */
+    entity_type: 1 | 2
+    /** Character id or spawn instance id */
+    entity_id: number
+    /** Spawn definition id (spawns only) */
+    spawn_id: number | null
+    reason: RemovalReason
+    /** Killing character id (Killed) or absorbing spawn instance id (Absorbed) */
+    by: number | null
+  }
+
+  /**
+   * Character entered or left combat
+   */
+  export interface CombatStateJson {
+    character_id: number
+    /** true = took damage, false = damage window elapsed without damage */
+    in_combat: boolean
+  }
+
   export interface HealthInfo {
     is_initialized: boolean
     game_initialized: boolean
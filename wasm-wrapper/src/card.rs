@@ -0,0 +1,197 @@
+//! Character cards (`export_character_card` / `import_character_card`)
+//!
+//! A card is one character definition bundled with exactly the definitions it references:
+//! the conditions and actions of its behaviors and every spawn reachable through action and
+//! spawn `spawns` slots. References inside a card index the card's own lists, so it can be
+//! shared on its own and imported into any configuration, which appends the definitions and
+//! shifts the references to their new indices.
+//!
+//! As in configurations, a zero spawn slot is empty, so card spawn ids count from 1: slot
+//! value `n` references `spawns[n - 1]`. Spawn ids written into scripts as literals are not
+//! remapped. Status effects are applied through the engine API rather than referenced by
+//! characters, so cards never carry them.
+
+use crate::types::{
+    ActionDefinitionJson, CharacterDefinitionJson, ConditionDefinitionJson, GameConfig,
+    SpawnDefinitionJson, ValidationError,
+};
+use serde::{Deserialize, Serialize};
+
+/// Card layout version, bumped whenever the layout changes incompatibly
+pub const CARD_VERSION: u8 = 1;
+
+/// Self-contained character with the definitions it references
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct CharacterCardJson {
+    pub version: u8,
+    pub character: CharacterDefinitionJson, // Behaviors index the card's conditions and actions
+    pub conditions: Vec<ConditionDefinitionJson>,
+    pub actions: Vec<ActionDefinitionJson>, // Spawn slot n references spawns[n - 1] (0 = empty)
+    pub spawns: Vec<SpawnDefinitionJson>,
+}
+
+/// Indices of the definitions a card takes from a configuration, in order of discovery
+#[derive(Default)]
+struct Picked {
+    ids: Vec<usize>,
+}
+
+impl Picked {
+    /// Card index of configuration definition `id`, picking it on first use
+    fn pick(&mut self, id: usize) -> usize {
+        match self.ids.iter().position(|&picked| picked == id) {
+            Some(index) => index,
+            None => {
+                self.ids.push(id);
+                self.ids.len() - 1
+            }
+        }
+    }
+}
+
+impl GameConfig {
+    /// Bundle character `index` with the definitions it references into a card
+    /// Returns None when the index or one of the references is out of range.
+    pub fn export_character_card(&self, index: usize) -> Option<CharacterCardJson> {
+        let mut character = self.characters.get(index)?.clone();
+        let (mut conditions, mut actions, mut spawns) =
+            (Picked::default(), Picked::default(), Picked::default());
+
+        for behavior in &mut character.behaviors {
+            self.conditions.get(behavior[0])?;
+            self.actions.get(behavior[1])?;
+            *behavior = [conditions.pick(behavior[0]), actions.pick(behavior[1])];
+        }
+
+        let mut card_actions = Vec::with_capacity(actions.ids.len());
+        for &id in &actions.ids {
+            let mut action = self.actions[id].clone();
+            action.spawns = pick_slots(&action.spawns, &mut spawns, self.spawns.len())?;
+            card_actions.push(action);
+        }
+
+        // Spawns picked while walking the list are appended to it, so children are included
+        let mut card_spawns = Vec::new();
+        while card_spawns.len() < spawns.ids.len() {
+            let mut spawn = self.spawns[spawns.ids[card_spawns.len()]].clone();
+            spawn.spawns = pick_slots(&spawn.spawns, &mut spawns, self.spawns.len())?;
+            card_spawns.push(spawn);
+        }
+
+        Some(CharacterCardJson {
+            version: CARD_VERSION,
+            character,
+            conditions: conditions
+                .ids
+                .iter()
+                .map(|&id| self.conditions[id].clone())
+                .collect(),
+            actions: card_actions,
+            spawns: card_spawns,
+        })
+    }
+
+    /// Append a card's character and definitions, remapping its references
+    /// The character joins `group` with the next free id; returns its index.
+    pub fn import_character_card(
+        &mut self,
+        card: CharacterCardJson,
+        group: u8,
+    ) -> Result<usize, ValidationError> {
+        let error = |field: &str, message: &str, context: String| ValidationError {
+            field: format!("card.{}", field),
+            message: message.to_string(),
+            context: Some(context),
+        };
+        if card.version != CARD_VERSION {
+            return Err(error(
+                "version",
+                "Unsupported character card version",
+                format!("Found version {}, expected {}", card.version, CARD_VERSION),
+            ));
+        }
+        // Spawn id 0 reads as an empty slot, so imported spawns must land after it
+        let spawn_offset = self.spawns.len();
+        if !card.spawns.is_empty() && (spawn_offset == 0 || spawn_offset + card.spawns.len() > 256)
+        {
+            return Err(error(
+                "spawns",
+                "Imported spawns need ids between 1 and 255",
+                format!(
+                    "{} spawns in the configuration plus {} in the card",
+                    spawn_offset,
+                    card.spawns.len()
+                ),
+            ));
+        }
+
+        let remap_slots = |mut slots: [u8; 4], field: String| {
+            for slot in slots.iter_mut().filter(|slot| **slot != 0) {
+                if *slot as usize > card.spawns.len() {
+                    return Err(error(
+                        &field,
+                        "Spawn slot references a spawn outside the card",
+                        format!("Found spawn id {}", slot),
+                    ));
+                }
+                *slot = (*slot as usize - 1 + spawn_offset) as u8;
+            }
+            Ok(slots)
+        };
+
+        let mut actions = Vec::with_capacity(card.actions.len());
+        for (index, action) in card.actions.iter().enumerate() {
+            let mut action = action.clone();
+            action.spawns = remap_slots(action.spawns, format!("actions[{}].spawns", index))?;
+            actions.push(action);
+        }
+        let mut spawns = Vec::with_capacity(card.spawns.len());
+        for (index, spawn) in card.spawns.iter().enumerate() {
+            let mut spawn = spawn.clone();
+            spawn.spawns = remap_slots(spawn.spawns, format!("spawns[{}].spawns", index))?;
+            spawns.push(spawn);
+        }
+
+        let mut character = card.character;
+        for (index, behavior) in character.behaviors.iter_mut().enumerate() {
+            if behavior[0] >= card.conditions.len() || behavior[1] >= actions.len() {
+                return Err(error(
+                    &format!("character.behaviors[{}]", index),
+                    "Behavior references a definition outside the card",
+                    format!("Found condition {} and action {}", behavior[0], behavior[1]),
+                ));
+            }
+            *behavior = [
+                behavior[0] + self.conditions.len(),
+                behavior[1] + self.actions.len(),
+            ];
+        }
+        character.id = self
+            .characters
+            .iter()
+            .map(|character| character.id.saturating_add(1))
+            .max()
+            .unwrap_or(0);
+        character.group = group;
+
+        self.conditions.extend(card.conditions);
+        self.actions.extend(actions);
+        self.spawns.extend(spawns);
+        self.characters.push(character);
+        Ok(self.characters.len() - 1)
+    }
+}
+
+/// Card spawn ids of a definition's spawn slots, picking the spawns they reference
+fn pick_slots(slots: &[u8; 4], spawns: &mut Picked, spawn_count: usize) -> Option<[u8; 4]> {
+    let mut picked = [0u8; 4];
+    for (slot, &spawn_id) in picked.iter_mut().zip(slots) {
+        if spawn_id as usize >= spawn_count {
+            return None;
+        }
+        if spawn_id != 0 {
+            *slot = spawns.pick(spawn_id as usize) as u8 + 1;
+        }
+    }
+    Some(picked)
+}
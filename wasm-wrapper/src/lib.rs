@@ -1,13 +1,20 @@
 use robot_masters_engine::{
     api::{new_game, GameError},
-    core,
-    math::Fixed,
-    state::GameState,
+    combat_log::CombatLog,
+    core::Frame,
+    entity::Element,
+    physics::BoundaryPolicy,
+    replay::Replay,
+    state::{GameState, GameStatus},
+    stream::SpectatorStream,
+    victory::{TieBreak, VictoryCondition},
 };
 // Removed unused import
 use wasm_bindgen::prelude::*;
 
+pub mod card;
 mod error;
+pub mod render;
 pub mod types;
 
 #[cfg(test)]
@@ -95,8 +102,12 @@ fn execution_error_to_js_value(message: &str) -> JsValue {
 pub struct GameWrapper {
     state: Option<GameState>,
     config: Option<GameConfig>,
+    // Recording of the current game since it was started, loaded or imported
+    replay: Option<Replay>,
+    // Spectator stream of the current game, once started with `start_stream`
+    stream: Option<SpectatorStream>,
     // Simple caching for serialized state - invalidated on frame changes
-    cached_frame: Option<u16>,
+    cached_frame: Option<u32>,
     cached_state_json: Option<String>,
     cached_characters_json: Option<String>,
     cached_spawns_json: Option<String>,
@@ -108,12 +119,15 @@ impl GameWrapper {
     /// Create a new GameWrapper instance with JSON configuration
     #[wasm_bindgen(constructor)]
     pub fn new(config_json: &str) -> Result<GameWrapper, JsValue> {
-        let config: GameConfig =
+        let mut config: GameConfig =
             serde_json::from_str(config_json).map_err(json_error_to_js_value)?;
         config.validate().map_err(validation_errors_to_js_value)?;
+        config.migrate_scripts();
         Ok(GameWrapper {
             state: None,
             config: Some(config),
+            replay: None,
+            stream: None,
             cached_frame: None,
             cached_state_json: None,
             cached_characters_json: None,
@@ -156,32 +170,420 @@ impl GameWrapper {
     }
 }
 
+#[wasm_bindgen]
+impl GameWrapper {
+    /// Get engine constants (timing, arena size, limits, opcodes, property addresses) as JSON
+    /// Frontend tooling should read these instead of hardcoding values that can drift
+    #[wasm_bindgen]
+    pub fn get_engine_constants_json() -> Result<String, JsValue> {
+        serde_json::to_string(&types::EngineConstantsJson::from_engine())
+            .map_err(json_error_to_js_value)
+    }
+}
+
+#[wasm_bindgen]
+impl GameWrapper {
+    /// Get the per-frame phase order and each phase's entity iteration order as JSON
+    /// Generated from the same list the engine executes, so verifiers can assert ordering
+    #[wasm_bindgen]
+    pub fn get_pipeline_info_json() -> Result<String, JsValue> {
+        serde_json::to_string(&types::PipelineInfoJson::from_engine())
+            .map_err(json_error_to_js_value)
+    }
+}
+
+#[wasm_bindgen]
+impl GameWrapper {
+    /// Export a configured character as a self-contained JSON character card
+    /// The card carries exactly the conditions, actions and spawns the character references,
+    /// renumbered from 0, so it can be shared and imported into another configuration
+    #[wasm_bindgen]
+    pub fn export_character_card(&self, character_index: usize) -> Result<String, JsValue> {
+        let config = self
+            .config
+            .as_ref()
+            .ok_or_else(|| execution_error_to_js_value("No configuration available"))?;
+        let card = config
+            .export_character_card(character_index)
+            .ok_or_else(|| {
+                execution_error_to_js_value("Character index or one of its references out of range")
+            })?;
+        serde_json::to_string(&card).map_err(json_error_to_js_value)
+    }
+
+    /// Import a JSON character card into a JSON configuration, joining `group`
+    /// The card's definitions are appended and its references remapped to their new indices.
+    /// Returns the validated configuration JSON with the character appended
+    #[wasm_bindgen]
+    pub fn import_character_card(
+        config_json: &str,
+        card_json: &str,
+        group: u8,
+    ) -> Result<String, JsValue> {
+        let mut config: GameConfig =
+            serde_json::from_str(config_json).map_err(json_error_to_js_value)?;
+        let card: card::CharacterCardJson =
+            serde_json::from_str(card_json).map_err(json_error_to_js_value)?;
+        config
+            .import_character_card(card, group)
+            .map_err(|error| validation_errors_to_js_value(vec![error]))?;
+        config.validate().map_err(validation_errors_to_js_value)?;
+        serde_json::to_string(&config).map_err(json_error_to_js_value)
+    }
+}
+
+#[wasm_bindgen]
+impl GameWrapper {
+    /// Deterministically roll post-match rewards from a final state hash and a JSON reward table
+    /// The same hash and table always yield the same drops, so results can be verified on-chain
+    #[wasm_bindgen]
+    pub fn roll_rewards(state_hash: u32, reward_table_json: &str) -> Result<String, JsValue> {
+        let table: types::RewardTableJson =
+            serde_json::from_str(reward_table_json).map_err(json_error_to_js_value)?;
+        let drops = robot_masters_engine::reward::roll_rewards(state_hash, &table.into())
+            .map_err(game_error_to_js_value)?;
+        let drops_json: Vec<types::RewardDropJson> = drops.iter().map(Into::into).collect();
+        serde_json::to_string(&drops_json).map_err(json_error_to_js_value)
+    }
+}
+
+#[wasm_bindgen]
+impl GameWrapper {
+    /// Run a single action definition in a sandbox built from a JSON configuration
+    /// Every character's behaviors are cleared so only the forced action runs: it executes once
+    /// for `character_index`, then the sandbox advances up to `frames` frames.
+    /// Returns the resulting state, every event emitted and any script error as JSON
+    #[wasm_bindgen]
+    pub fn test_action(
+        config_json: &str,
+        character_index: u8,
+        action_index: u8,
+        frames: u16,
+    ) -> Result<String, JsValue> {
+        let mut sandbox = GameWrapper::new(config_json)?;
+        sandbox.start_game(None)?;
+        let game_state = sandbox
+            .state
+            .as_mut()
+            .ok_or_else(|| execution_error_to_js_value("Sandbox failed to initialize"))?;
+
+        if character_index as usize >= game_state.characters.len() {
+            return Err(execution_error_to_js_value("Character index out of range"));
+        }
+        if action_index as usize >= game_state.action_definitions.len() {
+            return Err(execution_error_to_js_value("Action index out of range"));
+        }
+
+        for character in &mut game_state.characters {
+            character.behaviors.clear();
+        }
+
+        // Events are cleared every frame, so collect them as the sandbox runs
+        let script_error = game_state
+            .execute_action(character_index as usize, action_index as usize)
+            .err()
+            .map(|err| format!("{:?}", err));
+        let mut events: Vec<types::GameEventJson> = game_state
+            .events
+            .iter()
+            .map(types::GameEventJson::from_game_event)
+            .collect();
+
+        let mut frames_advanced = 0;
+        while frames_advanced < frames && game_state.status == GameStatus::Playing {
+            robot_masters_engine::api::game_loop(game_state).map_err(game_error_to_js_value)?;
+            events.extend(
+                game_state
+                    .events
+                    .iter()
+                    .map(types::GameEventJson::from_game_event),
+            );
+            frames_advanced += 1;
+        }
+
+        let mut state = types::GameStateJson::from_game_state(game_state);
+        if let Some(config) = &sandbox.config {
+            state.apply_labels(config);
+        }
+        let result = types::ActionTestResultJson {
+            script_error,
+            frames_advanced,
+            state,
+            events,
+        };
+        serde_json::to_string(&result).map_err(json_error_to_js_value)
+    }
+}
+
+#[wasm_bindgen]
+impl GameWrapper {
+    /// Evaluate a condition against a captured game state and trace its execution
+    /// `state_bytes` is a `save_state` snapshot, so RNG streams, static vars and instance
+    /// variables are evaluated exactly as captured. Returns the result, the raw exit value, any
+    /// script error and every executed instruction as JSON, so authors can see why a condition
+    /// failed
+    #[wasm_bindgen]
+    pub fn evaluate_condition_against_state(
+        state_bytes: &[u8],
+        character_index: u8,
+        condition_index: u8,
+    ) -> Result<String, JsValue> {
+        let mut game_state = GameState::from_bytes(state_bytes).map_err(game_error_to_js_value)?;
+        Self::trace_condition_json(&mut game_state, character_index, condition_index)
+    }
+
+    /// Evaluate a condition against a `get_state_json` capture and trace its execution
+    /// The capture is restored onto a match built from `config_json`. JSON captures leave out
+    /// RNG streams, static vars and instance variables, which keep the fresh match's values;
+    /// prefer `evaluate_condition_against_state` with a snapshot where one is available
+    #[wasm_bindgen]
+    pub fn evaluate_condition_against_state_json(
+        config_json: &str,
+        state_json: &str,
+        character_index: u8,
+        condition_index: u8,
+    ) -> Result<String, JsValue> {
+        let captured: types::GameStateJson =
+            serde_json::from_str(state_json).map_err(json_error_to_js_value)?;
+
+        let mut sandbox = GameWrapper::new(config_json)?;
+        sandbox.start_game(None)?;
+        let game_state = sandbox
+            .state
+            .as_mut()
+            .ok_or_else(|| execution_error_to_js_value("Sandbox failed to initialize"))?;
+
+        captured
+            .restore_into(game_state)
+            .map_err(|err| validation_errors_to_js_value(vec![err]))?;
+
+        Self::trace_condition_json(game_state, character_index, condition_index)
+    }
+}
+
+impl GameWrapper {
+    /// Trace one condition evaluation and encode it as `ConditionEvaluationJson`
+    fn trace_condition_json(
+        game_state: &mut GameState,
+        character_index: u8,
+        condition_index: u8,
+    ) -> Result<String, JsValue> {
+        if character_index as usize >= game_state.characters.len() {
+            return Err(execution_error_to_js_value("Character index out of range"));
+        }
+        if condition_index as usize >= game_state.condition_definitions.len() {
+            return Err(execution_error_to_js_value("Condition index out of range"));
+        }
+
+        let mut trace = Vec::new();
+        let outcome = game_state.trace_condition(
+            character_index as usize,
+            condition_index as usize,
+            &mut trace,
+        );
+        let (exit_value, script_error) = match outcome {
+            Ok(exit_value) => (exit_value, None),
+            Err(err) => (0, Some(format!("{:?}", err))),
+        };
+
+        let evaluation = types::ConditionEvaluationJson {
+            result: exit_value != 0,
+            exit_value,
+            script_error,
+            trace: trace.iter().map(Into::into).collect(),
+        };
+        serde_json::to_string(&evaluation).map_err(json_error_to_js_value)
+    }
+}
+
+#[wasm_bindgen]
+impl GameWrapper {
+    /// Disassemble a configured action's script into a mnemonic listing, one instruction per
+    /// line prefixed with its byte offset (e.g. `0003: ReadProp v0, CHARACTER_HEALTH`)
+    #[wasm_bindgen]
+    pub fn disassemble_action(&self, action_id: usize) -> Result<String, JsValue> {
+        let config = self
+            .config
+            .as_ref()
+            .ok_or_else(|| execution_error_to_js_value("No configuration available"))?;
+        let action = config
+            .actions
+            .get(action_id)
+            .ok_or_else(|| execution_error_to_js_value("Action index out of range"))?;
+        Ok(robot_masters_engine::script::disassemble(&action.script))
+    }
+
+    /// Disassemble a configured condition's script into a mnemonic listing
+    #[wasm_bindgen]
+    pub fn disassemble_condition(&self, condition_id: usize) -> Result<String, JsValue> {
+        let config = self
+            .config
+            .as_ref()
+            .ok_or_else(|| execution_error_to_js_value("No configuration available"))?;
+        let condition = config
+            .conditions
+            .get(condition_id)
+            .ok_or_else(|| execution_error_to_js_value("Condition index out of range"))?;
+        Ok(robot_masters_engine::script::disassemble(&condition.script))
+    }
+}
+
 #[wasm_bindgen]
 impl GameWrapper {
     /// Initialize a new game from the JSON configuration
     /// This creates a new game state using the game engine's new_game API
     #[wasm_bindgen]
     pub fn new_game(&mut self) -> Result<(), JsValue> {
+        self.start_game(None)
+    }
+
+    /// Rebuild the game from the stored configuration with a different seed
+    /// Skips JSON parsing and validation, making rematches and Monte-Carlo balance loops cheap.
+    /// The stored configuration (and `get_config_json`) keeps its original seed.
+    #[wasm_bindgen]
+    pub fn reset_with_seed(&mut self, seed: u16) -> Result<(), JsValue> {
+        self.start_game(Some(seed))
+    }
+
+    /// Save the complete match (definitions, instances, cooldowns, RNG position) as bytes
+    /// Pass the result to `load_state` to resume with bit-identical determinism.
+    #[wasm_bindgen]
+    pub fn save_state(&self) -> Result<Vec<u8>, JsValue> {
+        match &self.state {
+            Some(game_state) => Ok(game_state.to_bytes()),
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized before saving state",
+            )),
+        }
+    }
+
+    /// Resume a match saved with `save_state`, replacing the current game
+    /// The snapshot carries its own definitions; the stored configuration is left untouched.
+    #[wasm_bindgen]
+    pub fn load_state(&mut self, bytes: &[u8]) -> Result<(), JsValue> {
+        let game_state = GameState::from_bytes(bytes).map_err(game_error_to_js_value)?;
+        let replay = Replay::new(&game_state);
+        self.replace_game(game_state, replay)
+    }
+
+    /// Export the recording of the current game: its starting snapshot plus the state hash
+    /// after every frame advanced with `step_frame` or `advance_frames_unchecked`
+    #[wasm_bindgen]
+    pub fn export_replay(&self) -> Result<Vec<u8>, JsValue> {
+        match &self.replay {
+            Some(replay) => Ok(replay.to_bytes()),
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized before exporting a replay",
+            )),
+        }
+    }
+
+    /// Re-simulate a replay exported with `export_replay` and report whether it verifies
+    /// A verified replay replaces the current game with its final state and keeps recording
+    /// from there; a desynced replay leaves the current game untouched.
+    #[wasm_bindgen]
+    pub fn import_replay(&mut self, bytes: &[u8]) -> Result<String, JsValue> {
+        let replay = Replay::from_bytes(bytes).map_err(game_error_to_js_value)?;
+        let outcome = GameState::verify_replay(&replay).map_err(game_error_to_js_value)?;
+        let verification = types::ReplayVerificationJson::from_outcome(&replay, &outcome);
+
+        if outcome.is_verified() {
+            self.replace_game(outcome.state, replay)?;
+        }
+        serde_json::to_string(&verification).map_err(json_error_to_js_value)
+    }
+
+    /// Start streaming the current game for spectators, with a keyframe every
+    /// `keyframe_interval` frames (0 = only at the start and after batch advances)
+    /// Restarts the stream if one is running. The stream follows the game through
+    /// `new_game`, `reset_with_seed`, `load_state` and `import_replay`, reopening each time.
+    #[wasm_bindgen]
+    pub fn start_stream(&mut self, keyframe_interval: u16) -> Result<(), JsValue> {
+        match &self.state {
+            Some(game_state) => {
+                self.stream = Some(SpectatorStream::new(game_state, keyframe_interval));
+                Ok(())
+            }
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized before starting a stream",
+            )),
+        }
+    }
+
+    /// Drain everything the spectator stream recorded since the previous call as JSON
+    /// The first chunk after (re)opening carries the header; relays forward chunks in
+    /// sequence order and keep the latest keyframe for viewers who join mid-game.
+    #[wasm_bindgen]
+    pub fn take_stream_chunk(&mut self) -> Result<String, JsValue> {
+        match &mut self.stream {
+            Some(stream) => {
+                let chunk = types::StreamChunkJson::from(&stream.take_chunk());
+                serde_json::to_string(&chunk).map_err(json_error_to_js_value)
+            }
+            None => Err(execution_error_to_js_value(
+                "Call start_stream before taking stream chunks",
+            )),
+        }
+    }
+}
+
+impl GameWrapper {
+    /// Swap in a restored game with its recording, keeping the previous game if the
+    /// restored state fails validation
+    fn replace_game(&mut self, game_state: GameState, replay: Replay) -> Result<(), JsValue> {
+        let previous_stream = self.stream.clone();
+        self.restart_stream(&game_state);
+        let previous_state = self.state.replace(game_state);
+        let previous_replay = self.replay.replace(replay);
+        self.clear_cache();
+
+        if let Err(validation_error) = self.validate_game_state() {
+            if validation_error.severity == ErrorSeverity::Critical
+                || validation_error.severity == ErrorSeverity::Fatal
+            {
+                self.state = previous_state;
+                self.replay = previous_replay;
+                self.stream = previous_stream;
+                return Err(validation_error.to_js_value());
+            }
+        }
+
+        Ok(())
+    }
+
+    /// Reopen a started spectator stream on a new game with the same keyframe interval
+    /// Viewers see the new header and start over from its opening keyframe
+    fn restart_stream(&mut self, game_state: &GameState) {
+        if let Some(stream) = &mut self.stream {
+            *stream = SpectatorStream::new(game_state, stream.header().keyframe_interval);
+        }
+    }
+}
+
+impl GameWrapper {
+    /// Build a fresh game state from the stored configuration, optionally overriding the seed
+    fn start_game(&mut self, seed_override: Option<u16>) -> Result<(), JsValue> {
         // Convert configuration to game engine types
-        let (seed, tilemap, characters, actions, conditions, spawns, status_effects) =
+        let (config_seed, tilemap, characters, actions, conditions, spawns, status_effects) =
             self.convert_config_to_engine_types()?;
+        let seed = seed_override.unwrap_or(config_seed);
 
         // Initialize the game using the game engine API
-        let game_state = if let Some(config) = &self.config {
-            if let Some(gravity_array) = &config.gravity {
-                // Use custom gravity
-                let gravity = Fixed::from_frac(gravity_array[0], gravity_array[1]);
-                robot_masters_engine::state::GameState::new_with_gravity(
+        let mut game_state = if let Some(config) = &self.config {
+            if let Some(gravity) = &config.gravity {
+                // Use custom gravity (legacy scalars map to the y axis)
+                let mut game_state = GameState::new(
                     seed,
                     tilemap,
-                    gravity,
                     characters,
                     actions,
                     conditions,
                     spawns,
                     status_effects,
                 )
-                .map_err(game_error_to_js_value)?
+                .map_err(game_error_to_js_value)?;
+                game_state.gravity = gravity.to_vector();
+                game_state
             } else {
                 // Use default gravity
                 new_game(
@@ -199,7 +601,124 @@ impl GameWrapper {
             return Err(execution_error_to_js_value("No configuration available"));
         };
 
-        // Store the initialized game state
+        // Apply world boundary policies (validated with the configuration)
+        if let Some(boundary) = self
+            .config
+            .as_ref()
+            .and_then(|config| config.boundary.as_ref())
+        {
+            game_state.character_boundary =
+                BoundaryPolicy::from_u8(boundary.character).unwrap_or(BoundaryPolicy::Clamp);
+            game_state.spawn_boundary =
+                BoundaryPolicy::from_u8(boundary.spawn).unwrap_or(BoundaryPolicy::Despawn);
+            game_state.boundary_damage = boundary.damage;
+        }
+
+        // Apply recent-damage window for "in combat" tracking
+        if let Some(window) = self.config.as_ref().and_then(|config| config.damage_window) {
+            game_state.damage_window = window;
+        }
+
+        // Apply the respawn delay (0 = defeated characters stay down)
+        if let Some(delay) = self.config.as_ref().and_then(|config| config.respawn_delay) {
+            game_state.respawn_delay = delay;
+        }
+
+        // Apply match length (0 = unlimited training session)
+        if let Some(limit) = self.config.as_ref().and_then(|config| config.frame_limit) {
+            game_state.frame_limit = limit;
+        }
+
+        // Apply the victory condition (validated with the configuration)
+        if let Some(victory) = self
+            .config
+            .as_ref()
+            .and_then(|config| config.victory.as_ref())
+        {
+            game_state.victory =
+                VictoryCondition::from_parts(victory.condition, victory.target).unwrap_or_default();
+            game_state.tie_breaks = victory
+                .tie_breaks
+                .iter()
+                .filter_map(|&tie_break| TieBreak::from_u8(tie_break))
+                .collect();
+        }
+
+        // Keep spawns from hitting the owner's allies when friendly fire is off
+        if let Some(friendly_fire) = self.config.as_ref().and_then(|config| config.friendly_fire) {
+            game_state.friendly_fire = friendly_fire;
+        }
+
+        // Place the arena's moving platforms (validated with the configuration)
+        for placement in self
+            .config
+            .as_ref()
+            .and_then(|config| config.platforms.as_ref())
+            .into_iter()
+            .flatten()
+        {
+            game_state.place_platform(placement.spawn_id, placement.pos());
+        }
+
+        // Give the arena's Hazard tiles their element and damage (validated with the configuration)
+        for hazard in self
+            .config
+            .as_ref()
+            .and_then(|config| config.hazards.as_ref())
+            .into_iter()
+            .flatten()
+        {
+            if let Some(element) = Element::from_u8(hazard.element) {
+                let [x, y] = hazard.position;
+                game_state.place_hazard(x, y, element, hazard.damage_per_second);
+            }
+        }
+
+        // Surface invalid entity property accesses as script errors when requested
+        if let Some(strict) = self.config.as_ref().and_then(|config| config.strict_mode) {
+            game_state.strict_mode = strict;
+        }
+
+        // Bound how deeply scripts may nest subroutine CALLs
+        if let Some(depth) = self
+            .config
+            .as_ref()
+            .and_then(|config| config.max_call_depth)
+        {
+            game_state.max_call_depth = depth;
+        }
+
+        // Stop runaway scripts before they stall the frame
+        if let Some(budget) = self
+            .config
+            .as_ref()
+            .and_then(|config| config.script_step_budget)
+        {
+            game_state.script_step_budget = budget;
+        }
+        if let Some(budget) = self
+            .config
+            .as_ref()
+            .and_then(|config| config.frame_step_budget)
+        {
+            game_state.frame_step_budget = budget;
+        }
+        if let Some(budget) = self
+            .config
+            .as_ref()
+            .and_then(|config| config.frame_work_budget)
+        {
+            game_state.frame_work_budget = budget;
+        }
+
+        // Keep recent events queryable for late-mounting UI components
+        if let Some(frames) = self.config.as_ref().and_then(|config| config.event_history) {
+            game_state.event_history.set_frames(frames);
+        }
+
+        // Store the initialized game state and start recording it
+        self.replay = Some(Replay::new(&game_state));
+        self.restart_stream(&game_state);
         self.state = Some(game_state);
 
         // Clear cache when game state changes
@@ -212,13 +731,18 @@ impl GameWrapper {
             {
                 // Clear the invalid state
                 self.state = None;
+                self.replay = None;
+                self.stream = None;
                 return Err(validation_error.to_js_value());
             }
         }
 
         Ok(())
     }
+}
 
+#[wasm_bindgen]
+impl GameWrapper {
     /// Check if the game has been initialized and is ready for frame execution
     #[wasm_bindgen]
     pub fn is_game_initialized(&self) -> bool {
@@ -233,6 +757,12 @@ impl GameWrapper {
             Some(game_state) => {
                 let result = robot_masters_engine::api::game_loop(game_state)
                     .map_err(game_error_to_js_value);
+                if let (Ok(()), Some(replay)) = (&result, &mut self.replay) {
+                    replay.record_frame(game_state);
+                }
+                if let (Ok(()), Some(stream)) = (&result, &mut self.stream) {
+                    stream.record_frame(game_state);
+                }
 
                 // Clear cache when game state changes
                 if result.is_ok() {
@@ -247,9 +777,165 @@ impl GameWrapper {
         }
     }
 
+    /// Advance the game state by up to `frames` frames as fast as possible
+    /// Skips event collection, per-frame cache handling and JSON generation entirely;
+    /// intended for catch-up (e.g. rejoining a spectated match). Produces the same state as
+    /// calling `step_frame` the same number of times. Returns the number of frames actually
+    /// advanced.
+    #[wasm_bindgen]
+    pub fn advance_frames_unchecked(&mut self, frames: Frame) -> Result<Frame, JsValue> {
+        match &mut self.state {
+            Some(game_state) => {
+                // No events for the whole batch; the previous setting comes back afterwards
+                let events_enabled = game_state.events_enabled;
+                game_state.events_enabled = false;
+                let result = match &mut self.replay {
+                    Some(replay) => replay.advance_frames(game_state, frames),
+                    None => robot_masters_engine::api::advance_frames(game_state, frames),
+                }
+                .map_err(game_error_to_js_value);
+                game_state.events_enabled = events_enabled;
+
+                // Batches skip events, so spectators resync from a keyframe after them
+                if let Some(stream) = &mut self.stream {
+                    stream.record_keyframe(game_state);
+                }
+
+                // Clear cache once after the whole batch
+                self.clear_cache();
+
+                result
+            }
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized before advancing frames",
+            )),
+        }
+    }
+
+    /// Advance the game until it ends and return the match summary as JSON
+    /// Runs like `advance_frames_unchecked` (no events, recorded into the replay); intended for
+    /// tournament backends that only need the outcome. Fails for unlimited training sessions
+    #[wasm_bindgen]
+    pub fn run_to_end(&mut self) -> Result<String, JsValue> {
+        let game_state = match &mut self.state {
+            Some(game_state) => game_state,
+            None => {
+                return Err(execution_error_to_js_value(
+                    "Game must be initialized before running to the end",
+                ))
+            }
+        };
+        if game_state.frame_limit == 0 {
+            return Err(execution_error_to_js_value(
+                "Unlimited training sessions never end; set a frame_limit to run to the end",
+            ));
+        }
+
+        let events_enabled = game_state.events_enabled;
+        game_state.events_enabled = false;
+        let mut result = Ok(0);
+        while result.is_ok() && game_state.status == GameStatus::Playing {
+            result = match &mut self.replay {
+                Some(replay) => replay.advance_frames(game_state, Frame::MAX),
+                None => robot_masters_engine::api::advance_frames(game_state, Frame::MAX),
+            };
+        }
+        game_state.events_enabled = events_enabled;
+        let summary = result.map(|_| game_state.match_summary());
+        if let Some(stream) = &mut self.stream {
+            stream.record_keyframe(game_state);
+        }
+
+        // Clear cache once after the whole run
+        self.clear_cache();
+
+        let summary = summary.map_err(game_error_to_js_value)?;
+        serde_json::to_string(&types::MatchSummaryJson::from(&summary))
+            .map_err(json_error_to_js_value)
+    }
+
+    /// Get the match result under its victory condition as JSON
+    /// While playing, `winner_group` is the group that would win if time ran out now
+    #[wasm_bindgen]
+    pub fn get_game_result_json(&self) -> Result<String, JsValue> {
+        match &self.state {
+            Some(game_state) => {
+                serde_json::to_string(&types::MatchResultJson::from(&game_state.match_result()))
+                    .map_err(json_error_to_js_value)
+            }
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized to get the game result",
+            )),
+        }
+    }
+
+    /// Get per-group aggregate stats (survivors, health totals, kills, damage) as JSON
+    #[wasm_bindgen]
+    pub fn get_team_stats_json(&self) -> Result<String, JsValue> {
+        match &self.state {
+            Some(game_state) => {
+                let standings: Vec<types::GroupStandingJson> = game_state
+                    .group_standings()
+                    .iter()
+                    .map(types::GroupStandingJson::from)
+                    .collect();
+                serde_json::to_string(&standings).map_err(json_error_to_js_value)
+            }
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized to get team stats",
+            )),
+        }
+    }
+
+    /// Get a heuristic score and win chance for every group as JSON, for win bars
+    /// Scores weigh health left, closeness to the arena center and live spawns (per mille)
+    #[wasm_bindgen]
+    pub fn get_evaluation_json(&self) -> Result<String, JsValue> {
+        match &self.state {
+            Some(game_state) => {
+                let evaluations: Vec<types::GroupEvaluationJson> = game_state
+                    .evaluate()
+                    .iter()
+                    .map(types::GroupEvaluationJson::from)
+                    .collect();
+                serde_json::to_string(&evaluations).map_err(json_error_to_js_value)
+            }
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized to evaluate the match",
+            )),
+        }
+    }
+
+    /// Get a deterministic 32-bit hash of the current game state
+    /// Useful for verifying that two simulations are in sync and for seeding reward rolls
+    #[wasm_bindgen]
+    pub fn get_state_hash(&self) -> Result<u32, JsValue> {
+        match &self.state {
+            Some(game_state) => Ok(game_state.state_hash()),
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized to compute state hash",
+            )),
+        }
+    }
+
+    /// Get the state hash with per-section checksums (RNG, each character, spawns, status
+    /// effects) as JSON, for pinpointing where two simulations diverged
+    #[wasm_bindgen]
+    pub fn get_state_checksum(&self) -> Result<String, JsValue> {
+        match &self.state {
+            Some(game_state) => serde_json::to_string(&types::StateChecksumJson::from(
+                &game_state.compute_checksum(),
+            ))
+            .map_err(json_error_to_js_value),
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized to compute state checksum",
+            )),
+        }
+    }
+
     /// Get the current frame number for timing synchronization
     #[wasm_bindgen]
-    pub fn get_frame(&self) -> u16 {
+    pub fn get_frame(&self) -> u32 {
         match &self.state {
             Some(game_state) => game_state.frame,
             None => 0,
@@ -268,10 +954,12 @@ impl GameWrapper {
                         robot_masters_engine::state::GameStatus::Playing => "playing",
                         robot_masters_engine::state::GameStatus::Ended => "ended",
                     },
-                    "max_frames": core::MAX_FRAMES,
+                    "max_frames": game_state.frame_limit,
                     "fps": 60,
                     "elapsed_seconds": game_state.frame as f64 / 60.0,
-                    "remaining_seconds": (core::MAX_FRAMES.saturating_sub(game_state.frame)) as f64 / 60.0
+                    "remaining_seconds": (game_state.frame_limit != 0).then(|| {
+                        game_state.frame_limit.saturating_sub(game_state.frame) as f64 / 60.0
+                    })
                 });
 
                 serde_json::to_string(&frame_info).map_err(json_error_to_js_value)
@@ -393,7 +1081,10 @@ impl GameWrapper {
                 }
 
                 // Generate new JSON and cache it
-                let state_json = types::GameStateJson::from_game_state(game_state);
+                let mut state_json = types::GameStateJson::from_game_state(game_state);
+                if let Some(config) = &self.config {
+                    state_json.apply_labels(config);
+                }
                 let json_string =
                     serde_json::to_string(&state_json).map_err(json_error_to_js_value)?;
 
@@ -407,6 +1098,27 @@ impl GameWrapper {
         }
     }
 
+    /// Get the render-relevant state as a compact binary frame (a `Uint8Array` in JS)
+    /// Fixed little-endian layout documented in the `render` module, read with a DataView
+    #[wasm_bindgen]
+    pub fn get_state_binary(&self) -> Result<Vec<u8>, JsValue> {
+        match &self.state {
+            Some(game_state) => Ok(render::encode(game_state)),
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized to get state",
+            )),
+        }
+    }
+
+    /// Get the binary render frame as a standalone `ArrayBuffer`
+    /// Can be transferred (not copied) to a worker or render thread with `postMessage`,
+    /// where `StateView.from_transferable` reads it back
+    #[wasm_bindgen]
+    pub fn get_state_transferable(&self) -> Result<js_sys::ArrayBuffer, JsValue> {
+        let bytes = self.get_state_binary()?;
+        Ok(js_sys::Uint8Array::from(bytes.as_slice()).buffer())
+    }
+
     /// Get characters data as JSON string
     /// Returns detailed character information including position, health, energy, and status effects
     #[wasm_bindgen]
@@ -423,10 +1135,17 @@ impl GameWrapper {
                 }
 
                 // Generate new JSON
-                let characters_json: Vec<types::CharacterStateJson> = game_state
+                let characters_json: Vec<types::CharacterStateJson> = (0..game_state
                     .characters
-                    .iter()
-                    .map(types::CharacterStateJson::from_character)
+                    .len())
+                    .map(|character_idx| {
+                        let mut character =
+                            types::CharacterStateJson::from_character(game_state, character_idx);
+                        if let Some(config) = &self.config {
+                            character.apply_labels(config);
+                        }
+                        character
+                    })
                     .collect();
                 serde_json::to_string(&characters_json).map_err(json_error_to_js_value)
             }
@@ -453,9 +1172,15 @@ impl GameWrapper {
 
                 // Generate new JSON
                 let spawns_json: Vec<types::SpawnStateJson> = game_state
-                    .spawn_instances
+                    .spawn_instances()
                     .iter()
-                    .map(types::SpawnStateJson::from_spawn_instance)
+                    .map(|spawn| {
+                        let mut spawn = types::SpawnStateJson::from_spawn_instance(spawn);
+                        if let Some(config) = &self.config {
+                            spawn.apply_labels(config);
+                        }
+                        spawn
+                    })
                     .collect();
                 serde_json::to_string(&spawns_json).map_err(json_error_to_js_value)
             }
@@ -481,17 +1206,20 @@ impl GameWrapper {
                 }
 
                 // Generate new JSON
-                let status_effects_json: Vec<types::StatusEffectStateJson> = game_state
-                    .status_effect_instances
-                    .iter()
-                    .enumerate()
-                    .map(|(index, instance)| {
-                        types::StatusEffectStateJson::from_status_effect_instance(
-                            instance,
-                            index as u8,
-                        )
-                    })
-                    .collect();
+                let status_effects_json = types::StatusEffectStateJson::by_priority(
+                    game_state
+                        .status_effect_instances
+                        .iter()
+                        .enumerate()
+                        .map(|(index, instance)| {
+                            types::StatusEffectStateJson::from_status_effect_instance(
+                                game_state,
+                                instance,
+                                index as u8,
+                            )
+                        })
+                        .collect(),
+                );
                 serde_json::to_string(&status_effects_json).map_err(json_error_to_js_value)
             }
             None => Err(execution_error_to_js_value(
@@ -499,8 +1227,125 @@ impl GameWrapper {
             )),
         }
     }
+
+    /// Get a character's action, condition and status effect instances as JSON string
+    /// Includes the script variables each instance keeps between runs, for debugging tools
+    #[wasm_bindgen]
+    pub fn get_instances_json(&self, character_id: u8) -> Result<String, JsValue> {
+        match &self.state {
+            Some(game_state) => {
+                let instances =
+                    types::CharacterInstancesJson::from_game_state(game_state, character_id);
+                serde_json::to_string(&instances).map_err(json_error_to_js_value)
+            }
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized to get instances",
+            )),
+        }
+    }
+
+    /// Get events emitted during the most recent frame as JSON string
+    /// Events are presentation-only (e.g. camera hints for spectator mode)
+    #[wasm_bindgen]
+    pub fn get_events_json(&self) -> Result<String, JsValue> {
+        match &self.state {
+            Some(game_state) => {
+                let events_json: Vec<types::GameEventJson> = game_state
+                    .events
+                    .iter()
+                    .map(types::GameEventJson::from_game_event)
+                    .collect();
+                serde_json::to_string(&events_json).map_err(json_error_to_js_value)
+            }
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized to get events",
+            )),
+        }
+    }
+
+    /// Drain every event queued since the previous drain as JSON string
+    /// Covers all frames stepped in between (and effects applied between frames), so
+    /// renderers can trigger sounds and effects without polling after every frame
+    #[wasm_bindgen]
+    pub fn drain_events(&mut self) -> Result<String, JsValue> {
+        match &mut self.state {
+            Some(game_state) => {
+                let drained = game_state.drain_events();
+                let drained_json = types::DrainedEventsJson {
+                    events: drained
+                        .events
+                        .iter()
+                        .map(types::GameEventJson::from_game_event)
+                        .collect(),
+                    dropped: drained.dropped,
+                };
+                serde_json::to_string(&drained_json).map_err(json_error_to_js_value)
+            }
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized to drain events",
+            )),
+        }
+    }
+
+    /// Get retained events emitted during frames `first_frame..=last_frame` as JSON string
+    /// Covers the configured event history window, so late-mounting views can backfill
+    #[wasm_bindgen]
+    pub fn get_event_history_json(
+        &self,
+        first_frame: u32,
+        last_frame: u32,
+    ) -> Result<String, JsValue> {
+        match &self.state {
+            Some(game_state) => {
+                let events_json: Vec<types::GameEventJson> = game_state
+                    .event_history
+                    .range(first_frame, last_frame)
+                    .map(types::GameEventJson::from_game_event)
+                    .collect();
+                serde_json::to_string(&events_json).map_err(json_error_to_js_value)
+            }
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized to get event history",
+            )),
+        }
+    }
+
+    /// Get human-readable combat log lines for the retained events between two frames
+    /// (inclusive) as a JSON array of strings, using the configured character names
+    #[wasm_bindgen]
+    pub fn get_combat_log_json(
+        &self,
+        first_frame: u32,
+        last_frame: u32,
+    ) -> Result<String, JsValue> {
+        match &self.state {
+            Some(game_state) => {
+                let log = CombatLog::new(self.character_names());
+                let lines = log.lines(game_state.event_history.range(first_frame, last_frame));
+                serde_json::to_string(&lines).map_err(json_error_to_js_value)
+            }
+            None => Err(execution_error_to_js_value(
+                "Game must be initialized to get the combat log",
+            )),
+        }
+    }
 }
 impl GameWrapper {
+    /// Configured display names indexed by character id (empty for unnamed characters)
+    fn character_names(&self) -> Vec<String> {
+        let mut names = Vec::new();
+        if let Some(config) = &self.config {
+            for character in &config.characters {
+                let index = character.id as usize;
+                if names.len() <= index {
+                    names.resize(index + 1, String::new());
+                }
+                names[index] = character.name.clone().unwrap_or_default();
+            }
+        }
+        names
+    }
+
     /// Clear the serialization cache when game state changes
     fn clear_cache(&mut self) {
         self.cached_frame = None;
@@ -538,7 +1383,9 @@ impl GameWrapper {
                 }
 
                 // Check for reasonable frame count
-                if game_state.frame > core::MAX_FRAMES + 100 {
+                if game_state.frame_limit != 0
+                    && game_state.frame > game_state.frame_limit.saturating_add(100)
+                {
                     return Err(WasmError::with_context(
                         ErrorType::StateError,
                         "Game frame count is beyond expected limits".to_string(),
@@ -547,7 +1394,7 @@ impl GameWrapper {
                             stack_trace: None,
                             data: Some(serde_json::json!({
                                 "current_frame": game_state.frame,
-                                "max_frames": core::MAX_FRAMES
+                                "max_frames": game_state.frame_limit
                             })),
                             error_code: Some(4002),
                             debug_info: None,
@@ -642,7 +1489,7 @@ impl GameWrapper {
             "is_stable": self.is_stable(),
             "frame": self.state.as_ref().map(|s| s.frame).unwrap_or(0),
             "character_count": self.state.as_ref().map(|s| s.characters.len()).unwrap_or(0),
-            "spawn_count": self.state.as_ref().map(|s| s.spawn_instances.len()).unwrap_or(0),
+            "spawn_count": self.state.as_ref().map(|s| s.spawn_instances().len()).unwrap_or(0),
             "status_effect_count": self.state.as_ref().map(|s| s.status_effect_instances.len()).unwrap_or(0),
             "cache_status": {
                 "has_cached_frame": self.cached_frame.is_some(),
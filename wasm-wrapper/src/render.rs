@@ -0,0 +1,383 @@
+//! Compact binary render frames (`get_state_binary`)
+//!
+//! A fixed little-endian layout that renderers read with a `DataView` instead of parsing
+//! state JSON every frame. Fixed-point values are the raw engine `i16` (divide by
+//! `FIXED_SCALE`). The layout is versioned; fields are only ever appended to the end of a
+//! record together with a version bump and a stride change.
+//!
+//! ```text
+//! Header (HEADER_SIZE bytes)
+//!   0  u8   version            RENDER_FRAME_VERSION
+//!   1  u8   status             0=Playing, 1=Ended
+//!   2  u8   character count
+//!   3  u8   spawn count
+//!   4  u32  frame
+//!   8  u16  status effect count
+//!  10  u16  reserved (0)
+//! Character records (CHARACTER_STRIDE bytes each)
+//!   0  u8   id                 1  u8  group
+//!   2  u8   facing (dir.0)     3  u8  gravity dir (dir.1)
+//!   4  i16  pos x              6  i16 pos y
+//!   8  i16  vel x             10  i16 vel y
+//!  12  u16  health            14  u16 health cap
+//!  16  u8   energy            17  u8  energy cap
+//!  18  u8   width             19  u8  height
+//!  20  u8   locked action (255 = none)
+//!  21  u8   collision bits (1=top, 2=right, 4=bottom, 8=left)
+//!  22  u8   flags (1=in combat)
+//!  23  u8   reserved (0)
+//! Spawn records (SPAWN_STRIDE bytes each)
+//!   0  u8   instance id        1  u8  spawn definition id
+//!   2  u8   owner id           3  u8  element (0-8)
+//!   4  i16  pos x              6  i16 pos y
+//!   8  i16  vel x             10  i16 vel y
+//!  12  i16  rotation          14  u16 life span
+//!  16  u16  health            18  u8  width   19  u8 height
+//! Status effect records (STATUS_EFFECT_STRIDE bytes each)
+//!   0  u16  definition id      2  u8  character id   3  u8 stack count
+//!   4  u16  life span          6  u16 duration (from the definition)
+//! ```
+//!
+//! `get_state_transferable` hands the same bytes over as a standalone `ArrayBuffer` that
+//! can be transferred to a worker or render thread, where `StateView::from_transferable`
+//! hydrates a read-only view of it without going through JSON.
+
+use robot_masters_engine::entity::{Character, EntityCore, SpawnInstance, StatusEffectInstance};
+use robot_masters_engine::state::{GameState, GameStatus};
+use wasm_bindgen::prelude::*;
+
+/// Layout version stored in the first byte
+pub const RENDER_FRAME_VERSION: u8 = 1;
+/// Raw Fixed units per whole unit
+pub const FIXED_SCALE: i16 = 32;
+pub const HEADER_SIZE: usize = 12;
+pub const CHARACTER_STRIDE: usize = 24;
+pub const SPAWN_STRIDE: usize = 20;
+pub const STATUS_EFFECT_STRIDE: usize = 8;
+
+/// Encode the render-relevant part of the state in the layout documented above
+pub fn encode(state: &GameState) -> Vec<u8> {
+    let mut bytes = Vec::with_capacity(
+        HEADER_SIZE
+            + state.characters.len() * CHARACTER_STRIDE
+            + state.spawn_instances().len() * SPAWN_STRIDE
+            + state.status_effect_instances.len() * STATUS_EFFECT_STRIDE,
+    );
+
+    bytes.push(RENDER_FRAME_VERSION);
+    bytes.push(match state.status {
+        GameStatus::Playing => 0,
+        GameStatus::Ended => 1,
+    });
+    bytes.push(state.characters.len() as u8);
+    bytes.push(state.spawn_instances().len() as u8);
+    bytes.extend_from_slice(&state.frame.to_le_bytes());
+    bytes.extend_from_slice(&(state.status_effect_instances.len() as u16).to_le_bytes());
+    bytes.extend_from_slice(&[0, 0]);
+
+    for character in &state.characters {
+        write_character(&mut bytes, character);
+    }
+    for spawn in state.spawn_instances() {
+        write_spawn(&mut bytes, spawn);
+    }
+    for instance in &state.status_effect_instances {
+        let duration = state
+            .status_effect_definitions
+            .get(instance.definition_id)
+            .map_or(0, |definition| definition.duration);
+        write_status_effect(&mut bytes, instance, duration);
+    }
+    bytes
+}
+
+/// Position and velocity as four raw Fixed values
+fn write_motion(bytes: &mut Vec<u8>, core: &EntityCore) {
+    for value in [core.pos.0, core.pos.1, core.vel.0, core.vel.1] {
+        bytes.extend_from_slice(&value.raw().to_le_bytes());
+    }
+}
+
+fn write_character(bytes: &mut Vec<u8>, character: &Character) {
+    let core = &character.core;
+    bytes.extend_from_slice(&[core.id, core.group, core.dir.0, core.dir.1]);
+    write_motion(bytes, core);
+    bytes.extend_from_slice(&character.health.to_le_bytes());
+    bytes.extend_from_slice(&character.health_cap.to_le_bytes());
+    let collision = core.collision.0 as u8
+        | (core.collision.1 as u8) << 1
+        | (core.collision.2 as u8) << 2
+        | (core.collision.3 as u8) << 3;
+    bytes.extend_from_slice(&[
+        character.energy,
+        character.energy_cap,
+        core.size.0,
+        core.size.1,
+        character.locked_action.unwrap_or(u8::MAX),
+        collision,
+        character.is_in_combat() as u8,
+        0,
+    ]);
+}
+
+fn write_spawn(bytes: &mut Vec<u8>, spawn: &SpawnInstance) {
+    let core = &spawn.core;
+    bytes.extend_from_slice(&[core.id, spawn.spawn_id, spawn.owner_id, spawn.element as u8]);
+    write_motion(bytes, core);
+    bytes.extend_from_slice(&spawn.rotation.raw().to_le_bytes());
+    bytes.extend_from_slice(&spawn.life_span.to_le_bytes());
+    bytes.extend_from_slice(&spawn.health.to_le_bytes());
+    bytes.extend_from_slice(&[core.size.0, core.size.1]);
+}
+
+fn write_status_effect(bytes: &mut Vec<u8>, instance: &StatusEffectInstance, duration: u16) {
+    bytes.extend_from_slice(&(instance.definition_id as u16).to_le_bytes());
+    bytes.extend_from_slice(&[instance.character_id, instance.stack_count]);
+    bytes.extend_from_slice(&instance.life_span.to_le_bytes());
+    bytes.extend_from_slice(&duration.to_le_bytes());
+}
+
+/// Read-only view of a render frame, hydrated from a transferred `ArrayBuffer`
+#[wasm_bindgen]
+pub struct StateView {
+    bytes: Vec<u8>,
+}
+
+/// One character record of a render frame
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct CharacterRecord {
+    #[wasm_bindgen(readonly)]
+    pub id: u8,
+    #[wasm_bindgen(readonly)]
+    pub group: u8,
+    #[wasm_bindgen(readonly)]
+    pub facing: u8,
+    #[wasm_bindgen(readonly)]
+    pub gravity_dir: u8,
+    #[wasm_bindgen(readonly)]
+    pub pos_x: i16,
+    #[wasm_bindgen(readonly)]
+    pub pos_y: i16,
+    #[wasm_bindgen(readonly)]
+    pub vel_x: i16,
+    #[wasm_bindgen(readonly)]
+    pub vel_y: i16,
+    #[wasm_bindgen(readonly)]
+    pub health: u16,
+    #[wasm_bindgen(readonly)]
+    pub health_cap: u16,
+    #[wasm_bindgen(readonly)]
+    pub energy: u8,
+    #[wasm_bindgen(readonly)]
+    pub energy_cap: u8,
+    #[wasm_bindgen(readonly)]
+    pub width: u8,
+    #[wasm_bindgen(readonly)]
+    pub height: u8,
+    #[wasm_bindgen(readonly)]
+    pub locked_action: u8,
+    #[wasm_bindgen(readonly)]
+    pub collision: u8,
+    #[wasm_bindgen(readonly)]
+    pub flags: u8,
+}
+
+/// One spawn record of a render frame
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct SpawnRecord {
+    #[wasm_bindgen(readonly)]
+    pub id: u8,
+    #[wasm_bindgen(readonly)]
+    pub spawn_id: u8,
+    #[wasm_bindgen(readonly)]
+    pub owner_id: u8,
+    #[wasm_bindgen(readonly)]
+    pub element: u8,
+    #[wasm_bindgen(readonly)]
+    pub pos_x: i16,
+    #[wasm_bindgen(readonly)]
+    pub pos_y: i16,
+    #[wasm_bindgen(readonly)]
+    pub vel_x: i16,
+    #[wasm_bindgen(readonly)]
+    pub vel_y: i16,
+    #[wasm_bindgen(readonly)]
+    pub rotation: i16,
+    #[wasm_bindgen(readonly)]
+    pub life_span: u16,
+    #[wasm_bindgen(readonly)]
+    pub health: u16,
+    #[wasm_bindgen(readonly)]
+    pub width: u8,
+    #[wasm_bindgen(readonly)]
+    pub height: u8,
+}
+
+/// One status effect record of a render frame
+#[wasm_bindgen]
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct StatusEffectRecord {
+    #[wasm_bindgen(readonly)]
+    pub definition_id: u16,
+    #[wasm_bindgen(readonly)]
+    pub character_id: u8,
+    #[wasm_bindgen(readonly)]
+    pub stack_count: u8,
+    #[wasm_bindgen(readonly)]
+    pub life_span: u16,
+    #[wasm_bindgen(readonly)]
+    pub duration: u16,
+}
+
+impl StateView {
+    /// Check the header and the record counts against the byte length
+    pub fn from_bytes(bytes: Vec<u8>) -> Result<StateView, String> {
+        if bytes.len() < HEADER_SIZE {
+            return Err(format!(
+                "Render frame is {} bytes, shorter than its {} byte header",
+                bytes.len(),
+                HEADER_SIZE
+            ));
+        }
+        if bytes[0] != RENDER_FRAME_VERSION {
+            return Err(format!(
+                "Unsupported render frame version {} (expected {})",
+                bytes[0], RENDER_FRAME_VERSION
+            ));
+        }
+        let view = StateView { bytes };
+        let expected = view.status_effects_offset()
+            + view.status_effect_count() as usize * STATUS_EFFECT_STRIDE;
+        if view.bytes.len() != expected {
+            return Err(format!(
+                "Render frame is {} bytes but its header describes {}",
+                view.bytes.len(),
+                expected
+            ));
+        }
+        Ok(view)
+    }
+
+    fn u16_at(&self, at: usize) -> u16 {
+        u16::from_le_bytes([self.bytes[at], self.bytes[at + 1]])
+    }
+
+    fn i16_at(&self, at: usize) -> i16 {
+        i16::from_le_bytes([self.bytes[at], self.bytes[at + 1]])
+    }
+
+    fn spawns_offset(&self) -> usize {
+        HEADER_SIZE + self.character_count() as usize * CHARACTER_STRIDE
+    }
+
+    fn status_effects_offset(&self) -> usize {
+        self.spawns_offset() + self.spawn_count() as usize * SPAWN_STRIDE
+    }
+}
+
+#[wasm_bindgen]
+impl StateView {
+    /// Hydrate a view from a buffer produced by `get_state_transferable`
+    #[wasm_bindgen]
+    pub fn from_transferable(buffer: &js_sys::ArrayBuffer) -> Result<StateView, JsValue> {
+        StateView::from_bytes(js_sys::Uint8Array::new(buffer).to_vec())
+            .map_err(|message| crate::execution_error_to_js_value(&message))
+    }
+
+    #[wasm_bindgen]
+    pub fn frame(&self) -> u32 {
+        u32::from_le_bytes([self.bytes[4], self.bytes[5], self.bytes[6], self.bytes[7]])
+    }
+
+    #[wasm_bindgen]
+    pub fn is_ended(&self) -> bool {
+        self.bytes[1] == 1
+    }
+
+    #[wasm_bindgen]
+    pub fn character_count(&self) -> u8 {
+        self.bytes[2]
+    }
+
+    #[wasm_bindgen]
+    pub fn spawn_count(&self) -> u8 {
+        self.bytes[3]
+    }
+
+    #[wasm_bindgen]
+    pub fn status_effect_count(&self) -> u16 {
+        self.u16_at(8)
+    }
+
+    /// Character record at `index` (not the character id), if any
+    #[wasm_bindgen]
+    pub fn character(&self, index: u8) -> Option<CharacterRecord> {
+        if index >= self.character_count() {
+            return None;
+        }
+        let at = HEADER_SIZE + index as usize * CHARACTER_STRIDE;
+        let b = &self.bytes[at..at + CHARACTER_STRIDE];
+        Some(CharacterRecord {
+            id: b[0],
+            group: b[1],
+            facing: b[2],
+            gravity_dir: b[3],
+            pos_x: self.i16_at(at + 4),
+            pos_y: self.i16_at(at + 6),
+            vel_x: self.i16_at(at + 8),
+            vel_y: self.i16_at(at + 10),
+            health: self.u16_at(at + 12),
+            health_cap: self.u16_at(at + 14),
+            energy: b[16],
+            energy_cap: b[17],
+            width: b[18],
+            height: b[19],
+            locked_action: b[20],
+            collision: b[21],
+            flags: b[22],
+        })
+    }
+
+    /// Spawn record at `index`, if any
+    #[wasm_bindgen]
+    pub fn spawn(&self, index: u8) -> Option<SpawnRecord> {
+        if index >= self.spawn_count() {
+            return None;
+        }
+        let at = self.spawns_offset() + index as usize * SPAWN_STRIDE;
+        let b = &self.bytes[at..at + SPAWN_STRIDE];
+        Some(SpawnRecord {
+            id: b[0],
+            spawn_id: b[1],
+            owner_id: b[2],
+            element: b[3],
+            pos_x: self.i16_at(at + 4),
+            pos_y: self.i16_at(at + 6),
+            vel_x: self.i16_at(at + 8),
+            vel_y: self.i16_at(at + 10),
+            rotation: self.i16_at(at + 12),
+            life_span: self.u16_at(at + 14),
+            health: self.u16_at(at + 16),
+            width: b[18],
+            height: b[19],
+        })
+    }
+
+    /// Status effect record at `index`, if any
+    #[wasm_bindgen]
+    pub fn status_effect(&self, index: u16) -> Option<StatusEffectRecord> {
+        if index >= self.status_effect_count() {
+            return None;
+        }
+        let at = self.status_effects_offset() + index as usize * STATUS_EFFECT_STRIDE;
+        Some(StatusEffectRecord {
+            definition_id: self.u16_at(at),
+            character_id: self.bytes[at + 2],
+            stack_count: self.bytes[at + 3],
+            life_span: self.u16_at(at + 4),
+            duration: self.u16_at(at + 6),
+        })
+    }
+}
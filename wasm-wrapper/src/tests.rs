@@ -2,8 +2,18 @@
 //!
 //! These tests verify JSON serialization, game initialization, and basic functionality
 
-use crate::types::{convert_tilemap, CharacterDefinitionJson};
-use robot_masters_engine::{entity::Character, math::Fixed};
+use crate::types::{
+    convert_tilemap, ActionDefinitionJson, CharacterDefinitionJson, ConditionDefinitionJson,
+    GameConfig, RewardTableJson, ScriptQuotaJson, SpawnDefinitionJson, StatusEffectDefinitionJson,
+};
+use robot_masters_engine::{
+    entity::{
+        ActionDefinition, Character, ConditionDefinition, SpawnDefinition, StatusEffectDefinition,
+    },
+    math::Fixed,
+    reward::RewardTable,
+};
+use serde::{de::DeserializeOwned, Serialize};
 use wasm_bindgen_test::*;
 
 wasm_bindgen_test_configure!(run_in_browser);
@@ -23,6 +33,7 @@ fn test_character_json_conversion() {
         weight: 10,
         jump_force: [480, 32], // 15.0 as numerator/denominator
         move_speed: [160, 32], // 5.0 as numerator/denominator
+        wall_slide_speed: None,
         armor: [10, 20, 30, 40, 50, 60, 70, 80, 90],
         energy_regen: 2,
         energy_regen_rate: 60,
@@ -33,6 +44,12 @@ fn test_character_json_conversion() {
         target_id: None,
         target_type: 0,
         behaviors: vec![[0, 1], [2, 3]],
+        detection_radius: 0,
+        detection_requires_los: false,
+        overflow_mode: 0,
+        quota: ScriptQuotaJson::default(),
+        name: None,
+        sprite: None,
     };
 
     // Convert to engine type
@@ -107,39 +124,1701 @@ fn test_tilemap_conversion() {
     assert_eq!(tilemap[6], [0; 16]); // Row of all 0s
 }
 
-// NOTE: The remaining tests are broken due to missing new properties in CharacterDefinitionJson
-// They need to be updated in a separate task to include all the new properties:
-// - health_cap, energy_cap, power, weight, jump_force, move_speed, dir, enmity, target_id, target_type
-// For now, they are commented out to allow the validation logic to be tested independently
+/// Config exercising every definition field with a non-default value
+/// Fixed-point values use the engine denominator (32) so they convert back unchanged
+const ROUND_TRIP_CONFIG: &str = r#"{
+    "seed": 4242,
+    "gravity": [16, 32],
+    "tilemap": [],
+    "boundary": null,
+    "damage_window": 120,
+    "characters": [{
+        "id": 3, "group": 1,
+        "position": [[176, 32], [-328, 32]],
+        "size": [16, 24],
+        "health": 900, "health_cap": 1000,
+        "energy": 40, "energy_cap": 90,
+        "power": 7, "weight": 12,
+        "jump_force": [480, 32], "move_speed": [72, 32],
+        "armor": [101, 102, 103, 104, 105, 106, 107, 108, 109],
+        "energy_regen": 2, "energy_regen_rate": 30,
+        "energy_charge": 4, "energy_charge_rate": 8,
+        "dir": [2, 0], "enmity": 9,
+        "target_id": 5, "target_type": 2,
+        "behaviors": [[1, 0], [0, 2]],
+        "detection_radius": 96, "detection_requires_los": true,
+        "quota": { "max_instructions": 500, "max_property_writes": 40, "max_spawns": 3 }
+    }],
+    "actions": [{
+        "energy_cost": 11, "cooldown": 301,
+        "args": [1, 2, 3, 4, 5, 6, 7, 8],
+        "spawns": [9, 10, 11, 12],
+        "script": [20, 0, 1, 0, 1]
+    }],
+    "conditions": [{
+        "energy_mul": -48,
+        "args": [8, 7, 6, 5, 4, 3, 2, 1],
+        "script": [0, 1]
+    }],
+    "spawns": [{
+        "damage_base": 1200, "damage_range": 40,
+        "crit_chance": 15, "crit_multiplier": 3,
+        "health_cap": 4, "duration": 600,
+        "element": 5, "chance": 80,
+        "size": [8, 6],
+        "collision_mask": 3,
+        "falloff_start": 32, "falloff_end": 96,
+        "velocity_spread": [16, 32],
+        "duration_variance": 10, "damage_variance": 25,
+        "args": [11, 12, 13, 14, 15, 16, 17, 18],
+        "spawns": [1, 2, 3, 4],
+        "behavior_script": [84, 0],
+        "collision_script": [0, 0],
+        "despawn_script": [90, 1]
+    }],
+    "status_effects": [{
+        "duration": 240, "stack_limit": 3,
+        "reset_on_stack": true, "chance": 50,
+        "category": 2, "priority": 4,
+        "args": [21, 22, 23, 24, 25, 26, 27, 28],
+        "spawns": [5, 6, 7, 8],
+        "on_script": [1, 0],
+        "tick_script": [2, 3],
+        "off_script": [4, 5]
+    }]
+}"#;
+
+/// Assert JSON -> engine -> JSON leaves a definition unchanged, and that the engine
+/// value survives its own serde round trip
+fn assert_round_trip<J, E>(json: &J)
+where
+    J: Clone + Serialize + for<'a> From<&'a E>,
+    E: From<J> + Serialize + DeserializeOwned,
+{
+    let engine: E = json.clone().into();
+    let back = J::from(&engine);
+    assert_eq!(
+        serde_json::to_value(json).unwrap(),
+        serde_json::to_value(&back).unwrap(),
+        "JSON -> engine -> JSON changed the definition"
+    );
+
+    let engine_value = serde_json::to_value(&engine).unwrap();
+    let restored: E = serde_json::from_value(engine_value.clone()).unwrap();
+    assert_eq!(
+        engine_value,
+        serde_json::to_value(&restored).unwrap(),
+        "engine serde round trip changed the definition"
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_config_definitions_round_trip() {
+    let config: GameConfig = serde_json::from_str(ROUND_TRIP_CONFIG).unwrap();
+
+    for character in &config.characters {
+        assert_round_trip::<CharacterDefinitionJson, Character>(character);
+    }
+    for action in &config.actions {
+        assert_round_trip::<ActionDefinitionJson, ActionDefinition>(action);
+    }
+    for condition in &config.conditions {
+        assert_round_trip::<ConditionDefinitionJson, ConditionDefinition>(condition);
+    }
+    for spawn in &config.spawns {
+        assert_round_trip::<SpawnDefinitionJson, SpawnDefinition>(spawn);
+    }
+    for status_effect in &config.status_effects {
+        assert_round_trip::<StatusEffectDefinitionJson, StatusEffectDefinition>(status_effect);
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_definition_conversion_macro_maps_listed_fields() {
+    use crate::types::{convert, definition_conversion};
+    use robot_masters_engine::math::Fixed;
+
+    #[derive(Debug, Clone, PartialEq)]
+    struct ProbeJson {
+        power: u8,
+        speed: Option<[i16; 2]>,
+        size: [u8; 2],
+        label: Option<String>,
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Probe {
+        power: u8,
+        speed: Fixed,
+        size: (u8, u8),
+    }
+
+    definition_conversion!(ProbeJson <=> Probe {
+        power: copy,
+        speed: fixed_frac_or_zero,
+        size: pair,
+    } json_only {
+        label,
+    });
+
+    let json = ProbeJson {
+        power: 7,
+        speed: Some([3, 2]),
+        size: [4, 6],
+        label: Some("probe".into()),
+    };
+    let engine = Probe::from(json);
+    assert_eq!(
+        engine,
+        Probe {
+            power: 7,
+            speed: Fixed::from_frac(3, 2),
+            size: (4, 6),
+        }
+    );
+
+    // Each field goes back through its conversion; wrapper-only fields come back as defaults
+    let back = ProbeJson::from(&engine);
+    assert_eq!(back.power, 7);
+    assert_eq!(
+        back.speed.map(convert::fixed_frac::to_engine),
+        Some(engine.speed)
+    );
+    assert_eq!(back.size, [4, 6]);
+    assert_eq!(back.label, None);
+
+    let still = Probe {
+        speed: Fixed::ZERO,
+        ..engine
+    };
+    assert_eq!(ProbeJson::from(&still).speed, None);
+}
+
+#[wasm_bindgen_test]
+fn test_reward_table_round_trip() {
+    let table: RewardTableJson = serde_json::from_str(
+        r#"{"rolls": 3, "entries": [
+            {"item_id": 1, "weight": 90, "min_quantity": 1, "max_quantity": 3},
+            {"item_id": 700, "weight": 10, "min_quantity": 2, "max_quantity": 2}
+        ]}"#,
+    )
+    .unwrap();
+
+    assert_round_trip::<RewardTableJson, RewardTable>(&table);
+}
+
+#[wasm_bindgen_test]
+fn test_optional_spawn_fields_round_trip_to_defaults() {
+    // Omitted serde-default fields come back as their explicit defaults
+    let spawn: SpawnDefinitionJson = serde_json::from_str(
+        r#"{
+            "damage_base": 10, "damage_range": 0, "crit_chance": 0, "crit_multiplier": 1,
+            "health_cap": 1, "duration": 60, "element": null, "chance": 100, "size": [4, 4],
+            "args": [0, 0, 0, 0, 0, 0, 0, 0], "spawns": [0, 0, 0, 0],
+            "behavior_script": [], "collision_script": [], "despawn_script": []
+        }"#,
+    )
+    .unwrap();
+
+    let engine: SpawnDefinition = spawn.clone().into();
+    let back = SpawnDefinitionJson::from(&engine);
+    assert_eq!(back.velocity_spread, None);
+    assert_eq!(back.collision_mask, 0);
+    assert_eq!(back.falloff_end, 0);
+    assert_round_trip::<SpawnDefinitionJson, SpawnDefinition>(&spawn);
+}
+
+#[wasm_bindgen_test]
+fn test_engine_constants_export() {
+    let constants = crate::types::EngineConstantsJson::from_engine();
+
+    assert_eq!(constants.max_frames, 3840);
+    assert_eq!(constants.element_count, 9);
+    assert_eq!(constants.operators["EXIT"], 0);
+    assert_eq!(constants.operators["CAMERA_HINT"], 110);
+    assert_eq!(constants.properties["CHARACTER_HEALTH"], 0x18);
+    assert_eq!(constants.operators["REPEAT"], 14);
+    assert_eq!(constants.max_repeat_count, 32);
+    assert_eq!(constants.default_script_step_budget, 4096);
+
+    // Opcodes and property addresses must never collide within their own table
+    for table in [&constants.operators, &constants.properties] {
+        let mut values: Vec<u8> = table.values().copied().collect();
+        values.sort_unstable();
+        values.dedup();
+        assert_eq!(values.len(), table.len());
+    }
+}
+
+#[wasm_bindgen_test]
+fn test_legacy_frame_sentinels_migrate() {
+    use robot_masters_engine::core::NEVER_FRAME;
+    use robot_masters_engine::entity::ActionInstance;
+
+    // Snapshots from u16-frame builds stored "never used" as u16::MAX
+    let legacy: ActionInstance = serde_json::from_str(
+        r#"{
+            "definition_id": 0, "cooldown": 0, "last_used_frame": 65535,
+            "runtime_vars": [0, 0, 0, 0], "runtime_fixed": [0, 0, 0, 0]
+        }"#,
+    )
+    .unwrap();
+    assert_eq!(legacy.last_used_frame, NEVER_FRAME);
+    assert!(!legacy.is_on_cooldown(70_000, 60));
+
+    // Current snapshots keep long-session timestamps and the u32 sentinel intact
+    let mut character = Character::new(0, 0);
+    character.action_last_used = vec![70_000, NEVER_FRAME];
+    let json = serde_json::to_string(&character).unwrap();
+    let restored: Character = serde_json::from_str(&json).unwrap();
+    assert_eq!(restored.action_last_used, vec![70_000, NEVER_FRAME]);
+}
+
+#[wasm_bindgen_test]
+fn test_gravity_accepts_scalar_and_vector() {
+    use crate::types::GravityJson;
+
+    // Legacy scalar gravity only pulls along the y axis
+    let scalar: GravityJson = serde_json::from_str("[16, 32]").unwrap();
+    assert_eq!(scalar.to_vector(), (Fixed::ZERO, Fixed::from_frac(1, 2)));
+
+    // Vector gravity sets both axes, e.g. a sideways arena
+    let vector: GravityJson = serde_json::from_str("[[-1, 4], [0, 1]]").unwrap();
+    assert_eq!(vector.to_vector(), (Fixed::from_frac(-1, 4), Fixed::ZERO));
+
+    // Every denominator is validated
+    let mut config: GameConfig = serde_json::from_str(ROUND_TRIP_CONFIG).unwrap();
+    config.gravity = Some(serde_json::from_str("[[1, 0], [1, 2]]").unwrap());
+    let errors = config.validate().unwrap_err();
+    assert!(errors.iter().any(|error| error.field == "gravity"));
+}
+
+/// Playable single-character match built from the round-trip definitions
+fn sandbox_config() -> GameConfig {
+    let mut config: GameConfig = serde_json::from_str(ROUND_TRIP_CONFIG).unwrap();
+    config.tilemap = vec![vec![0; 16]; 15];
+    config.characters[0].position = [[64, 1], [64, 1]];
+    config.characters[0].behaviors.clear();
+    config.actions[0].spawns = [0; 4];
+    config.status_effects.clear();
+    config
+}
+
+#[wasm_bindgen_test]
+fn test_armor_and_elements_must_cover_every_element() {
+    use robot_masters_engine::core::ELEMENT_COUNT;
+    use robot_masters_engine::entity::Element;
+
+    let armor = "[101, 102, 103, 104, 105, 106, 107, 108, 109]";
+    for wrong in [
+        "[101, 102, 103, 104, 105, 106, 107, 108]",
+        "[1, 2, 3, 4, 5, 6, 7, 8, 9, 10]",
+    ] {
+        let json = ROUND_TRIP_CONFIG.replace(armor, wrong);
+        let error = serde_json::from_str::<GameConfig>(&json).unwrap_err();
+        assert!(error.to_string().contains("invalid length"), "{error}");
+    }
+
+    let config: GameConfig = serde_json::from_str(ROUND_TRIP_CONFIG).unwrap();
+    let character: Character = config.characters[0].clone().into();
+    assert_eq!(character.get_armor(Element::Virus), 109);
+    assert_eq!(Element::ALL.len(), ELEMENT_COUNT);
+
+    let mut config = sandbox_config();
+    config.spawns[0].element = Some(ELEMENT_COUNT as u8);
+    let errors = config.validate().unwrap_err();
+    assert_eq!(errors[0].field, "spawns[0].element");
+    assert_eq!(errors[0].message, "Element value must be between 0 and 8");
+}
+
+#[wasm_bindgen_test]
+fn test_malformed_scripts_fail_validation_with_offset() {
+    use robot_masters_engine::constants::{operator_address, property_address};
+
+    let mut config = sandbox_config();
+    assert!(config.validate().is_ok());
+
+    // Truncated ASSIGN_BYTE after a valid EXIT_IF_COOLDOWN
+    config.actions[0].script = vec![operator_address::EXIT_IF_COOLDOWN, 0, 20, 0];
+    // Conditions are read-only
+    config.conditions[0].script = vec![
+        operator_address::WRITE_PROP,
+        property_address::CHARACTER_VEL_X,
+        8,
+    ];
+    let errors = config.validate().unwrap_err();
+    assert_eq!(errors.len(), 2);
+    assert_eq!(errors[0].field, "conditions[0].script");
+    assert_eq!(errors[1].field, "actions[0].script");
+    assert_eq!(
+        errors[1].message,
+        "Invalid script bytecode at byte offset 2"
+    );
+    assert_eq!(errors[1].context.as_deref(), Some("Truncated"));
+}
+
+#[wasm_bindgen_test]
+fn test_opcode_version_limits_and_migrates_scripts() {
+    use crate::GameWrapper;
+    use robot_masters_engine::constants::operator_address;
+    use robot_masters_engine::core::OPCODE_VERSION;
+
+    let mut config = sandbox_config();
+    config.opcode_version = Some(1);
+    assert!(config.validate().is_ok());
+    let wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    let migrated: GameConfig = serde_json::from_str(&wrapper.get_config_json().unwrap()).unwrap();
+    assert_eq!(migrated.opcode_version, Some(OPCODE_VERSION));
+    assert_eq!(migrated.actions[0].script, config.actions[0].script);
+
+    // SIN joined the table in version 2
+    config.actions[0].script = vec![operator_address::SIN, 0, 0, operator_address::EXIT, 1];
+    let errors = config.validate().unwrap_err();
+    assert_eq!(errors[0].field, "actions[0].script");
+    assert_eq!(
+        errors[0].context.as_deref(),
+        Some("UnavailableOperator { opcode: 35, version: 1 }")
+    );
+    config.opcode_version = None;
+    assert!(config.validate().is_ok());
+
+    config.opcode_version = Some(OPCODE_VERSION + 1);
+    let errors = config.validate().unwrap_err();
+    assert_eq!(errors.len(), 1);
+    assert_eq!(errors[0].field, "opcode_version");
+}
+
+#[wasm_bindgen_test]
+fn test_constant_loads_are_checked_against_the_pool() {
+    use robot_masters_engine::constants::operator_address;
+
+    let mut config = sandbox_config();
+    config.actions[0].script = vec![
+        operator_address::LOAD_CONST,
+        0,
+        1,
+        operator_address::EXIT,
+        1,
+    ];
+    let errors = config.validate().unwrap_err();
+    assert_eq!(errors[0].field, "actions[0].script");
+    assert_eq!(
+        errors[0].context.as_deref(),
+        Some("UnknownConstant { index: 1 }")
+    );
+
+    config.actions[0].constants = vec![600, 1200];
+    assert!(config.validate().is_ok());
+    let action = ActionDefinition::from(config.actions[0].clone());
+    assert_eq!(action.constants, vec![600, 1200]);
+}
+
+#[wasm_bindgen_test]
+fn test_action_runs_in_sandbox() {
+    use crate::types::ActionTestResultJson;
+    use crate::GameWrapper;
+    use robot_masters_engine::constants::{operator_address, property_address};
+
+    // Action pushes the character right; behaviors are ignored by the sandbox
+    let mut config = sandbox_config();
+    config.actions[0].script = vec![
+        operator_address::ASSIGN_FIXED,
+        0,
+        3,
+        1,
+        operator_address::WRITE_PROP,
+        property_address::CHARACTER_VEL_X,
+        0,
+        operator_address::EXIT,
+        1,
+    ];
+    let config_json = serde_json::to_string(&config).unwrap();
+
+    let result: ActionTestResultJson =
+        serde_json::from_str(&GameWrapper::test_action(&config_json, 0, 0, 2).unwrap()).unwrap();
+    assert_eq!(result.script_error, None);
+    assert_eq!(result.frames_advanced, 2);
+    assert_eq!(result.state.frame, 2);
+    let [x_num, x_den] = result.state.characters[0].position[0];
+    assert!(x_num > 64 * x_den, "character should have moved right");
+}
+
+#[wasm_bindgen_test]
+fn test_condition_evaluates_against_captured_state() {
+    use crate::types::ConditionEvaluationJson;
+    use crate::GameWrapper;
+    use robot_masters_engine::constants::{operator_address, property_address};
+
+    // Condition passes while the character has health
+    let mut config = sandbox_config();
+    config.conditions[0].script = vec![
+        operator_address::READ_PROP,
+        0,
+        property_address::CHARACTER_HEALTH_BYTE,
+        operator_address::EXIT_WITH_VAR,
+        0,
+    ];
+    let config_json = serde_json::to_string(&config).unwrap();
+
+    let mut wrapper = GameWrapper::new(&config_json).unwrap();
+    wrapper.new_game().unwrap();
+    wrapper.state.as_mut().unwrap().characters[0].health = 0;
+    let snapshot = wrapper.save_state().unwrap();
+
+    let evaluation: ConditionEvaluationJson = serde_json::from_str(
+        &GameWrapper::evaluate_condition_against_state(&snapshot, 0, 0).unwrap(),
+    )
+    .unwrap();
+    assert!(!evaluation.result);
+    assert_eq!(evaluation.script_error, None);
+    let operators: Vec<&str> = evaluation
+        .trace
+        .iter()
+        .map(|step| step.operator.as_str())
+        .collect();
+    assert_eq!(operators, ["READ_PROP", "EXIT_WITH_VAR"]);
+    assert_eq!(evaluation.trace[0].vars[0], 0);
+
+    // The JSON capture entry point restores the same character state
+    let evaluation: ConditionEvaluationJson = serde_json::from_str(
+        &GameWrapper::evaluate_condition_against_state_json(
+            &config_json,
+            &wrapper.get_state_json().unwrap(),
+            0,
+            0,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert!(!evaluation.result);
+}
+
+#[wasm_bindgen_test]
+fn test_condition_snapshot_keeps_static_vars() {
+    use crate::types::ConditionEvaluationJson;
+    use crate::GameWrapper;
+    use robot_masters_engine::constants::{operator_address, property_address};
+
+    // Condition exits with its definition's first static var
+    let mut config = sandbox_config();
+    config.conditions[0].script = vec![
+        operator_address::READ_PROP,
+        0,
+        property_address::CONDITION_DEF_STATIC0,
+        operator_address::EXIT_WITH_VAR,
+        0,
+    ];
+    let config_json = serde_json::to_string(&config).unwrap();
+
+    let mut wrapper = GameWrapper::new(&config_json).unwrap();
+    wrapper.new_game().unwrap();
+    wrapper.state.as_mut().unwrap().static_vars.conditions[0][0] = 7;
+
+    let snapshot = wrapper.save_state().unwrap();
+    let evaluation: ConditionEvaluationJson = serde_json::from_str(
+        &GameWrapper::evaluate_condition_against_state(&snapshot, 0, 0).unwrap(),
+    )
+    .unwrap();
+    assert_eq!(evaluation.trace[0].vars[0], 7);
+
+    // JSON captures carry no static vars, so the fresh match's value is used
+    let evaluation: ConditionEvaluationJson = serde_json::from_str(
+        &GameWrapper::evaluate_condition_against_state_json(
+            &config_json,
+            &wrapper.get_state_json().unwrap(),
+            0,
+            0,
+        )
+        .unwrap(),
+    )
+    .unwrap();
+    assert_eq!(evaluation.trace[0].vars[0], 0);
+
+    assert!(GameWrapper::evaluate_condition_against_state(b"not a snapshot", 0, 0).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_conditions_call_action_scripts_up_to_the_configured_depth() {
+    use crate::types::ConditionEvaluationJson;
+    use crate::GameWrapper;
+    use robot_masters_engine::constants::{operator_address, property_address};
+
+    // The condition passes when action 0's script finds the character alive
+    let mut config = sandbox_config();
+    config.actions[0].script = vec![
+        operator_address::READ_PROP,
+        0,
+        property_address::CHARACTER_HEALTH_BYTE,
+    ];
+    config.conditions[0].script = vec![
+        operator_address::CALL,
+        0,
+        operator_address::EXIT_WITH_VAR,
+        0,
+    ];
+    let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    wrapper.new_game().unwrap();
+    let state_json = wrapper.get_state_json().unwrap();
+
+    let evaluate = |config: &GameConfig| -> ConditionEvaluationJson {
+        let config_json = serde_json::to_string(config).unwrap();
+        serde_json::from_str(
+            &GameWrapper::evaluate_condition_against_state_json(&config_json, &state_json, 0, 0)
+                .unwrap(),
+        )
+        .unwrap()
+    };
+
+    let evaluation = evaluate(&config);
+    assert!(evaluation.result);
+    let steps: Vec<(u8, &str)> = evaluation
+        .trace
+        .iter()
+        .map(|step| (step.depth, step.operator.as_str()))
+        .collect();
+    assert_eq!(steps, [(0, "CALL"), (1, "READ_PROP"), (0, "EXIT_WITH_VAR")]);
+
+    config.max_call_depth = Some(0);
+    assert!(!evaluate(&config).result);
+}
+
+#[wasm_bindgen_test]
+fn test_step_budgets_reach_the_engine() {
+    use crate::GameWrapper;
+    use robot_masters_engine::constants::operator_address;
+
+    let budgets = |config: &GameConfig| {
+        let mut wrapper = GameWrapper::new(&serde_json::to_string(config).unwrap()).unwrap();
+        wrapper.new_game().unwrap();
+        wrapper.step_frame().unwrap();
+        let state = wrapper.state.as_ref().unwrap();
+        (
+            state.script_step_budget,
+            state.frame_step_budget,
+            state.frame_steps(),
+        )
+    };
+
+    // The condition and the action run one instruction each
+    let mut config = sandbox_config();
+    config.characters[0].behaviors = vec![[0, 0]];
+    config.conditions[0].script = vec![operator_address::EXIT, 1];
+    config.actions[0].script = vec![operator_address::EXIT, 1];
+    assert_eq!(budgets(&config), (4096, 0, 2));
+
+    config.script_step_budget = Some(16);
+    config.frame_step_budget = Some(2);
+    assert_eq!(budgets(&config), (16, 2, 2));
+}
+
+#[wasm_bindgen_test]
+fn test_instances_json_lists_script_variables() {
+    use crate::types::CharacterInstancesJson;
+    use crate::GameWrapper;
+    use robot_masters_engine::constants::operator_address;
+
+    // The condition keeps 7 in var 1 and the (free) action keeps 3 in var 0
+    let mut config = sandbox_config();
+    config.characters[0].behaviors = vec![[0, 0]];
+    config.actions[0].energy_cost = 0;
+    config.actions[0].cooldown = 0;
+    config.conditions[0].script = vec![
+        operator_address::ASSIGN_BYTE,
+        1,
+        7,
+        operator_address::EXIT,
+        1,
+    ];
+    config.actions[0].script = vec![
+        operator_address::ASSIGN_BYTE,
+        0,
+        3,
+        operator_address::EXIT,
+        1,
+    ];
+    let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    wrapper.new_game().unwrap();
+    wrapper.step_frame().unwrap();
+
+    let instances: CharacterInstancesJson =
+        serde_json::from_str(&wrapper.get_instances_json(config.characters[0].id).unwrap())
+            .unwrap();
+    assert_eq!(instances.actions.len(), 1);
+    assert_eq!(instances.actions[0].runtime_vars[0], 3);
+    assert!(!instances.actions[0].locked);
+    assert_eq!(instances.conditions.len(), 1);
+    assert_eq!(instances.conditions[0].runtime_vars[1], 7);
+    assert!(instances.status_effects.is_empty());
+
+    let instances: CharacterInstancesJson =
+        serde_json::from_str(&wrapper.get_instances_json(5).unwrap()).unwrap();
+    assert!(instances.actions.is_empty() && instances.conditions.is_empty());
+}
 
-/*
 #[wasm_bindgen_test]
-fn test_game_wrapper_creation() {
-    // This test needs to be updated with new CharacterDefinitionJson properties
+fn test_frame_work_budget_reaches_the_engine() {
+    use crate::GameWrapper;
+
+    let work_budget = |config: &GameConfig| {
+        let mut wrapper = GameWrapper::new(&serde_json::to_string(config).unwrap()).unwrap();
+        wrapper.new_game().unwrap();
+        wrapper.state.as_ref().unwrap().frame_work_budget
+    };
+
+    let mut config = sandbox_config();
+    assert_eq!(work_budget(&config), 0);
+    config.frame_work_budget = Some(512);
+    assert_eq!(work_budget(&config), 512);
 }
 
 #[wasm_bindgen_test]
-fn test_game_initialization() {
-    // This test needs to be updated with new CharacterDefinitionJson properties
+fn test_saved_state_resumes_deterministically() {
+    use crate::GameWrapper;
+
+    let config_json = serde_json::to_string(&sandbox_config()).unwrap();
+    let mut wrapper = GameWrapper::new(&config_json).unwrap();
+    wrapper.new_game().unwrap();
+    wrapper.advance_frames_unchecked(30).unwrap();
+
+    let saved = wrapper.save_state().unwrap();
+    wrapper.advance_frames_unchecked(60).unwrap();
+    let continuous_hash = wrapper.get_state_hash().unwrap();
+
+    wrapper.load_state(&saved).unwrap();
+    assert_eq!(wrapper.get_frame(), 30);
+    wrapper.advance_frames_unchecked(60).unwrap();
+    assert_eq!(wrapper.get_state_hash().unwrap(), continuous_hash);
+}
+
+#[wasm_bindgen_test]
+fn test_bulk_advance_skips_events_and_restores_the_setting() {
+    use crate::GameWrapper;
+
+    let config_json = serde_json::to_string(&sandbox_config()).unwrap();
+    let mut stepped = GameWrapper::new(&config_json).unwrap();
+    stepped.new_game().unwrap();
+    for _ in 0..40 {
+        stepped.step_frame().unwrap();
+    }
+
+    let mut wrapper = GameWrapper::new(&config_json).unwrap();
+    wrapper.new_game().unwrap();
+    assert!(wrapper.state.as_ref().unwrap().events_enabled);
+    wrapper.advance_frames_unchecked(40).unwrap();
+    let state = wrapper.state.as_ref().unwrap();
+    assert!(state.events_enabled);
+    assert!(state.events.is_empty());
+    assert_eq!(wrapper.get_state_hash(), stepped.get_state_hash());
+
+    // A disabled setting stays disabled
+    wrapper.state.as_mut().unwrap().events_enabled = false;
+    wrapper.advance_frames_unchecked(10).unwrap();
+    assert!(!wrapper.state.as_ref().unwrap().events_enabled);
+}
+
+#[wasm_bindgen_test]
+fn test_event_history_backfills_past_frames() {
+    use crate::GameWrapper;
+    use robot_masters_engine::entity::SpawnInstance;
+
+    let mut config = sandbox_config();
+    config.event_history = Some(30);
+    let config_json = serde_json::to_string(&config).unwrap();
+    let mut wrapper = GameWrapper::new(&config_json).unwrap();
+    wrapper.new_game().unwrap();
+
+    // An already expired spawn is removed (and reported) during frame 0
+    let state = wrapper.state.as_mut().unwrap();
+    assert_eq!(state.event_history.frames(), 30);
+    let mut spawn = SpawnInstance::new(0, 0, (Fixed::from_int(64), Fixed::from_int(64)));
+    spawn.life_span = 0;
+    state.insert_spawn(spawn).expect("spawn fits");
+    wrapper.step_frame().unwrap();
+
+    let history: Vec<serde_json::Value> =
+        serde_json::from_str(&wrapper.get_event_history_json(0, 0).unwrap()).unwrap();
+    assert!(!history.is_empty());
+    assert!(history.iter().all(|event| event["frame"] == 0));
+    assert_eq!(wrapper.get_event_history_json(1, 10).unwrap(), "[]");
 }
 
 #[wasm_bindgen_test]
-fn test_frame_stepping() {
-    // This test needs to be updated with new CharacterDefinitionJson properties
+fn test_drain_events_collects_frames_until_drained() {
+    use crate::types::DrainedEventsJson;
+    use crate::GameWrapper;
+    use robot_masters_engine::entity::SpawnInstance;
+
+    let config_json = serde_json::to_string(&sandbox_config()).unwrap();
+    let mut wrapper = GameWrapper::new(&config_json).unwrap();
+    wrapper.new_game().unwrap();
+
+    let state = wrapper.state.as_mut().unwrap();
+    let mut spawn = SpawnInstance::new(0, 0, (Fixed::from_int(64), Fixed::from_int(64)));
+    spawn.life_span = 0;
+    state.insert_spawn(spawn).unwrap();
+    // The expired spawn is removed during frame 0; frame 1 adds nothing
+    wrapper.step_frame().unwrap();
+    wrapper.step_frame().unwrap();
+
+    let drained: DrainedEventsJson =
+        serde_json::from_str(&wrapper.drain_events().unwrap()).unwrap();
+    assert_eq!(drained.dropped, 0);
+    let types: Vec<&str> = drained
+        .events
+        .iter()
+        .map(|event| event.event_type.as_str())
+        .collect();
+    assert_eq!(types, ["spawn_created", "spawn_removed"]);
+    let created = drained.events[0].spawn.as_ref().unwrap();
+    assert_eq!((created.spawn_id, created.owner_id), (0, Some(0)));
+
+    let drained: DrainedEventsJson =
+        serde_json::from_str(&wrapper.drain_events().unwrap()).unwrap();
+    assert!(drained.events.is_empty());
 }
 
 #[wasm_bindgen_test]
-fn test_state_serialization() {
-    // This test needs to be updated with new CharacterDefinitionJson properties
+fn test_combat_log_uses_configured_names() {
+    use crate::GameWrapper;
+    use robot_masters_engine::entity::{Element, SpawnInstance};
+
+    let mut config = sandbox_config();
+    config.characters[0].name = Some("Gutsman".to_string());
+    config.characters[0].armor = [0; 9];
+    // No damage or crit rolls, so the hit deals exactly the spawn's damage
+    config.spawns[0].damage_range = 0;
+    config.spawns[0].crit_chance = 0;
+    config.spawns[0].behavior_script.clear();
+    config.spawns[0].collision_script.clear();
+    config.spawns[0].despawn_script.clear();
+    let config_json = serde_json::to_string(&config).unwrap();
+    let mut wrapper = GameWrapper::new(&config_json).unwrap();
+    wrapper.new_game().unwrap();
+
+    // An ownerless Heat spawn lands on the character during frame 0
+    let state = wrapper.state.as_mut().unwrap();
+    let mut spawn = SpawnInstance::new(0, 0, state.characters[0].core.pos);
+    spawn.core.size = (4, 4);
+    spawn.owner_type = 0;
+    spawn.life_span = 100;
+    spawn.damage = 14;
+    spawn.element = Element::Heat;
+    state.insert_spawn(spawn).expect("spawn fits");
+    wrapper.step_frame().unwrap();
+
+    let lines: Vec<String> =
+        serde_json::from_str(&wrapper.get_combat_log_json(0, 0).unwrap()).unwrap();
+    assert!(lines.contains(&"Frame 0: Gutsman takes 14 Heat damage".to_string()));
+
+    let events: Vec<serde_json::Value> =
+        serde_json::from_str(&wrapper.get_event_history_json(0, 0).unwrap()).unwrap();
+    let hit = events
+        .iter()
+        .find(|event| event["event_type"] == "character_hit")
+        .expect("hit event reported");
+    assert_eq!(hit["hit"]["amount"], 14);
+    assert_eq!(hit["hit"]["attacker_id"], serde_json::Value::Null);
 }
 
 #[wasm_bindgen_test]
-fn test_deterministic_behavior() {
-    // This test needs to be updated with new CharacterDefinitionJson properties
+fn test_config_labels_are_echoed_into_state_json() {
+    use crate::types::{GameStateJson, SpawnStateJson};
+    use crate::GameWrapper;
+    use robot_masters_engine::entity::SpawnInstance;
+
+    let mut config = sandbox_config();
+    config.characters[0].name = Some("Gutsman".to_string());
+    config.characters[0].sprite = Some("robots/gutsman".to_string());
+    config.spawns[0].name = Some("Super Arm Rock".to_string());
+    config.spawns[0].sprite = Some("projectiles/rock".to_string());
+    config.spawns[0].behavior_script.clear();
+    config.spawns[0].collision_script.clear();
+    config.spawns[0].despawn_script.clear();
+    let config_json = serde_json::to_string(&config).unwrap();
+    let mut wrapper = GameWrapper::new(&config_json).unwrap();
+    wrapper.new_game().unwrap();
+
+    let state = wrapper.state.as_mut().unwrap();
+    let mut spawn = SpawnInstance::new(0, 3, (Fixed::from_int(200), Fixed::from_int(64)));
+    spawn.life_span = 100;
+    state.insert_spawn(spawn).expect("spawn fits");
+
+    let state_json: GameStateJson =
+        serde_json::from_str(&wrapper.get_state_json().unwrap()).unwrap();
+    assert_eq!(state_json.characters[0].name.as_deref(), Some("Gutsman"));
+    assert_eq!(
+        state_json.characters[0].sprite.as_deref(),
+        Some("robots/gutsman")
+    );
+    assert_eq!(
+        state_json.spawns[0].sprite.as_deref(),
+        Some("projectiles/rock")
+    );
+
+    let spawns: Vec<SpawnStateJson> =
+        serde_json::from_str(&wrapper.get_spawns_json().unwrap()).unwrap();
+    assert_eq!(spawns[0].name.as_deref(), Some("Super Arm Rock"));
+
+    // Labels are presentation-only and never reach the engine
+    let mut unlabeled = config.clone();
+    unlabeled.characters[0].name = None;
+    unlabeled.characters[0].sprite = None;
+    unlabeled.spawns[0].name = None;
+    unlabeled.spawns[0].sprite = None;
+    let mut reference = GameWrapper::new(&serde_json::to_string(&unlabeled).unwrap()).unwrap();
+    reference.new_game().unwrap();
+    let mut labeled = GameWrapper::new(&config_json).unwrap();
+    labeled.new_game().unwrap();
+    assert_eq!(
+        labeled.get_state_hash().unwrap(),
+        reference.get_state_hash().unwrap()
+    );
 }
 
 #[wasm_bindgen_test]
-fn test_error_handling() {
-    // This test needs to be updated with new CharacterDefinitionJson properties
+fn test_spawn_variants_inherit_earlier_definitions() {
+    let mut config: serde_json::Value = serde_json::from_str(ROUND_TRIP_CONFIG).unwrap();
+    let spawns = config["spawns"].as_array_mut().unwrap();
+    spawns.push(serde_json::json!({ "extends": 0, "element": 4, "damage_base": 600 }));
+    spawns.push(serde_json::json!({ "extends": 1, "size": [4, 4] }));
+
+    let parsed: GameConfig = serde_json::from_value(config.clone()).unwrap();
+    let [base, reskin, small] = &parsed.spawns[..] else {
+        panic!("expected three spawn definitions");
+    };
+    assert_eq!(reskin.element, Some(4));
+    assert_eq!(reskin.damage_base, 600);
+    assert_eq!(reskin.behavior_script, base.behavior_script);
+    assert_eq!(reskin.crit_chance, base.crit_chance);
+    // Variants of variants see the already expanded parent
+    assert_eq!(small.element, Some(4));
+    assert_eq!(small.size, [4, 4]);
+    assert_eq!(base.size, [8, 6]);
+
+    // Bases must be declared before their variants
+    config["spawns"][1]["extends"] = serde_json::json!(2);
+    let err = serde_json::from_value::<GameConfig>(config).unwrap_err();
+    assert!(err.to_string().contains("spawns[1].extends"));
+}
+
+#[wasm_bindgen_test]
+fn test_state_binary_follows_documented_layout() {
+    use crate::render::{CHARACTER_STRIDE, FIXED_SCALE, HEADER_SIZE, SPAWN_STRIDE};
+    use crate::GameWrapper;
+    use robot_masters_engine::entity::{Element, SpawnInstance};
+
+    let mut config = sandbox_config();
+    config.spawns[0].behavior_script.clear();
+    let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    wrapper.new_game().unwrap();
+    let state = wrapper.state.as_mut().unwrap();
+    let mut spawn = SpawnInstance::new(0, 3, (Fixed::from_int(200), Fixed::from_int(40)));
+    spawn.life_span = 77;
+    spawn.element = Element::Cryo;
+    state.insert_spawn(spawn).expect("spawn fits");
+
+    let bytes = wrapper.get_state_binary().unwrap();
+    let u16_at = |at: usize| u16::from_le_bytes([bytes[at], bytes[at + 1]]);
+    let i16_at = |at: usize| i16::from_le_bytes([bytes[at], bytes[at + 1]]);
+    assert_eq!(bytes.len(), HEADER_SIZE + CHARACTER_STRIDE + SPAWN_STRIDE);
+    assert_eq!(&bytes[..4], &[1, 0, 1, 1]);
+    assert_eq!(u16_at(8), 0);
+
+    let character = HEADER_SIZE;
+    assert_eq!(bytes[character], 3);
+    assert_eq!(i16_at(character + 4), 64 * FIXED_SCALE);
+    assert_eq!(u16_at(character + 12), 900);
+    assert_eq!(u16_at(character + 14), 1000);
+    assert_eq!(bytes[character + 16], 40);
+    assert_eq!(bytes[character + 20], u8::MAX);
+
+    let spawn = HEADER_SIZE + CHARACTER_STRIDE;
+    assert_eq!(bytes[spawn + 2], 3);
+    assert_eq!(bytes[spawn + 3], Element::Cryo as u8);
+    assert_eq!(i16_at(spawn + 4), 200 * FIXED_SCALE);
+    assert_eq!(i16_at(spawn + 6), 40 * FIXED_SCALE);
+    assert_eq!(u16_at(spawn + 14), 77);
+}
+
+#[wasm_bindgen_test]
+fn test_state_view_reads_binary_frame() {
+    use crate::render::{StateView, FIXED_SCALE};
+    use crate::GameWrapper;
+
+    let config = sandbox_config();
+    let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    wrapper.new_game().unwrap();
+
+    let bytes = wrapper.get_state_binary().unwrap();
+    let view = StateView::from_bytes(bytes.clone()).unwrap();
+    assert_eq!(view.frame(), 0);
+    assert!(!view.is_ended());
+    assert_eq!(view.character_count(), 1);
+    assert_eq!(view.spawn(0), None);
+
+    let character = view.character(0).unwrap();
+    assert_eq!(character.id, 3);
+    assert_eq!(character.pos_x, 64 * FIXED_SCALE);
+    assert_eq!(character.health_cap, 1000);
+    assert_eq!(character.locked_action, u8::MAX);
+    assert_eq!(view.character(1), None);
+
+    // Truncated or foreign buffers are rejected instead of read out of bounds
+    assert!(StateView::from_bytes(bytes[..bytes.len() - 1].to_vec()).is_err());
+    let mut wrong_version = bytes;
+    wrong_version[0] = 0;
+    assert!(StateView::from_bytes(wrong_version).is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_run_to_end_returns_match_summary() {
+    use crate::types::MatchSummaryJson;
+    use crate::GameWrapper;
+
+    let mut config = sandbox_config();
+    config.frame_limit = Some(90);
+    let config_json = serde_json::to_string(&config).unwrap();
+    let mut wrapper = GameWrapper::new(&config_json).unwrap();
+    wrapper.new_game().unwrap();
+    wrapper.step_frame().unwrap();
+
+    let summary: MatchSummaryJson = serde_json::from_str(&wrapper.run_to_end().unwrap()).unwrap();
+    assert!(summary.ended);
+    assert_eq!(summary.frames, 90);
+    assert_eq!(summary.winner_group, Some(config.characters[0].group));
+    assert_eq!(summary.survivors, vec![config.characters[0].id]);
+    assert_eq!(summary.state_hash, wrapper.get_state_hash().unwrap());
+    assert!(wrapper.is_game_ended());
+
+    // The run is recorded like any other frames
+    let mut verifier = GameWrapper::new(&config_json).unwrap();
+    verifier.new_game().unwrap();
+    let imported = verifier
+        .import_replay(&wrapper.export_replay().unwrap())
+        .unwrap();
+    assert!(imported.contains("\"verified\":true"));
+}
+
+#[wasm_bindgen_test]
+fn test_stream_chunks_let_viewers_join_mid_game() {
+    use crate::types::StreamChunkJson;
+    use crate::GameWrapper;
+
+    let config_json = serde_json::to_string(&sandbox_config()).unwrap();
+    let mut wrapper = GameWrapper::new(&config_json).unwrap();
+    assert!(wrapper.take_stream_chunk().is_err());
+    wrapper.new_game().unwrap();
+    wrapper.start_stream(4).unwrap();
+    for _ in 0..6 {
+        wrapper.step_frame().unwrap();
+    }
+
+    let chunk: StreamChunkJson =
+        serde_json::from_str(&wrapper.take_stream_chunk().unwrap()).unwrap();
+    assert_eq!(chunk.sequence, 0);
+    assert_eq!(chunk.header.unwrap().keyframe_interval, 4);
+    let frames: Vec<u32> = chunk.keyframes.iter().map(|key| key.frame).collect();
+    assert_eq!(frames, [0, 4]);
+
+    // Batches end with a keyframe, which late viewers load directly
+    wrapper.advance_frames_unchecked(10).unwrap();
+    let chunk: StreamChunkJson =
+        serde_json::from_str(&wrapper.take_stream_chunk().unwrap()).unwrap();
+    assert_eq!(chunk.sequence, 1);
+    assert!(chunk.header.is_none());
+    let keyframe = chunk.keyframes.last().unwrap();
+    assert_eq!(keyframe.frame, 16);
+
+    let mut viewer = GameWrapper::new(&config_json).unwrap();
+    viewer.load_state(&keyframe.state).unwrap();
+    assert_eq!(viewer.get_state_hash().unwrap(), keyframe.state_hash);
+    assert_eq!(keyframe.state_hash, wrapper.get_state_hash().unwrap());
+
+    // New games reopen the stream
+    wrapper.new_game().unwrap();
+    let chunk: StreamChunkJson =
+        serde_json::from_str(&wrapper.take_stream_chunk().unwrap()).unwrap();
+    assert_eq!((chunk.sequence, chunk.keyframes[0].frame), (0, 0));
+}
+
+#[wasm_bindgen_test]
+fn test_game_result_follows_victory_condition() {
+    use crate::types::{MatchResultJson, VictoryConfigJson};
+    use crate::GameWrapper;
+
+    let mut config = sandbox_config();
+    config.frame_limit = Some(30);
+    let group = config.characters[0].group;
+    config.victory = Some(VictoryConfigJson {
+        condition: 4,
+        target: group,
+        tie_breaks: vec![0, 1],
+    });
+    let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    wrapper.new_game().unwrap();
+
+    let result: MatchResultJson =
+        serde_json::from_str(&wrapper.get_game_result_json().unwrap()).unwrap();
+    assert!(!result.ended);
+    assert_eq!(result.reason, None);
+
+    wrapper.run_to_end().unwrap();
+    let result: MatchResultJson =
+        serde_json::from_str(&wrapper.get_game_result_json().unwrap()).unwrap();
+    assert!(result.ended);
+    assert_eq!((result.condition, result.target), (4, group));
+    assert_eq!(result.reason, Some(0));
+    assert_eq!(result.winner_group, Some(group));
+    assert_eq!(result.standings[0].survivors, 1);
+    // The surviving group wins outright, so no tie-break was needed
+    assert_eq!(result.tie_break, None);
+
+    // A kill target of zero could never be reached
+    config.victory = Some(VictoryConfigJson {
+        condition: 2,
+        target: 0,
+        tie_breaks: Vec::new(),
+    });
+    assert!(config.validate().is_err());
+
+    config.victory = Some(VictoryConfigJson {
+        condition: 0,
+        target: 0,
+        tie_breaks: vec![5],
+    });
+    assert!(config.validate().is_err());
+}
+
+#[wasm_bindgen_test]
+fn test_platforms_config_places_arena_platforms() {
+    use crate::types::PlatformPlacementJson;
+    use crate::GameWrapper;
+
+    let mut config = sandbox_config();
+    config.platforms = Some(vec![PlatformPlacementJson {
+        spawn_id: 0,
+        position: [[96, 1], [160, 1]],
+    }]);
+    assert!(config.validate().is_err());
+
+    config.spawns[0].platform = true;
+    let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    wrapper.new_game().unwrap();
+    let state = wrapper.state.as_ref().unwrap();
+    assert_eq!(state.spawn_instances().len(), 1);
+    assert_eq!(state.spawn_instances()[0].owner_type, 0);
+    assert_eq!(
+        state.spawn_instances()[0].core.pos,
+        (Fixed::from_int(96), Fixed::from_int(160))
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_hazards_config_sets_hazard_tiles() {
+    use crate::types::HazardJson;
+    use crate::GameWrapper;
+    use robot_masters_engine::entity::Element;
+
+    let mut config = sandbox_config();
+    config.hazards = Some(vec![HazardJson {
+        position: [3, 14],
+        element: 4,
+        damage_per_second: 150,
+    }]);
+    let errors = config.validate().unwrap_err();
+    assert_eq!(errors[0].field, "hazards[0].position");
+
+    config.tilemap[14][3] = 10;
+    let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    wrapper.new_game().unwrap();
+    let hazards = &wrapper.state.as_ref().unwrap().hazards;
+    assert_eq!(hazards.len(), 1);
+    assert_eq!((hazards[0].x, hazards[0].y), (3, 14));
+    assert_eq!(hazards[0].element, Element::Heat);
+    assert_eq!(hazards[0].damage_per_second, 150);
+}
+
+#[wasm_bindgen_test]
+fn test_friendly_fire_config_and_team_stats() {
+    use crate::types::GroupStandingJson;
+    use crate::GameWrapper;
+
+    let mut config = sandbox_config();
+    config.friendly_fire = Some(false);
+    let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    wrapper.new_game().unwrap();
+    let state = wrapper.state.as_ref().unwrap();
+    assert!(!state.friendly_fire);
+    let health = state.characters[0].health as u32;
+
+    let teams: Vec<GroupStandingJson> =
+        serde_json::from_str(&wrapper.get_team_stats_json().unwrap()).unwrap();
+    assert_eq!(teams.len(), 1);
+    assert_eq!(teams[0].group, config.characters[0].group);
+    assert_eq!(teams[0].health, health);
+    assert_eq!(teams[0].damage_taken, 0);
+}
+
+#[wasm_bindgen_test]
+fn test_respawn_delay_config_and_dead_state() {
+    use crate::types::{GameEventJson, GameStateJson};
+    use crate::GameWrapper;
+
+    let mut config = sandbox_config();
+    config.respawn_delay = Some(3);
+    let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    wrapper.new_game().unwrap();
+    let state = wrapper.state.as_mut().unwrap();
+    assert_eq!(state.respawn_delay, 3);
+    state.characters[0].health = 0;
+    state.characters[0].respawn_timer = 3;
+
+    let json: GameStateJson = serde_json::from_str(&wrapper.get_state_json().unwrap()).unwrap();
+    assert!(json.characters[0].dead);
+    assert_eq!(json.characters[0].respawn_timer, 3);
+
+    for _ in 0..3 {
+        wrapper.step_frame().unwrap();
+    }
+    let json: GameStateJson = serde_json::from_str(&wrapper.get_state_json().unwrap()).unwrap();
+    assert!(!json.characters[0].dead);
+    assert_eq!(json.characters[0].health, json.characters[0].health_cap);
+    let events: Vec<GameEventJson> =
+        serde_json::from_str(&wrapper.get_events_json().unwrap()).unwrap();
+    let revival = events
+        .iter()
+        .find_map(|event| event.revival.as_ref())
+        .unwrap();
+    assert_eq!(revival.character_id, config.characters[0].id);
+}
+
+#[wasm_bindgen_test]
+fn test_energy_overflow_mode_builds_a_shield() {
+    use crate::types::{EngineConstantsJson, GameStateJson};
+    use crate::GameWrapper;
+    use robot_masters_engine::constants::energy_overflow;
+
+    let mut config = sandbox_config();
+    config.characters[0].overflow_mode = energy_overflow::SHIELD;
+    let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    wrapper.new_game().unwrap();
+    let state = wrapper.state.as_mut().unwrap();
+    assert_eq!(state.characters[0].overflow_mode, energy_overflow::SHIELD);
+    state.characters[0].energy = state.characters[0].energy_cap;
+    state.characters[0].energy_regen = 5;
+    state.characters[0].energy_regen_rate = 1;
+
+    for _ in 0..2 {
+        wrapper.step_frame().unwrap();
+    }
+    let json: GameStateJson = serde_json::from_str(&wrapper.get_state_json().unwrap()).unwrap();
+    assert_eq!(json.characters[0].overflow_mode, energy_overflow::SHIELD);
+    assert_eq!(json.characters[0].shield, 10);
+    assert_eq!(json.characters[0].charge, 0);
+
+    let constants = EngineConstantsJson::from_engine();
+    assert_eq!(
+        constants.energy_overflow_modes["CHARGE"],
+        energy_overflow::CHARGE
+    );
+    assert_eq!(constants.properties["CHARACTER_SHIELD"], 0xF0);
+}
+
+#[wasm_bindgen_test]
+fn test_evaluation_json_scores_every_group() {
+    use crate::types::GroupEvaluationJson;
+    use crate::GameWrapper;
+
+    let config = sandbox_config();
+    let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+    assert!(wrapper.get_evaluation_json().is_err());
+    wrapper.new_game().unwrap();
+
+    let groups: Vec<GroupEvaluationJson> =
+        serde_json::from_str(&wrapper.get_evaluation_json().unwrap()).unwrap();
+    assert_eq!(groups.len(), 1);
+    assert_eq!(groups[0].group, config.characters[0].group);
+    assert_eq!(groups[0].health, 1000);
+    assert_eq!(groups[0].win_chance, 1000);
+}
+
+#[wasm_bindgen_test]
+fn test_terrain_tiles_round_trip_through_state_json() {
+    use crate::types::GameStateJson;
+    use robot_masters_engine::{api::new_game, entity::SpawnInstance};
+
+    let mut wall = SpawnDefinition::from_def(Vec::new());
+    wall.terrain = 2;
+    let new_state = || {
+        new_game(
+            1,
+            [[0; 16]; 15],
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            vec![wall.clone()],
+            Vec::new(),
+        )
+        .unwrap()
+    };
+
+    let mut state = new_state();
+    let mut spawn = SpawnInstance::new(0, 0, (Fixed::from_int(32), Fixed::from_int(48)));
+    spawn.core.size = (16, 16);
+    spawn.life_span = 10;
+    let id = state.insert_spawn(spawn).unwrap();
+
+    let json = GameStateJson::from_game_state(&state);
+    assert_eq!(json.terrain.len(), 1);
+    assert_eq!(
+        (json.terrain[0].spawn_instance_id, json.terrain[0].tile),
+        (id, 2)
+    );
+    assert_eq!(json.tilemap[3][2], 2);
+
+    let mut resumed = new_state();
+    json.restore_into(&mut resumed).unwrap();
+    assert_eq!(resumed.terrain_tiles, state.terrain_tiles);
+    assert_eq!(
+        resumed.tile_map.get_raw_tiles(),
+        state.tile_map.get_raw_tiles()
+    );
+}
+
+#[wasm_bindgen_test]
+fn test_status_effects_json_lists_priorities_first() {
+    use crate::types::{CharacterInstancesJson, GameStateJson};
+    use robot_masters_engine::{
+        api::new_game,
+        entity::{Character, StatusEffectDefinition},
+        status::apply_status_effect,
+    };
+
+    let definitions: Vec<StatusEffectDefinition> = [0, 5, 2]
+        .into_iter()
+        .map(|priority| {
+            let mut definition =
+                StatusEffectDefinition::new(600, 1, false, 100, Vec::new(), Vec::new(), Vec::new());
+            definition.priority = priority;
+            definition
+        })
+        .collect();
+    let characters: Vec<Character> = (0..2)
+        .map(|id| {
+            let mut character = Character::new(id, id);
+            character.core.pos = (Fixed::from_int(32 + id as i16 * 64), Fixed::from_int(64));
+            character.core.size = (16, 16);
+            character
+        })
+        .collect();
+    let new_state = || {
+        new_game(
+            1,
+            [[0; 16]; 15],
+            characters.clone(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            definitions.clone(),
+        )
+        .unwrap()
+    };
+
+    // Instances 0-2 on character 0, instance 3 on character 1
+    let mut state = new_state();
+    for (character_idx, definition_id) in [(0, 0), (0, 1), (0, 2), (1, 1)] {
+        let mut character = state.characters[character_idx].clone();
+        assert!(apply_status_effect(&mut character, &mut state, definition_id).unwrap());
+        state.characters[character_idx] = character;
+    }
+
+    let json = GameStateJson::from_game_state(&state);
+    let listed: Vec<(u8, u8, u8, u8)> = json
+        .status_effects
+        .iter()
+        .map(|effect| {
+            (
+                effect.character_id,
+                effect.instance_id,
+                effect.priority,
+                effect.icon_slot,
+            )
+        })
+        .collect();
+    assert_eq!(
+        listed,
+        [(0, 1, 5, 0), (0, 2, 2, 1), (0, 0, 0, 2), (1, 3, 5, 0)]
+    );
+
+    let instances = CharacterInstancesJson::from_game_state(&state, 0);
+    let ids: Vec<u8> = instances
+        .status_effects
+        .iter()
+        .map(|effect| effect.instance_id)
+        .collect();
+    assert_eq!(ids, [1, 2, 0]);
+
+    // Restoring puts instances back at their ids regardless of the display order
+    let mut resumed = new_state();
+    json.restore_into(&mut resumed).unwrap();
+    let definition_ids = |state: &robot_masters_engine::state::GameState| -> Vec<usize> {
+        state
+            .status_effect_instances
+            .iter()
+            .map(|instance| instance.definition_id)
+            .collect()
+    };
+    assert_eq!(definition_ids(&resumed), [0, 1, 2, 1]);
+}
+
+#[wasm_bindgen_test]
+fn test_dash_steps_export_events_and_state() {
+    use crate::types::{GameEventJson, GameStateJson};
+    use robot_masters_engine::{api::new_game, constants::dash_direction, entity::Character};
+
+    let new_state = || {
+        let mut character = Character::new(0, 0);
+        character.core.pos = (Fixed::from_int(32), Fixed::from_int(64));
+        character.core.size = (16, 16);
+        new_game(
+            1,
+            [[0; 16]; 15],
+            vec![character],
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )
+        .unwrap()
+    };
+
+    let mut state = new_state();
+    assert!(state.characters[0].start_dash(dash_direction::RIGHT, Fixed::from_int(48), 3));
+    state.advance_frame().unwrap();
+
+    let events: Vec<GameEventJson> = state
+        .events
+        .iter()
+        .map(GameEventJson::from_game_event)
+        .filter(|event| event.event_type == "dash")
+        .collect();
+    assert_eq!(events.len(), 1);
+    let dash = events[0].dash.as_ref().unwrap();
+    assert_eq!((dash.from[0], dash.to[0]), ([32 * 32, 32], [48 * 32, 32]));
+    assert_eq!((dash.frames_left, dash.blocked), (2, false));
+
+    let json = GameStateJson::from_game_state(&state);
+    assert_eq!(json.characters[0].dash.as_ref().unwrap().frames_left, 2);
+
+    let mut resumed = new_state();
+    json.restore_into(&mut resumed).unwrap();
+    assert_eq!(resumed.characters[0].dash, state.characters[0].dash);
+}
+
+#[wasm_bindgen_test]
+fn test_aggro_round_trips_through_state_json() {
+    use crate::types::GameStateJson;
+    use robot_masters_engine::{api::new_game, entity::Character};
+
+    let new_state = || {
+        let characters = (0..2).map(|id| Character::new(id, id)).collect();
+        new_game(
+            1,
+            [[0; 16]; 15],
+            characters,
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )
+        .unwrap()
+    };
+
+    let mut state = new_state();
+    state.record_damage_dealt(1, 0, 25);
+
+    let json = GameStateJson::from_game_state(&state);
+    assert_eq!(json.characters[0].aggro, [0, 25, 0, 0, 0, 0, 0, 0]);
+
+    let mut resumed = new_state();
+    json.restore_into(&mut resumed).unwrap();
+    assert_eq!(resumed.characters[0].aggro, state.characters[0].aggro);
+}
+
+#[wasm_bindgen_test]
+fn test_scripts_accept_assembler_text() {
+    use crate::GameWrapper;
+
+    let mut config = serde_json::to_value(sandbox_config()).unwrap();
+    config["actions"][0]["script"] = "ASSIGN_BYTE v0 1; EXIT 1".into();
+    config["conditions"][0]["script"] = "Exit 1".into();
+    config["spawns"][0]["behavior_script"] = "".into();
+    let wrapper = GameWrapper::new(&config.to_string()).unwrap();
+    assert_eq!(
+        wrapper.disassemble_action(0).unwrap(),
+        "0000: AssignByte v0, 1\n0003: Exit 1"
+    );
+
+    // Serialized configurations keep byte arrays
+    let parsed: GameConfig = serde_json::from_value(config.clone()).unwrap();
+    assert_eq!(parsed.conditions[0].script, vec![0, 1]);
+    assert!(serde_json::to_value(&parsed).unwrap()["actions"][0]["script"].is_array());
+
+    config["actions"][0]["script"] = "EXIT 1\nREAD_PROPS v0 0x21".into();
+    let error = serde_json::from_value::<GameConfig>(config).unwrap_err();
+    assert!(error
+        .to_string()
+        .starts_with("script assembly failed on line 2: UnknownOperator"));
+}
+
+#[wasm_bindgen_test]
+fn test_wall_slide_speed_configures_sliding_characters() {
+    use crate::types::GameStateJson;
+    use crate::GameWrapper;
+
+    // The character starts in the air right against a wall filling tile column 5
+    let mut config = sandbox_config();
+    for row in config.tilemap.iter_mut() {
+        row[5] = 1;
+    }
+    config.characters[0].size = [16, 16];
+    config.characters[0].position = [[64, 1], [32, 1]];
+    config.characters[0].dir = [2, 2];
+    config.characters[0].wall_slide_speed = Some([1, 2]);
+    let config_json = serde_json::to_string(&config).unwrap();
+
+    let mut wrapper = GameWrapper::new(&config_json).unwrap();
+    wrapper.new_game().unwrap();
+    for _ in 0..10 {
+        wrapper.step_frame().unwrap();
+    }
+    let state: GameStateJson = serde_json::from_str(&wrapper.get_state_json().unwrap()).unwrap();
+    let character = &state.characters[0];
+    assert!(character.wall_sliding);
+    assert_eq!(character.velocity[1], [16, 32]);
+
+    config.characters[0].wall_slide_speed = Some([1, 0]);
+    let errors = config.validate().unwrap_err();
+    assert_eq!(errors[0].field, "characters[0].wall_slide_speed[1]");
+}
+
+#[wasm_bindgen_test]
+fn test_character_archetypes_fill_omitted_fields() {
+    use crate::GameWrapper;
+    use robot_masters_engine::constants::archetype;
+
+    let mut config = serde_json::to_value(sandbox_config()).unwrap();
+    config["characters"] = serde_json::json!([{
+        "archetype": "sniper",
+        "id": 0, "group": 0,
+        "position": [[64, 1], [64, 1]],
+        "behaviors": [],
+        "health": 50
+    }]);
+    let parsed: GameConfig = serde_json::from_value(config.clone()).unwrap();
+    let character = &parsed.characters[0];
+    assert_eq!(character.health, 50);
+    assert_eq!(character.health_cap, archetype::SNIPER.health);
+    assert_eq!(character.energy, archetype::SNIPER.energy);
+    assert_eq!(character.armor, archetype::SNIPER.armor);
+    assert_eq!(
+        character.detection_radius,
+        archetype::SNIPER.detection_radius
+    );
+    assert_eq!(character.position, [[64, 1], [64, 1]]);
+
+    let mut wrapper = GameWrapper::new(&config.to_string()).unwrap();
+    wrapper.new_game().unwrap();
+    wrapper.step_frame().unwrap();
+
+    config["characters"][0]["archetype"] = "wizard".into();
+    let error = serde_json::from_value::<GameConfig>(config).unwrap_err();
+    assert!(error
+        .to_string()
+        .contains("characters[0].archetype must be one of: bruiser, sniper, support"));
+}
+
+#[wasm_bindgen_test]
+fn test_cooldowns_preview_action_energy_costs() {
+    use crate::types::GameStateJson;
+    use crate::GameWrapper;
+
+    let cooldowns = |energy: u8| {
+        let mut config = sandbox_config();
+        config.characters[0].energy = energy;
+        config.characters[0].behaviors = vec![[0, 0]];
+        let mut wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+        wrapper.new_game().unwrap();
+        let state: GameStateJson =
+            serde_json::from_str(&wrapper.get_state_json().unwrap()).unwrap();
+        state.characters[0].cooldowns[0].clone()
+    };
+
+    let cooldown = cooldowns(40);
+    assert_eq!((cooldown.energy_cost, cooldown.affordable), (11, true));
+    let cooldown = cooldowns(10);
+    assert_eq!((cooldown.energy_cost, cooldown.affordable), (11, false));
+    assert!(!cooldown.usable);
+}
+
+#[wasm_bindgen_test]
+fn test_grapple_pulls_export_events_and_state() {
+    use crate::types::{GameEventJson, GameStateJson};
+    use robot_masters_engine::{
+        api::new_game,
+        constants::grapple_mode,
+        entity::{Character, SpawnDefinition, SpawnInstance},
+    };
+
+    let new_state = || {
+        let mut tilemap = [[0; 16]; 15];
+        tilemap[0][2] = 1;
+        let mut character = Character::new(0, 0);
+        character.core.pos = (Fixed::from_int(32), Fixed::from_int(64));
+        character.core.size = (16, 16);
+        let mut hook = SpawnDefinition::from_def(Vec::new());
+        hook.grapple = grapple_mode::PULL_OWNER;
+        hook.grapple_speed = Fixed::from_int(4);
+        new_game(
+            1,
+            tilemap,
+            vec![character],
+            Vec::new(),
+            Vec::new(),
+            vec![hook],
+            Vec::new(),
+        )
+        .unwrap()
+    };
+
+    // Hook placed right under the ceiling tile
+    let mut state = new_state();
+    let mut spawn = SpawnInstance::new(0, 0, (Fixed::from_int(34), Fixed::from_int(16)));
+    spawn.core.size = (4, 4);
+    spawn.core.dir.1 = 1;
+    spawn.life_span = 10;
+    state.insert_spawn(spawn).unwrap();
+    state.advance_frame().unwrap();
+
+    let events: Vec<GameEventJson> = state
+        .events
+        .iter()
+        .map(GameEventJson::from_game_event)
+        .filter(|event| event.event_type == "grapple_attached")
+        .collect();
+    assert_eq!(events.len(), 1);
+    let grapple = events[0].grapple.as_ref().unwrap();
+    assert_eq!((grapple.character_id, grapple.source_id), (0, 0));
+    assert_eq!(grapple.anchor, Some([[36 * 32, 32], [18 * 32, 32]]));
+
+    let json = GameStateJson::from_game_state(&state);
+    assert_eq!(
+        json.characters[0].grapple.as_ref().unwrap().speed,
+        [4 * 32, 32]
+    );
+
+    let mut resumed = new_state();
+    json.restore_into(&mut resumed).unwrap();
+    assert_eq!(resumed.characters[0].grapple, state.characters[0].grapple);
+}
+
+#[wasm_bindgen_test]
+fn test_knockback_config_and_hitstun_state() {
+    use crate::types::GameStateJson;
+    use robot_masters_engine::{api::new_game, entity::Character};
+
+    let new_state = || {
+        new_game(
+            1,
+            [[0; 16]; 15],
+            vec![Character::new(0, 0)],
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+            Vec::new(),
+        )
+        .unwrap()
+    };
+
+    let spawn: SpawnDefinitionJson = serde_json::from_str(
+        r#"{
+            "damage_base": 10, "damage_range": 0, "crit_chance": 0, "crit_multiplier": 1,
+            "health_cap": 1, "duration": 60, "element": null, "chance": 100, "size": [4, 4],
+            "knockback": [80, 32], "hitstun": 12,
+            "args": [0, 0, 0, 0, 0, 0, 0, 0], "spawns": [0, 0, 0, 0],
+            "behavior_script": [], "collision_script": [], "despawn_script": []
+        }"#,
+    )
+    .unwrap();
+    let engine: SpawnDefinition = spawn.clone().into();
+    assert_eq!(engine.knockback, Fixed::from_frac(5, 2));
+    assert_eq!(engine.hitstun, 12);
+    assert_round_trip::<SpawnDefinitionJson, SpawnDefinition>(&spawn);
+
+    // Hitstun frames left are exported and restored with the character state
+    let mut state = new_state();
+    state.characters[0].hitstun = 7;
+    let json = GameStateJson::from_game_state(&state);
+    assert_eq!(json.characters[0].hitstun, 7);
+    let mut resumed = new_state();
+    json.restore_into(&mut resumed).unwrap();
+    assert_eq!(resumed.characters[0].hitstun, 7);
+}
+
+#[wasm_bindgen_test]
+fn test_character_cards_carry_and_remap_their_definitions() {
+    use crate::card::CharacterCardJson;
+    use crate::GameWrapper;
+
+    // Character 0 uses condition 1 and action 1; the action reaches spawns 3 and 2
+    let mut config = sandbox_config();
+    config.conditions.push(config.conditions[0].clone());
+    config.actions.push(config.actions[0].clone());
+    config.spawns[0].spawns = [0; 4];
+    for damage_base in [10, 20, 30] {
+        let mut spawn = config.spawns[0].clone();
+        spawn.damage_base = damage_base;
+        config.spawns.push(spawn);
+    }
+    config.spawns[3].spawns = [2, 0, 0, 0];
+    config.actions[0].spawns = [1, 0, 0, 0];
+    config.actions[1].spawns = [3, 3, 0, 0];
+    config.characters[0].behaviors = vec![[1, 1]];
+    let wrapper = GameWrapper::new(&serde_json::to_string(&config).unwrap()).unwrap();
+
+    let card_json = wrapper.export_character_card(0).unwrap();
+    let card: CharacterCardJson = serde_json::from_str(&card_json).unwrap();
+    assert_eq!(card.character.behaviors, vec![[0, 0]]);
+    assert_eq!((card.conditions.len(), card.actions.len()), (1, 1));
+    assert_eq!(card.actions[0].spawns, [1, 1, 0, 0]);
+    let damage: Vec<u16> = card.spawns.iter().map(|spawn| spawn.damage_base).collect();
+    assert_eq!(damage, vec![30, 20]);
+    assert_eq!(card.spawns[0].spawns, [2, 0, 0, 0]);
+
+    // Importing appends the definitions after the target's own
+    let mut target = sandbox_config();
+    target.spawns[0].spawns = [0; 4];
+    let target_json = serde_json::to_string(&target).unwrap();
+    let imported = GameWrapper::import_character_card(&target_json, &card_json, 2).unwrap();
+    let imported: GameConfig = serde_json::from_str(&imported).unwrap();
+    let character = &imported.characters[1];
+    assert_eq!((character.id, character.group), (4, 2));
+    assert_eq!(character.behaviors, vec![[1, 1]]);
+    assert_eq!(imported.actions[1].spawns, [1, 1, 0, 0]);
+    assert_eq!(imported.spawns[1].spawns, [2, 0, 0, 0]);
+    assert_eq!(imported.spawns[2].damage_base, 20);
+
+    let mut game = GameWrapper::new(&serde_json::to_string(&imported).unwrap()).unwrap();
+    game.new_game().unwrap();
+
+    // Cards from other layouts or with dangling references are rejected
+    let mut broken = card.clone();
+    broken.version = 9;
+    let broken_json = serde_json::to_string(&broken).unwrap();
+    assert!(GameWrapper::import_character_card(&target_json, &broken_json, 2).is_err());
+    let mut broken = card;
+    broken.actions[0].spawns[3] = 3;
+    let broken_json = serde_json::to_string(&broken).unwrap();
+    assert!(GameWrapper::import_character_card(&target_json, &broken_json, 2).is_err());
+    assert!(wrapper.export_character_card(1).is_err());
 }
-*/
@@ -1,26 +1,248 @@
 //! JSON-compatible types for game configuration and serialization
 
 use robot_masters_engine::{
+    core::{ELEMENT_COUNT, OPCODE_VERSION},
     entity::{
-        ActionDefinition, Character, ConditionDefinition, SpawnDefinition, StatusEffectDefinition,
+        ActionDefinition, Armor, Character, ConditionDefinition, Element, ScriptQuota,
+        SpawnDefinition, StatusEffectDefinition,
     },
     math::Fixed,
+    physics::BoundaryPolicy,
+    reward::{RewardDrop, RewardEntry, RewardTable},
+    script::{self, ScriptContextKind, ScriptError},
+    tilemap::TileType,
+    victory::{TieBreak, VictoryCondition},
 };
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 
 /// Complete game configuration structure for JSON input
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct GameConfig {
     pub seed: u16,
-    pub gravity: Option<[i16; 2]>, // Optional gravity as [numerator, denominator], defaults to [1, 1] (downward)
-    pub tilemap: Vec<Vec<u8>>,     // 15x16 tilemap as nested arrays
-    pub characters: Vec<CharacterDefinitionJson>,
+    pub gravity: Option<GravityJson>, // Optional gravity, scalar [num, den] (vertical) or vector [[x_num, x_den], [y_num, y_den]]
+    pub tilemap: Vec<Vec<u8>>,        // 15x16 tilemap as nested arrays
+    pub hazards: Option<Vec<HazardJson>>, // Optional element and damage of Hazard tiles (type 10)
+    pub boundary: Option<BoundaryConfigJson>, // Optional world boundary policies, defaults to clamp/despawn
+    pub damage_window: Option<u16>, // Optional recent-damage window in frames, defaults to 180 (3 seconds)
+    pub respawn_delay: Option<u16>, // Optional frames a defeated character stays down before reviving, defaults to 0 (never)
+    pub strict_mode: Option<bool>,  // Optional strict property access checks, defaults to false
+    pub max_call_depth: Option<u8>, // Optional limit on nested script CALLs, defaults to 4
+    pub script_step_budget: Option<u16>, // Optional instructions per script run, defaults to 4096 (0 = unlimited)
+    pub frame_step_budget: Option<u32>, // Optional script instructions per frame, defaults to 0 (unlimited)
+    pub frame_work_budget: Option<u32>, // Optional script steps plus collision checks per frame before spawn interception defers, defaults to 0 (never)
+    pub event_history: Option<u16>, // Optional frames of events kept for backfill, defaults to 300 (5 seconds)
+    pub opcode_version: Option<u8>, // Optional opcode table version the scripts were written for, defaults to the current one
+    pub frame_limit: Option<u32>, // Optional match length in frames, defaults to 3840 (0 = unlimited training session)
+    pub victory: Option<VictoryConfigJson>, // Optional victory condition, defaults to timeout (most health left wins)
+    pub friendly_fire: Option<bool>, // Optional friendly fire, defaults to true (spawns can hit the owner's allies)
+    pub platforms: Option<Vec<PlatformPlacementJson>>, // Optional platform spawns placed by the arena at match start
+    #[serde(deserialize_with = "character_archetypes::deserialize")]
+    pub characters: Vec<CharacterDefinitionJson>, // Entries with "archetype" start from a built-in template
     pub actions: Vec<ActionDefinitionJson>,
     pub conditions: Vec<ConditionDefinitionJson>,
-    pub spawns: Vec<SpawnDefinitionJson>,
+    #[serde(deserialize_with = "spawn_variants::deserialize")]
+    pub spawns: Vec<SpawnDefinitionJson>, // Entries with "extends" inherit an earlier definition
     pub status_effects: Vec<StatusEffectDefinitionJson>,
 }
 
+/// Spawn definition variants
+///
+/// An entry with `"extends": <spawn id>` starts from that earlier definition and overrides
+/// only the fields it lists (e.g. a faster or reskinned projectile). Variants are expanded
+/// while the configuration is parsed, so validation and conversion only see complete
+/// definitions, and `get_config_json` returns the expanded form.
+mod spawn_variants {
+    use super::SpawnDefinitionJson;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer};
+    use serde_json::{Map, Value};
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<SpawnDefinitionJson>, D::Error> {
+        let entries = Vec::<Map<String, Value>>::deserialize(deserializer)?;
+
+        // Bases must come first, so every base is already expanded and cycles are impossible
+        let mut expanded: Vec<Map<String, Value>> = Vec::with_capacity(entries.len());
+        for (index, mut entry) in entries.into_iter().enumerate() {
+            if let Some(base) = entry.remove("extends") {
+                let base_index = base
+                    .as_u64()
+                    .map(|base| base as usize)
+                    .filter(|&base| base < index)
+                    .ok_or_else(|| {
+                        D::Error::custom(format!(
+                            "spawns[{}].extends must be the id of an earlier spawn definition",
+                            index
+                        ))
+                    })?;
+                let mut variant = expanded[base_index].clone();
+                variant.extend(entry);
+                entry = variant;
+            }
+            expanded.push(entry);
+        }
+
+        expanded
+            .into_iter()
+            .enumerate()
+            .map(|(index, entry)| {
+                serde_json::from_value(Value::Object(entry))
+                    .map_err(|err| D::Error::custom(format!("spawns[{}]: {}", index, err)))
+            })
+            .collect()
+    }
+}
+
+/// Character archetype templates
+///
+/// An entry with `"archetype": "bruiser" | "sniper" | "support"` starts from that engine
+/// template (`constants::archetype`) and overrides only the fields it lists, so a minimal
+/// character needs just an id, a group, a position and its behaviors. Templates are expanded
+/// while the configuration is parsed, like spawn variants.
+mod character_archetypes {
+    use super::CharacterDefinitionJson;
+    use robot_masters_engine::constants::archetype;
+    use robot_masters_engine::entity::Character;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer};
+    use serde_json::{Map, Value};
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<Vec<CharacterDefinitionJson>, D::Error> {
+        let entries = Vec::<Map<String, Value>>::deserialize(deserializer)?;
+
+        entries
+            .into_iter()
+            .enumerate()
+            .map(|(index, mut entry)| {
+                if let Some(name) = entry.remove("archetype") {
+                    let template = name.as_str().and_then(archetype::find).ok_or_else(|| {
+                        let names: Vec<&str> =
+                            archetype::ALL.iter().map(|(name, _)| *name).collect();
+                        D::Error::custom(format!(
+                            "characters[{}].archetype must be one of: {}",
+                            index,
+                            names.join(", ")
+                        ))
+                    })?;
+                    let base =
+                        CharacterDefinitionJson::from(&Character::from_archetype(0, 0, template));
+                    let Value::Object(mut expanded) =
+                        serde_json::to_value(base).map_err(D::Error::custom)?
+                    else {
+                        unreachable!("character definitions serialize as objects");
+                    };
+                    expanded.extend(entry);
+                    entry = expanded;
+                }
+                serde_json::from_value(Value::Object(entry))
+                    .map_err(|err| D::Error::custom(format!("characters[{}]: {}", index, err)))
+            })
+            .collect()
+    }
+}
+
+/// Script bytecode given as a byte array or as assembler text
+///
+/// Strings are compiled with `script::assemble`, e.g. `"READ_PROP v0 CHARACTER_HEALTH; EXIT 1"`.
+/// Configurations are always serialized back as byte arrays.
+mod script_source {
+    use robot_masters_engine::script;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer};
+    use serde_json::Value;
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Vec<u8>, D::Error> {
+        match Value::deserialize(deserializer)? {
+            Value::String(source) => script::assemble(&source).map_err(|err| {
+                D::Error::custom(format!(
+                    "script assembly failed on line {}: {:?}",
+                    err.line, err.problem
+                ))
+            }),
+            value => serde_json::from_value(value).map_err(D::Error::custom),
+        }
+    }
+}
+
+/// Gravity configuration: a legacy vertical scalar or a per-axis vector
+#[derive(Deserialize, Serialize, Debug, Clone, Copy)]
+#[serde(untagged)]
+pub enum GravityJson {
+    Scalar([i16; 2]),      // [numerator, denominator], applied to the y axis
+    Vector([[i16; 2]; 2]), // [[x_num, x_den], [y_num, y_den]]
+}
+
+impl GravityJson {
+    /// All [numerator, denominator] pairs in this gravity value
+    pub fn fractions(&self) -> Vec<[i16; 2]> {
+        match self {
+            GravityJson::Scalar(y) => vec![*y],
+            GravityJson::Vector(xy) => xy.to_vec(),
+        }
+    }
+
+    /// Convert to an engine gravity vector (x, y)
+    pub fn to_vector(&self) -> (Fixed, Fixed) {
+        match self {
+            GravityJson::Scalar([num, den]) => (Fixed::ZERO, Fixed::from_frac(*num, *den)),
+            GravityJson::Vector([[x_num, x_den], [y_num, y_den]]) => (
+                Fixed::from_frac(*x_num, *x_den),
+                Fixed::from_frac(*y_num, *y_den),
+            ),
+        }
+    }
+}
+
+/// World boundary policies per entity kind
+/// Policy values: 0=Clamp, 1=Wrap, 2=Despawn, 3=Damage
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct BoundaryConfigJson {
+    pub character: u8, // Characters treat Despawn as Clamp
+    pub spawn: u8,
+    pub damage: u16, // Damage per frame for the Damage policy
+}
+
+/// Victory condition
+/// Condition values: 0=Timeout, 1=LastTeamStanding, 2=KillTarget, 3=HealthPercent, 4=Survival
+/// Tie-break values: 0=HealthPercent, 1=DamageDealt, 2=TotalHealth, 3=Kills, 4=Survivors
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct VictoryConfigJson {
+    pub condition: u8,
+    #[serde(default)]
+    pub target: u8, // Kills needed for KillTarget (1+), group that must survive for Survival
+    #[serde(default)]
+    pub tie_breaks: Vec<u8>, // Tried in order on a tie at the frame limit (empty = draw)
+}
+
+/// Element and damage per second of a Hazard tile (lava = Heat, acid pools = Acid)
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct HazardJson {
+    pub position: [u8; 2], // [x, y] tile coordinates of a Hazard tile (type 10)
+    pub element: u8,       // Element as u8 value (0-8), reduced by the matching armor
+    pub damage_per_second: u16,
+}
+
+/// Platform spawn placed by the arena at match start
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct PlatformPlacementJson {
+    pub spawn_id: u8,            // Spawn definition with `platform` set
+    pub position: [[i16; 2]; 2], // [[x_num, x_den], [y_num, y_den]]
+}
+
+impl PlatformPlacementJson {
+    /// Placement position in engine coordinates
+    pub fn pos(&self) -> (Fixed, Fixed) {
+        (
+            convert::fixed_frac::to_engine(self.position[0]),
+            convert::fixed_frac::to_engine(self.position[1]),
+        )
+    }
+}
+
 /// JSON-compatible character definition
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct CharacterDefinitionJson {
@@ -31,12 +253,13 @@ pub struct CharacterDefinitionJson {
     pub health: u16,             // Updated from u8 to u16
     pub health_cap: u16,         // New property
     pub energy: u8,
-    pub energy_cap: u8,       // New property
-    pub power: u8,            // New property
-    pub weight: u8,           // New property
-    pub jump_force: [i16; 2], // New property [numerator, denominator]
-    pub move_speed: [i16; 2], // New property [numerator, denominator]
-    pub armor: [u8; 9],       // Armor values for all 9 elements
+    pub energy_cap: u8,                     // New property
+    pub power: u8,                          // New property
+    pub weight: u8,                         // New property
+    pub jump_force: [i16; 2],               // New property [numerator, denominator]
+    pub move_speed: [i16; 2],               // New property [numerator, denominator]
+    pub wall_slide_speed: Option<[i16; 2]>, // Max fall speed pressing into a wall in the air (default none)
+    pub armor: Armor,                       // Armor values for all 9 elements, indexed by element
     pub energy_regen: u8,
     pub energy_regen_rate: u8,
     pub energy_charge: u8,
@@ -46,6 +269,28 @@ pub struct CharacterDefinitionJson {
     pub target_id: Option<u8>,      // New property
     pub target_type: u8,            // New property
     pub behaviors: Vec<[usize; 2]>, // [condition_id, action_id] pairs
+    #[serde(default)]
+    pub detection_radius: u8, // Enemy detection range in pixels (0 = detects nothing)
+    #[serde(default)]
+    pub detection_requires_los: bool, // Detection is blocked by solid tiles
+    #[serde(default)]
+    pub overflow_mode: u8, // What energy above the cap turns into (0 = lost, 1 = shield, 2 = charge stacks)
+    #[serde(default)]
+    pub quota: ScriptQuotaJson, // Per-frame script limits, defaults to unlimited
+    pub name: Option<String>, // Display name for the combat log and state JSON (default "Character {id}")
+    pub sprite: Option<String>, // Sprite key echoed into character state JSON (ignored by the engine)
+}
+
+/// Per-frame script limits for one character (0 = unlimited)
+/// Spawn and status effect scripts count against the owning/afflicted character
+#[derive(Deserialize, Serialize, Debug, Clone, Default)]
+pub struct ScriptQuotaJson {
+    #[serde(default)]
+    pub max_instructions: u16,
+    #[serde(default)]
+    pub max_property_writes: u16,
+    #[serde(default)]
+    pub max_spawns: u16,
 }
 
 /// JSON-compatible action definition
@@ -54,7 +299,10 @@ pub struct ActionDefinitionJson {
     pub energy_cost: u8,
     pub cooldown: u16,
     pub args: [u8; 8],
+    #[serde(default)]
+    pub constants: Vec<u16>, // Constant pool read by LOAD_CONST operators
     pub spawns: [u8; 4],
+    #[serde(deserialize_with = "script_source::deserialize")]
     pub script: Vec<u8>,
 }
 
@@ -63,6 +311,9 @@ pub struct ActionDefinitionJson {
 pub struct ConditionDefinitionJson {
     pub energy_mul: i16, // Fixed-point value as raw integer for JSON
     pub args: [u8; 8],
+    #[serde(default)]
+    pub constants: Vec<u16>, // Constant pool read by LOAD_CONST operators
+    #[serde(deserialize_with = "script_source::deserialize")]
     pub script: Vec<u8>,
 }
 
@@ -78,11 +329,48 @@ pub struct SpawnDefinitionJson {
     pub element: Option<u8>, // Element as u8 value (0-8)
     pub chance: u8,          // New property
     pub size: [u8; 2],       // [width, height] in pixels
+    #[serde(default)]
+    pub collision_mask: u8, // Spawn-vs-spawn collision layers (0 = passes through other spawns)
+    #[serde(default)]
+    pub falloff_start: u16, // Full damage up to this distance in pixels
+    #[serde(default)]
+    pub falloff_end: u16, // Damage reaches zero at this distance (0 = no falloff)
+    #[serde(default)]
+    pub velocity_spread: Option<[i16; 2]>, // Max per-axis velocity jitter as [numerator, denominator]
+    #[serde(default)]
+    pub duration_variance: u16, // Max lifetime jitter in frames (+/-)
+    #[serde(default)]
+    pub damage_variance: u16, // Max base damage jitter (+/-)
+    #[serde(default)]
+    pub energy_damage: u8, // Energy drained from a hit character (armor does not apply)
+    #[serde(default)]
+    pub energy_steal: u8, // Percent of drained energy given to the owning character
+    #[serde(default)]
+    pub terrain: u8, // Tile written into the empty tiles covered at creation (0 = none)
+    #[serde(default)]
+    pub grapple: u8, // Pull started on hitting terrain or a character (see grapple_mode)
+    #[serde(default)]
+    pub grapple_speed: Option<[i16; 2]>, // Grapple pull speed in pixels per frame as [numerator, denominator]
+    #[serde(default)]
+    pub grapple_frames: u8, // Frames before a grapple pull breaks (0 = no limit)
+    #[serde(default)]
+    pub platform: bool, // Moving platform: characters land on its top edge and ride along
+    #[serde(default)]
+    pub knockback: Option<[i16; 2]>, // Speed a hit character is pushed away at as [numerator, denominator]
+    #[serde(default)]
+    pub hitstun: u8, // Frames a hit character's behaviors are suspended (0 = none)
     pub args: [u8; 8],
+    #[serde(default)]
+    pub constants: Vec<u16>, // Constant pool read by LOAD_CONST operators
     pub spawns: [u8; 4],
+    #[serde(deserialize_with = "script_source::deserialize")]
     pub behavior_script: Vec<u8>,
+    #[serde(deserialize_with = "script_source::deserialize")]
     pub collision_script: Vec<u8>,
+    #[serde(deserialize_with = "script_source::deserialize")]
     pub despawn_script: Vec<u8>,
+    pub name: Option<String>, // Display name echoed into spawn state JSON (ignored by the engine)
+    pub sprite: Option<String>, // Sprite key echoed into spawn state JSON (ignored by the engine)
 }
 
 /// JSON-compatible status effect definition
@@ -91,14 +379,56 @@ pub struct StatusEffectDefinitionJson {
     pub duration: u16,
     pub stack_limit: u8,
     pub reset_on_stack: bool,
+    #[serde(default)]
+    pub stack_behavior: u8, // Re-application rule (0=Stack, 1=Refresh, 2=Ignore)
     pub chance: u8, // New property
+    #[serde(default)]
+    pub category: u8, // UI grouping (0=neutral, 1=buff, 2=debuff)
+    #[serde(default)]
+    pub reflect: u8, // Percent of incoming spawn damage returned to the attacker, per stack
+    #[serde(default)]
+    pub taunt: bool, // Forces the afflicted character to target the character that applied it
+    #[serde(default)]
+    pub scale: u8, // Percent of the base size per stack while active (0 = no size change)
+    #[serde(default)]
+    pub priority: u8, // Display priority; status JSON lists higher priorities first
     pub args: [u8; 8],
+    #[serde(default)]
+    pub constants: Vec<u16>, // Constant pool read by LOAD_CONST operators
     pub spawns: [u8; 4],
+    #[serde(default)]
+    pub children: Vec<usize>, // Status effects applied together with this one (bundle)
+    #[serde(deserialize_with = "script_source::deserialize")]
     pub on_script: Vec<u8>,
+    #[serde(deserialize_with = "script_source::deserialize")]
     pub tick_script: Vec<u8>,
+    #[serde(deserialize_with = "script_source::deserialize")]
     pub off_script: Vec<u8>,
 }
 
+/// JSON-compatible reward table entry
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RewardEntryJson {
+    pub item_id: u16,
+    pub weight: u16,
+    pub min_quantity: u8,
+    pub max_quantity: u8,
+}
+
+/// JSON-compatible reward table for post-match reward rolls
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RewardTableJson {
+    pub rolls: u8,
+    pub entries: Vec<RewardEntryJson>,
+}
+
+/// JSON-compatible rolled reward
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RewardDropJson {
+    pub item_id: u16,
+    pub quantity: u8,
+}
+
 /// Validation error for game configuration
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct ValidationError {
@@ -114,7 +444,7 @@ impl GameConfig {
 
         // Validate gravity field if present
         if let Some(gravity) = &self.gravity {
-            if gravity[1] == 0 {
+            if gravity.fractions().iter().any(|fraction| fraction[1] == 0) {
                 errors.push(ValidationError {
                     field: "gravity".to_string(),
                     message: "Gravity denominator cannot be zero".to_string(),
@@ -123,6 +453,83 @@ impl GameConfig {
             }
         }
 
+        // Validate boundary policies if present
+        if let Some(boundary) = &self.boundary {
+            for (field, policy) in [
+                ("boundary.character", boundary.character),
+                ("boundary.spawn", boundary.spawn),
+            ] {
+                if BoundaryPolicy::from_u8(policy).is_none() {
+                    errors.push(ValidationError {
+                        field: field.to_string(),
+                        message: "Boundary policy must be between 0 and 3".to_string(),
+                        context: Some(format!("Found policy value {}", policy)),
+                    });
+                }
+            }
+        }
+
+        // Validate victory condition if present
+        if let Some(victory) = &self.victory {
+            if VictoryCondition::from_parts(victory.condition, victory.target).is_none() {
+                errors.push(ValidationError {
+                    field: "victory".to_string(),
+                    message: "Victory condition must be between 0 and 4, with a kill target of at least 1"
+                        .to_string(),
+                    context: Some(format!(
+                        "Found condition {} with target {}",
+                        victory.condition, victory.target
+                    )),
+                });
+            }
+            for &tie_break in &victory.tie_breaks {
+                if TieBreak::from_u8(tie_break).is_none() {
+                    errors.push(ValidationError {
+                        field: "victory.tie_breaks".to_string(),
+                        message: "Tie-break rule must be between 0 and 4".to_string(),
+                        context: Some(format!("Found tie-break value {}", tie_break)),
+                    });
+                }
+            }
+        }
+
+        // Validate arena platforms if present
+        for (index, placement) in self.platforms.iter().flatten().enumerate() {
+            let is_platform = self
+                .spawns
+                .get(placement.spawn_id as usize)
+                .is_some_and(|spawn| spawn.platform);
+            if !is_platform {
+                errors.push(ValidationError {
+                    field: format!("platforms[{}].spawn_id", index),
+                    message: "Platforms must use a spawn definition with platform set".to_string(),
+                    context: Some(format!("Found spawn id {}", placement.spawn_id)),
+                });
+            }
+            if placement.position[0][1] == 0 || placement.position[1][1] == 0 {
+                errors.push(ValidationError {
+                    field: format!("platforms[{}].position", index),
+                    message: "Position denominators cannot be zero".to_string(),
+                    context: None,
+                });
+            }
+        }
+
+        // Validate recent-damage window if present
+        if let Some(window) = self.damage_window {
+            if window as u32 > robot_masters_engine::core::MAX_FRAMES {
+                errors.push(ValidationError {
+                    field: "damage_window".to_string(),
+                    message: "Damage window cannot exceed the match length".to_string(),
+                    context: Some(format!(
+                        "Found {} frames, maximum is {}",
+                        window,
+                        robot_masters_engine::core::MAX_FRAMES
+                    )),
+                });
+            }
+        }
+
         // Validate tilemap dimensions
         if self.tilemap.len() != 15 {
             errors.push(ValidationError {
@@ -142,6 +549,26 @@ impl GameConfig {
             }
         }
 
+        // Validate hazards if present: each must name a Hazard tile and an element
+        for (index, hazard) in self.hazards.iter().flatten().enumerate() {
+            let [x, y] = hazard.position.map(|coord| coord as usize);
+            let tile = self.tilemap.get(y).and_then(|row| row.get(x)).copied();
+            if tile != Some(TileType::Hazard as u8) {
+                errors.push(ValidationError {
+                    field: format!("hazards[{}].position", index),
+                    message: "Hazards must be placed on Hazard tiles (type 10)".to_string(),
+                    context: Some(format!("Found tile {:?} at [{}, {}]", tile, x, y)),
+                });
+            }
+            if Element::from_u8(hazard.element).is_none() {
+                errors.push(ValidationError {
+                    field: format!("hazards[{}].element", index),
+                    message: format!("Element value must be between 0 and {}", ELEMENT_COUNT - 1),
+                    context: Some(format!("Found element value {}", hazard.element)),
+                });
+            }
+        }
+
         // Validate character properties
         for (char_idx, character) in self.characters.iter().enumerate() {
             // Validate health_cap >= health constraint
@@ -188,6 +615,16 @@ impl GameConfig {
                     context: Some("Fixed-point denominators must be non-zero".to_string()),
                 });
             }
+            if character
+                .wall_slide_speed
+                .is_some_and(|speed| speed[1] == 0)
+            {
+                errors.push(ValidationError {
+                    field: format!("characters[{}].wall_slide_speed[1]", char_idx),
+                    message: "Wall slide speed denominator cannot be zero".to_string(),
+                    context: Some("Fixed-point denominators must be non-zero".to_string()),
+                });
+            }
 
             // Validate target_type when target_id is set
             if character.target_id.is_some() && character.target_type == 0 {
@@ -248,14 +685,92 @@ impl GameConfig {
         for (spawn_idx, spawn) in self.spawns.iter().enumerate() {
             // Validate element values
             if let Some(element) = spawn.element {
-                if element > 8 {
+                if Element::from_u8(element).is_none() {
                     errors.push(ValidationError {
                         field: format!("spawns[{}].element", spawn_idx),
-                        message: "Element value must be between 0 and 8".to_string(),
+                        message: format!(
+                            "Element value must be between 0 and {}",
+                            ELEMENT_COUNT - 1
+                        ),
                         context: Some(format!("Found element value {}", element)),
                     });
                 }
             }
+
+            // Validate velocity spread (non-negative with a non-zero denominator)
+            if let Some(spread) = spawn.velocity_spread {
+                if spread[1] == 0 || Fixed::from_frac(spread[0], spread[1]) < Fixed::ZERO {
+                    errors.push(ValidationError {
+                        field: format!("spawns[{}].velocity_spread", spawn_idx),
+                        message: "Velocity spread must be non-negative with a non-zero denominator"
+                            .to_string(),
+                        context: Some(format!("Found [{}, {}]", spread[0], spread[1])),
+                    });
+                }
+            }
+        }
+
+        // Validate script bytecode, so malformed scripts fail here instead of mid-match
+        // (field, script, context, constant pool size)
+        let mut scripts: Vec<(String, &[u8], ScriptContextKind, usize)> = Vec::new();
+        for (idx, condition) in self.conditions.iter().enumerate() {
+            let field = format!("conditions[{}].script", idx);
+            let constants = condition.constants.len();
+            scripts.push((
+                field,
+                &condition.script,
+                ScriptContextKind::Condition,
+                constants,
+            ));
+        }
+        for (idx, action) in self.actions.iter().enumerate() {
+            let field = format!("actions[{}].script", idx);
+            let constants = action.constants.len();
+            scripts.push((field, &action.script, ScriptContextKind::Action, constants));
+        }
+        for (idx, spawn) in self.spawns.iter().enumerate() {
+            for (name, script) in [
+                ("behavior_script", &spawn.behavior_script),
+                ("collision_script", &spawn.collision_script),
+                ("despawn_script", &spawn.despawn_script),
+            ] {
+                let field = format!("spawns[{}].{}", idx, name);
+                let constants = spawn.constants.len();
+                scripts.push((field, script, ScriptContextKind::Spawn, constants));
+            }
+        }
+        for (idx, status_effect) in self.status_effects.iter().enumerate() {
+            for (name, script) in [
+                ("on_script", &status_effect.on_script),
+                ("tick_script", &status_effect.tick_script),
+                ("off_script", &status_effect.off_script),
+            ] {
+                let field = format!("status_effects[{}].{}", idx, name);
+                let constants = status_effect.constants.len();
+                scripts.push((field, script, ScriptContextKind::StatusEffect, constants));
+            }
+        }
+        // Scripts written for an older opcode table are checked after migrating them
+        let version = self.opcode_version.unwrap_or(OPCODE_VERSION);
+        if version == 0 || version > OPCODE_VERSION {
+            errors.push(ValidationError {
+                field: "opcode_version".to_string(),
+                message: format!("Opcode version must be between 1 and {}", OPCODE_VERSION),
+                context: Some(format!("Found version {}", version)),
+            });
+        }
+        for (field, script, context, constants) in scripts {
+            let result =
+                script::migrate(script, version.clamp(1, OPCODE_VERSION)).and_then(|migrated| {
+                    script::validate_with_constants(&migrated, context, constants)
+                });
+            if let Err(ScriptError::InvalidBytecode { offset, problem }) = result {
+                errors.push(ValidationError {
+                    field,
+                    message: format!("Invalid script bytecode at byte offset {}", offset),
+                    context: Some(format!("{:?}", problem)),
+                });
+            }
         }
 
         if errors.is_empty() {
@@ -264,17 +779,273 @@ impl GameConfig {
             Err(errors)
         }
     }
+
+    /// Rewrite every script to the current opcode table (see `script::migrate`)
+    /// Run after `validate`; the configuration then declares the current opcode version.
+    pub fn migrate_scripts(&mut self) {
+        let version = self.opcode_version.unwrap_or(OPCODE_VERSION);
+        if version == OPCODE_VERSION {
+            return;
+        }
+        let scripts = self
+            .conditions
+            .iter_mut()
+            .map(|condition| &mut condition.script)
+            .chain(self.actions.iter_mut().map(|action| &mut action.script))
+            .chain(self.spawns.iter_mut().flat_map(|spawn| {
+                [
+                    &mut spawn.behavior_script,
+                    &mut spawn.collision_script,
+                    &mut spawn.despawn_script,
+                ]
+            }))
+            .chain(self.status_effects.iter_mut().flat_map(|status_effect| {
+                [
+                    &mut status_effect.on_script,
+                    &mut status_effect.tick_script,
+                    &mut status_effect.off_script,
+                ]
+            }));
+        for script in scripts {
+            if let Ok(migrated) = script::migrate(script, version) {
+                *script = migrated;
+            }
+        }
+        self.opcode_version = Some(OPCODE_VERSION);
+    }
 }
 
-/// Helper functions for converting JSON types to game engine types
+/// Field conversion kinds used by `definition_conversion!`
+///
+/// Each kind converts one JSON field to its engine representation and back.
+pub(crate) mod convert {
+    use robot_masters_engine::{
+        entity::{Element, StackBehavior},
+        math::Fixed,
+    };
+
+    /// Same type on both sides
+    pub mod copy {
+        pub fn to_engine<T>(value: T) -> T {
+            value
+        }
+        pub fn to_json<T: Clone>(value: &T) -> T {
+            value.clone()
+        }
+    }
+
+    /// `[numerator, denominator]` <-> Fixed
+    pub mod fixed_frac {
+        use super::Fixed;
+        pub fn to_engine(value: [i16; 2]) -> Fixed {
+            Fixed::from_frac(value[0], value[1])
+        }
+        pub fn to_json(value: &Fixed) -> [i16; 2] {
+            [value.numer(), value.denom()]
+        }
+    }
+
+    /// Optional `[numerator, denominator]` <-> Fixed (absent = zero)
+    pub mod fixed_frac_or_zero {
+        use super::Fixed;
+        pub fn to_engine(value: Option<[i16; 2]>) -> Fixed {
+            value.map_or(Fixed::ZERO, super::fixed_frac::to_engine)
+        }
+        pub fn to_json(value: &Fixed) -> Option<[i16; 2]> {
+            if *value == Fixed::ZERO {
+                None
+            } else {
+                Some(super::fixed_frac::to_json(value))
+            }
+        }
+    }
+
+    /// Raw i16 <-> Fixed
+    pub mod fixed_raw {
+        use super::Fixed;
+        pub fn to_engine(value: i16) -> Fixed {
+            Fixed::from_raw(value)
+        }
+        pub fn to_json(value: &Fixed) -> i16 {
+            value.raw()
+        }
+    }
+
+    /// `[a, b]` <-> `(a, b)`
+    pub mod pair {
+        pub fn to_engine<T: Copy>(value: [T; 2]) -> (T, T) {
+            (value[0], value[1])
+        }
+        pub fn to_json<T: Copy>(value: &(T, T)) -> [T; 2] {
+            [value.0, value.1]
+        }
+    }
+
+    /// Optional element code <-> Option<Element> (unknown codes become None)
+    pub mod element {
+        use super::Element;
+        pub fn to_engine(value: Option<u8>) -> Option<Element> {
+            value.and_then(Element::from_u8)
+        }
+        pub fn to_json(value: &Option<Element>) -> Option<u8> {
+            value.map(|element| element as u8)
+        }
+    }
+
+    /// u8 <-> StackBehavior (unknown values fall back to Stack)
+    pub mod stack_behavior {
+        use super::StackBehavior;
+        pub fn to_engine(value: u8) -> StackBehavior {
+            StackBehavior::from_u8(value).unwrap_or_default()
+        }
+        pub fn to_json(value: &StackBehavior) -> u8 {
+            *value as u8
+        }
+    }
+
+    /// Vec of JSON items <-> Vec of engine items, using the item conversions
+    pub mod list {
+        pub fn to_engine<J, E: From<J>>(value: Vec<J>) -> Vec<E> {
+            value.into_iter().map(E::from).collect()
+        }
+        pub fn to_json<'a, E: 'a, J: From<&'a E>>(value: &'a [E]) -> Vec<J> {
+            value.iter().map(J::from).collect()
+        }
+    }
+}
+
+/// Generate conversions in both directions between a JSON type and its engine type
+///
+/// Both directions are exhaustive struct literals, so a field added to either side
+/// without being listed here fails to compile instead of being dropped at the WASM boundary.
+/// Wrapper-only presentation fields listed under `json_only` never reach the engine and
+/// come back as their defaults.
+macro_rules! definition_conversion {
+    (
+        $json:ident <=> $engine:ident { $($field:ident: $kind:ident),* $(,)? }
+        $(json_only { $($extra:ident),* $(,)? })?
+    ) => {
+        impl From<$json> for $engine {
+            fn from(json: $json) -> Self {
+                $engine {
+                    $($field: convert::$kind::to_engine(json.$field)),*
+                }
+            }
+        }
+
+        impl From<&$engine> for $json {
+            fn from(value: &$engine) -> Self {
+                $json {
+                    $($field: convert::$kind::to_json(&value.$field),)*
+                    $($($extra: Default::default(),)*)?
+                }
+            }
+        }
+    };
+}
+
+#[cfg(test)]
+pub(crate) use definition_conversion;
+
+definition_conversion!(ActionDefinitionJson <=> ActionDefinition {
+    energy_cost: copy,
+    cooldown: copy,
+    args: copy,
+    constants: copy,
+    spawns: copy,
+    script: copy,
+});
+
+definition_conversion!(ConditionDefinitionJson <=> ConditionDefinition {
+    energy_mul: fixed_raw,
+    args: copy,
+    constants: copy,
+    script: copy,
+});
+
+definition_conversion!(SpawnDefinitionJson <=> SpawnDefinition {
+    damage_base: copy,
+    damage_range: copy,
+    crit_chance: copy,
+    crit_multiplier: copy,
+    health_cap: copy,
+    duration: copy,
+    element: element,
+    chance: copy,
+    size: pair,
+    collision_mask: copy,
+    falloff_start: copy,
+    falloff_end: copy,
+    velocity_spread: fixed_frac_or_zero,
+    duration_variance: copy,
+    damage_variance: copy,
+    energy_damage: copy,
+    energy_steal: copy,
+    terrain: copy,
+    grapple: copy,
+    grapple_speed: fixed_frac_or_zero,
+    grapple_frames: copy,
+    platform: copy,
+    knockback: fixed_frac_or_zero,
+    hitstun: copy,
+    args: copy,
+    constants: copy,
+    spawns: copy,
+    behavior_script: copy,
+    collision_script: copy,
+    despawn_script: copy,
+} json_only {
+    name,
+    sprite,
+});
+
+definition_conversion!(StatusEffectDefinitionJson <=> StatusEffectDefinition {
+    duration: copy,
+    stack_limit: copy,
+    reset_on_stack: copy,
+    stack_behavior: stack_behavior,
+    chance: copy,
+    category: copy,
+    reflect: copy,
+    taunt: copy,
+    scale: copy,
+    priority: copy,
+    args: copy,
+    constants: copy,
+    spawns: copy,
+    children: copy,
+    on_script: copy,
+    tick_script: copy,
+    off_script: copy,
+});
+
+definition_conversion!(RewardEntryJson <=> RewardEntry {
+    item_id: copy,
+    weight: copy,
+    min_quantity: copy,
+    max_quantity: copy,
+});
+
+definition_conversion!(RewardTableJson <=> RewardTable {
+    rolls: copy,
+    entries: list,
+});
+
+definition_conversion!(RewardDropJson <=> RewardDrop {
+    item_id: copy,
+    quantity: copy,
+});
+
+/// Characters are built on top of `Character::new` because they also carry runtime state,
+/// so they keep a hand-written conversion
 impl From<CharacterDefinitionJson> for Character {
     fn from(json: CharacterDefinitionJson) -> Self {
         let mut character = Character::new(json.id, json.group);
 
         // Set position using Fixed-point conversion from numerator/denominator
         character.core.pos = (
-            Fixed::from_frac(json.position[0][0], json.position[0][1]),
-            Fixed::from_frac(json.position[1][0], json.position[1][1]),
+            convert::fixed_frac::to_engine(json.position[0]),
+            convert::fixed_frac::to_engine(json.position[1]),
         );
 
         // Set updated properties
@@ -284,17 +1055,28 @@ impl From<CharacterDefinitionJson> for Character {
         character.energy_cap = json.energy_cap;
         character.power = json.power;
         character.weight = json.weight;
-        character.jump_force = Fixed::from_frac(json.jump_force[0], json.jump_force[1]);
-        character.move_speed = Fixed::from_frac(json.move_speed[0], json.move_speed[1]);
+        character.jump_force = convert::fixed_frac::to_engine(json.jump_force);
+        character.move_speed = convert::fixed_frac::to_engine(json.move_speed);
+        if let Some(speed) = json.wall_slide_speed {
+            character.wall_slide_speed = convert::fixed_frac::to_engine(speed);
+        }
         character.armor = json.armor;
         character.energy_regen = json.energy_regen;
         character.energy_regen_rate = json.energy_regen_rate;
         character.energy_charge = json.energy_charge;
         character.energy_charge_rate = json.energy_charge_rate;
+        character.detection_radius = json.detection_radius;
+        character.detection_requires_los = json.detection_requires_los;
+        character.overflow_mode = json.overflow_mode;
+        character.quota = ScriptQuota {
+            max_instructions: json.quota.max_instructions,
+            max_property_writes: json.quota.max_property_writes,
+            max_spawns: json.quota.max_spawns,
+        };
 
         // Set EntityCore properties
-        character.core.size = (json.size[0], json.size[1]);
-        character.core.dir = (json.dir[0], json.dir[1]);
+        character.core.size = convert::pair::to_engine(json.size);
+        character.core.dir = convert::pair::to_engine(json.dir);
         character.core.enmity = json.enmity;
         character.core.target_id = json.target_id;
         character.core.target_type = json.target_type;
@@ -310,65 +1092,96 @@ impl From<CharacterDefinitionJson> for Character {
     }
 }
 
-impl From<ActionDefinitionJson> for ActionDefinition {
-    fn from(json: ActionDefinitionJson) -> Self {
-        ActionDefinition {
-            energy_cost: json.energy_cost,
-            cooldown: json.cooldown,
-            args: json.args,
-            spawns: json.spawns,
-            script: json.script,
-        }
-    }
-}
-
-impl From<ConditionDefinitionJson> for ConditionDefinition {
-    fn from(json: ConditionDefinitionJson) -> Self {
-        ConditionDefinition {
-            energy_mul: Fixed::from_raw(json.energy_mul), // Convert integer to fixed-point
-            args: json.args,
-            script: json.script,
-        }
-    }
-}
-
-impl From<SpawnDefinitionJson> for SpawnDefinition {
-    fn from(json: SpawnDefinitionJson) -> Self {
-        use robot_masters_engine::entity::Element;
-
-        let element = json.element.and_then(Element::from_u8);
-
-        SpawnDefinition {
-            damage_base: json.damage_base,
-            damage_range: json.damage_range,
-            crit_chance: json.crit_chance,
-            crit_multiplier: json.crit_multiplier,
-            health_cap: json.health_cap,
-            duration: json.duration,
-            element,
-            chance: json.chance,
-            size: (json.size[0], json.size[1]),
-            args: json.args,
-            spawns: json.spawns,
-            behavior_script: json.behavior_script,
-            collision_script: json.collision_script,
-            despawn_script: json.despawn_script,
-        }
-    }
-}
+impl From<&Character> for CharacterDefinitionJson {
+    fn from(character: &Character) -> Self {
+        // Exhaustive destructuring: new engine fields must be classified here as
+        // either configuration (exported) or runtime state (ignored)
+        let Character {
+            core,
+            health,
+            health_cap,
+            energy,
+            energy_cap,
+            power,
+            weight,
+            jump_force,
+            move_speed,
+            wall_slide_speed,
+            armor,
+            energy_regen,
+            energy_regen_rate,
+            energy_charge,
+            energy_charge_rate,
+            behaviors,
+            locked_action: _,
+            status_effects: _,
+            action_last_used: _,
+            stats: _,
+            recent_damage: _,
+            detection_radius,
+            detection_requires_los,
+            detected_enemies: _,
+            ground_tile: _,
+            ground_tile_pos: _,
+            facing_tile: _,
+            terrain_contact: _,
+            taunted_by: _,
+            aggro: _,
+            size_scale: _,
+            base_size,
+            dash: _,
+            grapple: _,
+            hitstun: _,
+            respawn_timer: _,
+            overflow_mode,
+            overflow_energy: _,
+            shield: _,
+            charge: _,
+            quota,
+            script_usage: _,
+        } = character;
 
-impl From<StatusEffectDefinitionJson> for StatusEffectDefinition {
-    fn from(json: StatusEffectDefinitionJson) -> Self {
-        StatusEffectDefinition {
-            duration: json.duration,
-            stack_limit: json.stack_limit,
-            reset_on_stack: json.reset_on_stack,
-            chance: json.chance,
-            args: json.args,
-            spawns: json.spawns,
-            on_script: json.on_script,
-            tick_script: json.tick_script,
-            off_script: json.off_script,
+        CharacterDefinitionJson {
+            id: core.id,
+            group: core.group,
+            position: [
+                convert::fixed_frac::to_json(&core.pos.0),
+                convert::fixed_frac::to_json(&core.pos.1),
+            ],
+            size: convert::pair::to_json(&base_size.unwrap_or(core.size)),
+            health: *health,
+            health_cap: *health_cap,
+            energy: *energy,
+            energy_cap: *energy_cap,
+            power: *power,
+            weight: *weight,
+            jump_force: convert::fixed_frac::to_json(jump_force),
+            move_speed: convert::fixed_frac::to_json(move_speed),
+            wall_slide_speed: (*wall_slide_speed != Fixed::ZERO)
+                .then(|| convert::fixed_frac::to_json(wall_slide_speed)),
+            armor: *armor,
+            energy_regen: *energy_regen,
+            energy_regen_rate: *energy_regen_rate,
+            energy_charge: *energy_charge,
+            energy_charge_rate: *energy_charge_rate,
+            dir: convert::pair::to_json(&core.dir),
+            enmity: core.enmity,
+            target_id: core.target_id,
+            target_type: core.target_type,
+            behaviors: behaviors
+                .iter()
+                .map(|&(condition_id, action_id)| [condition_id, action_id])
+                .collect(),
+            detection_radius: *detection_radius,
+            detection_requires_los: *detection_requires_los,
+            overflow_mode: *overflow_mode,
+            quota: ScriptQuotaJson {
+                max_instructions: quota.max_instructions,
+                max_property_writes: quota.max_property_writes,
+                max_spawns: quota.max_spawns,
+            },
+            name: None,
+            sprite: None,
         }
     }
 }
@@ -404,14 +1217,17 @@ pub fn convert_tilemap(json_tilemap: &[Vec<u8>]) -> Result<[[u8; 16]; 15], Valid
 /// JSON-compatible game state representation for serialization
 #[derive(Serialize, Deserialize, Debug, Clone)]
 pub struct GameStateJson {
-    pub frame: u16,
+    pub frame: u32,
     pub seed: u16,
-    pub gravity: [i16; 2], // Gravity as [numerator, denominator]
+    pub gravity: [i16; 2], // Vertical gravity as [numerator, denominator]
+    pub gravity_vector: [[i16; 2]; 2], // Gravity as [[x_num, x_den], [y_num, y_den]]
     pub status: String,
     pub characters: Vec<CharacterStateJson>,
     pub spawns: Vec<SpawnStateJson>,
     pub status_effects: Vec<StatusEffectStateJson>,
     pub tilemap: Vec<Vec<u8>>,
+    #[serde(default)]
+    pub terrain: Vec<TerrainTileJson>, // Tiles written by terrain spawns (already in tilemap)
 }
 
 /// JSON-compatible character state representation
@@ -429,20 +1245,95 @@ pub struct CharacterStateJson {
     pub weight: u8,           // New property
     pub jump_force: [i16; 2], // New property [numerator, denominator]
     pub move_speed: [i16; 2], // New property [numerator, denominator]
-    pub armor: [u8; 9],
+    pub armor: Armor,         // Armor values indexed by element
     pub energy_regen: u8,
     pub energy_regen_rate: u8,
     pub energy_charge: u8,
     pub energy_charge_rate: u8,
-    pub dir: [u8; 2],          // Replaces facing and gravity_dir
-    pub enmity: u8,            // New property
-    pub target_id: Option<u8>, // New property
-    pub target_type: u8,       // New property
-    pub size: [u8; 2],
-    pub collision: [bool; 4], // [top, right, bottom, left]
+    pub dir: [u8; 2],           // Replaces facing and gravity_dir
+    pub enmity: u8,             // New property
+    pub target_id: Option<u8>,  // New property
+    pub target_type: u8,        // New property
+    pub taunted_by: Option<u8>, // Taunting character forcing target_id (retargeting is refused)
+    #[serde(default)]
+    pub aggro: [u8; 8], // Aggro toward each character id (index = id)
+    pub size: [u8; 2],          // Current (scaled) hitbox size
+    pub size_scale: u16,        // Percent of base_size applied by status effects (100 = unscaled)
+    pub base_size: [u8; 2],     // Hitbox size without status effect scaling
+    pub collision: [bool; 4],   // [top, right, bottom, left]
+    #[serde(default)]
+    pub dash: Option<DashStateJson>, // Dash in progress (velocity is ignored while dashing)
+    #[serde(default)]
+    pub grapple: Option<GrappleStateJson>, // Grapple pull in progress (velocity is ignored while pulled)
+    #[serde(default)]
+    pub wall_sliding: bool, // Falling while pressing into a wall, capped at the wall slide speed
+    #[serde(default)]
+    pub hitstun: u8, // Frames left in which behaviors are suspended after a knockback hit
+    #[serde(default)]
+    pub dead: bool, // Health reached zero; behaviors are skipped until the character revives
+    #[serde(default)]
+    pub respawn_timer: u16, // Frames until a defeated character revives (0 = not reviving)
+    #[serde(default)]
+    pub overflow_mode: u8, // What energy above the cap turns into (0 = lost, 1 = shield, 2 = charge stacks)
+    #[serde(default)]
+    pub overflow_energy: u8, // Energy above the cap waiting for conversion
+    #[serde(default)]
+    pub shield: u16, // Overheal shield absorbing hit damage before health
+    #[serde(default)]
+    pub charge: u8, // Charge stacks built from overflow energy
     pub locked_action: Option<u8>,
     pub status_effects: Vec<u8>,
     pub behaviors: Vec<[usize; 2]>, // [condition_id, action_id] pairs
+    pub cooldowns: Vec<ActionCooldownJson>, // One entry per behavior, same order as behaviors
+    pub stats: CharacterStatsJson,  // Running match statistics
+    pub detection_radius: u8,       // Enemy detection range in pixels
+    pub detected_enemies: u8,       // Bitmask of detected enemy ids (bit n = id n)
+    pub ground_tile: u8,            // Tile type under the feet
+    pub ground_tile_pos: [u8; 2],   // [x, y] tile coordinates under the feet (255 = outside)
+    pub facing_tile: u8,            // Tile type beyond the facing edge
+    #[serde(default)]
+    pub terrain_contact: u8, // Slope/platform/ladder/hazard contact bits
+    pub name: Option<String>,       // Display name from the character configuration
+    pub sprite: Option<String>,     // Sprite key from the character configuration
+}
+
+/// JSON-compatible dash in progress
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DashStateJson {
+    pub remaining: [[i16; 2]; 2], // Displacement still to cover [[x_num, x_den], [y_num, y_den]]
+    pub frames_left: u8,          // Frames until the dash ends
+}
+
+/// JSON-compatible grapple pull in progress
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GrappleStateJson {
+    pub source_id: u8,         // Owner of the grapple spawn
+    pub anchor: [[i16; 2]; 2], // Point the center is pulled toward [[x_num, x_den], [y_num, y_den]]
+    pub anchor_id: Option<u8>, // Character whose center the anchor follows
+    pub speed: [i16; 2],       // Pull speed in pixels per frame as [numerator, denominator]
+    pub frames_left: u8,       // Frames until the pull breaks (0 = no limit)
+}
+
+/// JSON-compatible running match statistics for a character
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CharacterStatsJson {
+    pub damage_dealt: u16,         // Total damage dealt to other characters
+    pub damage_taken: u16,         // Total damage taken from any source
+    pub frames_since_hit: u16,     // Frames since the character last dealt damage
+    pub frames_since_damaged: u16, // Frames since the character last took damage
+    pub recent_damage: u16,        // Damage taken within the damage window
+    pub in_combat: bool,           // Damage was taken within the damage window
+}
+
+/// JSON-compatible cooldown state for a behavior's action
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ActionCooldownJson {
+    pub action_id: usize,
+    pub frames_remaining: u16, // 0 when the cooldown has elapsed
+    pub cooldown: u16,         // Total cooldown from the action definition
+    pub usable: bool,          // Off cooldown and enough energy to use right now
+    pub energy_cost: u8,       // Energy the action costs this character right now
+    pub affordable: bool,      // Enough energy for energy_cost, ignoring the cooldown
 }
 
 /// JSON-compatible spawn instance state representation
@@ -458,6 +1349,8 @@ pub struct SpawnStateJson {
     pub health_cap: u16,         // New property
     pub rotation: [i16; 2],      // New property [numerator, denominator]
     pub life_span: u16,          // Renamed from lifespan
+    pub origin: [[i16; 2]; 2],   // [[x_num, x_den], [y_num, y_den]] where the spawn was created
+    pub damage: u16,             // Base damage rolled at creation (before distance falloff)
     pub element: Option<u8>,     // Element as u8 value (0-8)
     pub dir: [u8; 2],            // Replaces facing and gravity_dir
     pub enmity: u8,              // New property
@@ -467,6 +1360,18 @@ pub struct SpawnStateJson {
     pub collision: [bool; 4],         // [top, right, bottom, left]
     pub runtime_vars: [u8; 4],        // Renamed from vars
     pub runtime_fixed: [[i16; 2]; 4], // Renamed from fixed, [numerator, denominator] pairs
+    pub name: Option<String>,         // Display name from the spawn definition configuration
+    pub sprite: Option<String>,       // Sprite key from the spawn definition configuration
+}
+
+/// JSON-compatible tile written by a terrain spawn
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TerrainTileJson {
+    pub spawn_instance_id: u8, // Spawn that owns the tile
+    pub x: u8,                 // Tile column
+    pub y: u8,                 // Tile row
+    pub tile: u8,              // Tile type written by the spawn
+    pub previous: u8,          // Tile type restored when the spawn is removed
 }
 
 /// JSON-compatible status effect instance state representation
@@ -474,8 +1379,21 @@ pub struct SpawnStateJson {
 pub struct StatusEffectStateJson {
     pub instance_id: u8,
     pub definition_id: usize,
-    pub life_span: u16, // Renamed from remaining_duration
+    pub character_id: u8,      // Character the effect is applied to
+    pub source_id: Option<u8>, // Entity that applied the effect (null = game/system)
+    pub source_type: u8,       // Source entity type (0=None, 1=Character, 2=Spawn)
+    pub category: u8,          // UI grouping from definition (0=neutral, 1=buff, 2=debuff)
+    #[serde(default)]
+    pub priority: u8, // Display priority from definition (higher first)
+    #[serde(default)]
+    pub icon_slot: u8, // Rank among the character's effects by priority (0 = most important)
+    pub life_span: u16,        // Remaining frames (renamed from remaining_duration)
+    pub duration: u16,         // Total duration from definition
     pub stack_count: u8,
+    pub stack_limit: u8,    // Maximum stacks from definition
+    pub stack_behavior: u8, // Re-application rule from definition (0=Stack, 1=Refresh, 2=Ignore)
+    #[serde(default)]
+    pub parent: Option<u8>, // Bundle instance this effect was applied with (null = applied directly)
     pub runtime_vars: [u8; 4],        // Renamed from vars
     pub runtime_fixed: [[i16; 2]; 4], // Renamed from fixed, [numerator, denominator] pairs
 }
@@ -489,10 +1407,7 @@ impl GameStateJson {
             let mut row: Vec<u8> = Vec::with_capacity(16);
             for x in 0..16 {
                 let tile_type = game_state.tile_map.get_tile(x, y);
-                row.push(match tile_type {
-                    robot_masters_engine::tilemap::TileType::Empty => 0,
-                    robot_masters_engine::tilemap::TileType::Block => 1,
-                });
+                row.push(tile_type.into());
             }
             tilemap.push(row);
         }
@@ -500,37 +1415,294 @@ impl GameStateJson {
         Self {
             frame: game_state.frame,
             seed: game_state.seed,
-            gravity: [game_state.gravity.numer(), game_state.gravity.denom()],
+            gravity: [game_state.gravity.1.numer(), game_state.gravity.1.denom()],
+            gravity_vector: [
+                [game_state.gravity.0.numer(), game_state.gravity.0.denom()],
+                [game_state.gravity.1.numer(), game_state.gravity.1.denom()],
+            ],
             status: match game_state.status {
                 robot_masters_engine::state::GameStatus::Playing => "playing".to_string(),
                 robot_masters_engine::state::GameStatus::Ended => "ended".to_string(),
             },
-            characters: game_state
-                .characters
-                .iter()
-                .map(CharacterStateJson::from_character)
+            characters: (0..game_state.characters.len())
+                .map(|character_idx| CharacterStateJson::from_character(game_state, character_idx))
                 .collect(),
             spawns: game_state
-                .spawn_instances
+                .spawn_instances()
                 .iter()
                 .map(SpawnStateJson::from_spawn_instance)
                 .collect(),
-            status_effects: game_state
-                .status_effect_instances
+            status_effects: StatusEffectStateJson::by_priority(
+                game_state
+                    .status_effect_instances
+                    .iter()
+                    .enumerate()
+                    .map(|(index, instance)| {
+                        StatusEffectStateJson::from_status_effect_instance(
+                            game_state,
+                            instance,
+                            index as u8,
+                        )
+                    })
+                    .collect(),
+            ),
+            terrain: game_state
+                .terrain_tiles
                 .iter()
-                .enumerate()
-                .map(|(index, instance)| {
-                    StatusEffectStateJson::from_status_effect_instance(instance, index as u8)
+                .map(|tile| TerrainTileJson {
+                    spawn_instance_id: tile.spawn_instance_id,
+                    x: tile.x,
+                    y: tile.y,
+                    tile: game_state
+                        .tile_map
+                        .get_raw_tile(tile.x as usize, tile.y as usize),
+                    previous: tile.previous,
                 })
                 .collect(),
             tilemap,
         }
     }
+
+    /// Echo configured display names and sprite keys into every character and spawn
+    pub fn apply_labels(&mut self, config: &GameConfig) {
+        for character in &mut self.characters {
+            character.apply_labels(config);
+        }
+        for spawn in &mut self.spawns {
+            spawn.apply_labels(config);
+        }
+    }
+}
+
+impl GameStateJson {
+    /// Restore a captured state onto a game state built from the same configuration
+    /// Characters are matched by index; spawns and status effects are rebuilt from the capture.
+    /// Timestamps the JSON only exposes as elapsed frames (cooldowns, hit statistics) are
+    /// reconstructed relative to the captured frame.
+    pub fn restore_into(
+        &self,
+        game_state: &mut robot_masters_engine::state::GameState,
+    ) -> Result<(), ValidationError> {
+        use robot_masters_engine::core::{NEVER_FRAME, TILEMAP_HEIGHT, TILEMAP_WIDTH};
+        use robot_masters_engine::entity::{
+            Dash, Element, Grapple, SpawnInstance, StatusEffectInstance,
+        };
+        use robot_masters_engine::state::GameStatus;
+        use robot_masters_engine::tilemap::TerrainTile;
+
+        let fixed = convert::fixed_frac::to_engine;
+        let pair = |value: [[i16; 2]; 2]| (fixed(value[0]), fixed(value[1]));
+
+        if self.characters.len() != game_state.characters.len() {
+            return Err(ValidationError {
+                field: "characters".to_string(),
+                message: "Captured state does not match the configuration".to_string(),
+                context: Some(format!(
+                    "Found {} characters, configuration has {}",
+                    self.characters.len(),
+                    game_state.characters.len()
+                )),
+            });
+        }
+
+        game_state.frame = self.frame;
+        game_state.status = if self.status == "ended" {
+            GameStatus::Ended
+        } else {
+            GameStatus::Playing
+        };
+        game_state.gravity = pair(self.gravity_vector);
+        let frame = self.frame;
+        let since = |elapsed: u16| frame.saturating_sub(elapsed as u32);
+
+        for (character, json) in game_state.characters.iter_mut().zip(&self.characters) {
+            character.core.id = json.id;
+            character.core.group = json.group;
+            character.core.pos = pair(json.position);
+            character.core.vel = pair(json.velocity);
+            character.core.size = (json.size[0], json.size[1]);
+            character.core.collision = (
+                json.collision[0],
+                json.collision[1],
+                json.collision[2],
+                json.collision[3],
+            );
+            character.core.dir = (json.dir[0], json.dir[1]);
+            character.core.enmity = json.enmity;
+            character.core.target_id = json.target_id;
+            character.core.target_type = json.target_type;
+            character.health = json.health;
+            character.health_cap = json.health_cap;
+            character.energy = json.energy;
+            character.energy_cap = json.energy_cap;
+            character.power = json.power;
+            character.weight = json.weight;
+            character.jump_force = fixed(json.jump_force);
+            character.move_speed = fixed(json.move_speed);
+            character.armor = json.armor;
+            character.energy_regen = json.energy_regen;
+            character.energy_regen_rate = json.energy_regen_rate;
+            character.energy_charge = json.energy_charge;
+            character.energy_charge_rate = json.energy_charge_rate;
+            character.behaviors = json
+                .behaviors
+                .iter()
+                .map(|&[condition_id, action_id]| (condition_id, action_id))
+                .collect();
+            character.locked_action = json.locked_action;
+            character.status_effects = json.status_effects.clone();
+            character.stats.damage_dealt = json.stats.damage_dealt;
+            character.stats.damage_taken = json.stats.damage_taken;
+            character.stats.last_hit_frame = since(json.stats.frames_since_hit);
+            character.stats.last_damaged_frame = since(json.stats.frames_since_damaged);
+            character.recent_damage = if json.stats.recent_damage > 0 {
+                vec![(frame, json.stats.recent_damage)]
+            } else {
+                Vec::new()
+            };
+            character.detection_radius = json.detection_radius;
+            character.detected_enemies = json.detected_enemies;
+            character.ground_tile = json.ground_tile;
+            character.ground_tile_pos = (json.ground_tile_pos[0], json.ground_tile_pos[1]);
+            character.facing_tile = json.facing_tile;
+            character.terrain_contact = json.terrain_contact;
+            character.aggro = json.aggro;
+            character.dash = json
+                .dash
+                .as_ref()
+                .filter(|dash| dash.frames_left > 0)
+                .map(|dash| Dash {
+                    remaining: pair(dash.remaining),
+                    frames_left: dash.frames_left,
+                });
+            character.grapple = json.grapple.as_ref().map(|grapple| Grapple {
+                source_id: grapple.source_id,
+                anchor: pair(grapple.anchor),
+                anchor_id: grapple.anchor_id,
+                speed: fixed(grapple.speed),
+                frames_left: grapple.frames_left,
+            });
+            character.hitstun = json.hitstun;
+            character.respawn_timer = json.respawn_timer;
+            character.overflow_mode = json.overflow_mode;
+            character.overflow_energy = json.overflow_energy;
+            character.shield = json.shield;
+            character.charge = json.charge;
+
+            // Actions still cooling down were used (cooldown - remaining) frames ago
+            for last_used in character.action_last_used.iter_mut() {
+                *last_used = NEVER_FRAME;
+            }
+            for cooldown in json.cooldowns.iter().filter(|c| c.frames_remaining > 0) {
+                if let Some(last_used) = character.action_last_used.get_mut(cooldown.action_id) {
+                    *last_used = since(cooldown.cooldown.saturating_sub(cooldown.frames_remaining));
+                }
+            }
+        }
+
+        let spawns = self
+            .spawns
+            .iter()
+            .map(|json| {
+                let element = json
+                    .element
+                    .and_then(Element::from_u8)
+                    .unwrap_or(Element::Punct);
+                let mut spawn = SpawnInstance::with_element(
+                    json.spawn_id,
+                    json.owner_id,
+                    pair(json.position),
+                    element,
+                );
+                spawn.core.id = json.id;
+                spawn.core.vel = pair(json.velocity);
+                spawn.core.size = (json.size[0], json.size[1]);
+                spawn.core.collision = (
+                    json.collision[0],
+                    json.collision[1],
+                    json.collision[2],
+                    json.collision[3],
+                );
+                spawn.core.dir = (json.dir[0], json.dir[1]);
+                spawn.core.enmity = json.enmity;
+                spawn.core.target_id = json.target_id;
+                spawn.core.target_type = json.target_type;
+                spawn.owner_type = json.owner_type;
+                spawn.health = json.health;
+                spawn.health_cap = json.health_cap;
+                spawn.rotation = fixed(json.rotation);
+                spawn.life_span = json.life_span;
+                spawn.origin = pair(json.origin);
+                spawn.damage = json.damage;
+                spawn.runtime_vars = json.runtime_vars;
+                spawn.runtime_fixed = json.runtime_fixed.map(fixed);
+                spawn
+            })
+            .collect();
+        game_state
+            .restore_spawns(spawns)
+            .map_err(|_| ValidationError {
+                field: "spawns".to_string(),
+                message: "Captured spawns do not fit the spawn pool".to_string(),
+                context: Some(format!(
+                    "Found {} spawns; at most {} with distinct ids are allowed",
+                    self.spawns.len(),
+                    robot_masters_engine::core::MAX_SPAWNS
+                )),
+            })?;
+
+        // Listed in display order; instance ids are positions in the instance list
+        let mut status_effects: Vec<&StatusEffectStateJson> = self.status_effects.iter().collect();
+        status_effects.sort_by_key(|json| json.instance_id);
+        game_state.status_effect_instances = status_effects
+            .into_iter()
+            .map(|json| StatusEffectInstance {
+                definition_id: json.definition_id,
+                character_id: json.character_id,
+                source_id: json.source_id,
+                source_type: json.source_type,
+                life_span: json.life_span,
+                stack_count: json.stack_count,
+                parent: json.parent,
+                runtime_vars: json.runtime_vars,
+                runtime_fixed: json.runtime_fixed.map(fixed),
+            })
+            .collect();
+
+        // Undo the current terrain, then write the captured tiles over the configured map
+        for tile in game_state.terrain_tiles.drain(..).rev() {
+            game_state.tile_map.get_raw_tiles_mut()[tile.y as usize][tile.x as usize] =
+                tile.previous;
+        }
+        for json in &self.terrain {
+            if json.x as usize >= TILEMAP_WIDTH || json.y as usize >= TILEMAP_HEIGHT {
+                return Err(ValidationError {
+                    field: "terrain".to_string(),
+                    message: "Terrain tile outside the tilemap".to_string(),
+                    context: Some(format!("Tile at ({}, {})", json.x, json.y)),
+                });
+            }
+            game_state.tile_map.get_raw_tiles_mut()[json.y as usize][json.x as usize] = json.tile;
+            game_state.terrain_tiles.push(TerrainTile {
+                spawn_instance_id: json.spawn_instance_id,
+                x: json.x,
+                y: json.y,
+                previous: json.previous,
+            });
+        }
+
+        Ok(())
+    }
 }
 
 impl CharacterStateJson {
-    /// Convert from game engine Character to JSON-compatible representation
-    pub fn from_character(character: &robot_masters_engine::entity::Character) -> Self {
+    /// Convert a character of the game state to JSON-compatible representation
+    pub fn from_character(
+        game_state: &robot_masters_engine::state::GameState,
+        character_idx: usize,
+    ) -> Self {
+        let character = &game_state.characters[character_idx];
+
         Self {
             id: character.core.id,
             group: character.core.group,
@@ -559,13 +1731,42 @@ impl CharacterStateJson {
             enmity: character.core.enmity,
             target_id: character.core.target_id,
             target_type: character.core.target_type,
+            taunted_by: character.taunted_by,
+            aggro: character.aggro,
             size: [character.core.size.0, character.core.size.1],
+            size_scale: character.size_scale,
+            base_size: [character.unscaled_size().0, character.unscaled_size().1],
             collision: [
                 character.core.collision.0,
                 character.core.collision.1,
                 character.core.collision.2,
                 character.core.collision.3,
             ],
+            dash: character.dash.map(|dash| DashStateJson {
+                remaining: [
+                    Self::fixed_to_numer_denom(dash.remaining.0),
+                    Self::fixed_to_numer_denom(dash.remaining.1),
+                ],
+                frames_left: dash.frames_left,
+            }),
+            grapple: character.grapple.map(|grapple| GrappleStateJson {
+                source_id: grapple.source_id,
+                anchor: [
+                    Self::fixed_to_numer_denom(grapple.anchor.0),
+                    Self::fixed_to_numer_denom(grapple.anchor.1),
+                ],
+                anchor_id: grapple.anchor_id,
+                speed: Self::fixed_to_numer_denom(grapple.speed),
+                frames_left: grapple.frames_left,
+            }),
+            wall_sliding: character.is_wall_sliding(),
+            hitstun: character.hitstun,
+            dead: character.health == 0,
+            respawn_timer: character.respawn_timer,
+            overflow_mode: character.overflow_mode,
+            overflow_energy: character.overflow_energy,
+            shield: character.shield,
+            charge: character.charge,
             locked_action: character.locked_action,
             status_effects: character.status_effects.clone(),
             behaviors: character
@@ -573,6 +1774,47 @@ impl CharacterStateJson {
                 .iter()
                 .map(|&(condition_id, action_id)| [condition_id, action_id])
                 .collect(),
+            cooldowns: character
+                .behaviors
+                .iter()
+                .map(|&(_, action_id)| ActionCooldownJson {
+                    action_id,
+                    frames_remaining: game_state
+                        .get_action_cooldown_remaining(character_idx, action_id),
+                    cooldown: game_state
+                        .get_action_definition(action_id)
+                        .map_or(0, |def| def.cooldown),
+                    usable: game_state.is_action_usable(character_idx, action_id),
+                    energy_cost: game_state
+                        .action_energy_cost(character_idx, action_id)
+                        .unwrap_or(0),
+                    affordable: game_state.can_afford_action(character_idx, action_id),
+                })
+                .collect(),
+            stats: CharacterStatsJson {
+                damage_dealt: character.stats.damage_dealt,
+                damage_taken: character.stats.damage_taken,
+                frames_since_hit: character.frames_since_hit(game_state.frame),
+                frames_since_damaged: character.frames_since_damaged(game_state.frame),
+                recent_damage: character.recent_damage_taken(),
+                in_combat: character.is_in_combat(),
+            },
+            detection_radius: character.detection_radius,
+            detected_enemies: character.detected_enemies,
+            ground_tile: character.ground_tile,
+            ground_tile_pos: [character.ground_tile_pos.0, character.ground_tile_pos.1],
+            facing_tile: character.facing_tile,
+            terrain_contact: character.terrain_contact,
+            name: None, // Labels come from the configuration (see apply_labels)
+            sprite: None,
+        }
+    }
+
+    /// Copy the display name and sprite key of the configured character with this id
+    pub fn apply_labels(&mut self, config: &GameConfig) {
+        if let Some(definition) = config.characters.iter().find(|def| def.id == self.id) {
+            self.name = definition.name.clone();
+            self.sprite = definition.sprite.clone();
         }
     }
 
@@ -602,6 +1844,11 @@ impl SpawnStateJson {
             health_cap: spawn.health_cap,
             rotation: Self::fixed_to_numer_denom(spawn.rotation),
             life_span: spawn.life_span,
+            origin: [
+                Self::fixed_to_numer_denom(spawn.origin.0),
+                Self::fixed_to_numer_denom(spawn.origin.1),
+            ],
+            damage: spawn.damage,
             element: Some(spawn.element as u8),
             dir: [spawn.core.dir.0, spawn.core.dir.1],
             enmity: spawn.core.enmity,
@@ -621,6 +1868,16 @@ impl SpawnStateJson {
                 Self::fixed_to_numer_denom(spawn.runtime_fixed[2]),
                 Self::fixed_to_numer_denom(spawn.runtime_fixed[3]),
             ],
+            name: None, // Labels come from the configuration (see apply_labels)
+            sprite: None,
+        }
+    }
+
+    /// Copy the display name and sprite key of this instance's spawn definition
+    pub fn apply_labels(&mut self, config: &GameConfig) {
+        if let Some(definition) = config.spawns.get(self.spawn_id as usize) {
+            self.name = definition.name.clone();
+            self.sprite = definition.sprite.clone();
         }
     }
 
@@ -633,14 +1890,27 @@ impl SpawnStateJson {
 impl StatusEffectStateJson {
     /// Convert from game engine StatusEffectInstance to JSON-compatible representation
     pub fn from_status_effect_instance(
+        game_state: &robot_masters_engine::state::GameState,
         instance: &robot_masters_engine::entity::StatusEffectInstance,
         instance_id: u8,
     ) -> Self {
+        let definition = game_state.get_status_effect_definition(instance.definition_id);
+
         Self {
             instance_id,
             definition_id: instance.definition_id,
+            character_id: instance.character_id,
+            source_id: instance.source_id,
+            source_type: instance.source_type,
+            category: definition.map_or(0, |def| def.category),
+            priority: definition.map_or(0, |def| def.priority),
+            icon_slot: 0,                  // Assigned by by_priority
             life_span: instance.life_span, // Renamed from remaining_duration
+            duration: definition.map_or(0, |def| def.duration),
             stack_count: instance.stack_count,
+            stack_limit: definition.map_or(1, |def| def.stack_limit),
+            stack_behavior: definition.map_or(0, |def| def.stack_behavior as u8),
+            parent: instance.parent,
             runtime_vars: instance.runtime_vars, // Renamed from vars
             runtime_fixed: [
                 // Renamed from fixed, [numerator, denominator] pairs
@@ -652,8 +1922,1032 @@ impl StatusEffectStateJson {
         }
     }
 
+    /// Order effects for display: grouped by character, highest priority first, ties in
+    /// instance order; each effect's `icon_slot` is its rank within its character, so UIs
+    /// show the most important N effects by keeping slots below N
+    pub fn by_priority(mut effects: Vec<Self>) -> Vec<Self> {
+        effects.sort_by_key(|effect| {
+            (
+                effect.character_id,
+                std::cmp::Reverse(effect.priority),
+                effect.instance_id,
+            )
+        });
+        let mut previous = None;
+        let mut slot = 0u8;
+        for effect in &mut effects {
+            if previous != Some(effect.character_id) {
+                previous = Some(effect.character_id);
+                slot = 0;
+            }
+            effect.icon_slot = slot;
+            slot = slot.saturating_add(1);
+        }
+        effects
+    }
+
     /// Convert Fixed-point value to [numerator, denominator] representation
     fn fixed_to_numer_denom(fixed: Fixed) -> [i16; 2] {
         [fixed.numer(), fixed.denom()]
     }
 }
+
+/// JSON-compatible script instances of one character (`get_instances_json`)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CharacterInstancesJson {
+    pub character_id: u8,
+    pub actions: Vec<ActionInstanceJson>, // Creation order (an action's instance appears once it has run)
+    pub conditions: Vec<ConditionInstanceJson>, // Creation order (once first evaluated)
+    pub status_effects: Vec<StatusEffectStateJson>, // Display priority order
+}
+
+/// JSON-compatible action instance with its persisted script variables
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ActionInstanceJson {
+    pub instance_id: u8,
+    pub action_id: usize,
+    pub last_used_frame: u32,
+    pub locked: bool, // The character is locked into this action
+    pub runtime_vars: [u8; 4],
+    pub runtime_fixed: [[i16; 2]; 4], // [numerator, denominator] pairs
+}
+
+/// JSON-compatible condition instance with its persisted script variables
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConditionInstanceJson {
+    pub instance_id: u8,
+    pub condition_id: usize,
+    pub runtime_vars: [u8; 4],
+    pub runtime_fixed: [[i16; 2]; 4], // [numerator, denominator] pairs
+}
+
+impl CharacterInstancesJson {
+    /// Collect a character's instances through the engine's read-only instance views
+    pub fn from_game_state(
+        game_state: &robot_masters_engine::state::GameState,
+        character_id: u8,
+    ) -> Self {
+        let fixed = |values: [Fixed; 4]| values.map(|value| [value.numer(), value.denom()]);
+        Self {
+            character_id,
+            actions: game_state
+                .action_instances_of(character_id)
+                .map(|view| ActionInstanceJson {
+                    instance_id: view.id as u8,
+                    action_id: view.instance.definition_id,
+                    last_used_frame: view.instance.last_used_frame,
+                    locked: view.locked,
+                    runtime_vars: view.instance.runtime_vars,
+                    runtime_fixed: fixed(view.instance.runtime_fixed),
+                })
+                .collect(),
+            conditions: game_state
+                .condition_instances_of(character_id)
+                .map(|view| ConditionInstanceJson {
+                    instance_id: view.id as u8,
+                    condition_id: view.instance.definition_id,
+                    runtime_vars: view.instance.runtime_vars,
+                    runtime_fixed: fixed(view.instance.runtime_fixed),
+                })
+                .collect(),
+            status_effects: StatusEffectStateJson::by_priority(
+                game_state
+                    .status_effect_instances_of(character_id)
+                    .map(|view| {
+                        StatusEffectStateJson::from_status_effect_instance(
+                            game_state,
+                            view.instance,
+                            view.id,
+                        )
+                    })
+                    .collect(),
+            ),
+        }
+    }
+}
+
+/// JSON-compatible camera hint payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CameraHintJson {
+    pub focus_type: u8, // 0=None, 1=Character, 2=Spawn
+    pub focus_id: u8,   // Character or spawn instance id
+    pub zoom: [i16; 2], // Zoom level as [numerator, denominator] (1.0 = default, 0 = keep current)
+    pub shake: u8,      // Screen shake magnitude (0 = none)
+}
+
+/// JSON-compatible game event representation
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GameEventJson {
+    pub frame: u32,
+    pub event_type: String, // "camera_hint" | "combat_state" | "spawn_created" | "spawn_removed" | "character_hit" | "damage_reflected" | "energy_drained" | "dash" | "grapple_attached" | "grapple_released" | "status_applied" | "status_expired" | "action_executed" | "character_defeated" | "character_revived" | "spawn_collisions_deferred"
+    pub camera_hint: Option<CameraHintJson>,
+    pub combat_state: Option<CombatStateJson>,
+    pub removal: Option<RemovalJson>,
+    pub hit: Option<HitJson>,
+    pub reflect: Option<ReflectJson>,
+    pub energy: Option<EnergyJson>,
+    pub dash: Option<DashJson>,
+    pub grapple: Option<GrappleJson>,
+    pub deferred: Option<DeferredWorkJson>,
+    pub spawn: Option<SpawnCreatedJson>,
+    pub status: Option<StatusEventJson>,
+    pub action: Option<ActionEventJson>,
+    pub revival: Option<RevivalJson>,
+}
+
+/// JSON-compatible queued events returned by `drain_events`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DrainedEventsJson {
+    pub events: Vec<GameEventJson>, // Oldest first
+    pub dropped: u32,               // Oldest events discarded because the queue was full
+}
+
+/// JSON-compatible combat state change payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CombatStateJson {
+    pub character_id: u8,
+    pub in_combat: bool, // true = took damage, false = damage window elapsed
+}
+
+/// JSON-compatible spawn hit payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct HitJson {
+    pub character_id: u8,
+    pub spawn_instance_id: u8,
+    pub attacker_id: Option<u8>, // Owning character of the spawn, if any
+    pub amount: u16,             // Damage after armor (0 = fully absorbed)
+    pub element: u8,             // Element index (0=Punct .. 8=Virus)
+    pub critical: bool,          // The damage roll was a critical hit
+    pub mitigated: u16,          // Damage absorbed by armor
+}
+
+/// JSON-compatible damage reflection payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReflectJson {
+    pub character_id: u8, // Character that took the reflected damage
+    pub reflector_id: u8, // Character whose status effect reflected it
+    pub amount: u16,
+}
+
+/// JSON-compatible energy drain payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EnergyJson {
+    pub character_id: u8,       // Character that lost the energy
+    pub drainer_id: Option<u8>, // Character responsible for the drain, if any
+    pub amount: u8,             // Energy removed from the character
+    pub stolen: u8,             // Part of the amount the drainer gained
+}
+
+/// JSON-compatible dash step payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DashJson {
+    pub character_id: u8,
+    pub from: [[i16; 2]; 2], // Position before the step [[x_num, x_den], [y_num, y_den]]
+    pub to: [[i16; 2]; 2],   // Position after the step
+    pub frames_left: u8,     // Dash frames still to come (0 = the dash ended)
+    pub blocked: bool,       // Solid tiles stopped the step short
+}
+
+/// JSON-compatible grapple attach/release payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GrappleJson {
+    pub character_id: u8,              // Character being pulled
+    pub source_id: u8,                 // Owner of the grapple spawn
+    pub anchor: Option<[[i16; 2]; 2]>, // Point pulled toward (grapple_attached only)
+    pub arrived: Option<bool>, // Reached the anchor rather than broke (grapple_released only)
+}
+
+/// JSON-compatible frame work budget deferral payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct DeferredWorkJson {
+    pub remaining: u8, // Spawns whose interception checks moved to the next frame
+    pub work: u32,     // Script steps plus collision checks spent this frame
+}
+
+/// JSON-compatible spawn creation payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct SpawnCreatedJson {
+    pub spawn_instance_id: u8,
+    pub spawn_id: u8,
+    pub owner_id: Option<u8>, // Owning character, if any
+}
+
+/// JSON-compatible status effect application/expiry payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StatusEventJson {
+    pub character_id: u8,
+    pub status_effect_id: usize, // Status effect definition id
+    pub instance_id: u8,
+    pub stacks: Option<u8>, // Stack count after the application (status_applied only)
+    pub refreshed: Option<bool>, // Stacked or refreshed an existing instance (status_applied only)
+}
+
+/// JSON-compatible action execution payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ActionEventJson {
+    pub character_id: u8,
+    pub action_id: usize,
+}
+
+/// JSON-compatible character revival payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RevivalJson {
+    pub character_id: u8, // Character back at full health and energy
+}
+
+/// JSON-compatible entity removal payload
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct RemovalJson {
+    pub entity_type: u8,      // 1=Character, 2=Spawn
+    pub entity_id: u8,        // Character id or spawn instance id
+    pub spawn_id: Option<u8>, // Spawn definition id (spawns only)
+    pub reason: u8,           // 0=Expired, 1=Killed, 2=OutOfBounds, 3=Absorbed
+    pub by: Option<u8>,       // Killing character id or absorbing spawn instance id
+}
+
+impl GameEventJson {
+    /// Convert from game engine GameEvent to JSON-compatible representation
+    pub fn from_game_event(event: &robot_masters_engine::event::GameEvent) -> Self {
+        use robot_masters_engine::event::{camera_focus, GameEventKind};
+
+        match &event.kind {
+            GameEventKind::CameraHint(hint) => Self {
+                frame: event.frame,
+                event_type: "camera_hint".to_string(),
+                camera_hint: Some(CameraHintJson {
+                    focus_type: hint.focus_type,
+                    focus_id: hint.focus_id,
+                    zoom: [hint.zoom.numer(), hint.zoom.denom()],
+                    shake: hint.shake,
+                }),
+                combat_state: None,
+                removal: None,
+                hit: None,
+                reflect: None,
+                energy: None,
+                dash: None,
+                grapple: None,
+                deferred: None,
+                spawn: None,
+                status: None,
+                action: None,
+                revival: None,
+            },
+            GameEventKind::CombatStateChanged {
+                character_id,
+                in_combat,
+            } => Self {
+                frame: event.frame,
+                event_type: "combat_state".to_string(),
+                camera_hint: None,
+                combat_state: Some(CombatStateJson {
+                    character_id: *character_id,
+                    in_combat: *in_combat,
+                }),
+                removal: None,
+                hit: None,
+                reflect: None,
+                energy: None,
+                dash: None,
+                grapple: None,
+                deferred: None,
+                spawn: None,
+                status: None,
+                action: None,
+                revival: None,
+            },
+            GameEventKind::SpawnRemoved {
+                spawn_instance_id,
+                spawn_id,
+                reason,
+            } => Self {
+                frame: event.frame,
+                event_type: "spawn_removed".to_string(),
+                camera_hint: None,
+                combat_state: None,
+                removal: Some(RemovalJson {
+                    entity_type: camera_focus::SPAWN,
+                    entity_id: *spawn_instance_id,
+                    spawn_id: Some(*spawn_id),
+                    reason: reason.code(),
+                    by: reason.by(),
+                }),
+                hit: None,
+                reflect: None,
+                energy: None,
+                dash: None,
+                grapple: None,
+                deferred: None,
+                spawn: None,
+                status: None,
+                action: None,
+                revival: None,
+            },
+            GameEventKind::CharacterHit {
+                character_id,
+                spawn_instance_id,
+                attacker_id,
+                amount,
+                element,
+                critical,
+                mitigated,
+            } => Self {
+                frame: event.frame,
+                event_type: "character_hit".to_string(),
+                camera_hint: None,
+                combat_state: None,
+                removal: None,
+                hit: Some(HitJson {
+                    character_id: *character_id,
+                    spawn_instance_id: *spawn_instance_id,
+                    attacker_id: *attacker_id,
+                    amount: *amount,
+                    element: *element as u8,
+                    critical: *critical,
+                    mitigated: *mitigated,
+                }),
+                reflect: None,
+                energy: None,
+                dash: None,
+                grapple: None,
+                deferred: None,
+                spawn: None,
+                status: None,
+                action: None,
+                revival: None,
+            },
+            GameEventKind::DamageReflected {
+                character_id,
+                reflector_id,
+                amount,
+            } => Self {
+                frame: event.frame,
+                event_type: "damage_reflected".to_string(),
+                camera_hint: None,
+                combat_state: None,
+                removal: None,
+                hit: None,
+                reflect: Some(ReflectJson {
+                    character_id: *character_id,
+                    reflector_id: *reflector_id,
+                    amount: *amount,
+                }),
+                energy: None,
+                dash: None,
+                grapple: None,
+                deferred: None,
+                spawn: None,
+                status: None,
+                action: None,
+                revival: None,
+            },
+            GameEventKind::EnergyDrained {
+                character_id,
+                drainer_id,
+                amount,
+                stolen,
+            } => Self {
+                frame: event.frame,
+                event_type: "energy_drained".to_string(),
+                camera_hint: None,
+                combat_state: None,
+                removal: None,
+                hit: None,
+                reflect: None,
+                energy: Some(EnergyJson {
+                    character_id: *character_id,
+                    drainer_id: *drainer_id,
+                    amount: *amount,
+                    stolen: *stolen,
+                }),
+                dash: None,
+                grapple: None,
+                deferred: None,
+                spawn: None,
+                status: None,
+                action: None,
+                revival: None,
+            },
+            GameEventKind::Dashed {
+                character_id,
+                from,
+                to,
+                frames_left,
+                blocked,
+            } => Self {
+                frame: event.frame,
+                event_type: "dash".to_string(),
+                camera_hint: None,
+                combat_state: None,
+                removal: None,
+                hit: None,
+                reflect: None,
+                energy: None,
+                dash: Some(DashJson {
+                    character_id: *character_id,
+                    from: [
+                        [from.0.numer(), from.0.denom()],
+                        [from.1.numer(), from.1.denom()],
+                    ],
+                    to: [[to.0.numer(), to.0.denom()], [to.1.numer(), to.1.denom()]],
+                    frames_left: *frames_left,
+                    blocked: *blocked,
+                }),
+                grapple: None,
+                deferred: None,
+                spawn: None,
+                status: None,
+                action: None,
+                revival: None,
+            },
+            GameEventKind::GrappleAttached {
+                character_id,
+                source_id,
+                anchor,
+            } => Self {
+                frame: event.frame,
+                event_type: "grapple_attached".to_string(),
+                camera_hint: None,
+                combat_state: None,
+                removal: None,
+                hit: None,
+                reflect: None,
+                energy: None,
+                dash: None,
+                grapple: Some(GrappleJson {
+                    character_id: *character_id,
+                    source_id: *source_id,
+                    anchor: Some([
+                        [anchor.0.numer(), anchor.0.denom()],
+                        [anchor.1.numer(), anchor.1.denom()],
+                    ]),
+                    arrived: None,
+                }),
+                deferred: None,
+                spawn: None,
+                status: None,
+                action: None,
+                revival: None,
+            },
+            GameEventKind::GrappleReleased {
+                character_id,
+                source_id,
+                arrived,
+            } => Self {
+                frame: event.frame,
+                event_type: "grapple_released".to_string(),
+                camera_hint: None,
+                combat_state: None,
+                removal: None,
+                hit: None,
+                reflect: None,
+                energy: None,
+                dash: None,
+                grapple: Some(GrappleJson {
+                    character_id: *character_id,
+                    source_id: *source_id,
+                    anchor: None,
+                    arrived: Some(*arrived),
+                }),
+                deferred: None,
+                spawn: None,
+                status: None,
+                action: None,
+                revival: None,
+            },
+            GameEventKind::CharacterDefeated {
+                character_id,
+                reason,
+            } => Self {
+                frame: event.frame,
+                event_type: "character_defeated".to_string(),
+                camera_hint: None,
+                combat_state: None,
+                removal: Some(RemovalJson {
+                    entity_type: camera_focus::CHARACTER,
+                    entity_id: *character_id,
+                    spawn_id: None,
+                    reason: reason.code(),
+                    by: reason.by(),
+                }),
+                hit: None,
+                reflect: None,
+                energy: None,
+                dash: None,
+                grapple: None,
+                deferred: None,
+                spawn: None,
+                status: None,
+                action: None,
+                revival: None,
+            },
+            GameEventKind::SpawnCollisionsDeferred { remaining, work } => Self {
+                frame: event.frame,
+                event_type: "spawn_collisions_deferred".to_string(),
+                camera_hint: None,
+                combat_state: None,
+                removal: None,
+                hit: None,
+                reflect: None,
+                energy: None,
+                dash: None,
+                grapple: None,
+                deferred: Some(DeferredWorkJson {
+                    remaining: *remaining,
+                    work: *work,
+                }),
+                spawn: None,
+                status: None,
+                action: None,
+                revival: None,
+            },
+            GameEventKind::SpawnCreated {
+                spawn_instance_id,
+                spawn_id,
+                owner_id,
+            } => Self {
+                spawn: Some(SpawnCreatedJson {
+                    spawn_instance_id: *spawn_instance_id,
+                    spawn_id: *spawn_id,
+                    owner_id: *owner_id,
+                }),
+                ..Self::empty(event.frame, "spawn_created")
+            },
+            GameEventKind::StatusApplied {
+                character_id,
+                status_effect_id,
+                instance_id,
+                stacks,
+                refreshed,
+            } => Self {
+                status: Some(StatusEventJson {
+                    character_id: *character_id,
+                    status_effect_id: *status_effect_id,
+                    instance_id: *instance_id,
+                    stacks: Some(*stacks),
+                    refreshed: Some(*refreshed),
+                }),
+                ..Self::empty(event.frame, "status_applied")
+            },
+            GameEventKind::StatusExpired {
+                character_id,
+                status_effect_id,
+                instance_id,
+            } => Self {
+                status: Some(StatusEventJson {
+                    character_id: *character_id,
+                    status_effect_id: *status_effect_id,
+                    instance_id: *instance_id,
+                    stacks: None,
+                    refreshed: None,
+                }),
+                ..Self::empty(event.frame, "status_expired")
+            },
+            GameEventKind::ActionExecuted {
+                character_id,
+                action_id,
+            } => Self {
+                action: Some(ActionEventJson {
+                    character_id: *character_id,
+                    action_id: *action_id,
+                }),
+                ..Self::empty(event.frame, "action_executed")
+            },
+            GameEventKind::CharacterRevived { character_id } => Self {
+                revival: Some(RevivalJson {
+                    character_id: *character_id,
+                }),
+                ..Self::empty(event.frame, "character_revived")
+            },
+        }
+    }
+
+    /// Event of the given type without a payload, for filling in one payload field
+    fn empty(frame: u32, event_type: &str) -> Self {
+        Self {
+            frame,
+            event_type: event_type.to_string(),
+            camera_hint: None,
+            combat_state: None,
+            removal: None,
+            hit: None,
+            reflect: None,
+            energy: None,
+            dash: None,
+            grapple: None,
+            deferred: None,
+            spawn: None,
+            status: None,
+            action: None,
+            revival: None,
+        }
+    }
+}
+
+/// JSON-compatible result of running a single action in a sandbox (`test_action`)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ActionTestResultJson {
+    pub script_error: Option<String>, // Script error raised by the forced action, if any
+    pub frames_advanced: u16, // Frames simulated after the action (stops early if the match ends)
+    pub state: GameStateJson, // Sandbox state after the last frame
+    pub events: Vec<GameEventJson>, // Events from the action and every simulated frame, in order
+}
+
+/// JSON-compatible match outcome returned by `run_to_end`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MatchSummaryJson {
+    pub frames: u32,
+    pub ended: bool,
+    pub winner_group: Option<u8>, // null when nobody survived or surviving groups tie on health
+    pub survivors: Vec<u8>,       // Ids of characters with health left
+    pub characters: Vec<CharacterSummaryJson>,
+    pub spawns_created: u32, // Spawn instances created during the match
+    pub state_hash: u32,     // Final state hash (e.g. for roll_rewards)
+}
+
+/// JSON-compatible match result returned by `get_game_result_json`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct MatchResultJson {
+    pub frame: u32,
+    pub condition: u8, // 0=Timeout, 1=LastTeamStanding, 2=KillTarget, 3=HealthPercent, 4=Survival
+    pub target: u8,    // Kill target or surviving group (0 when unused)
+    pub ended: bool,
+    pub reason: Option<u8>, // 0=FrameLimit, 1=LastTeamStanding, 2=KillTarget, 3=Eliminated (null while playing)
+    pub winner_group: Option<u8>, // Current leader while playing; null on a draw
+    pub tie_break: Option<u8>, // Tie-break rule that decided the winner (null when none was needed)
+    pub standings: Vec<GroupStandingJson>,
+}
+
+/// JSON-compatible standing of one group, also returned by `get_team_stats_json`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupStandingJson {
+    pub group: u8,
+    pub survivors: u8,
+    #[serde(default)]
+    pub respawning: u8, // Defeated characters waiting to revive
+    pub health: u32,
+    pub health_cap: u32,
+    pub kills: u16,
+    pub damage_dealt: u32,
+    pub damage_taken: u32,
+}
+
+impl From<&robot_masters_engine::victory::GroupStanding> for GroupStandingJson {
+    fn from(standing: &robot_masters_engine::victory::GroupStanding) -> Self {
+        Self {
+            group: standing.group,
+            survivors: standing.survivors,
+            respawning: standing.respawning,
+            health: standing.health,
+            health_cap: standing.health_cap,
+            kills: standing.kills,
+            damage_dealt: standing.damage_dealt,
+            damage_taken: standing.damage_taken,
+        }
+    }
+}
+
+/// JSON-compatible heuristic standing of one group, returned by `get_evaluation_json`
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct GroupEvaluationJson {
+    pub group: u8,
+    pub health: u16,     // Per mille of the group's health cap left
+    pub position: u16,   // Per mille closeness of the survivors to the arena center
+    pub spawns: u16,     // Per mille share of the live spawns owned by characters
+    pub score: u16,      // Weighted per mille score
+    pub win_chance: u16, // Per mille share of all scores
+}
+
+impl From<&robot_masters_engine::evaluation::GroupEvaluation> for GroupEvaluationJson {
+    fn from(evaluation: &robot_masters_engine::evaluation::GroupEvaluation) -> Self {
+        Self {
+            group: evaluation.group,
+            health: evaluation.health,
+            position: evaluation.position,
+            spawns: evaluation.spawns,
+            score: evaluation.score,
+            win_chance: evaluation.win_chance,
+        }
+    }
+}
+
+impl From<&robot_masters_engine::victory::MatchResult> for MatchResultJson {
+    fn from(result: &robot_masters_engine::victory::MatchResult) -> Self {
+        Self {
+            frame: result.frame,
+            condition: result.condition.mode(),
+            target: result.condition.target(),
+            ended: result.ended,
+            reason: result.reason.map(|reason| reason as u8),
+            winner_group: result.winner_group,
+            tie_break: result.tie_break.map(|tie_break| tie_break as u8),
+            standings: result
+                .standings
+                .iter()
+                .map(GroupStandingJson::from)
+                .collect(),
+        }
+    }
+}
+
+/// JSON-compatible final standing of one character
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CharacterSummaryJson {
+    pub id: u8,
+    pub group: u8,
+    pub health: u16,
+    pub energy: u8,
+    pub damage_dealt: u16,
+    pub damage_taken: u16,
+}
+
+impl From<&robot_masters_engine::summary::MatchSummary> for MatchSummaryJson {
+    fn from(summary: &robot_masters_engine::summary::MatchSummary) -> Self {
+        Self {
+            frames: summary.frames,
+            ended: summary.ended,
+            winner_group: summary.winner_group,
+            survivors: summary.survivors.clone(),
+            characters: summary
+                .characters
+                .iter()
+                .map(|character| CharacterSummaryJson {
+                    id: character.id,
+                    group: character.group,
+                    health: character.health,
+                    energy: character.energy,
+                    damage_dealt: character.damage_dealt,
+                    damage_taken: character.damage_taken,
+                })
+                .collect(),
+            spawns_created: summary.spawns_created,
+            state_hash: summary.state_hash,
+        }
+    }
+}
+
+/// JSON-compatible per-section state checksums (`get_state_checksum`)
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+pub struct StateChecksumJson {
+    pub frame: u32,
+    pub state: u32,           // Same value as get_state_hash
+    pub rng: u32,             // Seed and RNG stream positions
+    pub characters: Vec<u32>, // One checksum per character, in character order
+    pub spawns: u32,          // All spawn instances
+    pub status_effects: u32,  // All status effect instances
+}
+
+impl From<&robot_masters_engine::state::StateChecksum> for StateChecksumJson {
+    fn from(checksum: &robot_masters_engine::state::StateChecksum) -> Self {
+        Self {
+            frame: checksum.frame,
+            state: checksum.state,
+            rng: checksum.rng,
+            characters: checksum.characters.clone(),
+            spawns: checksum.spawns,
+            status_effects: checksum.status_effects,
+        }
+    }
+}
+
+/// JSON-compatible result of re-simulating an imported replay
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReplayVerificationJson {
+    pub verified: bool,             // Every recorded checksum was reproduced
+    pub frame_count: u32,           // Frames recorded in the replay
+    pub frames_verified: u32,       // Frames whose checksum matched before the first desync
+    pub desync_frame: Option<u32>,  // Frame counter after the first diverging frame
+    pub expected_hash: Option<u32>, // Recorded state hash at the desync
+    pub actual_hash: Option<u32>,   // Re-simulated state hash at the desync
+}
+
+impl ReplayVerificationJson {
+    pub fn from_outcome(
+        replay: &robot_masters_engine::replay::Replay,
+        outcome: &robot_masters_engine::replay::ReplayOutcome,
+    ) -> Self {
+        Self {
+            verified: outcome.is_verified(),
+            frame_count: replay.frame_count(),
+            frames_verified: outcome.frames_verified,
+            desync_frame: outcome.desync.map(|desync| desync.frame),
+            expected_hash: outcome.desync.map(|desync| desync.expected),
+            actual_hash: outcome.desync.map(|desync| desync.actual),
+        }
+    }
+}
+
+/// JSON-compatible spectator stream header
+#[derive(Serialize, Deserialize, Debug, Clone, Copy)]
+pub struct StreamHeaderJson {
+    pub version: u8,            // Stream layout version of the producer
+    pub keyframe_interval: u16, // Frames between periodic keyframes (0 = only forced ones)
+    pub start_frame: u32,       // Frame counter of the opening keyframe
+}
+
+/// JSON-compatible spectator stream keyframe
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StreamKeyframeJson {
+    pub frame: u32, // Frame counter of the snapshot; events from this frame on follow it
+    pub state_hash: u32, // State hash of the snapshot, for checking a restore
+    pub state: Vec<u8>, // Snapshot bytes, loadable with load_state
+}
+
+/// JSON-compatible spectator stream chunk
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct StreamChunkJson {
+    pub sequence: u32,                    // 0 for the opening chunk, then counting up
+    pub header: Option<StreamHeaderJson>, // Only on the opening chunk
+    pub keyframes: Vec<StreamKeyframeJson>,
+    pub events: Vec<GameEventJson>,
+}
+
+impl From<&robot_masters_engine::stream::StreamChunk> for StreamChunkJson {
+    fn from(chunk: &robot_masters_engine::stream::StreamChunk) -> Self {
+        Self {
+            sequence: chunk.sequence,
+            header: chunk.header.map(|header| StreamHeaderJson {
+                version: header.version,
+                keyframe_interval: header.keyframe_interval,
+                start_frame: header.start_frame,
+            }),
+            keyframes: chunk
+                .keyframes
+                .iter()
+                .map(|keyframe| StreamKeyframeJson {
+                    frame: keyframe.frame,
+                    state_hash: keyframe.state_hash,
+                    state: keyframe.state.clone(),
+                })
+                .collect(),
+            events: chunk
+                .events
+                .iter()
+                .map(GameEventJson::from_game_event)
+                .collect(),
+        }
+    }
+}
+
+/// JSON-compatible script trace step (engine state after one instruction)
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ScriptTraceStepJson {
+    pub pos: u16,             // Offset of the instruction in the script
+    pub depth: u8,            // CALLs in progress when the instruction ran
+    pub opcode: u8,           // Operator executed
+    pub operator: String,     // Operator name (empty for unknown opcodes)
+    pub vars: [u8; 8],        // Byte variables after the instruction
+    pub fixed: [[i16; 2]; 4], // Fixed-point variables after the instruction
+}
+
+impl From<&robot_masters_engine::script::ScriptTraceStep> for ScriptTraceStepJson {
+    fn from(step: &robot_masters_engine::script::ScriptTraceStep) -> Self {
+        use robot_masters_engine::constants::operator_address;
+
+        Self {
+            pos: step.pos,
+            depth: step.depth,
+            opcode: step.opcode,
+            operator: operator_address::ALL
+                .iter()
+                .find(|(_, opcode)| *opcode == step.opcode)
+                .map_or_else(String::new, |(name, _)| name.to_string()),
+            vars: step.vars,
+            fixed: step.fixed.map(|value| convert::fixed_frac::to_json(&value)),
+        }
+    }
+}
+
+/// JSON-compatible result of evaluating a condition against a captured state
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ConditionEvaluationJson {
+    pub result: bool,                    // Condition passed (non-zero exit value)
+    pub exit_value: u8,                  // Raw exit value of the condition script
+    pub script_error: Option<String>,    // Script error raised during evaluation, if any
+    pub trace: Vec<ScriptTraceStepJson>, // Executed instructions, in order
+}
+
+/// JSON-compatible description of a single frame phase
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PipelinePhaseJson {
+    pub index: u8,            // Position in the frame pipeline
+    pub name: String,         // Phase identifier, e.g. "behaviors"
+    pub entity_order: String, // Entity iteration order, e.g. "characters_by_index"
+    pub runs_scripts: bool,   // Condition, action, spawn or status effect scripts run here
+}
+
+/// JSON-compatible export of the per-frame processing pipeline
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct PipelineInfoJson {
+    pub phases: Vec<PipelinePhaseJson>, // In execution order
+}
+
+impl PipelineInfoJson {
+    /// Describe the pipeline the engine executes every frame
+    pub fn from_engine() -> Self {
+        use robot_masters_engine::pipeline::FRAME_PIPELINE;
+
+        Self {
+            phases: FRAME_PIPELINE
+                .iter()
+                .enumerate()
+                .map(|(index, phase)| PipelinePhaseJson {
+                    index: index as u8,
+                    name: phase.name().to_string(),
+                    entity_order: phase.entity_order().name().to_string(),
+                    runs_scripts: phase.runs_scripts(),
+                })
+                .collect(),
+        }
+    }
+}
+
+/// JSON-compatible export of engine constants for frontend tooling
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct EngineConstantsJson {
+    pub frames_per_second: u16,
+    pub game_duration_seconds: u16,
+    pub max_frames: u32,
+    pub default_damage_window: u16,
+    pub default_event_history: u16,
+    pub screen_width: u16,
+    pub screen_height: u16,
+    pub tile_size: u8,
+    pub tilemap_width: usize,
+    pub tilemap_height: usize,
+    pub element_count: usize,
+    pub max_characters: usize,
+    pub max_spawns: usize,
+    pub max_status_effects: usize,
+    pub max_script_length: usize,
+    pub max_script_variables: usize,
+    pub max_script_stack: usize,
+    pub opcode_version: u8, // Current opcode table version (configs default to it)
+    pub max_repeat_count: u8, // Most iterations a REPEAT runs
+    pub default_script_step_budget: u16, // Instructions per script run unless configured
+    pub energy_per_charge: u8, // Overflow energy converted into one charge stack
+    pub max_charge_stacks: u8, // Most charge stacks a character holds
+    pub operators: BTreeMap<String, u8>, // Operator name -> opcode
+    pub properties: BTreeMap<String, u8>, // Property name -> address
+    pub hit_results: BTreeMap<String, u8>, // Spawn collision script exit name -> value
+    pub teleport_modes: BTreeMap<String, u8>, // TELEPORT operator mode name -> value
+    pub dash_directions: BTreeMap<String, u8>, // DASH operator direction name -> value
+    pub target_queries: BTreeMap<String, u8>, // FIND_TARGET/ACQUIRE_TARGET query name -> value
+    pub grapple_modes: BTreeMap<String, u8>, // Spawn definition grapple mode name -> value
+    pub path_steps: BTreeMap<String, u8>, // PATH_DIRECTION_TO move name -> value
+    pub energy_overflow_modes: BTreeMap<String, u8>, // Character overflow mode name -> value
+}
+
+impl EngineConstantsJson {
+    /// Collect constants straight from the engine so exports never drift from it
+    pub fn from_engine() -> Self {
+        use robot_masters_engine::{
+            constants::{
+                dash_direction, energy_overflow, grapple_mode, hit_result, operator_address,
+                path_step, property_address, target_query, teleport_mode,
+            },
+            core,
+        };
+
+        let table = |entries: &[(&str, u8)]| {
+            entries
+                .iter()
+                .map(|&(name, value)| (name.to_string(), value))
+                .collect()
+        };
+
+        Self {
+            frames_per_second: core::FRAMES_PER_SECOND,
+            game_duration_seconds: core::GAME_DURATION_SECONDS,
+            max_frames: core::MAX_FRAMES,
+            default_damage_window: core::DEFAULT_DAMAGE_WINDOW,
+            default_event_history: core::DEFAULT_EVENT_HISTORY,
+            screen_width: core::SCREEN_WIDTH,
+            screen_height: core::SCREEN_HEIGHT,
+            tile_size: core::TILE_SIZE,
+            tilemap_width: core::TILEMAP_WIDTH,
+            tilemap_height: core::TILEMAP_HEIGHT,
+            element_count: core::ELEMENT_COUNT,
+            max_characters: core::MAX_CHARACTERS,
+            max_spawns: core::MAX_SPAWNS,
+            max_status_effects: core::MAX_STATUS_EFFECTS,
+            max_script_length: core::MAX_SCRIPT_LENGTH,
+            max_script_variables: core::MAX_SCRIPT_VARIABLES,
+            max_script_stack: core::MAX_SCRIPT_STACK,
+            opcode_version: core::OPCODE_VERSION,
+            max_repeat_count: core::MAX_REPEAT_COUNT,
+            default_script_step_budget: core::DEFAULT_SCRIPT_STEP_BUDGET,
+            energy_per_charge: core::ENERGY_PER_CHARGE,
+            max_charge_stacks: core::MAX_CHARGE_STACKS,
+            operators: table(operator_address::ALL),
+            properties: table(property_address::ALL),
+            hit_results: table(hit_result::ALL),
+            teleport_modes: table(teleport_mode::ALL),
+            dash_directions: table(dash_direction::ALL),
+            target_queries: table(target_query::ALL),
+            grapple_modes: table(grapple_mode::ALL),
+            path_steps: table(path_step::ALL),
+            energy_overflow_modes: table(energy_overflow::ALL),
+        }
+    }
+}
@@ -6,6 +6,7 @@
  * building script bytecode when working with the game engine from JavaScript/TypeScript.
  *
  * IMPORTANT: These values must stay in sync with the Rust constants!
+ * At runtime, prefer GameWrapper.getEngineConstantsJson(), which is generated from the engine.
  */
 
 /**
@@ -23,6 +24,9 @@ export const OperatorAddress = {
   // ===== CONTROL FLOW OPERATORS (10-14) =====
   SKIP: 10,
   GOTO: 11,
+  CALL: 12,
+  RETURN: 13,
+  REPEAT: 14,
 
   // ===== PROPERTY OPERATIONS (15-16) =====
   READ_PROP: 15,
@@ -42,6 +46,12 @@ export const OperatorAddress = {
   DIV: 33,
   NEGATE: 34,
 
+  // ===== FIXED-POINT MATH (35-39) =====
+  SIN: 35,
+  COS: 36,
+  ATAN2: 37,
+  SQRT: 38,
+
   // ===== BYTE ARITHMETIC (40-45) =====
   ADD_BYTE: 40,
   SUB_BYTE: 41,
@@ -61,9 +71,11 @@ export const OperatorAddress = {
   OR: 61,
   AND: 62,
 
-  // ===== UTILITY OPERATIONS (70-71) =====
+  // ===== UTILITY OPERATIONS (70-73) =====
   MIN: 70,
   MAX: 71,
+  CLAMP_FIXED: 72,
+  LERP: 73,
 
   // ===== GAME ACTIONS (80-85) =====
   LOCK_ACTION: 80,
@@ -73,6 +85,11 @@ export const OperatorAddress = {
   SPAWN: 84,
   SPAWN_WITH_VARS: 85,
 
+  // ===== SPAWN MANAGEMENT (86-89) =====
+  COUNT_SPAWNS: 86,
+  DESPAWN_MINE: 87,
+  DESPAWN_BY_ID: 88,
+
   // ===== DEBUG OPERATIONS (90-91) =====
   LOG_VARIABLE: 90,
 
@@ -92,16 +109,54 @@ export const OperatorAddress = {
   WRITE_CHARACTER_PROPERTY: 105,
   READ_SPAWN_PROPERTY: 106,
   WRITE_SPAWN_PROPERTY: 107,
+
+  // ===== ENERGY OPERATIONS (108-109) =====
+  DRAIN_ENERGY: 108,
+  STEAL_ENERGY: 109,
+
+  // ===== PRESENTATION OPERATIONS (110-114) =====
+  CAMERA_HINT: 110,
+
+  // ===== MOVEMENT OPERATIONS (115-119) =====
+  TELEPORT: 115,
+  DASH: 116,
+  WALL_JUMP: 117,
+
+  // ===== TARGETING OPERATIONS (120-124) =====
+  DISTANCE_TO: 120,
+  DIRECTION_TO: 121,
+  FIND_TARGET: 122,
+  ACQUIRE_TARGET: 123,
+  HAS_LINE_OF_SIGHT: 124,
+
+  // ===== CONSTANT OPERATIONS (125-127) =====
+  LOAD_CONST: 125,
+  LOAD_CONST_FIXED: 126,
+  ASSIGN_FIXED_RAW: 127,
+
+  // ===== NAVIGATION OPERATIONS (130-134) =====
+  PATH_DIRECTION_TO: 130,
+
+  // ===== COMBAT OPERATIONS (135-139) =====
+  ROLL_DAMAGE: 135,
+
+  // ===== STATUS EFFECT OPERATIONS (140-144) =====
+  APPLY_STATUS_EFFECT: 140,
 } as const
 
 /**
  * Property address constants for script property access
  */
 export const PropertyAddress = {
-  // ===== GAME STATE PROPERTIES (0x01-0x03) =====
+  // ===== GAME STATE PROPERTIES (0x01-0x08) =====
   GAME_SEED: 0x01,
   GAME_FRAME: 0x02,
   GAME_GRAVITY: 0x03,
+  GAME_GRAVITY_X: 0x04,
+  GAME_ARENA_WIDTH: 0x05,
+  GAME_ARENA_HEIGHT: 0x06,
+  GAME_ARENA_CENTER_X: 0x07,
+  GAME_ARENA_CENTER_Y: 0x08,
 
   // ===== CHARACTER CORE PROPERTIES (0x10-0x1F) =====
   CHARACTER_ID: 0x10,
@@ -148,6 +203,25 @@ export const PropertyAddress = {
   CHARACTER_ARMOR_ACID: 0x31,
   CHARACTER_ARMOR_VIRUS: 0x32,
 
+  // ===== CHARACTER MATCH STATISTICS (0x33-0x36, read-only) =====
+  CHARACTER_DAMAGE_DEALT: 0x33,
+  CHARACTER_DAMAGE_TAKEN: 0x34,
+  CHARACTER_FRAMES_SINCE_HIT: 0x35,
+  CHARACTER_FRAMES_SINCE_DAMAGED: 0x36,
+
+  // ===== CHARACTER COMBAT STATE (0x37-0x38, read-only) =====
+  CHARACTER_RECENT_DAMAGE: 0x37,
+  CHARACTER_IN_COMBAT: 0x38,
+
+  // ===== CHARACTER DETECTION (0x39-0x3A) =====
+  CHARACTER_DETECTION_RADIUS: 0x39,
+  CHARACTER_DETECTED_ENEMIES: 0x3a,
+  CHARACTER_GROUND_TILE: 0x3b,
+  CHARACTER_GROUND_TILE_X: 0x3c,
+  CHARACTER_GROUND_TILE_Y: 0x3d,
+  CHARACTER_FACING_TILE: 0x3e,
+  CHARACTER_HEALTH_BYTE: 0x3f,
+
   // ===== ENTITY CORE PROPERTIES (0x40-0x44) =====
   ENTITY_DIR_HORIZONTAL: 0x40,
   ENTITY_DIR_VERTICAL: 0x41,
@@ -182,6 +256,7 @@ export const PropertyAddress = {
   SPAWN_INST_ROTATION: 0x69,
   SPAWN_INST_LIFE_SPAN: 0x6a,
   SPAWN_INST_ELEMENT: 0x6b,
+  SPAWN_INST_HEALTH_BYTE: 0x6f,
 
   // ===== SPAWN INSTANCE RUNTIME VARIABLES (0x70-0x77) =====
   SPAWN_INST_VAR0: 0x70,
@@ -193,7 +268,13 @@ export const PropertyAddress = {
   SPAWN_INST_FIXED2: 0x76,
   SPAWN_INST_FIXED3: 0x77,
 
-  // ===== ACTION DEFINITION PROPERTIES (0x80-0x89) =====
+  // ===== SPAWN DEFINITION STATIC VARIABLES (0x78-0x7B, writable) =====
+  SPAWN_DEF_STATIC0: 0x78,
+  SPAWN_DEF_STATIC1: 0x79,
+  SPAWN_DEF_STATIC2: 0x7a,
+  SPAWN_DEF_STATIC3: 0x7b,
+
+  // ===== ACTION DEFINITION PROPERTIES (0x80-0x8E) =====
   ACTION_DEF_ENERGY_COST: 0x80,
   ACTION_DEF_COOLDOWN: 0x81,
   ACTION_DEF_ARG0: 0x82,
@@ -204,6 +285,11 @@ export const PropertyAddress = {
   ACTION_DEF_ARG5: 0x87,
   ACTION_DEF_ARG6: 0x88,
   ACTION_DEF_ARG7: 0x89,
+  ACTION_ENERGY_REQUIRED: 0x8a,
+  ACTION_DEF_STATIC0: 0x8b,
+  ACTION_DEF_STATIC1: 0x8c,
+  ACTION_DEF_STATIC2: 0x8d,
+  ACTION_DEF_STATIC3: 0x8e,
 
   // ===== ACTION INSTANCE PROPERTIES (0x90-0x99) =====
   ACTION_INST_VAR0: 0x90,
@@ -217,7 +303,7 @@ export const PropertyAddress = {
   ACTION_INST_COOLDOWN: 0x98,
   ACTION_INST_LAST_USED_FRAME: 0x99,
 
-  // ===== CONDITION DEFINITION PROPERTIES (0xA0-0xA9) =====
+  // ===== CONDITION DEFINITION PROPERTIES (0xA0-0xAD) =====
   CONDITION_DEF_ID: 0xa0,
   CONDITION_DEF_ENERGY_MUL: 0xa1,
   CONDITION_DEF_ARG0: 0xa2,
@@ -228,6 +314,10 @@ export const PropertyAddress = {
   CONDITION_DEF_ARG5: 0xa7,
   CONDITION_DEF_ARG6: 0xa8,
   CONDITION_DEF_ARG7: 0xa9,
+  CONDITION_DEF_STATIC0: 0xaa,
+  CONDITION_DEF_STATIC1: 0xab,
+  CONDITION_DEF_STATIC2: 0xac,
+  CONDITION_DEF_STATIC3: 0xad,
 
   // ===== CONDITION INSTANCE PROPERTIES (0xB0-0xB7) =====
   CONDITION_INST_VAR0: 0xb0,
@@ -239,7 +329,7 @@ export const PropertyAddress = {
   CONDITION_INST_FIXED2: 0xb6,
   CONDITION_INST_FIXED3: 0xb7,
 
-  // ===== STATUS EFFECT DEFINITION PROPERTIES (0xC0-0xC6) =====
+  // ===== STATUS EFFECT DEFINITION PROPERTIES (0xC0-0xCB) =====
   STATUS_EFFECT_DEF_DURATION: 0xc0,
   STATUS_EFFECT_DEF_STACK_LIMIT: 0xc1,
   STATUS_EFFECT_DEF_RESET_ON_STACK: 0xc2,
@@ -247,6 +337,11 @@ export const PropertyAddress = {
   STATUS_EFFECT_DEF_ARG0: 0xc4,
   STATUS_EFFECT_DEF_ARG1: 0xc5,
   STATUS_EFFECT_DEF_ARG2: 0xc6,
+  STATUS_EFFECT_DEF_STACK_BEHAVIOR: 0xc7,
+  STATUS_EFFECT_DEF_STATIC0: 0xc8,
+  STATUS_EFFECT_DEF_STATIC1: 0xc9,
+  STATUS_EFFECT_DEF_STATIC2: 0xca,
+  STATUS_EFFECT_DEF_STATIC3: 0xcb,
 
   // ===== STATUS EFFECT INSTANCE PROPERTIES (0xD0-0xD9) =====
   STATUS_EFFECT_INST_VAR0: 0xd0,
@@ -259,6 +354,9 @@ export const PropertyAddress = {
   STATUS_EFFECT_INST_FIXED3: 0xd7,
   STATUS_EFFECT_INST_LIFE_SPAN: 0xd8,
   STATUS_EFFECT_INST_STACK_COUNT: 0xd9,
+
+  // ===== CHARACTER TERRAIN PROPERTIES (0xE0-0xEF, read-only) =====
+  CHARACTER_TERRAIN_CONTACT: 0xe0,
 } as const
 
 /**
@@ -276,6 +374,15 @@ export const Element = {
   VIRUS: 8,
 } as const
 
+/**
+ * Spawn collision script exit values when a spawn touches a character
+ */
+export const HitResult = {
+  CONSUME: 0, // Apply damage and remove the spawn (empty scripts exit with 0)
+  BOUNCE: 1, // Apply damage and reverse the spawn's velocity
+  PASS: 2, // No damage and no removal
+} as const
+
 /**
  * Helper functions for building script bytecode
  */